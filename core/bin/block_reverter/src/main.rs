@@ -1,7 +1,7 @@
 use std::path::PathBuf;
 
 use anyhow::Context as _;
-use clap::{Parser, Subcommand};
+use clap::{Args, Parser, Subcommand};
 use tokio::{
     fs,
     io::{self, AsyncReadExt},
@@ -79,27 +79,15 @@ enum Command {
     /// Rolls back internal database state to a previous L1 batch.
     #[command(name = "rollback-db")]
     RollbackDB {
-        /// L1 batch number used to roll back to.
-        #[arg(long)]
-        l1_batch_number: u32,
-        /// Flag that specifies if Postgres DB should be rolled back.
-        #[arg(long)]
-        rollback_postgres: bool,
-        /// Flag that specifies if RocksDB with tree should be rolled back.
-        #[arg(long)]
-        rollback_tree: bool,
-        /// Flag that specifies if RocksDB with state keeper cache should be rolled back.
-        #[arg(long)]
-        rollback_sk_cache: bool,
-        /// Flag that specifies if RocksDBs with vm runners' caches should be rolled back.
-        #[arg(long)]
-        rollback_vm_runners_cache: bool,
-        /// Flag that specifies if snapshot files in GCS should be rolled back.
-        #[arg(long, requires = "rollback_postgres")]
-        rollback_snapshots: bool,
-        /// Flag that allows to roll back already executed blocks. It's ultra dangerous and required only for fixing external nodes.
-        #[arg(long)]
-        allow_executed_block_reversion: bool,
+        #[command(flatten)]
+        args: RollbackArgs,
+    },
+    /// Prints a JSON report on changes that `rollback-db` with the same args would make, without changing anything.
+    /// Checks that all components to roll back are reachable.
+    #[command(name = "rollback-db-dry-run")]
+    RollbackDBDryRun {
+        #[command(flatten)]
+        args: RollbackArgs,
     },
 
     /// Clears failed L1 transactions.
@@ -107,6 +95,31 @@ enum Command {
     ClearFailedL1Transactions,
 }
 
+#[derive(Debug, Args)]
+struct RollbackArgs {
+    /// L1 batch number used to roll back to.
+    #[arg(long)]
+    l1_batch_number: u32,
+    /// Flag that specifies if Postgres DB should be rolled back.
+    #[arg(long)]
+    rollback_postgres: bool,
+    /// Flag that specifies if RocksDB with tree should be rolled back.
+    #[arg(long)]
+    rollback_tree: bool,
+    /// Flag that specifies if RocksDB with state keeper cache should be rolled back.
+    #[arg(long)]
+    rollback_sk_cache: bool,
+    /// Flag that specifies if RocksDBs with vm runners' caches should be rolled back.
+    #[arg(long)]
+    rollback_vm_runners_cache: bool,
+    /// Flag that specifies if snapshot files in GCS should be rolled back.
+    #[arg(long, requires = "rollback_postgres")]
+    rollback_snapshots: bool,
+    /// Flag that allows to roll back already executed blocks. It's ultra dangerous and required only for fixing external nodes.
+    #[arg(long)]
+    allow_executed_block_reversion: bool,
+}
+
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let opts = Cli::parse();
@@ -306,16 +319,8 @@ async fn main() -> anyhow::Result<()> {
                 )
                 .await?;
         }
-        Command::RollbackDB {
-            l1_batch_number,
-            rollback_postgres,
-            rollback_tree,
-            rollback_sk_cache,
-            rollback_vm_runners_cache,
-            rollback_snapshots,
-            allow_executed_block_reversion,
-        } => {
-            if !rollback_tree && rollback_postgres {
+        Command::RollbackDB { args } => {
+            if !args.rollback_tree && args.rollback_postgres {
                 println!("You want to roll back Postgres DB without rolling back tree.");
                 println!(
                     "If the tree is not yet rolled back to this L1 batch, then the only way \
@@ -330,7 +335,7 @@ async fn main() -> anyhow::Result<()> {
                 }
             }
 
-            if allow_executed_block_reversion {
+            if args.allow_executed_block_reversion {
                 println!("You want to roll back already executed blocks. It's impossible to restore them for the main node");
                 println!("Make sure you are doing it ONLY for external node");
                 println!("Are you sure? Print y/n");
@@ -340,64 +345,94 @@ async fn main() -> anyhow::Result<()> {
                 if input[0] != b'y' && input[0] != b'Y' {
                     std::process::exit(0);
                 }
-                block_reverter.allow_rolling_back_executed_batches();
             }
 
-            if rollback_postgres {
-                block_reverter.enable_rolling_back_postgres();
-                if rollback_snapshots {
-                    let object_store_config = SnapshotsObjectStoreConfig::from_env()
-                        .context("SnapshotsObjectStoreConfig::from_env()")?;
-                    block_reverter.enable_rolling_back_snapshot_objects(
-                        ObjectStoreFactory::new(object_store_config.0)
-                            .create_store()
-                            .await?,
-                    );
-                }
-            }
-            if rollback_tree {
-                block_reverter.enable_rolling_back_merkle_tree(db_config.merkle_tree.path);
-            }
-            if rollback_sk_cache {
-                block_reverter.add_rocksdb_storage_path_to_rollback(db_config.state_keeper_db_path);
-            }
+            let l1_batch_number = L1BatchNumber(args.l1_batch_number);
+            configure_rollback(
+                &mut block_reverter,
+                args,
+                db_config,
+                protective_reads_writer_config,
+                basic_witness_input_producer_config,
+            )
+            .await?;
+            block_reverter.roll_back(l1_batch_number).await?;
+        }
+        Command::RollbackDBDryRun { args } => {
+            let l1_batch_number = L1BatchNumber(args.l1_batch_number);
+            configure_rollback(
+                &mut block_reverter,
+                args,
+                db_config,
+                protective_reads_writer_config,
+                basic_witness_input_producer_config,
+            )
+            .await?;
+            let report = block_reverter.dry_run(l1_batch_number).await?;
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        }
+        Command::ClearFailedL1Transactions => {
+            block_reverter.clear_failed_l1_transactions().await?;
+        }
+    }
+    Ok(())
+}
 
-            if rollback_vm_runners_cache {
-                let cache_exists = fs::try_exists(&protective_reads_writer_config.db_path)
-                    .await
-                    .with_context(|| {
-                        format!(
-                            "cannot check whether storage cache path `{}` exists",
-                            protective_reads_writer_config.db_path
-                        )
-                    })?;
-                if cache_exists {
-                    block_reverter.add_rocksdb_storage_path_to_rollback(
-                        protective_reads_writer_config.db_path,
-                    );
-                }
+async fn configure_rollback(
+    block_reverter: &mut BlockReverter,
+    args: RollbackArgs,
+    db_config: DBConfig,
+    protective_reads_writer_config: ProtectiveReadsWriterConfig,
+    basic_witness_input_producer_config: BasicWitnessInputProducerConfig,
+) -> anyhow::Result<()> {
+    if args.allow_executed_block_reversion {
+        block_reverter.allow_rolling_back_executed_batches();
+    }
 
-                let cache_exists = fs::try_exists(&basic_witness_input_producer_config.db_path)
-                    .await
-                    .with_context(|| {
-                        format!(
-                            "cannot check whether storage cache path `{}` exists",
-                            basic_witness_input_producer_config.db_path
-                        )
-                    })?;
-                if cache_exists {
-                    block_reverter.add_rocksdb_storage_path_to_rollback(
-                        basic_witness_input_producer_config.db_path,
-                    );
-                }
-            }
+    if args.rollback_postgres {
+        block_reverter.enable_rolling_back_postgres();
+        if args.rollback_snapshots {
+            let object_store_config = SnapshotsObjectStoreConfig::from_env()
+                .context("SnapshotsObjectStoreConfig::from_env()")?;
+            block_reverter.enable_rolling_back_snapshot_objects(
+                ObjectStoreFactory::new(object_store_config.0)
+                    .create_store()
+                    .await?,
+            );
+        }
+    }
+    if args.rollback_tree {
+        block_reverter.enable_rolling_back_merkle_tree(db_config.merkle_tree.path);
+    }
+    if args.rollback_sk_cache {
+        block_reverter.add_rocksdb_storage_path_to_rollback(db_config.state_keeper_db_path);
+    }
 
+    if args.rollback_vm_runners_cache {
+        let cache_exists = fs::try_exists(&protective_reads_writer_config.db_path)
+            .await
+            .with_context(|| {
+                format!(
+                    "cannot check whether storage cache path `{}` exists",
+                    protective_reads_writer_config.db_path
+                )
+            })?;
+        if cache_exists {
             block_reverter
-                .roll_back(L1BatchNumber(l1_batch_number))
-                .await?;
+                .add_rocksdb_storage_path_to_rollback(protective_reads_writer_config.db_path);
         }
-        Command::ClearFailedL1Transactions => {
-            block_reverter.clear_failed_l1_transactions().await?;
+
+        let cache_exists = fs::try_exists(&basic_witness_input_producer_config.db_path)
+            .await
+            .with_context(|| {
+                format!(
+                    "cannot check whether storage cache path `{}` exists",
+                    basic_witness_input_producer_config.db_path
+                )
+            })?;
+        if cache_exists {
+            block_reverter
+                .add_rocksdb_storage_path_to_rollback(basic_witness_input_producer_config.db_path);
         }
     }
     Ok(())
@@ -1066,6 +1066,9 @@ pub(crate) struct ExperimentalENConfig {
     /// This is a temporary flag that will eventually be removed together with version 0 snapshot support.
     #[serde(default)]
     pub snapshots_recovery_drop_storage_key_preimages: bool,
+    /// Path to a local snapshot bundle directory (e.g., exported by the snapshot creator). If specified, the snapshot
+    /// is recovered from the bundle instead of the main node and the snapshot object store.
+    pub snapshots_recovery_bundle_path: Option<String>,
     /// Approximate chunk size (measured in the number of entries) to recover in a single iteration.
     /// Reasonable values are order of 100,000 (meaning an iteration takes several seconds).
     ///
@@ -1103,6 +1106,7 @@ impl ExperimentalENConfig {
             state_keeper_db_max_open_files: None,
             snapshots_recovery_l1_batch: None,
             snapshots_recovery_drop_storage_key_preimages: false,
+            snapshots_recovery_bundle_path: None,
             snapshots_recovery_tree_chunk_size: Self::default_snapshots_recovery_tree_chunk_size(),
             snapshots_recovery_tree_parallel_persistence_buffer: None,
             commitment_generator_max_parallelism: None,
@@ -1139,6 +1143,10 @@ impl ExperimentalENConfig {
                 .snapshot_recovery
                 .as_ref()
                 .map_or(false, |config| config.drop_storage_key_preimages),
+            snapshots_recovery_bundle_path: load_config!(
+                general_config.snapshot_recovery,
+                bundle_path
+            ),
             commitment_generator_max_parallelism: general_config
                 .commitment_generator
                 .as_ref()
@@ -1431,6 +1439,8 @@ impl From<&ExternalNodeConfig> for TxSenderConfig {
                 .unwrap(),
             gas_price_scale_factor: config.optional.gas_price_scale_factor,
             max_nonce_ahead: config.optional.max_nonce_ahead,
+            // Pending transactions are stored by the main node, so the limit is enforced there.
+            max_pending_txs_per_initiator: None,
             vm_execution_cache_misses_limit: config.optional.vm_execution_cache_misses_limit,
             // We set these values to the maximum since we don't know the actual values
             // and they will be enforced by the main node anyway.
@@ -505,6 +505,11 @@ impl ExternalNodeBuilder {
                         .experimental
                         .snapshots_recovery_drop_storage_key_preimages,
                     object_store_config: config.optional.snapshots_recovery_object_store.clone(),
+                    bundle_path: config
+                        .experimental
+                        .snapshots_recovery_bundle_path
+                        .clone()
+                        .map(Into::into),
                 });
         self.node.add_layer(ExternalNodeInitStrategyLayer {
             l2_chain_id: self.config.required.l2_chain_id,
@@ -0,0 +1,248 @@
+use std::{io::Read, path::PathBuf};
+
+use anyhow::Context as _;
+use clap::{Parser, Subcommand};
+use flate2::read::GzDecoder;
+use tokio::fs;
+use zksync_config::{
+    configs::{
+        da_client::DAClientConfig, secrets::DataAvailabilitySecrets, DatabaseSecrets,
+        GeneralConfig, ObservabilityConfig,
+    },
+    PostgresConfig,
+};
+use zksync_core_leftovers::temp_config_store::read_yaml_repr;
+use zksync_da_client::DataAvailabilityClient;
+use zksync_da_clients::create_da_client;
+use zksync_dal::{ConnectionPool, Core, CoreDal};
+use zksync_env_config::FromEnv;
+use zksync_types::{pubdata_da::ParsedPubdata, L1BatchNumber};
+
+use crate::pubdata::{audit_l1_batch, fetch_blobs, fetch_l1_batch_pubdata, PubdataSummary};
+
+mod pubdata;
+
+#[derive(Debug, Parser)]
+#[command(author = "Matter Labs", version, about = "Pubdata reconstruction utility", long_about = None)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Path to yaml config. If set, it will be used instead of env vars
+    #[arg(long, global = true)]
+    config_path: Option<PathBuf>,
+    /// Path to yaml secrets config. If set, it will be used instead of env vars
+    #[arg(long, global = true)]
+    secrets_path: Option<PathBuf>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Fetches L1 batch pubdata from the DA layer and prints the decoded state diffs as JSON.
+    #[command(name = "reconstruct")]
+    Reconstruct {
+        /// L1 batch number to reconstruct. Blob IDs are taken from Postgres.
+        #[arg(
+            long,
+            required_unless_present = "blob_ids",
+            conflicts_with = "blob_ids"
+        )]
+        l1_batch_number: Option<u32>,
+        /// Blob IDs to fetch the pubdata from, in the order of chunks. Can be used if Postgres is not available.
+        #[arg(long = "blob-id")]
+        blob_ids: Vec<String>,
+        /// Path to write the raw reconstructed pubdata to.
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Decodes pubdata from a local gzip file (e.g., the one written by the object store DA client).
+    #[command(name = "decode-file")]
+    DecodeFile {
+        /// Path to the gzipped pubdata.
+        #[arg(long)]
+        path: PathBuf,
+    },
+    /// Checks that the pubdata posted to the DA layer matches the pubdata of L1 batches stored in Postgres.
+    #[command(name = "audit")]
+    Audit {
+        /// First L1 batch to audit.
+        #[arg(long)]
+        from_l1_batch: u32,
+        /// Last L1 batch to audit (inclusive).
+        #[arg(long)]
+        to_l1_batch: u32,
+    },
+    /// Clears the result of the DA blob verification for an L1 batch (e.g., a mismatch that tripped
+    /// the `da_verification` circuit breaker), so that the blob is verified again by the node.
+    #[command(name = "reset-verification")]
+    ResetVerification {
+        /// L1 batch to reset the verification result for.
+        #[arg(long)]
+        l1_batch_number: u32,
+    },
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let opts = Cli::parse();
+    let observability_config =
+        ObservabilityConfig::from_env().context("ObservabilityConfig::from_env()")?;
+
+    let logs = zksync_vlog::Logs::try_from(observability_config.clone())
+        .context("logs")?
+        .disable_default_logs(); // It's a CLI application, so we only need to show logs that were actually requested.
+    let _guard = zksync_vlog::ObservabilityBuilder::new()
+        .with_logs(Some(logs))
+        .build();
+
+    let general_config: Option<GeneralConfig> = if let Some(path) = opts.config_path {
+        Some(
+            read_yaml_repr::<zksync_protobuf_config::proto::general::GeneralConfig>(&path)
+                .context("failed decoding general YAML config")?,
+        )
+    } else {
+        None
+    };
+    let secrets_config = if let Some(path) = opts.secrets_path {
+        Some(
+            read_yaml_repr::<zksync_protobuf_config::proto::secrets::Secrets>(&path)
+                .context("failed decoding secrets YAML config")?,
+        )
+    } else {
+        None
+    };
+
+    let configs = Configs {
+        general: general_config,
+        secrets: secrets_config,
+    };
+
+    match opts.command {
+        Command::Reconstruct {
+            l1_batch_number,
+            blob_ids,
+            output,
+        } => {
+            let client = configs.da_client().await?;
+            let pubdata = if let Some(number) = l1_batch_number {
+                let pool = configs.connection_pool().await?;
+                let mut storage = pool.connection().await?;
+                fetch_l1_batch_pubdata(&mut storage, client.as_ref(), L1BatchNumber(number))
+                    .await?
+                    .with_context(|| format!("pubdata for L1 batch #{number} is not available"))?
+            } else {
+                fetch_blobs(client.as_ref(), &blob_ids).await?
+            };
+
+            if let Some(output) = output {
+                fs::write(&output, &pubdata)
+                    .await
+                    .with_context(|| format!("failed writing pubdata to {output:?}"))?;
+            }
+            print_pubdata(&pubdata)?;
+        }
+        Command::DecodeFile { path } => {
+            let compressed = fs::read(&path)
+                .await
+                .with_context(|| format!("failed reading {path:?}"))?;
+            let mut pubdata = vec![];
+            GzDecoder::new(compressed.as_slice())
+                .read_to_end(&mut pubdata)
+                .context("failed decompressing pubdata")?;
+            print_pubdata(&pubdata)?;
+        }
+        Command::Audit {
+            from_l1_batch,
+            to_l1_batch,
+        } => {
+            anyhow::ensure!(
+                from_l1_batch <= to_l1_batch,
+                "invalid L1 batch range: {from_l1_batch}..={to_l1_batch}"
+            );
+            let client = configs.da_client().await?;
+            let pool = configs.connection_pool().await?;
+            let mut storage = pool.connection().await?;
+
+            let mut failed_batches = vec![];
+            for number in from_l1_batch..=to_l1_batch {
+                let number = L1BatchNumber(number);
+                let status = audit_l1_batch(&mut storage, client.as_ref(), number).await?;
+                println!("L1 batch #{number}: {status}");
+                if status.is_failure() {
+                    failed_batches.push(number);
+                }
+            }
+            anyhow::ensure!(
+                failed_batches.is_empty(),
+                "pubdata check failed for L1 batches: {failed_batches:?}"
+            );
+        }
+        Command::ResetVerification { l1_batch_number } => {
+            let pool = configs.connection_pool().await?;
+            let mut storage = pool.connection().await?;
+            let number = L1BatchNumber(l1_batch_number);
+            let was_reset = storage
+                .data_availability_dal()
+                .reset_l1_batch_da_verification(number)
+                .await?;
+            anyhow::ensure!(was_reset, "L1 batch #{number} has no DA blob");
+            println!("Reset DA blob verification for L1 batch #{number}");
+        }
+    }
+    Ok(())
+}
+
+fn print_pubdata(pubdata: &[u8]) -> anyhow::Result<()> {
+    let parsed = ParsedPubdata::parse(pubdata).context("failed parsing pubdata")?;
+    let summary = PubdataSummary::from(parsed);
+    println!("{}", serde_json::to_string_pretty(&summary)?);
+    Ok(())
+}
+
+#[derive(Debug)]
+struct Configs {
+    general: Option<GeneralConfig>,
+    secrets: Option<zksync_config::configs::Secrets>,
+}
+
+impl Configs {
+    async fn connection_pool(&self) -> anyhow::Result<ConnectionPool<Core>> {
+        let database_secrets = match &self.secrets {
+            Some(secrets_config) => secrets_config
+                .database
+                .clone()
+                .context("Failed to find database config")?,
+            None => DatabaseSecrets::from_env().context("DatabaseSecrets::from_env()")?,
+        };
+        let postgres_config = match &self.general {
+            Some(general_config) => general_config
+                .postgres_config
+                .clone()
+                .context("Failed to find postgres config")?,
+            None => PostgresConfig::from_env().context("PostgresConfig::from_env()")?,
+        };
+
+        ConnectionPool::<Core>::builder(
+            database_secrets.master_url()?,
+            postgres_config.max_connections()?,
+        )
+        .build()
+        .await
+        .context("failed to build a connection pool")
+    }
+
+    async fn da_client(&self) -> anyhow::Result<Box<dyn DataAvailabilityClient>> {
+        let config = match &self.general {
+            Some(general_config) => general_config
+                .da_client_config
+                .clone()
+                .context("Failed to find DA client config")?,
+            None => DAClientConfig::from_env().context("DAClientConfig::from_env()")?,
+        };
+        let secrets = match &self.secrets {
+            Some(secrets_config) => secrets_config.data_availability.clone(),
+            None => DataAvailabilitySecrets::from_env().ok(),
+        };
+
+        create_da_client(config, secrets).await
+    }
+}
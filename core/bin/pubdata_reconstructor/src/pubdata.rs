@@ -0,0 +1,229 @@
+//! Fetching L1 batch pubdata from DA layers and comparing it with the pubdata stored in Postgres.
+
+use std::fmt;
+
+use anyhow::Context as _;
+use serde::Serialize;
+use zksync_da_client::DataAvailabilityClient;
+use zksync_dal::{Connection, Core, CoreDal};
+use zksync_types::{
+    l2_to_l1_log::L2ToL1Log,
+    pubdata_da::ParsedPubdata,
+    writes::{DecompressedStateDiff, StateDiffKey},
+    L1BatchNumber, H256, U256,
+};
+
+/// Fetches blobs with the specified IDs and concatenates them.
+pub(crate) async fn fetch_blobs(
+    client: &dyn DataAvailabilityClient,
+    blob_ids: &[String],
+) -> anyhow::Result<Vec<u8>> {
+    let mut pubdata = vec![];
+    for blob_id in blob_ids {
+        let blob = client
+            .get_blob(blob_id)
+            .await
+            .with_context(|| format!("failed fetching blob {blob_id}"))?
+            .with_context(|| format!("blob {blob_id} is not found on the DA layer"))?;
+        pubdata.extend(blob);
+    }
+    Ok(pubdata)
+}
+
+/// Fetches the pubdata of an L1 batch from the DA layer, joining chunks if the pubdata was dispatched
+/// as several blobs. Returns `None` if the pubdata wasn't dispatched yet.
+pub(crate) async fn fetch_l1_batch_pubdata(
+    storage: &mut Connection<'_, Core>,
+    client: &dyn DataAvailabilityClient,
+    number: L1BatchNumber,
+) -> anyhow::Result<Option<Vec<u8>>> {
+    let Some(blob) = storage
+        .data_availability_dal()
+        .get_l1_batch_da_blob(number)
+        .await?
+    else {
+        return Ok(None);
+    };
+
+    let chunks = storage
+        .data_availability_dal()
+        .get_l1_batch_da_chunks(number)
+        .await?;
+    let blob_ids = if chunks.is_empty() {
+        vec![blob.blob_id]
+    } else {
+        chunks.into_iter().map(|chunk| chunk.blob_id).collect()
+    };
+    fetch_blobs(client, &blob_ids).await.map(Some)
+}
+
+/// Outcome of auditing pubdata of a single L1 batch.
+#[derive(Debug)]
+pub(crate) enum AuditStatus {
+    /// Pubdata on the DA layer matches Postgres.
+    Ok,
+    /// Pubdata wasn't dispatched to the DA layer yet.
+    NotDispatched,
+    /// The batch is not in Postgres or has no pubdata.
+    MissingPubdata,
+    /// Blob was dispatched, but the DA layer doesn't return it.
+    NotFound(anyhow::Error),
+    /// Pubdata on the DA layer differs from Postgres.
+    Mismatch { first_diff_offset: usize },
+    /// Pubdata matches, but cannot be decoded.
+    Malformed(anyhow::Error),
+}
+
+impl AuditStatus {
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            Self::NotFound(_) | Self::Mismatch { .. } | Self::Malformed(_)
+        )
+    }
+}
+
+impl fmt::Display for AuditStatus {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ok => formatter.write_str("OK"),
+            Self::NotDispatched => formatter.write_str("not dispatched yet"),
+            Self::MissingPubdata => formatter.write_str("no pubdata in Postgres"),
+            Self::NotFound(err) => write!(formatter, "FAILED: {err:#}"),
+            Self::Mismatch { first_diff_offset } => write!(
+                formatter,
+                "FAILED: pubdata mismatch starting at byte {first_diff_offset}"
+            ),
+            Self::Malformed(err) => write!(formatter, "FAILED: malformed pubdata: {err:#}"),
+        }
+    }
+}
+
+pub(crate) async fn audit_l1_batch(
+    storage: &mut Connection<'_, Core>,
+    client: &dyn DataAvailabilityClient,
+    number: L1BatchNumber,
+) -> anyhow::Result<AuditStatus> {
+    let expected_pubdata = storage
+        .blocks_dal()
+        .get_l1_batch_header(number)
+        .await?
+        .and_then(|header| header.pubdata_input);
+    let Some(expected_pubdata) = expected_pubdata else {
+        return Ok(AuditStatus::MissingPubdata);
+    };
+
+    let pubdata = match fetch_l1_batch_pubdata(storage, client, number).await {
+        Ok(Some(pubdata)) => pubdata,
+        Ok(None) => return Ok(AuditStatus::NotDispatched),
+        Err(err) => return Ok(AuditStatus::NotFound(err)),
+    };
+    Ok(compare_pubdata(&expected_pubdata, &pubdata))
+}
+
+fn compare_pubdata(expected: &[u8], actual: &[u8]) -> AuditStatus {
+    if expected != actual {
+        let first_diff_offset = expected
+            .iter()
+            .zip(actual)
+            .position(|(expected, actual)| expected != actual)
+            .unwrap_or_else(|| expected.len().min(actual.len()));
+        return AuditStatus::Mismatch { first_diff_offset };
+    }
+    match ParsedPubdata::parse(actual) {
+        Ok(_) => AuditStatus::Ok,
+        Err(err) => AuditStatus::Malformed(err),
+    }
+}
+
+/// JSON-serializable representation of the parsed pubdata.
+#[derive(Debug, Serialize)]
+pub(crate) struct PubdataSummary {
+    user_logs: Vec<L2ToL1Log>,
+    l2_to_l1_messages: Vec<String>,
+    published_bytecodes: Vec<String>,
+    state_diffs: Vec<StateDiffSummary>,
+}
+
+impl From<ParsedPubdata> for PubdataSummary {
+    fn from(pubdata: ParsedPubdata) -> Self {
+        let to_hex = |bytes: Vec<u8>| format!("0x{}", hex::encode(bytes));
+        Self {
+            user_logs: pubdata.user_logs,
+            l2_to_l1_messages: pubdata.l2_to_l1_messages.into_iter().map(to_hex).collect(),
+            published_bytecodes: pubdata
+                .published_bytecodes
+                .into_iter()
+                .map(to_hex)
+                .collect(),
+            state_diffs: pubdata
+                .state_diffs
+                .into_iter()
+                .map(StateDiffSummary::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct StateDiffSummary {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    derived_key: Option<H256>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enumeration_index: Option<u64>,
+    /// Final value of the slot if it can be computed without the previous value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<U256>,
+    /// Diff with the previous value of the slot; used for additive / subtractive compression.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    added: Option<U256>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subtracted: Option<U256>,
+}
+
+impl From<DecompressedStateDiff> for StateDiffSummary {
+    fn from(diff: DecompressedStateDiff) -> Self {
+        let (derived_key, enumeration_index) = match diff.key {
+            StateDiffKey::Initial(key) => (Some(key), None),
+            StateDiffKey::Repeated(index) => (None, Some(index)),
+        };
+        let compressed = diff.value;
+        Self {
+            derived_key,
+            enumeration_index,
+            value: compressed
+                .is_standalone()
+                .then(|| compressed.decompress(U256::zero())),
+            added: (compressed.operation_id == 1).then_some(compressed.value),
+            subtracted: (compressed.operation_id == 2).then_some(compressed.value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn comparing_pubdata() {
+        let pubdata = [0_u8; 16]; // empty logs, messages and bytecodes + truncated state diffs
+        assert!(matches!(
+            compare_pubdata(&pubdata, &pubdata),
+            AuditStatus::Malformed(_)
+        ));
+        assert!(matches!(
+            compare_pubdata(&pubdata, &pubdata[..10]),
+            AuditStatus::Mismatch {
+                first_diff_offset: 10
+            }
+        ));
+        let mut other_pubdata = pubdata;
+        other_pubdata[5] = 1;
+        assert!(matches!(
+            compare_pubdata(&pubdata, &other_pubdata),
+            AuditStatus::Mismatch {
+                first_diff_offset: 5
+            }
+        ));
+    }
+}
@@ -0,0 +1,179 @@
+//! Export of snapshots as self-contained bundles stored in a local directory.
+
+use std::{path::Path, sync::Arc};
+
+use anyhow::Context as _;
+use zksync_dal::{ConnectionPool, Core, CoreDal};
+use zksync_object_store::{Bucket, FileBackedObjectStore, ObjectStore, StoredObject};
+use zksync_types::{
+    snapshots::{
+        SnapshotBundleMetadata, SnapshotFactoryDependencies, SnapshotHeader, SnapshotMetadata,
+        SnapshotStorageLogsChunk, SnapshotStorageLogsChunkMetadata, SnapshotStorageLogsStorageKey,
+    },
+    L1BatchNumber, L2BlockNumber,
+};
+
+/// Exporter of a complete snapshot (together with its base snapshots if the snapshot is incremental) into a local directory.
+/// The produced bundle can be applied without a main node.
+#[derive(Debug)]
+pub(crate) struct SnapshotBundleExporter {
+    pub blob_store: Arc<dyn ObjectStore>,
+    pub pool: ConnectionPool<Core>,
+}
+
+impl SnapshotBundleExporter {
+    /// Exports the snapshot for the specified L1 batch, or the newest complete snapshot if the L1 batch is not specified.
+    pub async fn export(
+        &self,
+        l1_batch_number: Option<L1BatchNumber>,
+        output_dir: &Path,
+    ) -> anyhow::Result<SnapshotBundleMetadata> {
+        let mut conn = self.pool.connection_tagged("snapshots_creator").await?;
+        let l1_batch_number = if let Some(number) = l1_batch_number {
+            number
+        } else {
+            let all_snapshots = conn.snapshots_dal().get_all_complete_snapshots().await?;
+            *all_snapshots
+                .snapshots_l1_batch_numbers
+                .first()
+                .context("no complete snapshots to export")?
+        };
+
+        // Collect the snapshot chain, starting from the exported snapshot.
+        let mut snapshots = vec![];
+        let mut next_l1_batch_number = Some(l1_batch_number);
+        while let Some(number) = next_l1_batch_number {
+            let snapshot = conn
+                .snapshots_dal()
+                .get_snapshot_metadata(number)
+                .await?
+                .with_context(|| format!("snapshot for L1 batch #{number} doesn't exist"))?;
+            anyhow::ensure!(
+                snapshot.is_complete(),
+                "snapshot for L1 batch #{number} is incomplete: {snapshot:?}"
+            );
+            next_l1_batch_number = snapshot.base_l1_batch_number;
+            snapshots.push(snapshot);
+        }
+        snapshots.reverse();
+
+        let output_path = output_dir
+            .to_str()
+            .with_context(|| format!("output path {output_dir:?} is not UTF-8"))?;
+        let output_store = FileBackedObjectStore::new(output_path.to_owned())
+            .await
+            .context("failed initializing bundle object store")?;
+
+        let mut headers = Vec::with_capacity(snapshots.len());
+        for snapshot in &snapshots {
+            let (_, l2_block_number) = conn
+                .blocks_dal()
+                .get_l2_block_range_of_l1_batch(snapshot.l1_batch_number)
+                .await?
+                .with_context(|| {
+                    format!("no L2 blocks for L1 batch #{}", snapshot.l1_batch_number)
+                })?;
+            headers.push(
+                self.export_snapshot_data(snapshot, l2_block_number, &output_store)
+                    .await?,
+            );
+        }
+
+        // `unwrap()` is safe: the snapshot chain always contains the exported snapshot.
+        let l2_block_number = headers.last().unwrap().l2_block_number;
+        let l1_batch = conn
+            .blocks_web3_dal()
+            .get_l1_batch_details(l1_batch_number)
+            .await?
+            .with_context(|| format!("L1 batch #{l1_batch_number} is missing"))?;
+        let l2_block = conn
+            .blocks_web3_dal()
+            .get_block_details(l2_block_number)
+            .await?
+            .with_context(|| format!("L2 block #{l2_block_number} is missing"))?;
+        let tokens = conn
+            .tokens_web3_dal()
+            .get_all_tokens(Some(l2_block_number))
+            .await?;
+        drop(conn);
+
+        let metadata = SnapshotBundleMetadata {
+            snapshots: headers,
+            l1_batch,
+            l2_block,
+            tokens,
+        };
+        // Metadata is written last, so that a bundle with metadata is guaranteed to be complete.
+        let metadata_path = output_dir.join(SnapshotBundleMetadata::FILE_NAME);
+        let raw_metadata =
+            serde_json::to_vec_pretty(&metadata).context("failed serializing bundle metadata")?;
+        tokio::fs::write(&metadata_path, raw_metadata)
+            .await
+            .with_context(|| format!("failed writing bundle metadata to {metadata_path:?}"))?;
+        tracing::info!(
+            "Exported snapshot for L1 batch #{l1_batch_number} ({} snapshot(s) in total) to {output_dir:?}",
+            metadata.snapshots.len()
+        );
+        Ok(metadata)
+    }
+
+    /// Copies factory deps and storage logs chunks of a single snapshot to the bundle store.
+    async fn export_snapshot_data(
+        &self,
+        snapshot: &SnapshotMetadata,
+        l2_block_number: L2BlockNumber,
+        output_store: &dyn ObjectStore,
+    ) -> anyhow::Result<SnapshotHeader> {
+        let l1_batch_number = snapshot.l1_batch_number;
+        let factory_deps_key = SnapshotFactoryDependencies::encode_key(l1_batch_number);
+        let factory_deps_filepath = self
+            .copy_object(&factory_deps_key, output_store)
+            .await
+            .with_context(|| {
+                format!("failed exporting factory deps for L1 batch #{l1_batch_number}")
+            })?;
+
+        let chunk_count = snapshot.storage_logs_filepaths.len() as u64;
+        let mut storage_logs_chunks = Vec::with_capacity(chunk_count as usize);
+        for chunk_id in 0..chunk_count {
+            // Storage logs chunks for all snapshot versions use the same keys.
+            let key = <SnapshotStorageLogsChunk as StoredObject>::encode_key(
+                SnapshotStorageLogsStorageKey {
+                    l1_batch_number,
+                    chunk_id,
+                },
+            );
+            let filepath = self
+                .copy_object(&key, output_store)
+                .await
+                .with_context(|| {
+                    format!("failed exporting storage logs chunk {chunk_id} for L1 batch #{l1_batch_number}")
+                })?;
+            storage_logs_chunks.push(SnapshotStorageLogsChunkMetadata { chunk_id, filepath });
+        }
+        tracing::info!(
+            "Exported factory deps and {chunk_count} storage logs chunks for L1 batch #{l1_batch_number}"
+        );
+
+        Ok(SnapshotHeader {
+            version: snapshot.version.into(),
+            l1_batch_number,
+            l2_block_number,
+            storage_logs_chunks,
+            factory_deps_filepath,
+            base_l1_batch_number: snapshot.base_l1_batch_number,
+        })
+    }
+
+    /// Copies a raw snapshot object and returns its path relative to the bundle directory.
+    async fn copy_object(
+        &self,
+        key: &str,
+        output_store: &dyn ObjectStore,
+    ) -> anyhow::Result<String> {
+        let bucket = Bucket::StorageSnapshot;
+        let bytes = self.blob_store.get_raw(bucket, key).await?;
+        output_store.put_raw(bucket, key, bytes).await?;
+        Ok(format!("{bucket}/{key}"))
+    }
+}
@@ -25,6 +25,8 @@ use crate::tests::HandleEvent;
 struct SnapshotProgress {
     version: SnapshotVersion,
     l1_batch_number: L1BatchNumber,
+    /// L1 batch of the base snapshot. Set for incremental snapshots only.
+    base_l1_batch_number: Option<L1BatchNumber>,
     /// `true` if the snapshot is new (i.e., its progress is not recovered from Postgres).
     is_new_snapshot: bool,
     chunk_count: u64,
@@ -32,10 +34,16 @@ struct SnapshotProgress {
 }
 
 impl SnapshotProgress {
-    fn new(version: SnapshotVersion, l1_batch_number: L1BatchNumber, chunk_count: u64) -> Self {
+    fn new(
+        version: SnapshotVersion,
+        l1_batch_number: L1BatchNumber,
+        base_l1_batch_number: Option<L1BatchNumber>,
+        chunk_count: u64,
+    ) -> Self {
         Self {
             version,
             l1_batch_number,
+            base_l1_batch_number,
             is_new_snapshot: true,
             chunk_count,
             remaining_chunk_ids: (0..chunk_count).collect(),
@@ -53,6 +61,7 @@ impl SnapshotProgress {
         Self {
             version: snapshot.version,
             l1_batch_number: snapshot.l1_batch_number,
+            base_l1_batch_number: snapshot.base_l1_batch_number,
             is_new_snapshot: false,
             chunk_count: snapshot.storage_logs_filepaths.len() as u64,
             remaining_chunk_ids,
@@ -60,6 +69,16 @@ impl SnapshotProgress {
     }
 }
 
+/// L2 blocks covered by a snapshot.
+#[derive(Debug)]
+struct L2BlockRange {
+    /// Last L2 block of the base snapshot L1 batch. Set for incremental snapshots only; the snapshot covers
+    /// L2 blocks after this one.
+    base_last: Option<L2BlockNumber>,
+    /// Last L2 block of the snapshot L1 batch.
+    last: L2BlockNumber,
+}
+
 /// Creator of a single storage snapshot.
 #[derive(Debug)]
 pub(crate) struct SnapshotCreator {
@@ -81,9 +100,10 @@ impl SnapshotCreator {
         &self,
         semaphore: &Semaphore,
         progress: &SnapshotProgress,
-        l2_block_number: L2BlockNumber,
+        l2_block_range: &L2BlockRange,
         chunk_id: u64,
     ) -> anyhow::Result<()> {
+        let l2_block_number = l2_block_range.last;
         let chunk_count = progress.chunk_count;
         let l1_batch_number = progress.l1_batch_number;
 
@@ -136,6 +156,30 @@ impl SnapshotCreator {
                 self.store_storage_logs_chunk(l1_batch_number, chunk_id, logs)
                     .await?
             }
+            SnapshotVersion::Version2 => {
+                let base_l2_block_number = l2_block_range
+                    .base_last
+                    .context("no base L2 block for an incremental snapshot")?;
+                let logs = conn
+                    .snapshots_creator_dal()
+                    .get_changed_storage_logs_chunk(
+                        base_l2_block_number,
+                        l2_block_number,
+                        l1_batch_number,
+                        hashed_keys_range,
+                    )
+                    .await
+                    .context("error fetching changed storage logs")?;
+                drop(conn);
+
+                let latency = latency.observe();
+                tracing::info!(
+                    "Loaded chunk {chunk_id} ({} changed logs) from Postgres in {latency:?}",
+                    logs.len()
+                );
+                self.store_storage_logs_chunk(l1_batch_number, chunk_id, logs)
+                    .await?
+            }
         };
 
         let mut master_conn = self
@@ -188,7 +232,7 @@ impl SnapshotCreator {
 
     async fn process_factory_deps(
         &self,
-        l2_block_number: L2BlockNumber,
+        l2_block_range: &L2BlockRange,
         l1_batch_number: L1BatchNumber,
     ) -> anyhow::Result<String> {
         let mut conn = self.connect_to_replica().await?;
@@ -196,10 +240,15 @@ impl SnapshotCreator {
         tracing::info!("Loading factory deps from Postgres...");
         let latency =
             METRICS.factory_deps_processing_duration[&FactoryDepsStage::LoadFromPostgres].start();
-        let factory_deps = conn
-            .snapshots_creator_dal()
-            .get_all_factory_deps(l2_block_number)
-            .await?;
+        let factory_deps = if let Some(base_l2_block_number) = l2_block_range.base_last {
+            conn.snapshots_creator_dal()
+                .get_new_factory_deps(base_l2_block_number, l2_block_range.last)
+                .await?
+        } else {
+            conn.snapshots_creator_dal()
+                .get_all_factory_deps(l2_block_range.last)
+                .await?
+        };
         drop(conn);
         let latency = latency.observe();
         tracing::info!("Loaded {} factory deps in {latency:?}", factory_deps.len());
@@ -239,7 +288,7 @@ impl SnapshotCreator {
         min_chunk_count: u64,
         conn: &mut Connection<'_, Core>,
     ) -> anyhow::Result<Option<SnapshotProgress>> {
-        let snapshot_version = SnapshotVersion::try_from(config.version)
+        let mut snapshot_version = SnapshotVersion::try_from(config.version)
             .context("invalid snapshot version specified in config")?;
 
         // Sanity check: the selected L1 batch should have Merkle tree data; otherwise, it could be impossible
@@ -254,6 +303,36 @@ impl SnapshotCreator {
                 )
             })?;
 
+        if snapshot_version.is_incremental() {
+            let base_snapshot = Self::select_base_snapshot(
+                l1_batch_number,
+                config.max_incremental_chain_length,
+                conn,
+            )
+            .await?;
+            if let Some(base_snapshot) = base_snapshot {
+                // Increments must use the same chunking as the base snapshot, so that a chunk in the increment
+                // covers the same key range as the corresponding chunk in the base snapshot.
+                let chunk_count = base_snapshot.storage_logs_filepaths.len() as u64;
+                tracing::info!(
+                    "Creating incremental snapshot for L1 batch {l1_batch_number} on top of snapshot for L1 batch {} \
+                     with {chunk_count} storage logs chunks",
+                    base_snapshot.l1_batch_number
+                );
+                return Ok(Some(SnapshotProgress::new(
+                    snapshot_version,
+                    l1_batch_number,
+                    Some(base_snapshot.l1_batch_number),
+                    chunk_count,
+                )));
+            }
+            tracing::info!(
+                "No suitable base snapshot for an incremental snapshot for L1 batch {l1_batch_number}; \
+                 creating a full snapshot instead"
+            );
+            snapshot_version = SnapshotVersion::Version1;
+        }
+
         let distinct_storage_logs_keys_count = conn
             .snapshots_creator_dal()
             .get_distinct_storage_logs_keys_count(l1_batch_number)
@@ -271,10 +350,63 @@ impl SnapshotCreator {
         Ok(Some(SnapshotProgress::new(
             snapshot_version,
             l1_batch_number,
+            None,
             chunk_count,
         )))
     }
 
+    /// Selects the base for an incremental snapshot, which is the newest complete snapshot preceding
+    /// the snapshot L1 batch. Returns `Ok(None)` if there's no such snapshot, or if it cannot be used as a base
+    /// (i.e., has version 0, or its chain already contains `max_chain_length` increments).
+    async fn select_base_snapshot(
+        l1_batch_number: L1BatchNumber,
+        max_chain_length: u32,
+        conn: &mut Connection<'_, Core>,
+    ) -> anyhow::Result<Option<SnapshotMetadata>> {
+        let all_snapshots = conn.snapshots_dal().get_all_complete_snapshots().await?;
+        // Snapshots are ordered by descending L1 batch number.
+        let Some(base_l1_batch_number) = all_snapshots
+            .snapshots_l1_batch_numbers
+            .into_iter()
+            .find(|&number| number < l1_batch_number)
+        else {
+            return Ok(None);
+        };
+        let base_snapshot = conn
+            .snapshots_dal()
+            .get_snapshot_metadata(base_l1_batch_number)
+            .await?
+            .with_context(|| {
+                format!("snapshot for L1 batch #{base_l1_batch_number} disappeared")
+            })?;
+        if base_snapshot.version == SnapshotVersion::Version0 {
+            return Ok(None);
+        }
+
+        let mut increment_count = 0;
+        let mut next_base = base_snapshot.base_l1_batch_number;
+        while let Some(number) = next_base {
+            increment_count += 1;
+            if increment_count >= max_chain_length {
+                break;
+            }
+            next_base = conn
+                .snapshots_dal()
+                .get_snapshot_metadata(number)
+                .await?
+                .with_context(|| format!("base snapshot for L1 batch #{number} is missing"))?
+                .base_l1_batch_number;
+        }
+        if increment_count >= max_chain_length {
+            tracing::info!(
+                "Snapshot chain ending with L1 batch #{base_l1_batch_number} has reached the maximum length \
+                 ({max_chain_length} increments)"
+            );
+            return Ok(None);
+        }
+        Ok(Some(base_snapshot))
+    }
+
     /// Returns `Ok(None)` if a snapshot should not be created / resumed.
     async fn load_or_initialize_snapshot_progress(
         &self,
@@ -368,7 +500,22 @@ impl SnapshotCreator {
             .get_l2_block_range_of_l1_batch(progress.l1_batch_number)
             .await?
             .context("No L2 blocks for L1 batch")?;
+        let base_last_l2_block_number =
+            if let Some(base_l1_batch_number) = progress.base_l1_batch_number {
+                let (_, last_l2_block_number) = conn
+                    .blocks_dal()
+                    .get_l2_block_range_of_l1_batch(base_l1_batch_number)
+                    .await?
+                    .context("No L2 blocks for base snapshot L1 batch")?;
+                Some(last_l2_block_number)
+            } else {
+                None
+            };
         drop(conn);
+        let l2_block_range = L2BlockRange {
+            base_last: base_last_l2_block_number,
+            last: last_l2_block_number_in_batch,
+        };
 
         METRICS.storage_logs_chunks_count.set(progress.chunk_count);
         tracing::info!(
@@ -379,7 +526,7 @@ impl SnapshotCreator {
 
         if progress.is_new_snapshot {
             let factory_deps_output_file = self
-                .process_factory_deps(last_l2_block_number_in_batch, progress.l1_batch_number)
+                .process_factory_deps(&l2_block_range, progress.l1_batch_number)
                 .await?;
 
             let mut master_conn = self
@@ -393,6 +540,7 @@ impl SnapshotCreator {
                     progress.l1_batch_number,
                     progress.chunk_count,
                     &factory_deps_output_file,
+                    progress.base_l1_batch_number,
                 )
                 .await?;
         }
@@ -409,7 +557,7 @@ impl SnapshotCreator {
                 self.process_storage_logs_single_chunk(
                     &semaphore,
                     &progress,
-                    last_l2_block_number_in_batch,
+                    &l2_block_range,
                     chunk_id,
                 )
             });
@@ -8,6 +8,14 @@
 //!
 //! It is assumed that the snapshot creator is run as a singleton process (no more than 1 instance
 //! at a time).
+//!
+//! If the `--export-bundle` option is specified, the creator doesn't create a snapshot; instead, it exports
+//! an existing complete snapshot into a self-contained bundle in a local directory, which can be used
+//! to recover a node without a main node.
+//!
+//! If the `--verify` option is specified, the creator doesn't create a snapshot either; instead, it verifies
+//! integrity of an existing complete snapshot by rebuilding the Merkle tree from its storage logs and comparing
+//! the tree root hash with the root hash of the snapshot L1 batch.
 
 use anyhow::Context as _;
 use structopt::StructOpt;
@@ -15,15 +23,18 @@ use tokio::{sync::watch, task::JoinHandle};
 use zksync_config::configs::PrometheusConfig;
 use zksync_core_leftovers::temp_config_store::{load_database_secrets, load_general_config};
 use zksync_dal::{ConnectionPool, Core};
+use zksync_merkle_tree::{PatchSet, RocksDBWrapper};
 use zksync_object_store::ObjectStoreFactory;
 use zksync_vlog::prometheus::PrometheusExporterConfig;
 
-use crate::creator::SnapshotCreator;
+use crate::{bundle::SnapshotBundleExporter, creator::SnapshotCreator, verifier::SnapshotVerifier};
 
+mod bundle;
 mod creator;
 mod metrics;
 #[cfg(test)]
 mod tests;
+mod verifier;
 
 async fn maybe_enable_prometheus_metrics(
     prometheus_config: Option<PrometheusConfig>,
@@ -57,6 +68,22 @@ struct Opt {
     /// Path to the secrets file.
     #[structopt(long)]
     secrets_path: Option<std::path::PathBuf>,
+
+    /// Path to a local directory to export a complete snapshot to. If specified, no new snapshot is created;
+    /// the exported snapshot is selected using `l1_batch_number` from the creator config, or is the newest
+    /// complete snapshot if the L1 batch is not specified.
+    #[structopt(long)]
+    export_bundle: Option<std::path::PathBuf>,
+
+    /// Verifies a complete snapshot instead of creating a new one. The verified snapshot is selected
+    /// in the same way as for `--export-bundle`.
+    #[structopt(long, conflicts_with = "export-bundle")]
+    verify: bool,
+
+    /// Path to an empty directory to store the Merkle tree rebuilt during verification in RocksDB.
+    /// If not specified, the tree is kept in memory, which is only feasible for small snapshots.
+    #[structopt(long, requires = "verify")]
+    verify_tree_path: Option<std::path::PathBuf>,
 }
 
 #[tokio::main]
@@ -99,16 +126,45 @@ async fn main() -> anyhow::Result<()> {
         .build()
         .await?;
 
-    let creator = SnapshotCreator {
-        blob_store,
-        master_pool,
-        replica_pool,
-        #[cfg(test)]
-        event_listener: Box::new(()),
-    };
-    creator.run(creator_config, MIN_CHUNK_COUNT).await?;
+    if opt.verify {
+        let verifier = SnapshotVerifier {
+            blob_store,
+            pool: replica_pool,
+        };
+        let l1_batch_number = creator_config.l1_batch_number;
+        let report = if let Some(tree_path) = &opt.verify_tree_path {
+            let db = RocksDBWrapper::new(tree_path).with_context(|| {
+                format!("failed initializing Merkle tree RocksDB at {tree_path:?}")
+            })?;
+            verifier.verify(l1_batch_number, db).await?
+        } else {
+            verifier
+                .verify(l1_batch_number, PatchSet::default())
+                .await?
+        };
+        report.check()?;
+        tracing::info!("Finished verifying snapshot!");
+    } else if let Some(bundle_path) = &opt.export_bundle {
+        let exporter = SnapshotBundleExporter {
+            blob_store,
+            pool: replica_pool,
+        };
+        exporter
+            .export(creator_config.l1_batch_number, bundle_path)
+            .await?;
+        tracing::info!("Finished exporting snapshot bundle!");
+    } else {
+        let creator = SnapshotCreator {
+            blob_store,
+            master_pool,
+            replica_pool,
+            #[cfg(test)]
+            event_listener: Box::new(()),
+        };
+        creator.run(creator_config, MIN_CHUNK_COUNT).await?;
+        tracing::info!("Finished running snapshot creator!");
+    }
 
-    tracing::info!("Finished running snapshot creator!");
     stop_sender.send(true).ok();
     if let Some(prometheus_exporter_task) = prometheus_exporter_task {
         prometheus_exporter_task
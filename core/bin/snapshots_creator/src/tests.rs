@@ -13,22 +13,29 @@ use rand::{thread_rng, Rng};
 use test_casing::test_casing;
 use zksync_config::SnapshotsCreatorConfig;
 use zksync_dal::{Connection, CoreDal};
-use zksync_object_store::{MockObjectStore, ObjectStore};
+use zksync_merkle_tree::{MerkleTree, PatchSet, TreeEntry};
+use zksync_object_store::{
+    Bucket, FileBackedObjectStore, MockObjectStore, ObjectStore, StoredObject,
+};
 use zksync_types::{
     block::{L1BatchHeader, L1BatchTreeData, L2BlockHeader},
     snapshots::{
-        SnapshotFactoryDependencies, SnapshotFactoryDependency, SnapshotStorageLog,
-        SnapshotStorageLogsChunk, SnapshotStorageLogsStorageKey,
+        SnapshotBundleMetadata, SnapshotFactoryDependencies, SnapshotFactoryDependency,
+        SnapshotStorageLog, SnapshotStorageLogsChunk, SnapshotStorageLogsStorageKey,
+        SnapshotVersion,
     },
     AccountTreeId, Address, L1BatchNumber, L2BlockNumber, ProtocolVersion, StorageKey, StorageLog,
     H256,
 };
+use zksync_utils::h256_to_u256;
 
 use super::*;
+use crate::verifier::UnorderedChunk;
 
 const TEST_CONFIG: SnapshotsCreatorConfig = SnapshotsCreatorConfig {
     version: 1,
     l1_batch_number: None,
+    max_incremental_chain_length: SnapshotsCreatorConfig::max_incremental_chain_length_default(),
     storage_logs_chunk_size: 1_000_000,
     concurrent_queries_count: 10,
     object_store: None,
@@ -448,6 +455,220 @@ async fn persisting_snapshot_logs_for_v0_snapshot() {
     assert_eq!(actual_logs, expected_outputs.storage_logs);
 }
 
+#[tokio::test]
+async fn persisting_incremental_snapshot() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut rng = thread_rng();
+    let object_store = MockObjectStore::arc();
+    let mut conn = pool.connection().await.unwrap();
+    let expected_outputs = prepare_postgres(&mut rng, &mut conn, 10).await;
+
+    // Without a base snapshot, a full snapshot should be created.
+    let base_l1_batch_number = L1BatchNumber(4);
+    let config = SnapshotsCreatorConfig {
+        version: 2,
+        l1_batch_number: Some(base_l1_batch_number),
+        ..TEST_CONFIG
+    };
+    SnapshotCreator::for_tests(object_store.clone(), pool.clone())
+        .run(config, MIN_CHUNK_COUNT)
+        .await
+        .unwrap();
+    let base_snapshot = conn
+        .snapshots_dal()
+        .get_snapshot_metadata(base_l1_batch_number)
+        .await
+        .unwrap()
+        .expect("No snapshot metadata");
+    assert_eq!(base_snapshot.version, SnapshotVersion::Version1);
+    assert_eq!(base_snapshot.base_l1_batch_number, None);
+
+    let config = SnapshotsCreatorConfig {
+        version: 2,
+        ..TEST_CONFIG
+    };
+    SnapshotCreator::for_tests(object_store.clone(), pool.clone())
+        .run(config, MIN_CHUNK_COUNT)
+        .await
+        .unwrap();
+    let snapshot_l1_batch_number = L1BatchNumber(8);
+    let snapshot = conn
+        .snapshots_dal()
+        .get_snapshot_metadata(snapshot_l1_batch_number)
+        .await
+        .unwrap()
+        .expect("No snapshot metadata");
+    assert!(snapshot.is_complete(), "{snapshot:#?}");
+    assert_eq!(snapshot.version, SnapshotVersion::Version2);
+    assert_eq!(snapshot.base_l1_batch_number, Some(base_l1_batch_number));
+    assert_eq!(
+        snapshot.storage_logs_filepaths.len(),
+        base_snapshot.storage_logs_filepaths.len()
+    );
+
+    // The increment should only contain logs and factory deps introduced after the base snapshot.
+    let mut actual_logs = HashSet::new();
+    for chunk_id in 0..MIN_CHUNK_COUNT {
+        let key = SnapshotStorageLogsStorageKey {
+            l1_batch_number: snapshot_l1_batch_number,
+            chunk_id,
+        };
+        let chunk: SnapshotStorageLogsChunk = object_store.get(key).await.unwrap();
+        actual_logs.extend(chunk.storage_logs);
+    }
+    let expected_logs: HashSet<_> = expected_outputs
+        .storage_logs
+        .iter()
+        .filter(|log| {
+            log.l1_batch_number_of_initial_write > base_l1_batch_number
+                && log.l1_batch_number_of_initial_write <= snapshot_l1_batch_number
+        })
+        .cloned()
+        .collect();
+    assert_eq!(actual_logs, expected_logs);
+
+    let SnapshotFactoryDependencies { factory_deps } =
+        object_store.get(snapshot_l1_batch_number).await.unwrap();
+    assert_eq!(factory_deps.len(), 40); // 10 deps for each of L2 blocks 5..=8
+    for dep in &factory_deps {
+        assert!(expected_outputs.deps.contains(dep), "{dep:?}");
+    }
+}
+
+#[tokio::test]
+async fn limiting_incremental_snapshot_chain_length() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut rng = thread_rng();
+    let object_store = MockObjectStore::arc();
+    let mut conn = pool.connection().await.unwrap();
+    prepare_postgres(&mut rng, &mut conn, 10).await;
+
+    let expected_snapshots = [
+        (2, SnapshotVersion::Version1, None),
+        (4, SnapshotVersion::Version2, Some(2)),
+        (6, SnapshotVersion::Version2, Some(4)),
+        // The chain has reached the maximum length, so a full snapshot is created.
+        (8, SnapshotVersion::Version1, None),
+    ];
+    for (number, expected_version, expected_base) in expected_snapshots {
+        let config = SnapshotsCreatorConfig {
+            version: 2,
+            l1_batch_number: Some(L1BatchNumber(number)),
+            max_incremental_chain_length: 2,
+            ..TEST_CONFIG
+        };
+        SnapshotCreator::for_tests(object_store.clone(), pool.clone())
+            .run(config, MIN_CHUNK_COUNT)
+            .await
+            .unwrap();
+        let snapshot = conn
+            .snapshots_dal()
+            .get_snapshot_metadata(L1BatchNumber(number))
+            .await
+            .unwrap()
+            .expect("No snapshot metadata");
+        assert!(snapshot.is_complete(), "{snapshot:#?}");
+        assert_eq!(snapshot.version, expected_version);
+        assert_eq!(
+            snapshot.base_l1_batch_number,
+            expected_base.map(L1BatchNumber)
+        );
+    }
+
+    // Incremental snapshots can be disabled by setting the maximum chain length to 0.
+    let config = SnapshotsCreatorConfig {
+        version: 2,
+        l1_batch_number: Some(L1BatchNumber(9)),
+        max_incremental_chain_length: 0,
+        ..TEST_CONFIG
+    };
+    SnapshotCreator::for_tests(object_store.clone(), pool.clone())
+        .run(config, MIN_CHUNK_COUNT)
+        .await
+        .unwrap();
+    let snapshot = conn
+        .snapshots_dal()
+        .get_snapshot_metadata(L1BatchNumber(9))
+        .await
+        .unwrap()
+        .expect("No snapshot metadata");
+    assert_eq!(snapshot.version, SnapshotVersion::Version1);
+}
+
+#[tokio::test]
+async fn exporting_snapshot_bundle() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut rng = thread_rng();
+    let object_store = MockObjectStore::arc();
+    let mut conn = pool.connection().await.unwrap();
+    let expected_outputs = prepare_postgres(&mut rng, &mut conn, 10).await;
+
+    let base_l1_batch_number = L1BatchNumber(4);
+    let config = SnapshotsCreatorConfig {
+        l1_batch_number: Some(base_l1_batch_number),
+        ..TEST_CONFIG
+    };
+    SnapshotCreator::for_tests(object_store.clone(), pool.clone())
+        .run(config, MIN_CHUNK_COUNT)
+        .await
+        .unwrap();
+    let config = SnapshotsCreatorConfig {
+        version: 2,
+        ..TEST_CONFIG
+    };
+    SnapshotCreator::for_tests(object_store.clone(), pool.clone())
+        .run(config, MIN_CHUNK_COUNT)
+        .await
+        .unwrap();
+
+    let bundle_dir = tempfile::TempDir::new().unwrap();
+    let exporter = SnapshotBundleExporter {
+        blob_store: object_store,
+        pool: pool.clone(),
+    };
+    let metadata = exporter.export(None, bundle_dir.path()).await.unwrap();
+
+    let snapshot_l1_batch_number = L1BatchNumber(8);
+    let l1_batch_numbers: Vec<_> = metadata
+        .snapshots
+        .iter()
+        .map(|header| header.l1_batch_number)
+        .collect();
+    assert_eq!(
+        l1_batch_numbers,
+        [base_l1_batch_number, snapshot_l1_batch_number]
+    );
+    let snapshot = metadata.snapshot().unwrap();
+    assert_eq!(snapshot.version, u16::from(SnapshotVersion::Version2));
+    assert_eq!(snapshot.base_l1_batch_number, Some(base_l1_batch_number));
+    assert_eq!(snapshot.l2_block_number, L2BlockNumber(8));
+    assert_eq!(metadata.l1_batch.number, snapshot_l1_batch_number);
+    assert_eq!(metadata.l2_block.number, L2BlockNumber(8));
+
+    let raw_metadata =
+        std::fs::read(bundle_dir.path().join(SnapshotBundleMetadata::FILE_NAME)).unwrap();
+    let persisted_metadata: SnapshotBundleMetadata = serde_json::from_slice(&raw_metadata).unwrap();
+    assert_eq!(persisted_metadata.snapshots.len(), 2);
+
+    // Check that the bundle contains all snapshot data.
+    let bundle_store = FileBackedObjectStore::new(bundle_dir.path().to_str().unwrap().to_owned())
+        .await
+        .unwrap();
+    let bundle_store: &dyn ObjectStore = &bundle_store;
+    assert_storage_logs(bundle_store, base_l1_batch_number, &expected_outputs).await;
+    for header in &metadata.snapshots {
+        assert_eq!(header.storage_logs_chunks.len(), MIN_CHUNK_COUNT as usize);
+        for chunk in &header.storage_logs_chunks {
+            assert!(
+                bundle_dir.path().join(&chunk.filepath).is_file(),
+                "{chunk:?}"
+            );
+        }
+        let _: SnapshotFactoryDependencies =
+            bundle_store.get(header.l1_batch_number).await.unwrap();
+    }
+}
+
 #[test_casing(2, [false, true])]
 #[tokio::test]
 async fn recovery_workflow(specify_batch_after_recovery: bool) {
@@ -627,3 +848,139 @@ async fn creator_fails_if_specified_l1_batch_is_missing() {
         .await
         .unwrap_err();
 }
+
+fn expected_root_hash(expected_outputs: &ExpectedOutputs, l1_batch_number: L1BatchNumber) -> H256 {
+    let entries = expected_outputs
+        .storage_logs
+        .iter()
+        .filter(|log| log.l1_batch_number_of_initial_write <= l1_batch_number)
+        .map(|log| TreeEntry::new(h256_to_u256(log.key), log.enumeration_index, log.value))
+        .collect();
+    let mut tree = MerkleTree::new(PatchSet::default()).unwrap();
+    tree.extend(entries).unwrap().root_hash
+}
+
+#[test_casing(3, [0, 1, 2])]
+#[tokio::test]
+async fn verifying_snapshot(version: u16) {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut rng = thread_rng();
+    let object_store = MockObjectStore::arc();
+    let mut conn = pool.connection().await.unwrap();
+    let expected_outputs = prepare_postgres(&mut rng, &mut conn, 10).await;
+
+    if version == 2 {
+        // Create a base snapshot, so that the verified snapshot is incremental.
+        let config = SnapshotsCreatorConfig {
+            version,
+            l1_batch_number: Some(L1BatchNumber(4)),
+            ..TEST_CONFIG
+        };
+        SnapshotCreator::for_tests(object_store.clone(), pool.clone())
+            .run(config, MIN_CHUNK_COUNT)
+            .await
+            .unwrap();
+    }
+    let config = SnapshotsCreatorConfig {
+        version,
+        ..TEST_CONFIG
+    };
+    SnapshotCreator::for_tests(object_store.clone(), pool.clone())
+        .run(config, MIN_CHUNK_COUNT)
+        .await
+        .unwrap();
+
+    let verifier = SnapshotVerifier {
+        blob_store: object_store,
+        pool: pool.clone(),
+    };
+    let report = verifier.verify(None, PatchSet::default()).await.unwrap();
+    let snapshot_l1_batch_number = L1BatchNumber(8);
+    assert_eq!(report.l1_batch_number, snapshot_l1_batch_number);
+    assert!(report.bad_chunks.is_empty(), "{report:?}");
+    assert_eq!(
+        report.root_hash,
+        Some(expected_root_hash(
+            &expected_outputs,
+            snapshot_l1_batch_number
+        ))
+    );
+    // Test L1 batches have a zero root hash, so the root hash check must fail.
+    assert_eq!(report.expected_root_hash, H256::zero());
+    assert!(!report.is_ok());
+    let err = report.check().unwrap_err().to_string();
+    assert!(err.contains("root hash"), "{err}");
+}
+
+#[tokio::test]
+async fn verifier_reports_bad_chunks() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut rng = thread_rng();
+    let object_store = MockObjectStore::arc();
+    let mut conn = pool.connection().await.unwrap();
+    prepare_postgres(&mut rng, &mut conn, 10).await;
+
+    SnapshotCreator::for_tests(object_store.clone(), pool.clone())
+        .run(TEST_CONFIG, MIN_CHUNK_COUNT)
+        .await
+        .unwrap();
+    let snapshot_l1_batch_number = L1BatchNumber(8);
+
+    // Reverse logs in one chunk. This is reported as a warning, but doesn't fail verification.
+    let key = SnapshotStorageLogsStorageKey {
+        l1_batch_number: snapshot_l1_batch_number,
+        chunk_id: 2,
+    };
+    let mut chunk: SnapshotStorageLogsChunk = object_store.get(key).await.unwrap();
+    assert!(chunk.storage_logs.len() > 1);
+    chunk.storage_logs.reverse();
+    object_store.put(key, &chunk).await.unwrap();
+
+    let verifier = SnapshotVerifier {
+        blob_store: object_store.clone(),
+        pool: pool.clone(),
+    };
+    let report = verifier
+        .verify(Some(snapshot_l1_batch_number), PatchSet::default())
+        .await
+        .unwrap();
+    let expected_unordered_chunk = UnorderedChunk {
+        l1_batch_number: snapshot_l1_batch_number,
+        chunk_id: 2,
+    };
+    assert_eq!(report.unordered_chunks, [expected_unordered_chunk]);
+    assert!(report.bad_chunks.is_empty());
+    assert!(report.is_ok(), "{report:?}");
+
+    // Duplicate a log in the chunk and remove another chunk.
+    let duplicate_log = chunk.storage_logs[0].clone();
+    chunk.storage_logs.push(duplicate_log);
+    object_store.put(key, &chunk).await.unwrap();
+    let key = SnapshotStorageLogsStorageKey {
+        l1_batch_number: snapshot_l1_batch_number,
+        chunk_id: 5,
+    };
+    let key = <SnapshotStorageLogsChunk as StoredObject>::encode_key(key);
+    object_store
+        .remove_raw(Bucket::StorageSnapshot, &key)
+        .await
+        .unwrap();
+
+    let report = verifier
+        .verify(Some(snapshot_l1_batch_number), PatchSet::default())
+        .await
+        .unwrap();
+    let bad_chunk_ids: Vec<_> = report
+        .bad_chunks
+        .iter()
+        .map(|chunk| chunk.chunk_id)
+        .collect();
+    assert_eq!(bad_chunk_ids, [2, 5]);
+    for bad_chunk in &report.bad_chunks {
+        assert_eq!(bad_chunk.l1_batch_number, snapshot_l1_batch_number);
+    }
+    let err = report.bad_chunks[0].error.to_string();
+    assert!(err.contains("duplicate"), "{err}");
+    assert_eq!(report.root_hash, None);
+    assert!(!report.is_ok());
+}
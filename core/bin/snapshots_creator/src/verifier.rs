@@ -0,0 +1,337 @@
+//! Integrity verification for complete snapshots.
+
+use std::{collections::BTreeMap, fmt, ops, sync::Arc};
+
+use anyhow::Context as _;
+use zksync_dal::{ConnectionPool, Core, CoreDal};
+use zksync_merkle_tree::{recovery::MerkleTreeRecovery, PruneDatabase, TreeEntry};
+use zksync_object_store::{ObjectStore, ObjectStoreError};
+use zksync_types::{
+    snapshots::{
+        uniform_hashed_keys_chunk, SnapshotMetadata, SnapshotStorageLog, SnapshotStorageLogsChunk,
+        SnapshotStorageLogsStorageKey, SnapshotVersion,
+    },
+    L1BatchNumber, StorageKey, H256,
+};
+use zksync_utils::h256_to_u256;
+
+/// Storage logs chunk that has failed verification.
+#[derive(Debug)]
+pub(crate) struct BadChunk {
+    /// L1 batch of the snapshot containing the chunk. May differ from the verified snapshot
+    /// if the chunk belongs to one of its base snapshots.
+    pub l1_batch_number: L1BatchNumber,
+    pub chunk_id: u64,
+    pub error: anyhow::Error,
+}
+
+impl fmt::Display for BadChunk {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "chunk {} of snapshot for L1 batch #{}: {:#}",
+            self.chunk_id, self.l1_batch_number, self.error
+        )
+    }
+}
+
+/// Storage logs chunk with logs not ordered by increasing hashed key. Such a chunk can still be used
+/// for recovery, so it's reported as a warning rather than an error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct UnorderedChunk {
+    pub l1_batch_number: L1BatchNumber,
+    pub chunk_id: u64,
+}
+
+/// Outcome of verifying a snapshot.
+#[derive(Debug)]
+pub(crate) struct SnapshotVerificationReport {
+    pub l1_batch_number: L1BatchNumber,
+    pub bad_chunks: Vec<BadChunk>,
+    /// Chunks with unordered logs. Doesn't influence whether verification is successful.
+    pub unordered_chunks: Vec<UnorderedChunk>,
+    /// Root hash of the Merkle tree rebuilt from the snapshot. Not computed if there are bad chunks.
+    pub root_hash: Option<H256>,
+    /// Root hash of the snapshot L1 batch stored in Postgres.
+    pub expected_root_hash: H256,
+}
+
+impl SnapshotVerificationReport {
+    pub fn is_ok(&self) -> bool {
+        self.bad_chunks.is_empty() && self.root_hash == Some(self.expected_root_hash)
+    }
+
+    /// Converts this report into an error if verification has failed.
+    pub fn check(&self) -> anyhow::Result<()> {
+        if self.is_ok() {
+            return Ok(());
+        }
+        let l1_batch_number = self.l1_batch_number;
+        if !self.bad_chunks.is_empty() {
+            let bad_chunks: Vec<_> = self.bad_chunks.iter().map(ToString::to_string).collect();
+            anyhow::bail!(
+                "snapshot for L1 batch #{l1_batch_number} has {} bad chunk(s):\n{}",
+                bad_chunks.len(),
+                bad_chunks.join("\n")
+            );
+        }
+        anyhow::bail!(
+            "root hash of the tree rebuilt from snapshot for L1 batch #{l1_batch_number} ({:?}) \
+             differs from the L1 batch root hash ({:?})",
+            self.root_hash,
+            self.expected_root_hash
+        )
+    }
+}
+
+/// Verifier of a complete snapshot (together with its base snapshots if the snapshot is incremental).
+///
+/// The verifier checks that all storage logs chunks are present and well-formed (i.e., contain logs
+/// with unique hashed keys in the expected key range). It then rebuilds the Merkle tree from the snapshot
+/// using tree recovery and compares its root hash with the root hash of the snapshot L1 batch. Chunks with logs
+/// not ordered by increasing key are reported as warnings.
+#[derive(Debug)]
+pub(crate) struct SnapshotVerifier {
+    pub blob_store: Arc<dyn ObjectStore>,
+    pub pool: ConnectionPool<Core>,
+}
+
+impl SnapshotVerifier {
+    /// Verifies the snapshot for the specified L1 batch, or the newest complete snapshot if the L1 batch is not specified.
+    /// The Merkle tree is rebuilt in the provided `db`, which must be empty.
+    pub async fn verify<DB>(
+        &self,
+        l1_batch_number: Option<L1BatchNumber>,
+        db: DB,
+    ) -> anyhow::Result<SnapshotVerificationReport>
+    where
+        DB: PruneDatabase + 'static,
+    {
+        let mut conn = self.pool.connection_tagged("snapshots_creator").await?;
+        let l1_batch_number = if let Some(number) = l1_batch_number {
+            number
+        } else {
+            let all_snapshots = conn.snapshots_dal().get_all_complete_snapshots().await?;
+            *all_snapshots
+                .snapshots_l1_batch_numbers
+                .first()
+                .context("no complete snapshots to verify")?
+        };
+        let expected_root_hash = conn
+            .blocks_dal()
+            .get_l1_batch_state_root(l1_batch_number)
+            .await?
+            .with_context(|| format!("root hash for L1 batch #{l1_batch_number} is missing"))?;
+
+        // Collect the snapshot chain, starting from the base snapshot.
+        let mut snapshots = vec![];
+        let mut next_l1_batch_number = Some(l1_batch_number);
+        while let Some(number) = next_l1_batch_number {
+            let snapshot = conn
+                .snapshots_dal()
+                .get_snapshot_metadata(number)
+                .await?
+                .with_context(|| format!("snapshot for L1 batch #{number} doesn't exist"))?;
+            anyhow::ensure!(
+                snapshot.is_complete(),
+                "snapshot for L1 batch #{number} is incomplete: {snapshot:?}"
+            );
+            next_l1_batch_number = snapshot.base_l1_batch_number;
+            snapshots.push(snapshot);
+        }
+        drop(conn);
+        snapshots.reverse();
+
+        // Indexing is safe: the chain always contains at least one snapshot.
+        let chunk_count = snapshots[0].storage_logs_filepaths.len() as u64;
+        for snapshot in &snapshots[1..] {
+            anyhow::ensure!(
+                snapshot.storage_logs_filepaths.len() as u64 == chunk_count,
+                "chunk count for incremental snapshot {snapshot:?} differs from the base snapshot ({chunk_count})"
+            );
+        }
+        tracing::info!(
+            "Verifying snapshot for L1 batch #{l1_batch_number} ({} snapshot(s) in total, {chunk_count} chunks each)",
+            snapshots.len()
+        );
+
+        let mut recovery = MerkleTreeRecovery::new(db, l1_batch_number.0.into())
+            .context("failed initializing Merkle tree recovery")?;
+        anyhow::ensure!(
+            recovery.last_processed_key().is_none(),
+            "Merkle tree used for verification must be empty"
+        );
+
+        let mut bad_chunks = vec![];
+        let mut unordered_chunks = vec![];
+        for chunk_id in 0..chunk_count {
+            let entries = match self
+                .load_chunk_entries(&snapshots, chunk_id, &mut unordered_chunks)
+                .await?
+            {
+                Ok(entries) => entries,
+                Err(bad_chunk) => {
+                    tracing::warn!("Found bad {bad_chunk}");
+                    bad_chunks.push(bad_chunk);
+                    continue;
+                }
+            };
+            if !bad_chunks.is_empty() || entries.is_empty() {
+                // The tree cannot be rebuilt if there are bad chunks, but we still want to check the remaining chunks.
+                continue;
+            }
+
+            recovery = tokio::task::spawn_blocking(move || {
+                recovery.extend_linear(entries)?;
+                anyhow::Ok(recovery)
+            })
+            .await
+            .context("panicked extending Merkle tree")??;
+            tracing::info!("Verified chunk {chunk_id} / {chunk_count}");
+        }
+
+        let root_hash = bad_chunks.is_empty().then(|| recovery.root_hash());
+        let report = SnapshotVerificationReport {
+            l1_batch_number,
+            bad_chunks,
+            unordered_chunks,
+            root_hash,
+            expected_root_hash,
+        };
+        tracing::info!(
+            "Finished verifying snapshot for L1 batch #{l1_batch_number}; {} bad chunk(s), {} unordered chunk(s), \
+             root hash: {:?}, expected: {expected_root_hash:?}",
+            report.bad_chunks.len(),
+            report.unordered_chunks.len(),
+            report.root_hash
+        );
+        Ok(report)
+    }
+
+    /// Loads the chunk with the specified ID for all snapshots in the chain, checks it and converts it to tree entries.
+    /// Errors accessing the object store that are not caused by bad chunks are propagated. Chunks with unordered logs
+    /// are added to `unordered_chunks`.
+    async fn load_chunk_entries(
+        &self,
+        snapshots: &[SnapshotMetadata],
+        chunk_id: u64,
+        unordered_chunks: &mut Vec<UnorderedChunk>,
+    ) -> anyhow::Result<Result<Vec<TreeEntry>, BadChunk>> {
+        let chunk_count = snapshots[0].storage_logs_filepaths.len() as u64;
+        let key_range = uniform_hashed_keys_chunk(chunk_id, chunk_count);
+        let mut logs_by_key = BTreeMap::new();
+
+        for snapshot in snapshots {
+            let l1_batch_number = snapshot.l1_batch_number;
+            let bad_chunk = |error| BadChunk {
+                l1_batch_number,
+                chunk_id,
+                error,
+            };
+            let logs = match self.load_chunk(snapshot, chunk_id).await {
+                Ok(logs) => logs,
+                Err(err) if err.is_retriable() => {
+                    return Err(anyhow::Error::from(err).context(format!(
+                        "failed loading chunk {chunk_id} for L1 batch #{l1_batch_number}"
+                    )));
+                }
+                Err(err) => return Ok(Err(bad_chunk(err.into()))),
+            };
+            match check_chunk(&logs, snapshot, &key_range) {
+                Ok(true) => {}
+                Ok(false) => {
+                    tracing::warn!(
+                        "Storage logs in chunk {chunk_id} of snapshot for L1 batch #{l1_batch_number} \
+                         are not ordered by increasing key"
+                    );
+                    unordered_chunks.push(UnorderedChunk {
+                        l1_batch_number,
+                        chunk_id,
+                    });
+                }
+                Err(err) => return Ok(Err(bad_chunk(err))),
+            }
+
+            for log in logs {
+                if let Some(prev_log) = logs_by_key.get(&log.key) {
+                    if !is_consistent_update(prev_log, &log) {
+                        let err = anyhow::anyhow!(
+                            "incremental storage log {log:?} is inconsistent with the base log {prev_log:?}"
+                        );
+                        return Ok(Err(bad_chunk(err)));
+                    }
+                }
+                logs_by_key.insert(log.key, log);
+            }
+        }
+
+        let entries = logs_by_key
+            .into_values()
+            .map(|log| TreeEntry::new(h256_to_u256(log.key), log.enumeration_index, log.value))
+            .collect();
+        Ok(Ok(entries))
+    }
+
+    async fn load_chunk(
+        &self,
+        snapshot: &SnapshotMetadata,
+        chunk_id: u64,
+    ) -> Result<Vec<SnapshotStorageLog>, ObjectStoreError> {
+        let key = SnapshotStorageLogsStorageKey {
+            l1_batch_number: snapshot.l1_batch_number,
+            chunk_id,
+        };
+        Ok(match snapshot.version {
+            SnapshotVersion::Version0 => {
+                let chunk: SnapshotStorageLogsChunk<StorageKey> = self.blob_store.get(key).await?;
+                chunk
+                    .storage_logs
+                    .into_iter()
+                    .map(SnapshotStorageLog::<StorageKey>::drop_key_preimage)
+                    .collect()
+            }
+            SnapshotVersion::Version1 | SnapshotVersion::Version2 => {
+                let chunk: SnapshotStorageLogsChunk = self.blob_store.get(key).await?;
+                chunk.storage_logs
+            }
+        })
+    }
+}
+
+/// Checks that the chunk logs are well-formed. Returns whether the logs are ordered by increasing hashed key;
+/// unordered logs don't prevent recovery, so they are not treated as an error.
+fn check_chunk(
+    logs: &[SnapshotStorageLog],
+    snapshot: &SnapshotMetadata,
+    key_range: &ops::RangeInclusive<H256>,
+) -> anyhow::Result<bool> {
+    for log in logs {
+        anyhow::ensure!(
+            key_range.contains(&log.key),
+            "storage log {log:?} is outside the chunk key range {key_range:?}"
+        );
+        anyhow::ensure!(
+            log.enumeration_index > 0,
+            "invalid storage log with zero enumeration_index: {log:?}"
+        );
+        anyhow::ensure!(
+            log.l1_batch_number_of_initial_write <= snapshot.l1_batch_number,
+            "invalid storage log with `l1_batch_number_of_initial_write` from the future: {log:?}"
+        );
+    }
+    let is_ordered = logs.windows(2).all(|window| window[0].key < window[1].key);
+    if !is_ordered {
+        // Ordered logs cannot contain duplicate keys, so this check is only necessary for unordered ones.
+        let mut keys: Vec<_> = logs.iter().map(|log| log.key).collect();
+        keys.sort_unstable();
+        if let Some(window) = keys.windows(2).find(|window| window[0] == window[1]) {
+            anyhow::bail!("duplicate storage logs for hashed key {:?}", window[0]);
+        }
+    }
+    Ok(is_ordered)
+}
+
+fn is_consistent_update(prev_log: &SnapshotStorageLog, log: &SnapshotStorageLog) -> bool {
+    prev_log.enumeration_index == log.enumeration_index
+        && prev_log.l1_batch_number_of_initial_write == log.l1_batch_number_of_initial_write
+}
@@ -18,17 +18,20 @@ use zksync_node_api_server::{
 use zksync_node_framework::{
     implementations::layers::{
         base_token::{
+            aggregated_price_client::AggregatedPriceClientLayer,
             base_token_ratio_persister::BaseTokenRatioPersisterLayer,
             base_token_ratio_provider::BaseTokenRatioProviderLayer,
-            coingecko_client::CoingeckoClientLayer, forced_price_client::ForcedPriceClientLayer,
+            coingecko_client::CoingeckoClientLayer, coinmarketcap_client::CoinMarketCapClientLayer,
+            forced_price_client::ForcedPriceClientLayer,
             no_op_external_price_api_client::NoOpExternalPriceApiClientLayer,
+            uniswap_twap_client::UniswapTwapClientLayer,
         },
         circuit_breaker_checker::CircuitBreakerCheckerLayer,
         commitment_generator::CommitmentGeneratorLayer,
         consensus::MainNodeConsensusLayer,
         contract_verification_api::ContractVerificationApiLayer,
         da_clients::{
-            avail::AvailWiringLayer, no_da::NoDAClientWiringLayer,
+            avail::AvailWiringLayer, multi::MultiDAClientWiringLayer, no_da::NoDAClientWiringLayer,
             object_store::ObjectStorageClientWiringLayer,
         },
         da_dispatcher::DataAvailabilityDispatcherLayer,
@@ -199,8 +202,9 @@ impl MainNodeBuilder {
 
     fn add_object_store_layer(mut self) -> anyhow::Result<Self> {
         let object_store_config = try_load_config!(self.configs.core_object_store);
+        let secrets = self.secrets.object_store.clone();
         self.node
-            .add_layer(ObjectStoreLayer::new(object_store_config));
+            .add_layer(ObjectStoreLayer::new(object_store_config).with_secrets(secrets));
         Ok(self)
     }
 
@@ -310,7 +314,9 @@ impl MainNodeBuilder {
         };
 
         // On main node we always use master pool sink.
-        self.node.add_layer(MasterPoolSinkLayer);
+        self.node.add_layer(MasterPoolSinkLayer::new(
+            rpc_config.tx_replacement_fee_bump_percentage(),
+        ));
         self.node.add_layer(TxSenderLayer::new(
             TxSenderConfig::new(
                 &sk_config,
@@ -448,9 +454,17 @@ impl MainNodeBuilder {
 
     fn add_house_keeper_layer(mut self) -> anyhow::Result<Self> {
         let house_keeper_config = try_load_config!(self.configs.house_keeper_config);
+        let mut layer = HouseKeeperLayer::new(house_keeper_config);
+        let snapshots_object_store_config = self
+            .configs
+            .snapshot_creator
+            .as_ref()
+            .and_then(|config| config.object_store.clone());
+        if let Some(config) = snapshots_object_store_config {
+            layer = layer.with_snapshots_object_store(config, self.secrets.object_store.clone());
+        }
 
-        self.node
-            .add_layer(HouseKeeperLayer::new(house_keeper_config));
+        self.node.add_layer(layer);
 
         Ok(self)
     }
@@ -520,6 +534,11 @@ impl MainNodeBuilder {
                 self.node
                     .add_layer(ObjectStorageClientWiringLayer::new(config));
             }
+
+            (DAClientConfig::Multi(config), secrets) => {
+                self.node
+                    .add_layer(MultiDAClientWiringLayer::new(config, Some(secrets)));
+            }
         }
 
         Ok(self)
@@ -567,6 +586,15 @@ impl MainNodeBuilder {
             ForcedPriceClientLayer::CLIENT_NAME => {
                 self.node.add_layer(ForcedPriceClientLayer::new(config));
             }
+            CoinMarketCapClientLayer::CLIENT_NAME => {
+                self.node.add_layer(CoinMarketCapClientLayer::new(config));
+            }
+            UniswapTwapClientLayer::CLIENT_NAME => {
+                self.node.add_layer(UniswapTwapClientLayer::new(config));
+            }
+            AggregatedPriceClientLayer::CLIENT_NAME => {
+                self.node.add_layer(AggregatedPriceClientLayer::new(config));
+            }
             _ => {
                 anyhow::bail!(
                     "Unknown external price API client source: {}",
@@ -695,6 +723,11 @@ impl MainNodeBuilder {
         for component in &components {
             match component {
                 Component::StateKeeper => {
+                    // The DA client must be wired before the state keeper, so that L1 batches
+                    // are sealed according to the DA blob size limit.
+                    if components.contains(&Component::DADispatcher) {
+                        self = self.add_da_client_layer()?;
+                    }
                     // State keeper is the core component of the sequencer,
                     // which is why we consider it to be responsible for the storage initialization.
                     self = self
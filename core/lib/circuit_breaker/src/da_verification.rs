@@ -0,0 +1,35 @@
+use anyhow::Context as _;
+use zksync_dal::{ConnectionPool, Core, CoreDal};
+
+use crate::{CircuitBreaker, CircuitBreakerError};
+
+/// Trips if a blob posted to the DA layer didn't match the local L1 batch pubdata.
+#[derive(Debug)]
+pub struct DAVerificationChecker {
+    pub pool: ConnectionPool<Core>,
+}
+
+#[async_trait::async_trait]
+impl CircuitBreaker for DAVerificationChecker {
+    fn name(&self) -> &'static str {
+        "da_verification"
+    }
+
+    async fn check(&self) -> Result<(), CircuitBreakerError> {
+        let failed_verification = self
+            .pool
+            .connection_tagged("circuit_breaker")
+            .await?
+            .data_availability_dal()
+            .get_first_failed_da_verification()
+            .await
+            .context("cannot get failed DA verifications")?;
+        if let Some((l1_batch_number, reason)) = failed_verification {
+            return Err(CircuitBreakerError::DAVerificationFailed {
+                l1_batch_number: l1_batch_number.0,
+                reason,
+            });
+        }
+        Ok(())
+    }
+}
@@ -3,6 +3,7 @@ use std::{fmt, sync::Arc, time::Duration};
 use thiserror::Error;
 use tokio::sync::{watch, Mutex};
 
+pub mod da_verification;
 pub mod l1_txs;
 mod metrics;
 pub mod replication_lag;
@@ -33,8 +34,15 @@ impl CircuitBreakers {
 pub enum CircuitBreakerError {
     #[error("System has failed L1 transaction")]
     FailedL1Transaction,
+    #[error("DA blob for L1 batch #{l1_batch_number} doesn't match the local pubdata: {reason}")]
+    DAVerificationFailed {
+        l1_batch_number: u32,
+        reason: String,
+    },
     #[error("Replication lag ({lag:?}) is above the threshold ({threshold:?})")]
     ReplicationLag { lag: Duration, threshold: Duration },
+    #[error("Fetched base token ratio violates guardrails: {0}")]
+    BaseTokenRatioGuardrail(String),
     #[error("Internal error running circuit breaker checks")]
     Internal(#[from] anyhow::Error),
 }
@@ -209,6 +209,12 @@ pub struct Web3JsonRpcConfig {
     pub mempool_cache_update_interval: Option<u64>,
     /// Maximum number of transactions to be stored in the mempool cache. Default is 10000.
     pub mempool_cache_size: Option<usize>,
+    /// Minimum increase (in percent) of `max_fee_per_gas` and `max_priority_fee_per_gas` required for a transaction
+    /// to replace a pending transaction with the same initiator and nonce. Default is 10%.
+    pub tx_replacement_fee_bump_percentage: Option<u32>,
+    /// Maximum number of pending (i.e., not included into an L2 block) transactions per initiator account.
+    /// If not set, the number of pending transactions is only limited by `max_nonce_ahead`.
+    pub max_pending_txs_per_initiator: Option<u32>,
     /// List of L2 token addresses that are white-listed to use by paymasters
     /// (additionally to natively bridged tokens).
     #[serde(default)]
@@ -255,6 +261,8 @@ impl Web3JsonRpcConfig {
             websocket_requests_per_minute_limit: Default::default(),
             mempool_cache_update_interval: Default::default(),
             mempool_cache_size: Default::default(),
+            tx_replacement_fee_bump_percentage: Default::default(),
+            max_pending_txs_per_initiator: Default::default(),
             tree_api_url: None,
             whitelisted_tokens_for_aa: Default::default(),
             api_namespaces: None,
@@ -342,6 +350,10 @@ impl Web3JsonRpcConfig {
     pub fn mempool_cache_size(&self) -> usize {
         self.mempool_cache_size.unwrap_or(10_000)
     }
+
+    pub fn tx_replacement_fee_bump_percentage(&self) -> u32 {
+        self.tx_replacement_fee_bump_percentage.unwrap_or(10)
+    }
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq)]
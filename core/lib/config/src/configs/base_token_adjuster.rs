@@ -44,6 +44,9 @@ const DEFAULT_MAX_ACCEPTABLE_PRIORITY_FEE_IN_GWEI: u64 = 100_000_000_000;
 /// Default value for halting on error
 const DEFAULT_HALT_ON_ERROR: bool = false;
 
+/// Default number of fetched ratios required to confirm a ratio change exceeding the change limit
+const DEFAULT_RATIO_CHANGE_CONFIRMATIONS: u32 = 5;
+
 #[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct BaseTokenAdjusterConfig {
     /// How often to spark a new cycle of the ratio persister to fetch external prices and persis ratios.
@@ -100,6 +103,32 @@ pub struct BaseTokenAdjusterConfig {
     /// the server process if an external api is not available or if L1 is congested.
     #[serde(default = "BaseTokenAdjusterConfig::default_halt_on_error")]
     pub halt_on_error: bool,
+
+    /// Maximum change of the ratio (in percent) compared to the previously persisted ratio. A fetched ratio changing more
+    /// is considered invalid, and the previous ratio is held instead, unless the change is confirmed by
+    /// `ratio_change_confirmations` subsequent fetched ratios (i.e., ones within this limit from the first rejected ratio).
+    /// If not set, the change is not limited.
+    #[serde(default)]
+    pub max_ratio_change_percentage: Option<u32>,
+
+    /// Number of consecutive fetched ratios required to confirm a ratio change exceeding `max_ratio_change_percentage`.
+    /// If set to 0, such changes are never accepted automatically; the operator must persist the new ratio manually
+    /// or raise the change limit.
+    #[serde(default = "BaseTokenAdjusterConfig::default_ratio_change_confirmations")]
+    pub ratio_change_confirmations: u32,
+
+    /// Maximum age of a fetched ratio (i.e., of the price quote it's based on). Stale ratios are considered invalid,
+    /// and the previous ratio is held instead. If not set, the age is not limited.
+    ///
+    /// Only has effect for price sources reporting the quote timestamp (CoinMarketCap). Other sources (CoinGecko,
+    /// Uniswap TWAP, forced prices) timestamp ratios when they are fetched, so they are never considered stale.
+    #[serde(default)]
+    pub max_ratio_staleness_ms: Option<u64>,
+
+    /// Defines whether the server should be halted (via a circuit breaker) if a fetched ratio violates
+    /// the configured change or staleness limits.
+    #[serde(default)]
+    pub halt_on_guardrail_violation: bool,
 }
 
 impl Default for BaseTokenAdjusterConfig {
@@ -119,6 +148,10 @@ impl Default for BaseTokenAdjusterConfig {
             price_fetching_sleep_ms: Self::default_price_fetching_sleep_ms(),
             price_fetching_max_attempts: Self::default_price_fetching_max_attempts(),
             halt_on_error: Self::default_halt_on_error(),
+            max_ratio_change_percentage: None,
+            ratio_change_confirmations: Self::default_ratio_change_confirmations(),
+            max_ratio_staleness_ms: None,
+            halt_on_guardrail_violation: false,
         }
     }
 }
@@ -148,6 +181,10 @@ impl BaseTokenAdjusterConfig {
         DEFAULT_HALT_ON_ERROR
     }
 
+    pub fn default_ratio_change_confirmations() -> u32 {
+        DEFAULT_RATIO_CHANGE_CONFIRMATIONS
+    }
+
     pub fn price_cache_update_interval(&self) -> Duration {
         Duration::from_millis(self.price_cache_update_interval_ms)
     }
@@ -164,6 +201,10 @@ impl BaseTokenAdjusterConfig {
         Duration::from_millis(self.price_fetching_sleep_ms)
     }
 
+    pub fn max_ratio_staleness(&self) -> Option<Duration> {
+        self.max_ratio_staleness_ms.map(Duration::from_millis)
+    }
+
     pub fn default_l1_receipt_checking_max_attempts() -> u32 {
         DEFAULT_L1_RECEIPT_CHECKING_MAX_ATTEMPTS
     }
@@ -47,6 +47,17 @@ impl Default for FeeModelVersion {
     }
 }
 
+/// Policy used to order L2 transactions in the mempool.
+///  - `Fifo`, transactions are ordered by the time they were received by the node.
+///  - `PriorityFee`, transactions with a higher priority fee per gas are included first. Transactions
+///    of the same account are still included in the nonce order.
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq, Eq)]
+pub enum MempoolOrdering {
+    #[default]
+    Fifo,
+    PriorityFee,
+}
+
 #[derive(Debug, Deserialize, Clone, PartialEq, Default)]
 pub struct StateKeeperConfig {
     /// The max number of slots for txs in a block before it should be sealed by the slots sealer.
@@ -132,6 +143,10 @@ pub struct StateKeeperConfig {
     #[serde(default)]
     pub protective_reads_persistence_enabled: bool,
 
+    /// Policy used to order L2 transactions in the mempool.
+    #[serde(default)]
+    pub mempool_ordering: MempoolOrdering,
+
     // Base system contract hashes, required only for generating genesis config.
     // #PLA-811
     #[deprecated(note = "Use GenesisConfig::bootloader_hash instead")]
@@ -178,6 +193,7 @@ impl StateKeeperConfig {
             save_call_traces: true,
             max_circuits_per_batch: 24100,
             protective_reads_persistence_enabled: true,
+            mempool_ordering: MempoolOrdering::Fifo,
             bootloader_hash: None,
             default_aa_hash: None,
             evm_emulator_hash: None,
@@ -1,12 +1,15 @@
 use crate::{AvailConfig, ObjectStoreConfig};
 
 pub mod avail;
+pub mod multi;
 
 pub const AVAIL_CLIENT_CONFIG_NAME: &str = "Avail";
 pub const OBJECT_STORE_CLIENT_CONFIG_NAME: &str = "ObjectStore";
+pub const MULTI_CLIENT_CONFIG_NAME: &str = "Multi";
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum DAClientConfig {
     Avail(AvailConfig),
     ObjectStore(ObjectStoreConfig),
+    Multi(multi::MultiDAClientConfig),
 }
@@ -0,0 +1,11 @@
+use crate::configs::da_client::DAClientConfig;
+
+/// Configuration of the DA client that dispatches every blob to several DA layers.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MultiDAClientConfig {
+    /// Configurations of the underlying DA clients. Nested multi-DA clients are not supported.
+    pub clients: Vec<DAClientConfig>,
+    /// Minimum number of the underlying DA clients that must provide inclusion data for a blob
+    /// to consider it included.
+    pub inclusion_quorum: usize,
+}
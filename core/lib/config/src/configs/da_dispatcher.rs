@@ -6,6 +6,7 @@ pub const DEFAULT_POLLING_INTERVAL_MS: u32 = 5000;
 pub const DEFAULT_MAX_ROWS_TO_DISPATCH: u32 = 100;
 pub const DEFAULT_MAX_RETRIES: u16 = 5;
 pub const DEFAULT_USE_DUMMY_INCLUSION_DATA: bool = false;
+pub const DEFAULT_VERIFICATION_MAX_ATTEMPTS: u32 = 5;
 
 #[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct DADispatcherConfig {
@@ -15,10 +16,22 @@ pub struct DADispatcherConfig {
     pub max_rows_to_dispatch: Option<u32>,
     /// The maximum number of retries for the dispatch of a blob.
     pub max_retries: Option<u16>,
-    /// Use dummy value as inclusion proof instead of getting it from the client.
+    /// Use dummy (empty) value as inclusion proof instead of getting it from the client. If not set or set
+    /// to `false`, the dispatcher waits for the inclusion data to be provided by the DA client before the batch
+    /// can be committed.
+    ///
+    /// **Important.** Previously, this flag had the inverted effect: inclusion data was only fetched from the client
+    /// if it was set to `true`. Configs setting it to `true` in order to fetch real inclusion data must remove it.
     // TODO: run a verification task to check if the L1 contract expects the inclusion proofs to
     // avoid the scenario where contracts expect real proofs, and server is using dummy proofs.
     pub use_dummy_inclusion_data: Option<bool>,
+    /// The interval between the iterations of the verifier re-fetching dispatched blobs from the DA layer
+    /// and comparing them with the local pubdata. If not set, the verifier is not run. Must not be set
+    /// for DA clients that don't store blob contents (e.g., the no-DA client).
+    pub verification_interval_ms: Option<u32>,
+    /// The maximum number of verifier iterations a blob may be missing on the DA layer (or fail to be fetched
+    /// because of a non-retriable error) before it is flagged as mismatched.
+    pub verification_max_attempts: Option<u32>,
 }
 
 impl DADispatcherConfig {
@@ -28,6 +41,8 @@ impl DADispatcherConfig {
             max_rows_to_dispatch: Some(DEFAULT_MAX_ROWS_TO_DISPATCH),
             max_retries: Some(DEFAULT_MAX_RETRIES),
             use_dummy_inclusion_data: Some(DEFAULT_USE_DUMMY_INCLUSION_DATA),
+            verification_interval_ms: None,
+            verification_max_attempts: Some(DEFAULT_VERIFICATION_MAX_ATTEMPTS),
         }
     }
 
@@ -51,4 +66,14 @@ impl DADispatcherConfig {
         self.use_dummy_inclusion_data
             .unwrap_or(DEFAULT_USE_DUMMY_INCLUSION_DATA)
     }
+
+    pub fn verification_interval(&self) -> Option<Duration> {
+        self.verification_interval_ms
+            .map(|interval| Duration::from_millis(interval.into()))
+    }
+
+    pub fn verification_max_attempts(&self) -> u32 {
+        self.verification_max_attempts
+            .unwrap_or(DEFAULT_VERIFICATION_MAX_ATTEMPTS)
+    }
 }
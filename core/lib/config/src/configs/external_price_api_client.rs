@@ -1,8 +1,11 @@
 use std::time::Duration;
 
 use serde::Deserialize;
+use zksync_basic_types::Address;
 
 pub const DEFAULT_TIMEOUT_MS: u64 = 10_000;
+pub const DEFAULT_MAX_DEVIATION_PERCENT: f64 = 5.0;
+pub const DEFAULT_MIN_SOURCES: usize = 2;
 
 #[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct ForcedPriceClientConfig {
@@ -24,6 +27,11 @@ pub struct ExternalPriceApiClientConfig {
     #[serde(default = "ExternalPriceApiClientConfig::default_timeout")]
     pub client_timeout_ms: u64,
     pub forced: Option<ForcedPriceClientConfig>,
+    /// Configuration of the Uniswap TWAP price client. Required if the Uniswap TWAP client is used.
+    pub uniswap_twap: Option<UniswapTwapClientConfig>,
+    /// Configuration of the client aggregating prices from multiple sources. Required if the aggregating
+    /// client is used.
+    pub aggregated: Option<AggregatedPriceClientConfig>,
 }
 
 impl ExternalPriceApiClientConfig {
@@ -35,3 +43,49 @@ impl ExternalPriceApiClientConfig {
         Duration::from_millis(self.client_timeout_ms)
     }
 }
+
+/// Configuration of the client reading a time-weighted average price from a Uniswap V3-compatible pool on L1.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct UniswapTwapClientConfig {
+    /// Address of the pool trading the base token against wrapped ETH.
+    pub pool_address: Address,
+    /// Address of the wrapped ETH token on L1. The pool must have this token as one of its tokens.
+    pub weth_address: Address,
+    /// Interval to average the price over.
+    #[serde(default = "UniswapTwapClientConfig::default_twap_interval_sec")]
+    pub twap_interval_sec: u32,
+}
+
+impl UniswapTwapClientConfig {
+    const fn default_twap_interval_sec() -> u32 {
+        1_800
+    }
+
+    pub fn twap_interval(&self) -> Duration {
+        Duration::from_secs(self.twap_interval_sec.into())
+    }
+}
+
+/// Configuration of the client aggregating prices from multiple sources.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct AggregatedPriceClientConfig {
+    /// Configurations of the price sources. Nested aggregating clients are not supported.
+    pub sources: Vec<ExternalPriceApiClientConfig>,
+    /// Maximum deviation of a price from the median of all fetched prices, in percent. Prices deviating more
+    /// are considered outliers and are ignored.
+    #[serde(default = "AggregatedPriceClientConfig::default_max_deviation_percent")]
+    pub max_deviation_percent: f64,
+    /// Minimum number of sources that must provide a non-outlier price.
+    #[serde(default = "AggregatedPriceClientConfig::default_min_sources")]
+    pub min_sources: usize,
+}
+
+impl AggregatedPriceClientConfig {
+    const fn default_max_deviation_percent() -> f64 {
+        DEFAULT_MAX_DEVIATION_PERCENT
+    }
+
+    const fn default_min_sources() -> usize {
+        DEFAULT_MIN_SOURCES
+    }
+}
@@ -1,7 +1,26 @@
+use std::time::Duration;
+
 use serde::Deserialize;
 
 /// Configuration for the house keeper.
 #[derive(Debug, Deserialize, Clone, PartialEq)]
 pub struct HouseKeeperConfig {
     pub l1_batch_metrics_reporting_interval_ms: u64,
+    /// Number of L1 batches before the last executed one for which witness inputs, proofs and snapshot objects
+    /// are kept in the object store. Objects for older L1 batches are periodically removed. Snapshot objects are removed
+    /// from the snapshot creator object store, and only if it is configured. If not specified, objects are never removed.
+    pub object_store_retention_l1_batches: Option<u32>,
+    /// Interval between object store retention runs. Only used if `object_store_retention_l1_batches` is set.
+    pub object_store_retention_interval_ms: Option<u64>,
+}
+
+impl HouseKeeperConfig {
+    const DEFAULT_OBJECT_STORE_RETENTION_INTERVAL: Duration = Duration::from_secs(3_600);
+
+    pub fn object_store_retention_interval(&self) -> Duration {
+        self.object_store_retention_interval_ms.map_or(
+            Self::DEFAULT_OBJECT_STORE_RETENTION_INTERVAL,
+            Duration::from_millis,
+        )
+    }
 }
@@ -16,6 +16,13 @@ pub struct ObjectStoreConfig {
     /// **Important.** Mirroring logic assumes that objects in the underlying store are immutable. If this is not the case,
     /// the mirrored objects may become stale.
     pub local_mirror_path: Option<String>,
+    /// If set, objects are compressed with zstd using the specified compression level, and are prepended with
+    /// a header containing a checksum that is verified on reads. Objects stored without compression are still readable.
+    ///
+    /// **Important.** All readers of the store must have compression enabled as well; otherwise, they will receive
+    /// compressed objects with the header.
+    #[serde(default)]
+    pub zstd_compression_level: Option<i32>,
 }
 
 impl ObjectStoreConfig {
@@ -40,4 +47,18 @@ pub enum ObjectStoreMode {
     FileBacked {
         file_backed_base_path: String,
     },
+    /// S3-compatible store (AWS S3, MinIO, Cloudflare R2 etc.) accessed via path-style URLs.
+    S3 {
+        /// Base URL of the S3 API, e.g. `https://s3.us-east-1.amazonaws.com` or `http://localhost:9000`.
+        endpoint: String,
+        bucket: String,
+        region: String,
+        /// Access key ID. If not specified, it is taken from the `AWS_ACCESS_KEY_ID` env variable.
+        /// The secret access key is specified in [`ObjectStoreSecrets`](crate::configs::secrets::ObjectStoreSecrets).
+        access_key_id: Option<String>,
+        /// Timeout for a single HTTP request to the S3 API. Timed out requests are retried according to `max_retries`.
+        /// If not specified, a default timeout of 60 seconds is used.
+        #[serde(default)]
+        request_timeout_ms: Option<u64>,
+    },
 }
@@ -1,4 +1,7 @@
+use std::fmt;
+
 use anyhow::Context;
+use serde::Deserialize;
 use zksync_basic_types::url::SensitiveUrl;
 
 use crate::configs::{consensus::ConsensusSecrets, da_client::avail::AvailSecrets};
@@ -20,12 +23,31 @@ pub enum DataAvailabilitySecrets {
     Avail(AvailSecrets),
 }
 
+/// Secret access key for the S3 object store. Redacted in the `Debug` output.
+#[derive(Clone, PartialEq, Deserialize)]
+#[serde(transparent)]
+pub struct S3SecretAccessKey(pub String);
+
+impl fmt::Debug for S3SecretAccessKey {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("[REDACTED]")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ObjectStoreSecrets {
+    /// Secret access key for S3 object stores. If not specified, it is taken from the `AWS_SECRET_ACCESS_KEY`
+    /// env variable.
+    pub s3_secret_access_key: Option<S3SecretAccessKey>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Secrets {
     pub consensus: Option<ConsensusSecrets>,
     pub database: Option<DatabaseSecrets>,
     pub l1: Option<L1Secrets>,
     pub data_availability: Option<DataAvailabilitySecrets>,
+    pub object_store: Option<ObjectStoreSecrets>,
 }
 
 impl DatabaseSecrets {
@@ -42,6 +42,9 @@ pub struct SnapshotRecoveryConfig {
     /// This is a temporary flag that will eventually be removed together with version 0 snapshot support.
     #[serde(default)]
     pub drop_storage_key_preimages: bool,
+    /// Path to a local snapshot bundle directory (e.g., exported by the snapshot creator). If specified, the snapshot
+    /// is recovered from the bundle instead of the main node and the snapshot object store.
+    pub bundle_path: Option<String>,
     pub tree: TreeRecoveryConfig,
     pub postgres: PostgresRecoveryConfig,
     pub object_store: Option<ObjectStoreConfig>,
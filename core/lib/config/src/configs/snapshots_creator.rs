@@ -6,6 +6,12 @@ use crate::ObjectStoreConfig;
 #[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct SnapshotsCreatorConfig {
     /// Version of snapshots to create.
+    ///
+    /// Version 2 corresponds to incremental snapshots; they are only created if explicitly requested by setting
+    /// this version, since nodes running older software cannot recover from them. Incremental snapshots are created
+    /// on top of the newest complete snapshot preceding the snapshot L1 batch. If there is no such snapshot, it has
+    /// version 0, or its chain already has `max_incremental_chain_length` increments, a full version 1 snapshot
+    /// is created instead.
     // Raw integer version is used because `SnapshotVersion` is defined in `zksync_types` crate.
     #[serde(default)]
     pub version: u16,
@@ -16,6 +22,11 @@ pub struct SnapshotsCreatorConfig {
     /// - If a snapshot with this L1 batch exists and is incomplete, the creator will continue creating it,
     ///   regardless of whether the specified snapshot `version` matches.
     pub l1_batch_number: Option<L1BatchNumber>,
+    /// Maximum number of incremental snapshots on top of a full snapshot. Once the chain reaches this length,
+    /// the next snapshot is full, so that recovery doesn't need to apply an unbounded number of increments.
+    /// Only has effect if `version` is 2; if set to 0, incremental snapshots are never created.
+    #[serde(default = "SnapshotsCreatorConfig::max_incremental_chain_length_default")]
+    pub max_incremental_chain_length: u32,
     #[serde(default = "SnapshotsCreatorConfig::storage_logs_chunk_size_default")]
     pub storage_logs_chunk_size: u64,
     #[serde(default = "SnapshotsCreatorConfig::concurrent_queries_count")]
@@ -31,4 +42,8 @@ impl SnapshotsCreatorConfig {
     const fn concurrent_queries_count() -> u32 {
         25
     }
+
+    pub const fn max_incremental_chain_length_default() -> u32 {
+        10
+    }
 }
@@ -16,7 +16,9 @@ use zksync_crypto_primitives::K256PrivateKey;
 
 use crate::{
     configs::{
-        self, da_client::DAClientConfig::Avail, eth_sender::PubdataSendingMode,
+        self,
+        da_client::DAClientConfig::{Avail, Multi, ObjectStore},
+        eth_sender::PubdataSendingMode,
         external_price_api_client::ForcedPriceClientConfig,
     },
     AvailConfig,
@@ -52,6 +54,16 @@ impl Distribution<configs::chain::FeeModelVersion> for EncodeDist {
     }
 }
 
+impl Distribution<configs::chain::MempoolOrdering> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::chain::MempoolOrdering {
+        type T = configs::chain::MempoolOrdering;
+        match rng.gen_range(0..2) {
+            0 => T::Fifo,
+            _ => T::PriorityFee,
+        }
+    }
+}
+
 impl Distribution<configs::ApiConfig> for EncodeDist {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::ApiConfig {
         configs::ApiConfig {
@@ -105,6 +117,8 @@ impl Distribution<configs::api::Web3JsonRpcConfig> for EncodeDist {
             tree_api_url: self.sample(rng),
             mempool_cache_update_interval: self.sample(rng),
             mempool_cache_size: self.sample(rng),
+            tx_replacement_fee_bump_percentage: self.sample(rng),
+            max_pending_txs_per_initiator: self.sample(rng),
             whitelisted_tokens_for_aa: self.sample_range(rng).map(|_| rng.gen()).collect(),
             api_namespaces: self
                 .sample_opt(|| self.sample_range(rng).map(|_| self.sample(rng)).collect()),
@@ -188,6 +202,7 @@ impl Distribution<configs::chain::StateKeeperConfig> for EncodeDist {
             save_call_traces: self.sample(rng),
             max_circuits_per_batch: self.sample(rng),
             protective_reads_persistence_enabled: self.sample(rng),
+            mempool_ordering: self.sample(rng),
             // These values are not involved into files serialization skip them
             fee_account_addr: None,
             bootloader_hash: None,
@@ -639,6 +654,8 @@ impl Distribution<configs::house_keeper::HouseKeeperConfig> for EncodeDist {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::house_keeper::HouseKeeperConfig {
         configs::house_keeper::HouseKeeperConfig {
             l1_batch_metrics_reporting_interval_ms: self.sample(rng),
+            object_store_retention_l1_batches: self.sample(rng),
+            object_store_retention_interval_ms: self.sample(rng),
         }
     }
 }
@@ -646,7 +663,7 @@ impl Distribution<configs::house_keeper::HouseKeeperConfig> for EncodeDist {
 impl Distribution<configs::object_store::ObjectStoreMode> for EncodeDist {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::object_store::ObjectStoreMode {
         type T = configs::object_store::ObjectStoreMode;
-        match rng.gen_range(0..4) {
+        match rng.gen_range(0..5) {
             0 => T::GCS {
                 bucket_base_url: self.sample(rng),
             },
@@ -657,6 +674,13 @@ impl Distribution<configs::object_store::ObjectStoreMode> for EncodeDist {
             2 => T::FileBacked {
                 file_backed_base_path: self.sample(rng),
             },
+            3 => T::S3 {
+                endpoint: self.sample(rng),
+                bucket: self.sample(rng),
+                region: self.sample(rng),
+                access_key_id: self.sample(rng),
+                request_timeout_ms: self.sample(rng),
+            },
             _ => T::GCSAnonymousReadOnly {
                 bucket_base_url: self.sample(rng),
             },
@@ -670,6 +694,7 @@ impl Distribution<configs::ObjectStoreConfig> for EncodeDist {
             mode: self.sample(rng),
             max_retries: self.sample(rng),
             local_mirror_path: self.sample(rng),
+            zstd_compression_level: self.sample(rng),
         }
     }
 }
@@ -689,6 +714,7 @@ impl Distribution<configs::SnapshotsCreatorConfig> for EncodeDist {
         configs::SnapshotsCreatorConfig {
             l1_batch_number: self.sample_opt(|| L1BatchNumber(rng.gen())),
             version: if rng.gen() { 0 } else { 1 },
+            max_incremental_chain_length: self.sample(rng),
             storage_logs_chunk_size: self.sample(rng),
             concurrent_queries_count: self.sample(rng),
             object_store: self.sample(rng),
@@ -871,6 +897,16 @@ impl Distribution<configs::secrets::Secrets> for EncodeDist {
             database: self.sample_opt(|| self.sample(rng)),
             l1: self.sample_opt(|| self.sample(rng)),
             data_availability: self.sample_opt(|| self.sample(rng)),
+            object_store: self.sample_opt(|| self.sample(rng)),
+        }
+    }
+}
+
+impl Distribution<configs::secrets::ObjectStoreSecrets> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::secrets::ObjectStoreSecrets {
+        configs::secrets::ObjectStoreSecrets {
+            s3_secret_access_key: self
+                .sample_opt(|| configs::secrets::S3SecretAccessKey(self.sample(rng))),
         }
     }
 }
@@ -941,15 +977,27 @@ impl Distribution<configs::en_config::ENConfig> for EncodeDist {
     }
 }
 
-impl Distribution<configs::da_client::DAClientConfig> for EncodeDist {
-    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::da_client::DAClientConfig {
-        Avail(AvailConfig {
+impl Distribution<AvailConfig> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> AvailConfig {
+        AvailConfig {
             api_node_url: self.sample(rng),
             bridge_api_url: self.sample(rng),
             app_id: self.sample(rng),
             timeout: self.sample(rng),
             max_retries: self.sample(rng),
-        })
+        }
+    }
+}
+
+impl Distribution<configs::da_client::DAClientConfig> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::da_client::DAClientConfig {
+        match rng.gen_range(0..2) {
+            0 => Avail(self.sample(rng)),
+            _ => Multi(configs::da_client::multi::MultiDAClientConfig {
+                clients: vec![Avail(self.sample(rng)), ObjectStore(self.sample(rng))],
+                inclusion_quorum: self.sample(rng),
+            }),
+        }
     }
 }
 
@@ -968,6 +1016,8 @@ impl Distribution<configs::da_dispatcher::DADispatcherConfig> for EncodeDist {
             max_rows_to_dispatch: self.sample(rng),
             max_retries: self.sample(rng),
             use_dummy_inclusion_data: self.sample(rng),
+            verification_interval_ms: self.sample(rng),
+            verification_max_attempts: self.sample(rng),
         }
     }
 }
@@ -1036,10 +1086,14 @@ impl Distribution<configs::snapshot_recovery::SnapshotRecoveryConfig> for Encode
     ) -> configs::snapshot_recovery::SnapshotRecoveryConfig {
         use configs::snapshot_recovery::{SnapshotRecoveryConfig, TreeRecoveryConfig};
         let tree: TreeRecoveryConfig = self.sample(rng);
+        let bundle_path: Option<String> = self.sample_opt(|| self.sample(rng));
+        // Experimental params are only persisted if the tree config or the bundle path are set.
+        let has_experimental = tree != TreeRecoveryConfig::default() || bundle_path.is_some();
         SnapshotRecoveryConfig {
             enabled: self.sample(rng),
             l1_batch: self.sample_opt(|| L1BatchNumber(rng.gen())),
-            drop_storage_key_preimages: (tree != TreeRecoveryConfig::default()) && self.sample(rng),
+            drop_storage_key_preimages: has_experimental && self.sample(rng),
+            bundle_path,
             tree,
             postgres: self.sample(rng),
             object_store: self.sample(rng),
@@ -1077,6 +1131,10 @@ impl Distribution<configs::base_token_adjuster::BaseTokenAdjusterConfig> for Enc
             price_fetching_max_attempts: self.sample(rng),
             price_fetching_sleep_ms: self.sample(rng),
             halt_on_error: self.sample(rng),
+            max_ratio_change_percentage: self.sample(rng),
+            ratio_change_confirmations: self.sample(rng),
+            max_ratio_staleness_ms: self.sample(rng),
+            halt_on_guardrail_violation: self.sample(rng),
         }
     }
 }
@@ -1100,16 +1158,44 @@ impl Distribution<configs::external_price_api_client::ExternalPriceApiClientConf
         rng: &mut R,
     ) -> configs::external_price_api_client::ExternalPriceApiClientConfig {
         configs::external_price_api_client::ExternalPriceApiClientConfig {
-            source: self.sample(rng),
-            base_url: self.sample(rng),
-            api_key: self.sample(rng),
-            client_timeout_ms: self.sample(rng),
-            forced: Some(ForcedPriceClientConfig {
-                numerator: self.sample(rng),
-                denominator: self.sample(rng),
-                fluctuation: self.sample(rng),
+            aggregated: self.sample_opt(|| {
+                configs::external_price_api_client::AggregatedPriceClientConfig {
+                    // Nested aggregated clients are not supported.
+                    sources: vec![
+                        sample_price_source(self, rng),
+                        sample_price_source(self, rng),
+                    ],
+                    max_deviation_percent: self.sample(rng),
+                    min_sources: self.sample(rng),
+                }
             }),
-        }
+            ..sample_price_source(self, rng)
+        }
+    }
+}
+
+fn sample_price_source<R: Rng + ?Sized>(
+    dist: &EncodeDist,
+    rng: &mut R,
+) -> configs::external_price_api_client::ExternalPriceApiClientConfig {
+    configs::external_price_api_client::ExternalPriceApiClientConfig {
+        source: dist.sample(rng),
+        base_url: dist.sample(rng),
+        api_key: dist.sample(rng),
+        client_timeout_ms: dist.sample(rng),
+        forced: Some(ForcedPriceClientConfig {
+            numerator: dist.sample(rng),
+            denominator: dist.sample(rng),
+            fluctuation: dist.sample(rng),
+        }),
+        uniswap_twap: dist.sample_opt(|| {
+            configs::external_price_api_client::UniswapTwapClientConfig {
+                pool_address: rng.gen(),
+                weth_address: rng.gen(),
+                twap_interval_sec: dist.sample(rng),
+            }
+        }),
+        aggregated: None,
     }
 }
 
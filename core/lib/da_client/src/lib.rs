@@ -15,9 +15,25 @@ pub trait DataAvailabilityClient: Sync + Send + fmt::Debug {
         data: Vec<u8>,
     ) -> Result<DispatchResponse, DAError>;
 
+    /// Dispatches a chunk of the L1 batch pubdata to the data availability layer. Used if the pubdata
+    /// exceeds [`Self::blob_size_limit()`]. Clients that identify blobs by the L1 batch number
+    /// must override this method so that chunks of the same batch don't collide.
+    async fn dispatch_blob_chunk(
+        &self,
+        batch_number: u32,
+        _chunk_index: usize,
+        data: Vec<u8>,
+    ) -> Result<DispatchResponse, DAError> {
+        self.dispatch_blob(batch_number, data).await
+    }
+
     /// Fetches the inclusion data for a given blob_id.
     async fn get_inclusion_data(&self, blob_id: &str) -> Result<Option<InclusionData>, DAError>;
 
+    /// Fetches the blob contents for a given blob_id. Returns `None` if the blob is not found or the
+    /// DA layer doesn't store the blob contents.
+    async fn get_blob(&self, blob_id: &str) -> Result<Option<Vec<u8>>, DAError>;
+
     /// Clones the client and wraps it in a Box.
     fn clone_boxed(&self) -> Box<dyn DataAvailabilityClient>;
 
@@ -0,0 +1,16 @@
+-- Blobs for the L1 batches which pubdata doesn't fit into a single blob of the DA layer.
+-- The `data_availability` row for such a batch is only inserted after all chunks are dispatched,
+-- and its inclusion data is an aggregate of the inclusion data of all chunks.
+CREATE TABLE IF NOT EXISTS data_availability_chunks
+(
+    l1_batch_number BIGINT    NOT NULL REFERENCES l1_batches (number) ON DELETE CASCADE,
+    chunk_index     INT       NOT NULL,
+
+    blob_id         TEXT      NOT NULL,
+    inclusion_data  BYTEA,
+    sent_at         TIMESTAMP NOT NULL,
+
+    created_at      TIMESTAMP NOT NULL,
+    updated_at      TIMESTAMP NOT NULL,
+    PRIMARY KEY (l1_batch_number, chunk_index)
+);
@@ -0,0 +1,7 @@
+DROP INDEX IF EXISTS data_availability_awaiting_verification_idx;
+
+ALTER TABLE data_availability
+    DROP COLUMN IF EXISTS verified_at,
+    DROP COLUMN IF EXISTS verification_error,
+    DROP COLUMN IF EXISTS verification_attempts,
+    DROP COLUMN IF EXISTS local_pubdata_missing;
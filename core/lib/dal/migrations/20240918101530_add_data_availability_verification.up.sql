@@ -0,0 +1,13 @@
+-- Result of re-fetching the dispatched blob from the DA layer and comparing it with the local pubdata.
+-- `verification_error` is NULL if the blob matches the pubdata.
+ALTER TABLE data_availability
+    ADD COLUMN IF NOT EXISTS verified_at TIMESTAMP,
+    ADD COLUMN IF NOT EXISTS verification_error TEXT,
+    -- Number of verifier iterations in which the blob couldn't be fetched from the DA layer.
+    ADD COLUMN IF NOT EXISTS verification_attempts INT NOT NULL DEFAULT 0,
+    -- Set if the dispatched blob couldn't be checked because the local pubdata is not available (e.g., it was pruned).
+    -- Such blobs are not considered verified, but aren't re-checked either.
+    ADD COLUMN IF NOT EXISTS local_pubdata_missing BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE INDEX IF NOT EXISTS data_availability_awaiting_verification_idx
+    ON data_availability (l1_batch_number) WHERE verified_at IS NULL;
@@ -0,0 +1,2 @@
+DROP TRIGGER IF EXISTS notify_l2_block_inserted ON miniblocks;
+DROP FUNCTION IF EXISTS notify_l2_block_inserted;
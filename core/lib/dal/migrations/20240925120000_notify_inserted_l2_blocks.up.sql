@@ -0,0 +1,12 @@
+-- Notifies listeners (e.g., pub-sub notifiers in the API server) about inserted L2 blocks.
+-- The payload is the number of the inserted L2 block. Notifications are delivered once the inserting transaction commits.
+CREATE OR REPLACE FUNCTION notify_l2_block_inserted() RETURNS TRIGGER AS $$
+BEGIN
+    PERFORM pg_notify('l2_block_inserted', NEW.number::TEXT);
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER notify_l2_block_inserted
+    AFTER INSERT ON miniblocks
+    FOR EACH ROW EXECUTE FUNCTION notify_l2_block_inserted();
@@ -0,0 +1,18 @@
+-- Journal of block reverts performed by the block reverter. Allows to resume a revert that was interrupted midway.
+CREATE TABLE IF NOT EXISTS block_reverts (
+    id SERIAL PRIMARY KEY,
+    -- Last L1 batch retained after the revert.
+    l1_batch_number BIGINT NOT NULL,
+    -- Names of the revert steps enabled for the revert. A revert can only be resumed with the same set of steps.
+    enabled_steps TEXT[] NOT NULL,
+    -- Names of the revert steps that were completed.
+    completed_steps TEXT[] NOT NULL,
+    created_at TIMESTAMP NOT NULL,
+    updated_at TIMESTAMP NOT NULL,
+    finished_at TIMESTAMP
+);
+
+-- Ensures that there's at most one unfinished revert.
+CREATE UNIQUE INDEX IF NOT EXISTS block_reverts_unfinished_idx
+    ON block_reverts ((finished_at IS NULL))
+    WHERE finished_at IS NULL;
@@ -0,0 +1,3 @@
+-- L1 batch number of the base snapshot for incremental snapshots; `NULL` for full snapshots.
+ALTER TABLE snapshots
+    ADD COLUMN IF NOT EXISTS base_l1_batch_number BIGINT;
@@ -0,0 +1,4 @@
+ALTER TABLE miniblocks
+    DROP COLUMN IF EXISTS gas_used,
+    DROP COLUMN IF EXISTS priority_fees_per_gas,
+    DROP COLUMN IF EXISTS priority_fees_gas_used;
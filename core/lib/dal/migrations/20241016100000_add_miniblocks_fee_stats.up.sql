@@ -0,0 +1,7 @@
+-- Fee statistics of L2 blocks used by `eth_feeHistory` and `eth_maxPriorityFeePerGas`. NULL for blocks sealed before the columns were added.
+-- Priority fees are the ones actually charged from L2 transactions, aggregated by value and sorted in the ascending order;
+-- `priority_fees_gas_used` contains gas used by transactions paying the corresponding fee.
+ALTER TABLE miniblocks
+    ADD COLUMN IF NOT EXISTS gas_used BIGINT,
+    ADD COLUMN IF NOT EXISTS priority_fees_per_gas NUMERIC(80)[],
+    ADD COLUMN IF NOT EXISTS priority_fees_gas_used BIGINT[];
@@ -0,0 +1,169 @@
+use zksync_db_connection::{connection::Connection, error::DalResult, instrument::InstrumentExt};
+use zksync_types::L1BatchNumber;
+
+use crate::Core;
+
+/// Revert that was started by the block reverter, but wasn't finished (e.g., because the reverter has crashed midway).
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnfinishedBlockRevert {
+    /// Last L1 batch retained after the revert.
+    pub l1_batch_number: L1BatchNumber,
+    /// Names of revert steps enabled for the revert.
+    pub enabled_steps: Vec<String>,
+    /// Names of completed revert steps, in the order of completion.
+    pub completed_steps: Vec<String>,
+}
+
+/// DAL for the journal of block reverts.
+#[derive(Debug)]
+pub struct BlockRevertsDal<'a, 'c> {
+    pub(crate) storage: &'a mut Connection<'c, Core>,
+}
+
+impl BlockRevertsDal<'_, '_> {
+    /// Returns the unfinished revert, if any.
+    pub async fn get_unfinished_revert(&mut self) -> DalResult<Option<UnfinishedBlockRevert>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                l1_batch_number,
+                enabled_steps,
+                completed_steps
+            FROM
+                block_reverts
+            WHERE
+                finished_at IS NULL
+            "#
+        )
+        .instrument("get_unfinished_revert")
+        .fetch_optional(self.storage)
+        .await?;
+
+        Ok(row.map(|row| UnfinishedBlockRevert {
+            l1_batch_number: L1BatchNumber(row.l1_batch_number as u32),
+            enabled_steps: row.enabled_steps,
+            completed_steps: row.completed_steps,
+        }))
+    }
+
+    /// Starts a new revert to the specified L1 batch with the specified enabled steps. An unfinished revert, if any,
+    /// is discarded.
+    pub async fn start_revert(
+        &mut self,
+        l1_batch_number: L1BatchNumber,
+        enabled_steps: &[&str],
+    ) -> DalResult<()> {
+        let mut transaction = self.storage.start_transaction().await?;
+        sqlx::query!(
+            r#"
+            DELETE FROM block_reverts
+            WHERE
+                finished_at IS NULL
+            "#
+        )
+        .instrument("start_revert#discard_unfinished")
+        .execute(&mut transaction)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO
+            block_reverts (
+                l1_batch_number, enabled_steps, completed_steps, created_at, updated_at
+            )
+            VALUES
+            ($1, $2, '{}', NOW(), NOW())
+            "#,
+            i64::from(l1_batch_number.0),
+            enabled_steps as &[&str]
+        )
+        .instrument("start_revert")
+        .with_arg("l1_batch_number", &l1_batch_number)
+        .with_arg("enabled_steps", &enabled_steps)
+        .execute(&mut transaction)
+        .await?;
+
+        transaction.commit().await
+    }
+
+    /// Marks a step of the unfinished revert as completed.
+    pub async fn mark_revert_step_completed(&mut self, step: &str) -> DalResult<()> {
+        sqlx::query!(
+            r#"
+            UPDATE block_reverts
+            SET
+                completed_steps = ARRAY_APPEND(completed_steps, $1),
+                updated_at = NOW()
+            WHERE
+                finished_at IS NULL
+            "#,
+            step
+        )
+        .instrument("mark_revert_step_completed")
+        .with_arg("step", &step)
+        .execute(self.storage)
+        .await?;
+        Ok(())
+    }
+
+    /// Marks the unfinished revert as finished.
+    pub async fn finish_revert(&mut self) -> DalResult<()> {
+        sqlx::query!(
+            r#"
+            UPDATE block_reverts
+            SET
+                finished_at = NOW(),
+                updated_at = NOW()
+            WHERE
+                finished_at IS NULL
+            "#
+        )
+        .instrument("finish_revert")
+        .execute(self.storage)
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ConnectionPool, CoreDal};
+
+    #[tokio::test]
+    async fn block_revert_journal_basics() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        let mut dal = conn.block_reverts_dal();
+        assert_eq!(dal.get_unfinished_revert().await.unwrap(), None);
+
+        dal.start_revert(L1BatchNumber(10), &["merkle_tree", "postgres"])
+            .await
+            .unwrap();
+        dal.mark_revert_step_completed("merkle_tree").await.unwrap();
+        dal.mark_revert_step_completed("postgres").await.unwrap();
+        let revert = dal.get_unfinished_revert().await.unwrap().unwrap();
+        assert_eq!(revert.l1_batch_number, L1BatchNumber(10));
+        assert_eq!(revert.enabled_steps, ["merkle_tree", "postgres"]);
+        assert_eq!(revert.completed_steps, ["merkle_tree", "postgres"]);
+
+        // Starting a new revert should discard the unfinished one.
+        dal.start_revert(L1BatchNumber(5), &["postgres"])
+            .await
+            .unwrap();
+        let revert = dal.get_unfinished_revert().await.unwrap().unwrap();
+        assert_eq!(revert.l1_batch_number, L1BatchNumber(5));
+        assert_eq!(revert.enabled_steps, ["postgres"]);
+        assert!(revert.completed_steps.is_empty());
+
+        dal.finish_revert().await.unwrap();
+        assert_eq!(dal.get_unfinished_revert().await.unwrap(), None);
+
+        // Finished reverts must not interfere with starting new ones.
+        dal.start_revert(L1BatchNumber(3), &["postgres"])
+            .await
+            .unwrap();
+        let revert = dal.get_unfinished_revert().await.unwrap().unwrap();
+        assert_eq!(revert.l1_batch_number, L1BatchNumber(3));
+    }
+}
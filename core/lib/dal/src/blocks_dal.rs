@@ -20,11 +20,13 @@ use zksync_types::{
         StorageOracleInfo, UnsealedL1BatchHeader,
     },
     commitment::{L1BatchCommitmentArtifacts, L1BatchWithMetadata},
+    fee::L2BlockFeeStats,
     fee_model::BatchFeeInput,
     l2_to_l1_log::UserL2ToL1Log,
     writes::TreeWrite,
     Address, Bloom, L1BatchNumber, L2BlockNumber, ProtocolVersionId, H256, U256,
 };
+use zksync_utils::u256_to_big_decimal;
 use zksync_vm_interface::CircuitStatistic;
 
 pub use crate::models::storage_block::{L1BatchMetadataError, L1BatchWithOptionalMetadata};
@@ -863,6 +865,54 @@ impl BlocksDal<'_, '_> {
         Ok(())
     }
 
+    /// Saves fee statistics for a sealed L2 block. Must be called after [`Self::insert_l2_block()`].
+    pub async fn insert_l2_block_fee_stats(
+        &mut self,
+        number: L2BlockNumber,
+        stats: &L2BlockFeeStats,
+    ) -> DalResult<()> {
+        let (priority_fees_per_gas, priority_fees_gas_used): (Vec<_>, Vec<_>) = stats
+            .priority_fees
+            .iter()
+            .map(|fee| {
+                (
+                    u256_to_big_decimal(fee.priority_fee_per_gas),
+                    fee.gas_used as i64,
+                )
+            })
+            .unzip();
+
+        let instrumentation =
+            Instrumented::new("insert_l2_block_fee_stats").with_arg("number", &number);
+        let query = sqlx::query!(
+            r#"
+            UPDATE miniblocks
+            SET
+                gas_used = $2,
+                priority_fees_per_gas = $3,
+                priority_fees_gas_used = $4,
+                updated_at = NOW()
+            WHERE
+                number = $1
+            "#,
+            i64::from(number.0),
+            stats.gas_used as i64,
+            &priority_fees_per_gas,
+            &priority_fees_gas_used
+        );
+        let result = instrumentation
+            .clone()
+            .with(query)
+            .execute(self.storage)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            let err = instrumentation.constraint_error(anyhow::anyhow!("L2 block is not inserted"));
+            return Err(err);
+        }
+        Ok(())
+    }
+
     pub async fn get_last_sealed_l2_block_header(&mut self) -> DalResult<Option<L2BlockHeader>> {
         let header = sqlx::query_as!(
             StorageL2BlockHeader,
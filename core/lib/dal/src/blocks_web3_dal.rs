@@ -1,3 +1,5 @@
+use std::{collections::HashMap, ops};
+
 use zksync_db_connection::{
     connection::Connection, error::DalResult, instrument::InstrumentExt, interpolate_query,
     match_query_as,
@@ -5,6 +7,7 @@ use zksync_db_connection::{
 use zksync_system_constants::EMPTY_UNCLES_HASH;
 use zksync_types::{
     api,
+    fee::TxPriorityFee,
     fee_model::BatchFeeInput,
     l2_to_l1_log::L2ToL1Log,
     web3::{BlockHeader, Bytes},
@@ -584,13 +587,14 @@ impl BlocksWeb3Dal<'_, '_> {
         .collect())
     }
 
-    /// Returns `base_fee_per_gas` and `fair_pubdata_price` for L2 block range [min(newest_block - block_count + 1, 0), newest_block]
-    /// in descending order of L2 block numbers.
+    /// Returns `base_fee_per_gas`, `fair_pubdata_price` and gas used ratio for L2 block range
+    /// [min(newest_block - block_count + 1, 0), newest_block] in descending order of L2 block numbers.
+    /// Gas used ratio is zero for L2 blocks sealed before gas used was persisted.
     pub async fn get_fee_history(
         &mut self,
         newest_block: L2BlockNumber,
         block_count: u64,
-    ) -> DalResult<(Vec<U256>, Vec<U256>)> {
+    ) -> DalResult<(Vec<U256>, Vec<U256>, Vec<f64>)> {
         let result: Vec<_> = sqlx::query!(
             r#"
             SELECT
@@ -598,7 +602,9 @@ impl BlocksWeb3Dal<'_, '_> {
                 l2_fair_gas_price,
                 fair_pubdata_price,
                 protocol_version,
-                l1_gas_price
+                l1_gas_price,
+                gas_used,
+                gas_limit
             FROM
                 miniblocks
             WHERE
@@ -627,17 +633,75 @@ impl BlocksWeb3Dal<'_, '_> {
                 row.l1_gas_price as u64,
             );
 
+            let gas_limit = row.gas_limit.unwrap_or(i64::from(LEGACY_BLOCK_GAS_LIMIT));
+            let gas_used_ratio = match row.gas_used {
+                Some(gas_used) if gas_limit > 0 => (gas_used as f64 / gas_limit as f64).min(1.0),
+                _ => 0.0,
+            };
+
             (
                 bigdecimal_to_u256(row.base_fee_per_gas),
                 U256::from(fee_input.fair_pubdata_price()),
+                gas_used_ratio,
             )
         })
         .collect();
 
-        let (base_fee_per_gas, effective_pubdata_price): (Vec<U256>, Vec<U256>) =
-            result.into_iter().unzip();
+        let mut base_fee_per_gas = Vec::with_capacity(result.len());
+        let mut effective_pubdata_price = Vec::with_capacity(result.len());
+        let mut gas_used_ratio = Vec::with_capacity(result.len());
+        for (base_fee, pubdata_price, ratio) in result {
+            base_fee_per_gas.push(base_fee);
+            effective_pubdata_price.push(pubdata_price);
+            gas_used_ratio.push(ratio);
+        }
+
+        Ok((base_fee_per_gas, effective_pubdata_price, gas_used_ratio))
+    }
+
+    /// Returns priority fees charged from L2 transactions in the specified range of L2 blocks, grouped by L2 block.
+    /// L2 blocks without fee statistics (i.e., sealed before the statistics were persisted) are not present
+    /// in the returned map.
+    pub async fn get_priority_fees(
+        &mut self,
+        l2_blocks: ops::RangeInclusive<L2BlockNumber>,
+    ) -> DalResult<HashMap<L2BlockNumber, Vec<TxPriorityFee>>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                number,
+                priority_fees_per_gas AS "priority_fees_per_gas!",
+                priority_fees_gas_used AS "priority_fees_gas_used!"
+            FROM
+                miniblocks
+            WHERE
+                number BETWEEN $1 AND $2
+                AND priority_fees_per_gas IS NOT NULL
+                AND priority_fees_gas_used IS NOT NULL
+            "#,
+            i64::from(l2_blocks.start().0),
+            i64::from(l2_blocks.end().0)
+        )
+        .instrument("get_priority_fees")
+        .with_arg("l2_blocks", &l2_blocks)
+        .fetch_all(self.storage)
+        .await?;
 
-        Ok((base_fee_per_gas, effective_pubdata_price))
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let fees = row
+                    .priority_fees_per_gas
+                    .into_iter()
+                    .zip(row.priority_fees_gas_used)
+                    .map(|(priority_fee_per_gas, gas_used)| TxPriorityFee {
+                        priority_fee_per_gas: bigdecimal_to_u256(priority_fee_per_gas),
+                        gas_used: gas_used as u64,
+                    })
+                    .collect();
+                (L2BlockNumber(row.number as u32), fees)
+            })
+            .collect())
     }
 
     pub async fn get_block_details(
@@ -787,6 +851,7 @@ mod tests {
     use zksync_types::{
         aggregated_operations::AggregatedActionType,
         block::{L2BlockHasher, L2BlockHeader},
+        fee::L2BlockFeeStats,
         Address, L2BlockNumber, ProtocolVersion, ProtocolVersionId,
     };
     use zksync_vm_interface::TransactionExecutionMetrics;
@@ -1059,6 +1124,64 @@ mod tests {
         assert_eq!(l2_block_number.unwrap(), None);
     }
 
+    #[tokio::test]
+    async fn getting_priority_fees_and_fee_history() {
+        let connection_pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = connection_pool.connection().await.unwrap();
+        conn.protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+        for number in 1..=2 {
+            let mut header = create_l2_block_header(number);
+            header.gas_limit = 1_000_000;
+            conn.blocks_dal().insert_l2_block(&header).await.unwrap();
+        }
+
+        let mut stats = L2BlockFeeStats::default();
+        stats.push_tx(Some(20.into()), 100_000);
+        stats.push_tx(None, 50_000);
+        stats.push_tx(Some(0.into()), 200_000);
+        stats.push_tx(Some(20.into()), 150_000);
+        assert_eq!(stats.gas_used, 500_000);
+        conn.blocks_dal()
+            .insert_l2_block_fee_stats(L2BlockNumber(1), &stats)
+            .await
+            .unwrap();
+        conn.blocks_dal()
+            .insert_l2_block_fee_stats(L2BlockNumber(3), &stats)
+            .await
+            .unwrap_err();
+
+        let fees = conn
+            .blocks_web3_dal()
+            .get_priority_fees(L2BlockNumber(1)..=L2BlockNumber(2))
+            .await
+            .unwrap();
+        assert_eq!(fees.len(), 1);
+        assert_eq!(
+            fees[&L2BlockNumber(1)],
+            [
+                TxPriorityFee {
+                    priority_fee_per_gas: 0.into(),
+                    gas_used: 200_000,
+                },
+                TxPriorityFee {
+                    priority_fee_per_gas: 20.into(),
+                    gas_used: 250_000,
+                },
+            ]
+        );
+
+        let (_, _, gas_used_ratio) = conn
+            .blocks_web3_dal()
+            .get_fee_history(L2BlockNumber(2), 2)
+            .await
+            .unwrap();
+        // Fee stats are not persisted for L2 block #2.
+        assert_eq!(gas_used_ratio, [0.0, 0.5]);
+    }
+
     #[tokio::test]
     async fn getting_traces_for_block() {
         let connection_pool = ConnectionPool::<Core>::test_pool().await;
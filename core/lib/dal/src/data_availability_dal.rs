@@ -3,10 +3,13 @@ use zksync_db_connection::{
     error::DalResult,
     instrument::{InstrumentExt, Instrumented},
 };
-use zksync_types::{pubdata_da::DataAvailabilityBlob, L1BatchNumber};
+use zksync_types::{
+    pubdata_da::{DataAvailabilityBlob, DataAvailabilityChunk},
+    L1BatchNumber,
+};
 
 use crate::{
-    models::storage_data_availability::{L1BatchDA, StorageDABlob},
+    models::storage_data_availability::{L1BatchDA, StorageDABlob, StorageDAChunk},
     Core,
 };
 
@@ -147,6 +150,171 @@ impl DataAvailabilityDal<'_, '_> {
         Ok(())
     }
 
+    /// Inserts the blob_id for a chunk of the given L1 batch pubdata. If the chunk is already present,
+    /// verifies that its blob_id matches the one provided in the function arguments.
+    pub async fn insert_l1_batch_da_chunk(
+        &mut self,
+        number: L1BatchNumber,
+        chunk_index: usize,
+        blob_id: &str,
+        sent_at: chrono::NaiveDateTime,
+    ) -> DalResult<()> {
+        let update_result = sqlx::query!(
+            r#"
+            INSERT INTO
+            data_availability_chunks (
+                l1_batch_number, chunk_index, blob_id, sent_at, created_at, updated_at
+            )
+            VALUES
+            ($1, $2, $3, $4, NOW(), NOW())
+            ON CONFLICT DO NOTHING
+            "#,
+            i64::from(number.0),
+            chunk_index as i32,
+            blob_id,
+            sent_at,
+        )
+        .instrument("insert_l1_batch_da_chunk")
+        .with_arg("number", &number)
+        .with_arg("chunk_index", &chunk_index)
+        .with_arg("blob_id", &blob_id)
+        .report_latency()
+        .execute(self.storage)
+        .await?;
+
+        if update_result.rows_affected() == 0 {
+            tracing::debug!(
+                "L1 batch #{number}: DA blob_id for chunk #{chunk_index} wasn't updated as it's already present"
+            );
+
+            let instrumentation = Instrumented::new("get_matching_batch_da_chunk_blob_id")
+                .with_arg("number", &number)
+                .with_arg("chunk_index", &chunk_index);
+
+            // Chunk was already processed. Verify that existing DA blob_id matches
+            let query = sqlx::query!(
+                r#"
+                SELECT
+                    blob_id
+                FROM
+                    data_availability_chunks
+                WHERE
+                    l1_batch_number = $1
+                    AND chunk_index = $2
+                "#,
+                i64::from(number.0),
+                chunk_index as i32,
+            );
+
+            let matched: String = instrumentation
+                .clone()
+                .with(query)
+                .report_latency()
+                .fetch_one(self.storage)
+                .await?
+                .blob_id;
+
+            if matched != blob_id {
+                let err = instrumentation.constraint_error(anyhow::anyhow!(
+                    "Error storing DA blob id. DA blob_id {blob_id} for chunk #{chunk_index} of L1 batch #{number} does not match the expected value"
+                ));
+                return Err(err);
+            }
+        }
+        Ok(())
+    }
+
+    /// Saves the inclusion data for a chunk of the given L1 batch pubdata. Does nothing if the inclusion data
+    /// for the chunk is already present.
+    pub async fn save_l1_batch_chunk_inclusion_data(
+        &mut self,
+        number: L1BatchNumber,
+        chunk_index: usize,
+        da_inclusion_data: &[u8],
+    ) -> DalResult<()> {
+        sqlx::query!(
+            r#"
+            UPDATE data_availability_chunks
+            SET
+                inclusion_data = $1,
+                updated_at = NOW()
+            WHERE
+                l1_batch_number = $2
+                AND chunk_index = $3
+                AND inclusion_data IS NULL
+            "#,
+            da_inclusion_data,
+            i64::from(number.0),
+            chunk_index as i32,
+        )
+        .instrument("save_l1_batch_chunk_inclusion_data")
+        .with_arg("number", &number)
+        .with_arg("chunk_index", &chunk_index)
+        .report_latency()
+        .execute(self.storage)
+        .await?;
+        Ok(())
+    }
+
+    /// Returns all dispatched chunks of the given L1 batch pubdata ordered by their index. Returns an empty `Vec`
+    /// if the pubdata of the batch was dispatched as a single blob.
+    pub async fn get_l1_batch_da_chunks(
+        &mut self,
+        number: L1BatchNumber,
+    ) -> DalResult<Vec<DataAvailabilityChunk>> {
+        let chunks = sqlx::query_as!(
+            StorageDAChunk,
+            r#"
+            SELECT
+                l1_batch_number,
+                chunk_index,
+                blob_id,
+                inclusion_data,
+                sent_at
+            FROM
+                data_availability_chunks
+            WHERE
+                l1_batch_number = $1
+            ORDER BY
+                chunk_index
+            "#,
+            i64::from(number.0),
+        )
+        .instrument("get_l1_batch_da_chunks")
+        .with_arg("number", &number)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(chunks.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns the DA blob for the given L1 batch, or `None` if the batch pubdata wasn't dispatched yet.
+    pub async fn get_l1_batch_da_blob(
+        &mut self,
+        number: L1BatchNumber,
+    ) -> DalResult<Option<DataAvailabilityBlob>> {
+        Ok(sqlx::query_as!(
+            StorageDABlob,
+            r#"
+            SELECT
+                l1_batch_number,
+                blob_id,
+                inclusion_data,
+                sent_at
+            FROM
+                data_availability
+            WHERE
+                l1_batch_number = $1
+            "#,
+            i64::from(number.0),
+        )
+        .instrument("get_l1_batch_da_blob")
+        .with_arg("number", &number)
+        .fetch_optional(self.storage)
+        .await?
+        .map(DataAvailabilityBlob::from))
+    }
+
     /// Assumes that the L1 batches are sorted by number, and returns the first one that is ready for DA dispatch.
     pub async fn get_first_da_blob_awaiting_inclusion(
         &mut self,
@@ -175,6 +343,200 @@ impl DataAvailabilityDal<'_, '_> {
         .map(DataAvailabilityBlob::from))
     }
 
+    /// Returns dispatched DA blobs that weren't yet checked against the local pubdata, ordered by L1 batch number.
+    pub async fn get_da_blobs_awaiting_verification(
+        &mut self,
+        limit: usize,
+    ) -> DalResult<Vec<DataAvailabilityBlob>> {
+        let blobs = sqlx::query_as!(
+            StorageDABlob,
+            r#"
+            SELECT
+                l1_batch_number,
+                blob_id,
+                inclusion_data,
+                sent_at
+            FROM
+                data_availability
+            WHERE
+                verified_at IS NULL
+            ORDER BY
+                l1_batch_number
+            LIMIT
+                $1
+            "#,
+            limit as i64,
+        )
+        .instrument("get_da_blobs_awaiting_verification")
+        .with_arg("limit", &limit)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(blobs.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns the pubdata of the given L1 batch, as it is sent to the DA layer.
+    pub async fn get_l1_batch_pubdata(
+        &mut self,
+        number: L1BatchNumber,
+    ) -> DalResult<Option<Vec<u8>>> {
+        Ok(sqlx::query!(
+            r#"
+            SELECT
+                pubdata_input
+            FROM
+                l1_batches
+            WHERE
+                number = $1
+            "#,
+            i64::from(number.0),
+        )
+        .instrument("get_l1_batch_pubdata")
+        .with_arg("number", &number)
+        .fetch_optional(self.storage)
+        .await?
+        .and_then(|row| row.pubdata_input))
+    }
+
+    /// Saves the result of checking the DA blob of the given L1 batch against the local pubdata.
+    /// `error` is `None` if the blob matches the pubdata.
+    pub async fn save_l1_batch_da_verification_result(
+        &mut self,
+        number: L1BatchNumber,
+        error: Option<&str>,
+    ) -> DalResult<()> {
+        sqlx::query!(
+            r#"
+            UPDATE data_availability
+            SET
+                verified_at = NOW(),
+                verification_error = $1,
+                updated_at = NOW()
+            WHERE
+                l1_batch_number = $2
+            "#,
+            error,
+            i64::from(number.0),
+        )
+        .instrument("save_l1_batch_da_verification_result")
+        .with_arg("number", &number)
+        .report_latency()
+        .execute(self.storage)
+        .await?;
+        Ok(())
+    }
+
+    /// Marks the DA blob of the given L1 batch as checked without comparing it with the local pubdata,
+    /// because the pubdata is not available. Such blobs are neither considered verified nor mismatched.
+    pub async fn mark_l1_batch_da_pubdata_missing(
+        &mut self,
+        number: L1BatchNumber,
+    ) -> DalResult<()> {
+        sqlx::query!(
+            r#"
+            UPDATE data_availability
+            SET
+                verified_at = NOW(),
+                local_pubdata_missing = TRUE,
+                updated_at = NOW()
+            WHERE
+                l1_batch_number = $1
+            "#,
+            i64::from(number.0),
+        )
+        .instrument("mark_l1_batch_da_pubdata_missing")
+        .with_arg("number", &number)
+        .execute(self.storage)
+        .await?;
+        Ok(())
+    }
+
+    /// Records that the DA blob of the given L1 batch couldn't be fetched from the DA layer. The blob stays awaiting
+    /// verification, so that it's re-checked on the next verifier iteration. Returns the number of failed attempts
+    /// recorded for the blob so far.
+    pub async fn record_failed_l1_batch_da_verification_attempt(
+        &mut self,
+        number: L1BatchNumber,
+    ) -> DalResult<u32> {
+        let attempts = sqlx::query!(
+            r#"
+            UPDATE data_availability
+            SET
+                verification_attempts = verification_attempts + 1,
+                updated_at = NOW()
+            WHERE
+                l1_batch_number = $1
+            RETURNING
+                verification_attempts
+            "#,
+            i64::from(number.0),
+        )
+        .instrument("record_failed_l1_batch_da_verification_attempt")
+        .with_arg("number", &number)
+        .fetch_one(self.storage)
+        .await?
+        .verification_attempts;
+        Ok(attempts as u32)
+    }
+
+    /// Clears the verification result of the DA blob of the given L1 batch (e.g., a mismatch that was investigated
+    /// by an operator), so that the blob is verified again. Returns `false` if the batch has no DA blob.
+    pub async fn reset_l1_batch_da_verification(
+        &mut self,
+        number: L1BatchNumber,
+    ) -> DalResult<bool> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE data_availability
+            SET
+                verified_at = NULL,
+                verification_error = NULL,
+                verification_attempts = 0,
+                local_pubdata_missing = FALSE,
+                updated_at = NOW()
+            WHERE
+                l1_batch_number = $1
+            "#,
+            i64::from(number.0),
+        )
+        .instrument("reset_l1_batch_da_verification")
+        .with_arg("number", &number)
+        .execute(self.storage)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Returns the first L1 batch which DA blob didn't match the local pubdata, together with the reason.
+    pub async fn get_first_failed_da_verification(
+        &mut self,
+    ) -> DalResult<Option<(L1BatchNumber, String)>> {
+        Ok(sqlx::query!(
+            r#"
+            SELECT
+                l1_batch_number,
+                verification_error
+            FROM
+                data_availability
+            WHERE
+                verification_error IS NOT NULL
+            ORDER BY
+                l1_batch_number
+            LIMIT
+                1
+            "#,
+        )
+        .instrument("get_first_failed_da_verification")
+        .fetch_optional(self.storage)
+        .await?
+        .map(|row| {
+            (
+                L1BatchNumber(row.l1_batch_number as u32),
+                // `unwrap` is safe here because we have a `WHERE` clause that filters out `NULL` values
+                row.verification_error.unwrap(),
+            )
+        }))
+    }
+
     /// Fetches the pubdata and `l1_batch_number` for the L1 batches that are ready for DA dispatch.
     pub async fn get_ready_for_da_dispatch_l1_batches(
         &mut self,
@@ -217,3 +579,149 @@ impl DataAvailabilityDal<'_, '_> {
             .collect())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tests::create_l1_batch_header, ConnectionPool, CoreDal};
+
+    #[tokio::test]
+    async fn storing_da_chunks() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        conn.blocks_dal()
+            .insert_mock_l1_batch(&create_l1_batch_header(1))
+            .await
+            .unwrap();
+
+        let number = L1BatchNumber(1);
+        let sent_at = chrono::Utc::now().naive_utc();
+        let mut dal = conn.data_availability_dal();
+        assert!(dal.get_l1_batch_da_chunks(number).await.unwrap().is_empty());
+
+        dal.insert_l1_batch_da_chunk(number, 1, "blob_1", sent_at)
+            .await
+            .unwrap();
+        dal.insert_l1_batch_da_chunk(number, 0, "blob_0", sent_at)
+            .await
+            .unwrap();
+        // Repeated insertion of the same chunk is a no-op
+        dal.insert_l1_batch_da_chunk(number, 0, "blob_0", sent_at)
+            .await
+            .unwrap();
+        dal.insert_l1_batch_da_chunk(number, 0, "other_blob", sent_at)
+            .await
+            .unwrap_err();
+
+        dal.save_l1_batch_chunk_inclusion_data(number, 1, &[1, 2, 3])
+            .await
+            .unwrap();
+        let chunks = dal.get_l1_batch_da_chunks(number).await.unwrap();
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].chunk_index, 0);
+        assert_eq!(chunks[0].blob_id, "blob_0");
+        assert_eq!(chunks[0].inclusion_data, None);
+        assert_eq!(chunks[1].chunk_index, 1);
+        assert_eq!(chunks[1].blob_id, "blob_1");
+        assert_eq!(chunks[1].inclusion_data, Some(vec![1, 2, 3]));
+    }
+
+    #[tokio::test]
+    async fn getting_da_blob() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        conn.blocks_dal()
+            .insert_mock_l1_batch(&create_l1_batch_header(1))
+            .await
+            .unwrap();
+
+        let number = L1BatchNumber(1);
+        let mut dal = conn.data_availability_dal();
+        assert!(dal.get_l1_batch_da_blob(number).await.unwrap().is_none());
+
+        dal.insert_l1_batch_da(number, "blob", chrono::Utc::now().naive_utc())
+            .await
+            .unwrap();
+        let blob = dal.get_l1_batch_da_blob(number).await.unwrap().unwrap();
+        assert_eq!(blob.l1_batch_number, number);
+        assert_eq!(blob.blob_id, "blob");
+        assert_eq!(blob.inclusion_data, None);
+    }
+
+    #[tokio::test]
+    async fn storing_da_verification_results() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        for number in [1, 2, 3] {
+            conn.blocks_dal()
+                .insert_mock_l1_batch(&create_l1_batch_header(number))
+                .await
+                .unwrap();
+        }
+
+        let sent_at = chrono::Utc::now().naive_utc();
+        let mut dal = conn.data_availability_dal();
+        dal.insert_l1_batch_da(L1BatchNumber(1), "blob_1", sent_at)
+            .await
+            .unwrap();
+        dal.insert_l1_batch_da(L1BatchNumber(2), "blob_2", sent_at)
+            .await
+            .unwrap();
+        dal.insert_l1_batch_da(L1BatchNumber(3), "blob_3", sent_at)
+            .await
+            .unwrap();
+        let blobs = dal.get_da_blobs_awaiting_verification(10).await.unwrap();
+        let blob_ids: Vec<_> = blobs.iter().map(|blob| blob.blob_id.as_str()).collect();
+        assert_eq!(blob_ids, ["blob_1", "blob_2", "blob_3"]);
+
+        dal.mark_l1_batch_da_pubdata_missing(L1BatchNumber(3))
+            .await
+            .unwrap();
+        let blobs = dal.get_da_blobs_awaiting_verification(10).await.unwrap();
+        let blob_ids: Vec<_> = blobs.iter().map(|blob| blob.blob_id.as_str()).collect();
+        assert_eq!(blob_ids, ["blob_1", "blob_2"]);
+
+        dal.save_l1_batch_da_verification_result(L1BatchNumber(1), None)
+            .await
+            .unwrap();
+        assert_eq!(dal.get_first_failed_da_verification().await.unwrap(), None);
+        dal.save_l1_batch_da_verification_result(L1BatchNumber(2), Some("mismatch"))
+            .await
+            .unwrap();
+
+        assert!(dal
+            .get_da_blobs_awaiting_verification(10)
+            .await
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            dal.get_first_failed_da_verification().await.unwrap(),
+            Some((L1BatchNumber(2), "mismatch".to_owned()))
+        );
+
+        assert!(dal
+            .reset_l1_batch_da_verification(L1BatchNumber(2))
+            .await
+            .unwrap());
+        assert!(!dal
+            .reset_l1_batch_da_verification(L1BatchNumber(4))
+            .await
+            .unwrap());
+        assert_eq!(dal.get_first_failed_da_verification().await.unwrap(), None);
+        let blobs = dal.get_da_blobs_awaiting_verification(10).await.unwrap();
+        let blob_ids: Vec<_> = blobs.iter().map(|blob| blob.blob_id.as_str()).collect();
+        assert_eq!(blob_ids, ["blob_2"]);
+
+        for expected_attempts in 1..=3 {
+            let attempts = dal
+                .record_failed_l1_batch_da_verification_attempt(L1BatchNumber(2))
+                .await
+                .unwrap();
+            assert_eq!(attempts, expected_attempts);
+        }
+        // Failed attempts don't affect the verification status.
+        let blobs = dal.get_da_blobs_awaiting_verification(10).await.unwrap();
+        assert_eq!(blobs.len(), 1);
+        assert_eq!(dal.get_first_failed_da_verification().await.unwrap(), None);
+    }
+}
@@ -706,6 +706,58 @@ impl EthSenderDal<'_, '_> {
         Ok(())
     }
 
+    /// Returns IDs of Ethereum transactions that would be removed by [`Self::delete_eth_txs()`]
+    /// with the same argument, in the ascending order.
+    pub async fn get_eth_tx_ids_after_l1_batch(
+        &mut self,
+        last_batch_to_keep: L1BatchNumber,
+    ) -> sqlx::Result<Vec<u32>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                id
+            FROM
+                eth_txs
+            WHERE
+                id IN (
+                    (
+                        SELECT
+                            eth_commit_tx_id
+                        FROM
+                            l1_batches
+                        WHERE
+                            number > $1
+                    )
+                    UNION
+                    (
+                        SELECT
+                            eth_prove_tx_id
+                        FROM
+                            l1_batches
+                        WHERE
+                            number > $1
+                    )
+                    UNION
+                    (
+                        SELECT
+                            eth_execute_tx_id
+                        FROM
+                            l1_batches
+                        WHERE
+                            number > $1
+                    )
+                )
+            ORDER BY
+                id
+            "#,
+            i64::from(last_batch_to_keep.0)
+        )
+        .fetch_all(self.storage.conn())
+        .await?;
+
+        Ok(rows.into_iter().map(|row| row.id as u32).collect())
+    }
+
     pub async fn delete_eth_txs(&mut self, last_batch_to_keep: L1BatchNumber) -> sqlx::Result<()> {
         sqlx::query!(
             r#"
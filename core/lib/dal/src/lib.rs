@@ -12,12 +12,12 @@ pub use zksync_db_connection::{
 };
 
 use crate::{
-    base_token_dal::BaseTokenDal, blocks_dal::BlocksDal, blocks_web3_dal::BlocksWeb3Dal,
-    consensus_dal::ConsensusDal, contract_verification_dal::ContractVerificationDal,
-    data_availability_dal::DataAvailabilityDal, eth_sender_dal::EthSenderDal,
-    eth_watcher_dal::EthWatcherDal, events_dal::EventsDal, events_web3_dal::EventsWeb3Dal,
-    factory_deps_dal::FactoryDepsDal, proof_generation_dal::ProofGenerationDal,
-    protocol_versions_dal::ProtocolVersionsDal,
+    base_token_dal::BaseTokenDal, block_reverts_dal::BlockRevertsDal, blocks_dal::BlocksDal,
+    blocks_web3_dal::BlocksWeb3Dal, consensus_dal::ConsensusDal,
+    contract_verification_dal::ContractVerificationDal, data_availability_dal::DataAvailabilityDal,
+    eth_sender_dal::EthSenderDal, eth_watcher_dal::EthWatcherDal, events_dal::EventsDal,
+    events_web3_dal::EventsWeb3Dal, factory_deps_dal::FactoryDepsDal,
+    proof_generation_dal::ProofGenerationDal, protocol_versions_dal::ProtocolVersionsDal,
     protocol_versions_web3_dal::ProtocolVersionsWeb3Dal, pruning_dal::PruningDal,
     snapshot_recovery_dal::SnapshotRecoveryDal, snapshots_creator_dal::SnapshotsCreatorDal,
     snapshots_dal::SnapshotsDal, storage_logs_dal::StorageLogsDal,
@@ -29,6 +29,7 @@ use crate::{
 };
 
 pub mod base_token_dal;
+pub mod block_reverts_dal;
 pub mod blocks_dal;
 pub mod blocks_web3_dal;
 pub mod consensus;
@@ -43,6 +44,7 @@ pub mod factory_deps_dal;
 pub mod helpers;
 pub mod metrics;
 mod models;
+pub mod notifications;
 pub mod proof_generation_dal;
 pub mod protocol_versions_dal;
 pub mod protocol_versions_web3_dal;
@@ -136,6 +138,8 @@ where
     fn base_token_dal(&mut self) -> BaseTokenDal<'_, 'a>;
 
     fn processed_events_dal(&mut self) -> EthWatcherDal<'_, 'a>;
+
+    fn block_reverts_dal(&mut self) -> BlockRevertsDal<'_, 'a>;
 }
 
 #[derive(Clone, Debug)]
@@ -266,4 +270,8 @@ impl<'a> CoreDal<'a> for Connection<'a, Core> {
     fn processed_events_dal(&mut self) -> EthWatcherDal<'_, 'a> {
         EthWatcherDal { storage: self }
     }
+
+    fn block_reverts_dal(&mut self) -> BlockRevertsDal<'_, 'a> {
+        BlockRevertsDal { storage: self }
+    }
 }
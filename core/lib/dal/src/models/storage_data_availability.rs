@@ -1,5 +1,8 @@
 use chrono::NaiveDateTime;
-use zksync_types::{pubdata_da::DataAvailabilityBlob, L1BatchNumber};
+use zksync_types::{
+    pubdata_da::{DataAvailabilityBlob, DataAvailabilityChunk},
+    L1BatchNumber,
+};
 
 /// Represents a blob in the data availability layer.
 #[derive(Debug, Clone)]
@@ -21,6 +24,28 @@ impl From<StorageDABlob> for DataAvailabilityBlob {
     }
 }
 
+/// Represents a chunk of the L1 batch pubdata dispatched as a separate blob.
+#[derive(Debug, Clone)]
+pub(crate) struct StorageDAChunk {
+    pub l1_batch_number: i64,
+    pub chunk_index: i32,
+    pub blob_id: String,
+    pub inclusion_data: Option<Vec<u8>>,
+    pub sent_at: NaiveDateTime,
+}
+
+impl From<StorageDAChunk> for DataAvailabilityChunk {
+    fn from(chunk: StorageDAChunk) -> DataAvailabilityChunk {
+        DataAvailabilityChunk {
+            l1_batch_number: L1BatchNumber(chunk.l1_batch_number as u32),
+            chunk_index: chunk.chunk_index as usize,
+            blob_id: chunk.blob_id,
+            inclusion_data: chunk.inclusion_data,
+            sent_at: chunk.sent_at.and_utc(),
+        }
+    }
+}
+
 /// A small struct used to store a batch and its data availability, which are retrieved from the database.
 #[derive(Debug)]
 pub struct L1BatchDA {
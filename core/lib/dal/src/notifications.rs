@@ -0,0 +1,110 @@
+//! Postgres notifications (see the `LISTEN` / `NOTIFY` commands) emitted by the database.
+
+use anyhow::Context as _;
+use sqlx::postgres::PgListener;
+use zksync_types::L2BlockNumber;
+
+use crate::{ConnectionPool, Core};
+
+/// Listener of notifications about inserted L2 blocks. Notifications are emitted by a trigger on the `miniblocks` table,
+/// so they are sent regardless of the component inserting L2 blocks (e.g., the state keeper on the main node
+/// or on an external node).
+///
+/// Notifications are transient; they are not delivered while the listener is disconnected.
+#[derive(Debug)]
+pub struct L2BlockListener(PgListener);
+
+impl L2BlockListener {
+    const CHANNEL: &'static str = "l2_block_inserted";
+
+    /// Creates a listener using a dedicated connection, i.e., one not taken from the `pool`.
+    pub async fn new(pool: &ConnectionPool<Core>) -> anyhow::Result<Self> {
+        let mut listener = PgListener::connect(pool.database_url().expose_str())
+            .await
+            .context("failed connecting to Postgres")?;
+        listener
+            .listen(Self::CHANNEL)
+            .await
+            .with_context(|| format!("failed listening to `{}` channel", Self::CHANNEL))?;
+        Ok(Self(listener))
+    }
+
+    /// Waits for the next notification about an inserted L2 block.
+    ///
+    /// Returns `Ok(None)` if the connection to Postgres was lost. In this case, some notifications may have been missed;
+    /// the listener will try to reconnect on the next call.
+    pub async fn recv(&mut self) -> anyhow::Result<Option<L2BlockNumber>> {
+        let Some(notification) = self
+            .0
+            .try_recv()
+            .await
+            .context("failed receiving notification")?
+        else {
+            return Ok(None);
+        };
+        let number = notification.payload().parse::<u32>().with_context(|| {
+            format!("invalid notification payload: {:?}", notification.payload())
+        })?;
+        Ok(Some(L2BlockNumber(number)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use zksync_types::ProtocolVersion;
+
+    use super::*;
+    use crate::{tests::create_l2_block_header, CoreDal};
+
+    #[tokio::test]
+    async fn listening_to_inserted_l2_blocks() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut listener = L2BlockListener::new(&pool).await.unwrap();
+
+        let mut conn = pool.connection().await.unwrap();
+        conn.protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+        for number in 1..=2 {
+            conn.blocks_dal()
+                .insert_l2_block(&create_l2_block_header(number))
+                .await
+                .unwrap();
+        }
+
+        for number in 1..=2 {
+            let notification = tokio::time::timeout(Duration::from_secs(10), listener.recv())
+                .await
+                .expect("timed out waiting for notification")
+                .unwrap();
+            assert_eq!(notification, Some(L2BlockNumber(number)));
+        }
+
+        // Notifications are sent only after the inserting transaction is committed.
+        let mut transaction = conn.start_transaction().await.unwrap();
+        transaction
+            .blocks_dal()
+            .insert_l2_block(&create_l2_block_header(3))
+            .await
+            .unwrap();
+        pool.connection()
+            .await
+            .unwrap()
+            .blocks_dal()
+            .insert_l2_block(&create_l2_block_header(4))
+            .await
+            .unwrap();
+        transaction.commit().await.unwrap();
+
+        for number in [4, 3] {
+            let notification = tokio::time::timeout(Duration::from_secs(10), listener.recv())
+                .await
+                .expect("timed out waiting for notification")
+                .unwrap();
+            assert_eq!(notification, Some(L2BlockNumber(number)));
+        }
+    }
+}
@@ -84,6 +84,8 @@ impl SnapshotsCreatorDal<'_, '_> {
             INNER JOIN initial_writes ON keys.hashed_key = initial_writes.hashed_key
             WHERE
                 initial_writes.l1_batch_number <= $2
+            ORDER BY
+                storage_logs.hashed_key
             "#,
             i64::from(l2_block_number.0),
             i64::from(l1_batch_number.0),
@@ -109,6 +111,80 @@ impl SnapshotsCreatorDal<'_, '_> {
         Ok(storage_logs)
     }
 
+    /// Constructs a `storage_logs` chunk for an incremental snapshot, i.e., returns the state AFTER processing
+    /// `[0..l1_batch_number]` batches only for keys that were changed in L2 blocks `(base_l2_block_number..=l2_block_number]`.
+    /// `l2_block_number` MUST be the last L2 block of the `l1_batch_number` batch, and `base_l2_block_number` MUST be
+    /// the last L2 block of the base snapshot L1 batch.
+    pub async fn get_changed_storage_logs_chunk(
+        &mut self,
+        base_l2_block_number: L2BlockNumber,
+        l2_block_number: L2BlockNumber,
+        l1_batch_number: L1BatchNumber,
+        hashed_keys_range: std::ops::RangeInclusive<H256>,
+    ) -> DalResult<Vec<SnapshotStorageLog>> {
+        // See `get_storage_logs_chunk()` for the reasoning behind filtering by `l1_batch_number`.
+        let storage_logs = sqlx::query!(
+            r#"
+            SELECT
+                storage_logs.hashed_key AS "hashed_key!",
+                storage_logs.value AS "value!",
+                storage_logs.miniblock_number AS "miniblock_number!",
+                initial_writes.l1_batch_number AS "l1_batch_number!",
+                initial_writes.index
+            FROM
+                (
+                    SELECT
+                        hashed_key,
+                        MAX(ARRAY[miniblock_number, operation_number]::INT []) AS op
+                    FROM
+                        storage_logs
+                    WHERE
+                        miniblock_number > $5
+                        AND miniblock_number <= $1
+                        AND hashed_key >= $3
+                        AND hashed_key <= $4
+                    GROUP BY
+                        hashed_key
+                    ORDER BY
+                        hashed_key
+                ) AS keys
+            INNER JOIN storage_logs
+                ON
+                    keys.hashed_key = storage_logs.hashed_key
+                    AND storage_logs.miniblock_number = keys.op[1]
+                    AND storage_logs.operation_number = keys.op[2]
+            INNER JOIN initial_writes ON keys.hashed_key = initial_writes.hashed_key
+            WHERE
+                initial_writes.l1_batch_number <= $2
+            ORDER BY
+                storage_logs.hashed_key
+            "#,
+            i64::from(l2_block_number.0),
+            i64::from(l1_batch_number.0),
+            hashed_keys_range.start().as_bytes(),
+            hashed_keys_range.end().as_bytes(),
+            i64::from(base_l2_block_number.0)
+        )
+        .instrument("get_changed_storage_logs_chunk")
+        .with_arg("base_l2_block_number", &base_l2_block_number)
+        .with_arg("l2_block_number", &l2_block_number)
+        .with_arg("min_hashed_key", &hashed_keys_range.start())
+        .with_arg("max_hashed_key", &hashed_keys_range.end())
+        .report_latency()
+        .expect_slow_query()
+        .fetch_all(self.storage)
+        .await?
+        .iter()
+        .map(|row| SnapshotStorageLog {
+            key: H256::from_slice(&row.hashed_key),
+            value: H256::from_slice(&row.value),
+            l1_batch_number_of_initial_write: L1BatchNumber(row.l1_batch_number as u32),
+            enumeration_index: row.index as u64,
+        })
+        .collect();
+        Ok(storage_logs)
+    }
+
     /// Same as [`Self::get_storage_logs_chunk()`], but returns full keys.
     #[deprecated(
         note = "will fail if called on a node restored from a v1 snapshot; use `get_storage_logs_chunk()` instead"
@@ -208,6 +284,40 @@ impl SnapshotsCreatorDal<'_, '_> {
             .map(|row| (H256::from_slice(&row.bytecode_hash), row.bytecode))
             .collect())
     }
+
+    /// Returns factory dependencies added in L2 blocks `(base_l2_block_number..=l2_block_number]`.
+    pub async fn get_new_factory_deps(
+        &mut self,
+        base_l2_block_number: L2BlockNumber,
+        l2_block_number: L2BlockNumber,
+    ) -> DalResult<Vec<(H256, Vec<u8>)>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                bytecode_hash,
+                bytecode
+            FROM
+                factory_deps
+            WHERE
+                miniblock_number > $1
+                AND miniblock_number <= $2
+            "#,
+            i64::from(base_l2_block_number.0),
+            i64::from(l2_block_number.0),
+        )
+        .instrument("get_new_factory_deps")
+        .with_arg("base_l2_block_number", &base_l2_block_number)
+        .with_arg("l2_block_number", &l2_block_number)
+        .report_latency()
+        .expect_slow_query()
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| (H256::from_slice(&row.bytecode_hash), row.bytecode))
+            .collect())
+    }
 }
 
 #[cfg(test)]
@@ -289,6 +399,26 @@ mod tests {
             .unwrap();
         assert_eq!(log_row_count, (logs.len() + all_new_logs_len) as u64);
         assert_logs_for_snapshot(&mut conn, L2BlockNumber(1), L1BatchNumber(1), &logs).await;
+
+        // Only the new and updated logs should be returned for an incremental snapshot.
+        let mut changed_logs = conn
+            .snapshots_creator_dal()
+            .get_changed_storage_logs_chunk(
+                L2BlockNumber(1),
+                L2BlockNumber(2),
+                L1BatchNumber(2),
+                H256::zero()..=H256::repeat_byte(0xff),
+            )
+            .await
+            .unwrap();
+        changed_logs.sort_unstable_by_key(|log| log.key);
+        let mut expected_changed_logs = all_new_logs;
+        expected_changed_logs.sort_unstable_by_key(|log| log.key.hashed_key());
+        assert_eq!(changed_logs.len(), expected_changed_logs.len());
+        for (log, expected_log) in changed_logs.iter().zip(&expected_changed_logs) {
+            assert_eq!(log.key, expected_log.key.hashed_key());
+            assert_eq!(log.value, expected_log.value);
+        }
     }
 
     async fn assert_logs_for_snapshot(
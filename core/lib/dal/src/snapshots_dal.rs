@@ -16,6 +16,7 @@ struct StorageSnapshotMetadata {
     l1_batch_number: i64,
     storage_logs_filepaths: Vec<String>,
     factory_deps_filepath: String,
+    base_l1_batch_number: Option<i64>,
 }
 
 impl TryFrom<StorageSnapshotMetadata> for SnapshotMetadata {
@@ -34,6 +35,9 @@ impl TryFrom<StorageSnapshotMetadata> for SnapshotMetadata {
                 .map(|path| (!path.is_empty()).then_some(path))
                 .collect(),
             factory_deps_filepath: row.factory_deps_filepath,
+            base_l1_batch_number: row
+                .base_l1_batch_number
+                .map(|number| L1BatchNumber(number as u32)),
         })
     }
 }
@@ -50,6 +54,7 @@ impl SnapshotsDal<'_, '_> {
         l1_batch_number: L1BatchNumber,
         storage_logs_chunk_count: u64,
         factory_deps_filepaths: &str,
+        base_l1_batch_number: Option<L1BatchNumber>,
     ) -> DalResult<()> {
         sqlx::query!(
             r#"
@@ -59,20 +64,23 @@ impl SnapshotsDal<'_, '_> {
                 l1_batch_number,
                 storage_logs_filepaths,
                 factory_deps_filepath,
+                base_l1_batch_number,
                 created_at,
                 updated_at
             )
             VALUES
-            ($1, $2, ARRAY_FILL(''::TEXT, ARRAY[$3::INTEGER]), $4, NOW(), NOW())
+            ($1, $2, ARRAY_FILL(''::TEXT, ARRAY[$3::INTEGER]), $4, $5, NOW(), NOW())
             "#,
             version as i32,
             l1_batch_number.0 as i32,
             storage_logs_chunk_count as i32,
             factory_deps_filepaths,
+            base_l1_batch_number.map(|number| i64::from(number.0)),
         )
         .instrument("add_snapshot")
         .with_arg("version", &version)
         .with_arg("l1_batch_number", &l1_batch_number)
+        .with_arg("base_l1_batch_number", &base_l1_batch_number)
         .report_latency()
         .execute(self.storage)
         .await?;
@@ -143,7 +151,8 @@ impl SnapshotsDal<'_, '_> {
                 VERSION,
                 L1_BATCH_NUMBER,
                 FACTORY_DEPS_FILEPATH,
-                STORAGE_LOGS_FILEPATHS
+                STORAGE_LOGS_FILEPATHS,
+                BASE_L1_BATCH_NUMBER
             FROM
                 SNAPSHOTS
             ORDER BY
@@ -170,7 +179,8 @@ impl SnapshotsDal<'_, '_> {
                 VERSION,
                 L1_BATCH_NUMBER,
                 FACTORY_DEPS_FILEPATH,
-                STORAGE_LOGS_FILEPATHS
+                STORAGE_LOGS_FILEPATHS,
+                BASE_L1_BATCH_NUMBER
             FROM
                 SNAPSHOTS
             WHERE
@@ -186,6 +196,77 @@ impl SnapshotsDal<'_, '_> {
         .await
     }
 
+    /// Returns metadata for all snapshots after the specified L1 batch number, ordered by the L1 batch number.
+    pub async fn get_snapshots_after(
+        &mut self,
+        last_retained_l1_batch_number: L1BatchNumber,
+    ) -> DalResult<Vec<SnapshotMetadata>> {
+        sqlx::query_as!(
+            StorageSnapshotMetadata,
+            r#"
+            SELECT
+                VERSION,
+                L1_BATCH_NUMBER,
+                FACTORY_DEPS_FILEPATH,
+                STORAGE_LOGS_FILEPATHS,
+                BASE_L1_BATCH_NUMBER
+            FROM
+                SNAPSHOTS
+            WHERE
+                L1_BATCH_NUMBER > $1
+            ORDER BY
+                L1_BATCH_NUMBER
+            "#,
+            last_retained_l1_batch_number.0 as i32
+        )
+        .try_map(SnapshotMetadata::try_from)
+        .instrument("get_snapshots_after")
+        .with_arg(
+            "last_retained_l1_batch_number",
+            &last_retained_l1_batch_number,
+        )
+        .fetch_all(self.storage)
+        .await
+    }
+
+    /// Deletes all snapshots before the specified L1 batch number, except for `retained_l1_batch_numbers`.
+    /// Returns L1 batch numbers of the deleted snapshots.
+    pub async fn delete_snapshots_before(
+        &mut self,
+        threshold: L1BatchNumber,
+        retained_l1_batch_numbers: &[L1BatchNumber],
+    ) -> DalResult<Vec<L1BatchNumber>> {
+        let retained_l1_batch_numbers: Vec<_> = retained_l1_batch_numbers
+            .iter()
+            .map(|number| i64::from(number.0))
+            .collect();
+        let rows = sqlx::query!(
+            r#"
+            DELETE FROM snapshots
+            WHERE
+                l1_batch_number < $1
+                AND NOT (l1_batch_number = ANY($2::BIGINT []))
+            RETURNING
+            l1_batch_number
+            "#,
+            i64::from(threshold.0),
+            &retained_l1_batch_numbers
+        )
+        .instrument("delete_snapshots_before")
+        .with_arg("threshold", &threshold)
+        .with_arg(
+            "retained_l1_batch_numbers.len",
+            &retained_l1_batch_numbers.len(),
+        )
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| L1BatchNumber(row.l1_batch_number as u32))
+            .collect())
+    }
+
     /// Deletes all snapshots after the specified L1 batch number and returns their metadata.
     pub async fn delete_snapshots_after(
         &mut self,
@@ -201,7 +282,8 @@ impl SnapshotsDal<'_, '_> {
             version,
             l1_batch_number,
             factory_deps_filepath,
-            storage_logs_filepaths
+            storage_logs_filepaths,
+            base_l1_batch_number
             "#,
             last_retained_l1_batch_number.0 as i32
         )
@@ -233,6 +315,7 @@ mod tests {
             l1_batch_number,
             2,
             "gs:///bucket/factory_deps.bin",
+            None,
         )
         .await
         .expect("Failed to add snapshot");
@@ -267,6 +350,46 @@ mod tests {
         assert_eq!(snapshot_metadata.l1_batch_number, l1_batch_number);
     }
 
+    #[tokio::test]
+    async fn adding_incremental_snapshot() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        let mut dal = conn.snapshots_dal();
+        dal.add_snapshot(
+            SnapshotVersion::Version1,
+            L1BatchNumber(100),
+            2,
+            "gs:///bucket/factory_deps_100.bin",
+            None,
+        )
+        .await
+        .unwrap();
+        dal.add_snapshot(
+            SnapshotVersion::Version2,
+            L1BatchNumber(150),
+            2,
+            "gs:///bucket/factory_deps_150.bin",
+            Some(L1BatchNumber(100)),
+        )
+        .await
+        .unwrap();
+
+        let base_snapshot = dal
+            .get_snapshot_metadata(L1BatchNumber(100))
+            .await
+            .unwrap()
+            .expect("snapshot is not persisted");
+        assert_eq!(base_snapshot.base_l1_batch_number, None);
+        let snapshot = dal
+            .get_newest_snapshot_metadata()
+            .await
+            .unwrap()
+            .expect("snapshot is not persisted");
+        assert_eq!(snapshot.version, SnapshotVersion::Version2);
+        assert_eq!(snapshot.l1_batch_number, L1BatchNumber(150));
+        assert_eq!(snapshot.base_l1_batch_number, Some(L1BatchNumber(100)));
+    }
+
     #[tokio::test]
     async fn deleting_snapshots() {
         let pool = ConnectionPool::<Core>::test_pool().await;
@@ -278,6 +401,7 @@ mod tests {
             l1_batch_number,
             2,
             "gs:///bucket/factory_deps.bin",
+            None,
         )
         .await
         .unwrap();
@@ -299,6 +423,12 @@ mod tests {
             .expect("snapshot is not persisted");
         assert!(snapshot_metadata.is_complete());
 
+        let snapshots = dal.get_snapshots_after(l1_batch_number).await.unwrap();
+        assert!(snapshots.is_empty(), "{snapshots:?}");
+        let snapshots = dal.get_snapshots_after(l1_batch_number - 1).await.unwrap();
+        assert_eq!(snapshots.len(), 1);
+        assert_eq!(snapshots[0].l1_batch_number, l1_batch_number);
+
         let deleted_snapshots = dal.delete_snapshots_after(l1_batch_number).await.unwrap();
         assert!(deleted_snapshots.is_empty(), "{deleted_snapshots:?}");
         let deleted_snapshots = dal
@@ -330,6 +460,38 @@ mod tests {
         assert_eq!(complete_snapshots.snapshots_l1_batch_numbers, []);
     }
 
+    #[tokio::test]
+    async fn deleting_old_snapshots() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        let mut dal = conn.snapshots_dal();
+        for number in [10, 20, 30, 40] {
+            dal.add_snapshot(
+                SnapshotVersion::Version1,
+                L1BatchNumber(number),
+                1,
+                &format!("gs:///bucket/factory_deps_{number}.bin"),
+                None,
+            )
+            .await
+            .unwrap();
+        }
+
+        let mut deleted = dal
+            .delete_snapshots_before(L1BatchNumber(40), &[L1BatchNumber(20)])
+            .await
+            .unwrap();
+        deleted.sort_unstable();
+        assert_eq!(deleted, [L1BatchNumber(10), L1BatchNumber(30)]);
+        for (number, is_present) in [(10, false), (20, true), (30, false), (40, true)] {
+            let snapshot = dal
+                .get_snapshot_metadata(L1BatchNumber(number))
+                .await
+                .unwrap();
+            assert_eq!(snapshot.is_some(), is_present, "{number}");
+        }
+    }
+
     #[tokio::test]
     async fn adding_files() {
         let pool = ConnectionPool::<Core>::test_pool().await;
@@ -341,6 +503,7 @@ mod tests {
             l1_batch_number,
             2,
             "gs:///bucket/factory_deps.bin",
+            None,
         )
         .await
         .expect("Failed to add snapshot");
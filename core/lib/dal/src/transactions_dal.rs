@@ -10,11 +10,11 @@ use zksync_db_connection::{
     utils::pg_interval_from_duration,
 };
 use zksync_types::{
-    block::L2BlockExecutionData, l1::L1Tx, l2::L2Tx, protocol_upgrade::ProtocolUpgradeTx, Address,
-    ExecuteTransactionCommon, L1BatchNumber, L1BlockNumber, L2BlockNumber, PriorityOpId,
-    ProtocolVersionId, Transaction, H256, PROTOCOL_UPGRADE_TX_TYPE, U256,
+    block::L2BlockExecutionData, fee::Fee, l1::L1Tx, l2::L2Tx, protocol_upgrade::ProtocolUpgradeTx,
+    Address, ExecuteTransactionCommon, L1BatchNumber, L1BlockNumber, L2BlockNumber, Nonce,
+    PriorityOpId, ProtocolVersionId, Transaction, H256, PROTOCOL_UPGRADE_TX_TYPE, U256,
 };
-use zksync_utils::u256_to_big_decimal;
+use zksync_utils::{bigdecimal_to_u256, u256_to_big_decimal};
 use zksync_vm_interface::{
     Call, TransactionExecutionMetrics, TransactionExecutionResult, TxExecutionStatus,
 };
@@ -487,6 +487,89 @@ impl TransactionsDal<'_, '_> {
         Ok(l2_tx_insertion_result)
     }
 
+    /// Returns the hash and fee parameters of a pending L2 transaction (i.e., one not included into an L2 block yet)
+    /// with the specified initiator and nonce. Used to decide whether a new transaction can replace the pending one.
+    pub async fn get_pending_l2_tx_fee(
+        &mut self,
+        initiator_address: Address,
+        nonce: Nonce,
+    ) -> DalResult<Option<(H256, Fee)>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                hash,
+                gas_limit,
+                max_fee_per_gas,
+                max_priority_fee_per_gas,
+                gas_per_pubdata_limit
+            FROM
+                transactions
+            WHERE
+                initiator_address = $1
+                AND nonce = $2
+                AND is_priority = FALSE
+                AND miniblock_number IS NULL
+            "#,
+            initiator_address.as_bytes(),
+            i64::from(nonce.0)
+        )
+        .instrument("get_pending_l2_tx_fee")
+        .with_arg("initiator_address", &initiator_address)
+        .with_arg("nonce", &nonce)
+        .fetch_optional(self.storage)
+        .await?;
+
+        Ok(row.map(|row| {
+            let fee = Fee {
+                gas_limit: row.gas_limit.map(bigdecimal_to_u256).unwrap_or_default(),
+                max_fee_per_gas: row
+                    .max_fee_per_gas
+                    .map(bigdecimal_to_u256)
+                    .unwrap_or_default(),
+                max_priority_fee_per_gas: row
+                    .max_priority_fee_per_gas
+                    .map(bigdecimal_to_u256)
+                    .unwrap_or_default(),
+                gas_per_pubdata_limit: row
+                    .gas_per_pubdata_limit
+                    .map(bigdecimal_to_u256)
+                    .unwrap_or_default(),
+            };
+            (H256::from_slice(&row.hash), fee)
+        }))
+    }
+
+    /// Returns the number of pending L2 transactions (i.e., ones not included into an L2 block yet) of the specified
+    /// initiator, not counting a transaction with `excluded_nonce` (which may be replaced by a new transaction).
+    pub async fn get_pending_l2_txs_count(
+        &mut self,
+        initiator_address: Address,
+        excluded_nonce: Nonce,
+    ) -> DalResult<usize> {
+        let count = sqlx::query!(
+            r#"
+            SELECT
+                COUNT(*) AS "count!"
+            FROM
+                transactions
+            WHERE
+                initiator_address = $1
+                AND nonce != $2
+                AND is_priority = FALSE
+                AND miniblock_number IS NULL
+                AND error IS NULL
+            "#,
+            initiator_address.as_bytes(),
+            i64::from(excluded_nonce.0)
+        )
+        .instrument("get_pending_l2_txs_count")
+        .with_arg("initiator_address", &initiator_address)
+        .fetch_one(self.storage)
+        .await?
+        .count;
+        Ok(count as usize)
+    }
+
     pub async fn mark_txs_as_executed_in_l1_batch(
         &mut self,
         l1_batch_number: L1BatchNumber,
@@ -1653,6 +1736,34 @@ impl TransactionsDal<'_, '_> {
         Ok(())
     }
 
+    /// Returns hashes of transactions that would be returned to the mempool by [`Self::reset_transactions_state()`]
+    /// with the same argument, ordered by the L2 block number and index in block.
+    pub async fn get_transaction_hashes_after_l2_block(
+        &mut self,
+        l2_block_number: L2BlockNumber,
+    ) -> DalResult<Vec<H256>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                hash
+            FROM
+                transactions
+            WHERE
+                miniblock_number > $1
+            ORDER BY
+                miniblock_number,
+                index_in_block
+            "#,
+            i64::from(l2_block_number.0)
+        )
+        .instrument("get_transaction_hashes_after_l2_block")
+        .with_arg("l2_block_number", &l2_block_number)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows.iter().map(|row| H256::from_slice(&row.hash)).collect())
+    }
+
     pub async fn reset_transactions_state(
         &mut self,
         l2_block_number: L2BlockNumber,
@@ -2260,6 +2371,82 @@ mod tests {
         assert_eq!(call_trace, expected_call_trace);
     }
 
+    #[tokio::test]
+    async fn getting_pending_l2_tx_fee() {
+        let connection_pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = connection_pool.connection().await.unwrap();
+        conn.protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+        conn.blocks_dal()
+            .insert_l2_block(&create_l2_block_header(1))
+            .await
+            .unwrap();
+
+        let tx = mock_l2_transaction();
+        let initiator = tx.initiator_account();
+        let pending_fee = conn
+            .transactions_dal()
+            .get_pending_l2_tx_fee(initiator, tx.nonce())
+            .await
+            .unwrap();
+        assert_eq!(pending_fee, None);
+
+        conn.transactions_dal()
+            .insert_transaction_l2(&tx, TransactionExecutionMetrics::default())
+            .await
+            .unwrap();
+        let pending_fee = conn
+            .transactions_dal()
+            .get_pending_l2_tx_fee(initiator, tx.nonce())
+            .await
+            .unwrap();
+        assert_eq!(pending_fee, Some((tx.hash(), tx.common_data.fee.clone())));
+        let pending_fee = conn
+            .transactions_dal()
+            .get_pending_l2_tx_fee(initiator, tx.nonce() + 1)
+            .await
+            .unwrap();
+        assert_eq!(pending_fee, None);
+        let pending_count = conn
+            .transactions_dal()
+            .get_pending_l2_txs_count(initiator, tx.nonce() + 1)
+            .await
+            .unwrap();
+        assert_eq!(pending_count, 1);
+        let pending_count = conn
+            .transactions_dal()
+            .get_pending_l2_txs_count(initiator, tx.nonce())
+            .await
+            .unwrap();
+        assert_eq!(pending_count, 0);
+
+        let tx_result = mock_execution_result(tx.clone());
+        conn.transactions_dal()
+            .mark_txs_as_executed_in_l2_block(
+                L2BlockNumber(1),
+                &[tx_result],
+                1.into(),
+                ProtocolVersionId::latest(),
+                false,
+            )
+            .await
+            .unwrap();
+        let pending_fee = conn
+            .transactions_dal()
+            .get_pending_l2_tx_fee(initiator, tx.nonce())
+            .await
+            .unwrap();
+        assert_eq!(pending_fee, None);
+        let pending_count = conn
+            .transactions_dal()
+            .get_pending_l2_txs_count(initiator, tx.nonce() + 1)
+            .await
+            .unwrap();
+        assert_eq!(pending_count, 0);
+    }
+
     #[tokio::test]
     async fn insert_l2_block_executed_txs() {
         let connection_pool = ConnectionPool::<Core>::test_pool().await;
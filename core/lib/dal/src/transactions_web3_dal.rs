@@ -10,7 +10,7 @@ use zksync_db_connection::{
 };
 use zksync_types::{
     api, api::TransactionReceipt, block::build_bloom, Address, BloomInput, L2BlockNumber,
-    L2ChainId, Transaction, CONTRACT_DEPLOYER_ADDRESS, H256, U256,
+    L2ChainId, Nonce, Transaction, CONTRACT_DEPLOYER_ADDRESS, H256, U256,
 };
 use zksync_vm_interface::VmEvent;
 
@@ -28,6 +28,17 @@ enum TransactionSelector<'a> {
     Position(L2BlockNumber, u32),
 }
 
+/// Aggregated information about pending L2 transactions of a single account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PendingAccountTransactions {
+    /// Smallest nonce among pending transactions.
+    pub min_nonce: Nonce,
+    /// Number of pending transactions with nonces forming a contiguous range starting from `min_nonce`.
+    pub contiguous_count: u64,
+    /// Total number of pending transactions.
+    pub total_count: u64,
+}
+
 #[derive(Debug)]
 pub struct TransactionsWeb3Dal<'a, 'c> {
     pub(crate) storage: &'a mut Connection<'c, Core>,
@@ -372,6 +383,122 @@ impl TransactionsWeb3Dal<'_, '_> {
         Ok(hashes)
     }
 
+    /// Returns pending L2 transactions, i.e. ones not included into an L2 block yet, optionally filtering them
+    /// by the initiator address. Transactions are ordered by initiator address and nonce.
+    pub async fn get_pending_transactions(
+        &mut self,
+        initiator_address: Option<Address>,
+        limit: usize,
+        chain_id: L2ChainId,
+    ) -> DalResult<Vec<api::Transaction>> {
+        let rows = sqlx::query_as!(
+            StorageApiTransaction,
+            r#"
+            SELECT
+                transactions.hash AS tx_hash,
+                transactions.index_in_block AS index_in_block,
+                NULL::BIGINT AS "block_number?",
+                transactions.nonce AS nonce,
+                transactions.signature AS signature,
+                transactions.initiator_address AS initiator_address,
+                transactions.tx_format AS tx_format,
+                transactions.value AS value,
+                transactions.gas_limit AS gas_limit,
+                transactions.max_fee_per_gas AS max_fee_per_gas,
+                transactions.max_priority_fee_per_gas AS max_priority_fee_per_gas,
+                transactions.effective_gas_price AS effective_gas_price,
+                transactions.l1_batch_number AS l1_batch_number,
+                transactions.l1_batch_tx_index AS l1_batch_tx_index,
+                transactions.data -> 'contractAddress' AS "execute_contract_address!",
+                transactions.data -> 'calldata' AS "calldata!",
+                NULL::BYTEA AS "block_hash?"
+            FROM
+                transactions
+            WHERE
+                transactions.miniblock_number IS NULL
+                AND transactions.is_priority = FALSE
+                AND transactions.error IS NULL
+                AND (
+                    $1::BYTEA IS NULL
+                    OR transactions.initiator_address = $1
+                )
+            ORDER BY
+                transactions.initiator_address,
+                transactions.nonce
+            LIMIT
+                $2
+            "#,
+            initiator_address.as_ref().map(Address::as_bytes),
+            limit as i64
+        )
+        .instrument("get_pending_transactions")
+        .with_arg("initiator_address", &initiator_address)
+        .with_arg("limit", &limit)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows.into_iter().map(|row| row.into_api(chain_id)).collect())
+    }
+
+    /// Returns aggregated information about pending L2 transactions (i.e., ones not included into an L2 block yet)
+    /// for all accounts having such transactions.
+    pub async fn get_pending_transactions_by_account(
+        &mut self,
+    ) -> DalResult<HashMap<Address, PendingAccountTransactions>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                initiator_address AS "initiator_address!",
+                MIN(nonce) AS "min_nonce!",
+                COUNT(*) FILTER (
+                    WHERE
+                    nonce - nonce_index = first_nonce
+                ) AS "contiguous_count!",
+                COUNT(*) AS "total_count!"
+            FROM
+                (
+                    SELECT
+                        initiator_address,
+                        nonce,
+                        ROW_NUMBER() OVER (
+                            PARTITION BY
+                                initiator_address
+                            ORDER BY
+                                nonce
+                        ) - 1 AS nonce_index,
+                        MIN(nonce) OVER (
+                            PARTITION BY
+                                initiator_address
+                        ) AS first_nonce
+                    FROM
+                        transactions
+                    WHERE
+                        miniblock_number IS NULL
+                        AND is_priority = FALSE
+                        AND error IS NULL
+                ) AS pending_transactions
+            GROUP BY
+                initiator_address
+            "#
+        )
+        .instrument("get_pending_transactions_by_account")
+        .report_latency()
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let info = PendingAccountTransactions {
+                    min_nonce: Nonce(row.min_nonce as u32),
+                    contiguous_count: row.contiguous_count as u64,
+                    total_count: row.total_count as u64,
+                };
+                (Address::from_slice(&row.initiator_address), info)
+            })
+            .collect())
+    }
+
     /// `committed_next_nonce` should equal the nonce for `initiator_address` in the storage.
     pub async fn next_nonce_by_initiator_account(
         &mut self,
@@ -798,6 +925,96 @@ mod tests {
         assert_eq!(next_nonce, 2.into());
     }
 
+    #[tokio::test]
+    async fn getting_pending_transactions() {
+        let connection_pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = connection_pool.connection().await.unwrap();
+        conn.protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+
+        let mut txs = vec![];
+        for (initiator, nonce) in [(2, 1), (1, 0), (2, 0), (1, 3)] {
+            let mut tx = mock_l2_transaction();
+            // Changing transaction fields invalidates its signature, but it's OK for test purposes
+            tx.common_data.nonce = Nonce(nonce);
+            tx.common_data.initiator_address = Address::repeat_byte(initiator);
+            conn.transactions_dal()
+                .insert_transaction_l2(&tx, TransactionExecutionMetrics::default())
+                .await
+                .unwrap();
+            txs.push(tx);
+        }
+
+        let pending_txs = conn
+            .transactions_web3_dal()
+            .get_pending_transactions(None, 100, L2ChainId::default())
+            .await
+            .unwrap();
+        let pending_tx_hashes: Vec<_> = pending_txs.iter().map(|tx| tx.hash).collect();
+        assert_eq!(
+            pending_tx_hashes,
+            [txs[1].hash(), txs[3].hash(), txs[2].hash(), txs[0].hash()]
+        );
+        assert!(pending_txs.iter().all(|tx| tx.block_number.is_none()));
+
+        let pending_txs = conn
+            .transactions_web3_dal()
+            .get_pending_transactions(Some(Address::repeat_byte(2)), 100, L2ChainId::default())
+            .await
+            .unwrap();
+        let pending_tx_hashes: Vec<_> = pending_txs.iter().map(|tx| tx.hash).collect();
+        assert_eq!(pending_tx_hashes, [txs[2].hash(), txs[0].hash()]);
+
+        let txs_by_account = conn
+            .transactions_web3_dal()
+            .get_pending_transactions_by_account()
+            .await
+            .unwrap();
+        assert_eq!(
+            txs_by_account,
+            HashMap::from([
+                (
+                    Address::repeat_byte(1),
+                    PendingAccountTransactions {
+                        min_nonce: Nonce(0),
+                        contiguous_count: 1,
+                        total_count: 2,
+                    }
+                ),
+                (
+                    Address::repeat_byte(2),
+                    PendingAccountTransactions {
+                        min_nonce: Nonce(0),
+                        contiguous_count: 2,
+                        total_count: 2,
+                    }
+                ),
+            ])
+        );
+
+        let pending_txs = conn
+            .transactions_web3_dal()
+            .get_pending_transactions(None, 1, L2ChainId::default())
+            .await
+            .unwrap();
+        assert_eq!(pending_txs.len(), 1);
+
+        // Rejected transactions must not be returned.
+        conn.transactions_dal()
+            .mark_tx_as_rejected(txs[1].hash(), "oops")
+            .await
+            .unwrap();
+        let pending_txs = conn
+            .transactions_web3_dal()
+            .get_pending_transactions(Some(Address::repeat_byte(1)), 100, L2ChainId::default())
+            .await
+            .unwrap();
+        let pending_tx_hashes: Vec<_> = pending_txs.iter().map(|tx| tx.hash).collect();
+        assert_eq!(pending_tx_hashes, [txs[3].hash()]);
+    }
+
     #[tokio::test]
     async fn getting_next_nonce_by_initiator_account_after_snapshot_recovery() {
         // Emulate snapshot recovery: no transactions with past nonces are present in the storage
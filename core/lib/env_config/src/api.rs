@@ -90,6 +90,8 @@ mod tests {
                 tree_api_url: None,
                 mempool_cache_update_interval: Some(50),
                 mempool_cache_size: Some(10000),
+                tx_replacement_fee_bump_percentage: Some(15),
+                max_pending_txs_per_initiator: Some(20),
                 whitelisted_tokens_for_aa: vec![
                     addr("0x0000000000000000000000000000000000000001"),
                     addr("0x0000000000000000000000000000000000000002"),
@@ -141,6 +143,8 @@ mod tests {
             API_WEB3_JSON_RPC_WEBSOCKET_REQUESTS_PER_MINUTE_LIMIT=10
             API_WEB3_JSON_RPC_MEMPOOL_CACHE_SIZE=10000
             API_WEB3_JSON_RPC_MEMPOOL_CACHE_UPDATE_INTERVAL=50
+            API_WEB3_JSON_RPC_TX_REPLACEMENT_FEE_BUMP_PERCENTAGE=15
+            API_WEB3_JSON_RPC_MAX_PENDING_TXS_PER_INITIATOR=20
             API_CONTRACT_VERIFICATION_PORT="3070"
             API_CONTRACT_VERIFICATION_URL="http://127.0.0.1:3070"
             API_WEB3_JSON_RPC_MAX_RESPONSE_BODY_SIZE_MB=10
@@ -30,6 +30,10 @@ mod tests {
             price_fetching_sleep_ms: 10_000,
             l1_update_deviation_percentage: 20,
             halt_on_error: true,
+            max_ratio_change_percentage: Some(15),
+            ratio_change_confirmations: 3,
+            max_ratio_staleness_ms: Some(600_000),
+            halt_on_guardrail_violation: true,
         }
     }
 
@@ -48,6 +52,10 @@ mod tests {
             price_fetching_sleep_ms: 5_000,
             l1_update_deviation_percentage: 10,
             halt_on_error: false,
+            max_ratio_change_percentage: None,
+            ratio_change_confirmations: 5,
+            max_ratio_staleness_ms: None,
+            halt_on_guardrail_violation: false,
         }
     }
 
@@ -68,6 +76,10 @@ mod tests {
             BASE_TOKEN_ADJUSTER_PRICE_FETCHING_MAX_ATTEMPTS=20
             BASE_TOKEN_ADJUSTER_PRICE_FETCHING_SLEEP_MS=10000
             BASE_TOKEN_ADJUSTER_HALT_ON_ERROR=true
+            BASE_TOKEN_ADJUSTER_MAX_RATIO_CHANGE_PERCENTAGE=15
+            BASE_TOKEN_ADJUSTER_RATIO_CHANGE_CONFIRMATIONS=3
+            BASE_TOKEN_ADJUSTER_MAX_RATIO_STALENESS_MS=600000
+            BASE_TOKEN_ADJUSTER_HALT_ON_GUARDRAIL_VIOLATION=true
         "#;
         lock.set_env(config);
 
@@ -92,6 +104,10 @@ mod tests {
             "BASE_TOKEN_ADJUSTER_PRICE_FETCHING_MAX_ATTEMPTS",
             "BASE_TOKEN_ADJUSTER_PRICE_FETCHING_SLEEP_MS",
             "BASE_TOKEN_ADJUSTER_HALT_ON_ERROR",
+            "BASE_TOKEN_ADJUSTER_MAX_RATIO_CHANGE_PERCENTAGE",
+            "BASE_TOKEN_ADJUSTER_RATIO_CHANGE_CONFIRMATIONS",
+            "BASE_TOKEN_ADJUSTER_MAX_RATIO_STALENESS_MS",
+            "BASE_TOKEN_ADJUSTER_HALT_ON_GUARDRAIL_VIOLATION",
         ]);
 
         let actual = BaseTokenAdjusterConfig::from_env().unwrap();
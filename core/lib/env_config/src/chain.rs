@@ -37,7 +37,7 @@ impl FromEnv for MempoolConfig {
 #[cfg(test)]
 mod tests {
     use zksync_basic_types::{commitment::L1BatchCommitmentMode, L2ChainId};
-    use zksync_config::configs::chain::FeeModelVersion;
+    use zksync_config::configs::chain::{FeeModelVersion, MempoolOrdering};
 
     use super::*;
     use crate::test_utils::{addr, hash, EnvMutex};
@@ -106,6 +106,7 @@ mod tests {
             l1_batch_commit_data_generator_mode,
             max_circuits_per_batch: 24100,
             protective_reads_persistence_enabled: true,
+            mempool_ordering: MempoolOrdering::PriorityFee,
         }
     }
 
@@ -134,6 +135,7 @@ mod tests {
             CHAIN_STATE_KEEPER_MAX_PUBDATA_PER_BATCH="100000"
             CHAIN_STATE_KEEPER_MAX_CIRCUITS_PER_BATCH="24100"
             CHAIN_STATE_KEEPER_FEE_MODEL_VERSION="V2"
+            CHAIN_STATE_KEEPER_MEMPOOL_ORDERING="PriorityFee"
             CHAIN_STATE_KEEPER_VALIDATION_COMPUTATIONAL_GAS_LIMIT="10000000"
             CHAIN_STATE_KEEPER_SAVE_CALL_TRACES="false"
             CHAIN_STATE_KEEPER_BOOTLOADER_HASH=0x010007ede999d096c84553fb514d3d6ca76fbf39789dda76bfeda9f3ae06236e
@@ -1,9 +1,10 @@
 use std::env;
 
+use anyhow::Context as _;
 use zksync_config::configs::{
     da_client::{
-        avail::AvailSecrets, DAClientConfig, AVAIL_CLIENT_CONFIG_NAME,
-        OBJECT_STORE_CLIENT_CONFIG_NAME,
+        avail::AvailSecrets, multi::MultiDAClientConfig, DAClientConfig, AVAIL_CLIENT_CONFIG_NAME,
+        MULTI_CLIENT_CONFIG_NAME, OBJECT_STORE_CLIENT_CONFIG_NAME,
     },
     secrets::DataAvailabilitySecrets,
 };
@@ -18,6 +19,7 @@ impl FromEnv for DAClientConfig {
             OBJECT_STORE_CLIENT_CONFIG_NAME => {
                 Self::ObjectStore(envy_load("da_object_store", "DA_")?)
             }
+            MULTI_CLIENT_CONFIG_NAME => Self::Multi(multi_da_client_config_from_env()?),
             _ => anyhow::bail!("Unknown DA client name: {}", client_tag),
         };
 
@@ -25,11 +27,40 @@ impl FromEnv for DAClientConfig {
     }
 }
 
+/// Loads the multi-DA client config. Underlying clients are listed in `DA_MULTI_CLIENTS` as comma-separated
+/// client names; the config of each client is loaded with the `DA_{CLIENT_NAME}_` prefix, e.g. `DA_AVAIL_`.
+fn multi_da_client_config_from_env() -> anyhow::Result<MultiDAClientConfig> {
+    let client_tags = env::var("DA_MULTI_CLIENTS").context("DA_MULTI_CLIENTS")?;
+    let clients = client_tags
+        .split(',')
+        .map(|tag| match tag.trim() {
+            AVAIL_CLIENT_CONFIG_NAME => Ok(DAClientConfig::Avail(envy_load(
+                "da_multi_avail_config",
+                "DA_AVAIL_",
+            )?)),
+            OBJECT_STORE_CLIENT_CONFIG_NAME => Ok(DAClientConfig::ObjectStore(envy_load(
+                "da_multi_object_store",
+                "DA_OBJECT_STORE_",
+            )?)),
+            tag => anyhow::bail!("Unsupported DA client name for the multi-DA client: {tag}"),
+        })
+        .collect::<anyhow::Result<_>>()?;
+    let inclusion_quorum = env::var("DA_MULTI_INCLUSION_QUORUM")
+        .context("DA_MULTI_INCLUSION_QUORUM")?
+        .parse()
+        .context("DA_MULTI_INCLUSION_QUORUM")?;
+
+    Ok(MultiDAClientConfig {
+        clients,
+        inclusion_quorum,
+    })
+}
+
 impl FromEnv for DataAvailabilitySecrets {
     fn from_env() -> anyhow::Result<Self> {
         let client_tag = std::env::var("DA_CLIENT")?;
         let secrets = match client_tag.as_str() {
-            AVAIL_CLIENT_CONFIG_NAME => {
+            AVAIL_CLIENT_CONFIG_NAME | MULTI_CLIENT_CONFIG_NAME => {
                 let seed_phrase = env::var("DA_SECRETS_SEED_PHRASE")
                     .ok()
                     .map(|s| s.parse())
@@ -65,6 +96,7 @@ mod tests {
             },
             max_retries,
             local_mirror_path: None,
+            zstd_compression_level: None,
         })
     }
 
@@ -129,6 +161,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn from_env_multi_client() {
+        let mut lock = MUTEX.lock();
+        let config = r#"
+            DA_CLIENT="Multi"
+            DA_MULTI_CLIENTS="Avail,ObjectStore"
+            DA_MULTI_INCLUSION_QUORUM="1"
+
+            DA_AVAIL_API_NODE_URL="localhost:12345"
+            DA_AVAIL_BRIDGE_API_URL="localhost:54321"
+            DA_AVAIL_APP_ID="1"
+            DA_AVAIL_TIMEOUT="2"
+            DA_AVAIL_MAX_RETRIES="3"
+
+            DA_OBJECT_STORE_BUCKET_BASE_URL="sometestpath"
+            DA_OBJECT_STORE_MODE="GCS"
+            DA_OBJECT_STORE_MAX_RETRIES="5"
+        "#;
+        lock.set_env(config);
+
+        let actual = DAClientConfig::from_env().unwrap();
+        assert_eq!(
+            actual,
+            DAClientConfig::Multi(MultiDAClientConfig {
+                clients: vec![
+                    expected_avail_da_layer_config("localhost:12345", "localhost:54321", 1, 2, 3),
+                    expected_object_store_da_client_config("sometestpath".to_string(), 5),
+                ],
+                inclusion_quorum: 1,
+            })
+        );
+    }
+
     #[test]
     fn from_env_avail_secrets() {
         let mut lock = MUTEX.lock();
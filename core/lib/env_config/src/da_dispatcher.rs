@@ -27,6 +27,8 @@ mod tests {
             max_rows_to_dispatch: Some(rows_limit),
             max_retries: Some(max_retries),
             use_dummy_inclusion_data: Some(true),
+            verification_interval_ms: Some(60_000),
+            verification_max_attempts: Some(3),
         }
     }
 
@@ -38,6 +40,8 @@ mod tests {
             DA_DISPATCHER_MAX_ROWS_TO_DISPATCH=60
             DA_DISPATCHER_MAX_RETRIES=7
             DA_DISPATCHER_USE_DUMMY_INCLUSION_DATA="true"
+            DA_DISPATCHER_VERIFICATION_INTERVAL_MS=60000
+            DA_DISPATCHER_VERIFICATION_MAX_ATTEMPTS=3
         "#;
         lock.set_env(config);
         let actual = DADispatcherConfig::from_env().unwrap();
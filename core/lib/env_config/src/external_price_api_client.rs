@@ -1,14 +1,27 @@
+use std::env;
+
+use anyhow::Context as _;
 use zksync_config::configs::{
-    external_price_api_client::ForcedPriceClientConfig, ExternalPriceApiClientConfig,
+    external_price_api_client::{
+        AggregatedPriceClientConfig, ForcedPriceClientConfig, UniswapTwapClientConfig,
+        DEFAULT_MAX_DEVIATION_PERCENT, DEFAULT_MIN_SOURCES,
+    },
+    ExternalPriceApiClientConfig,
 };
 
 use crate::{envy_load, FromEnv};
 
+const PREFIX: &str = "EXTERNAL_PRICE_API_CLIENT_";
+
 impl FromEnv for ExternalPriceApiClientConfig {
     fn from_env() -> anyhow::Result<Self> {
         let mut config: ExternalPriceApiClientConfig =
-            envy_load("external_price_api_client", "EXTERNAL_PRICE_API_CLIENT_")?;
+            envy_load("external_price_api_client", PREFIX)?;
         config.forced = ForcedPriceClientConfig::from_env().ok();
+        config.uniswap_twap = UniswapTwapClientConfig::from_env().ok();
+        if env::var_os(format!("{PREFIX}AGGREGATED_SOURCES")).is_some() {
+            config.aggregated = Some(AggregatedPriceClientConfig::from_env()?);
+        }
         Ok(config)
     }
 }
@@ -22,6 +35,63 @@ impl FromEnv for ForcedPriceClientConfig {
     }
 }
 
+impl FromEnv for UniswapTwapClientConfig {
+    fn from_env() -> anyhow::Result<Self> {
+        envy_load(
+            "external_price_api_client_uniswap_twap",
+            "EXTERNAL_PRICE_API_CLIENT_UNISWAP_TWAP_",
+        )
+    }
+}
+
+/// Loads the aggregating price client config. Price sources are listed in `EXTERNAL_PRICE_API_CLIENT_AGGREGATED_SOURCES`
+/// as comma-separated source names; the config of each source is loaded with the `EXTERNAL_PRICE_API_CLIENT_{SOURCE}_`
+/// prefix, e.g. `EXTERNAL_PRICE_API_CLIENT_COINGECKO_`.
+impl FromEnv for AggregatedPriceClientConfig {
+    fn from_env() -> anyhow::Result<Self> {
+        #[derive(serde::Deserialize)]
+        struct AggregationParams {
+            max_deviation_percent: Option<f64>,
+            min_sources: Option<usize>,
+        }
+
+        let sources_var = format!("{PREFIX}AGGREGATED_SOURCES");
+        let source_names = env::var(&sources_var).context(sources_var)?;
+        let sources = source_names
+            .split(',')
+            .map(|source| source_config_from_env(source.trim()))
+            .collect::<anyhow::Result<_>>()?;
+        let params: AggregationParams = envy_load(
+            "external_price_api_client_aggregated",
+            "EXTERNAL_PRICE_API_CLIENT_AGGREGATED_",
+        )?;
+
+        Ok(Self {
+            sources,
+            max_deviation_percent: params
+                .max_deviation_percent
+                .unwrap_or(DEFAULT_MAX_DEVIATION_PERCENT),
+            min_sources: params.min_sources.unwrap_or(DEFAULT_MIN_SOURCES),
+        })
+    }
+}
+
+fn source_config_from_env(source: &str) -> anyhow::Result<ExternalPriceApiClientConfig> {
+    let prefix = format!("{PREFIX}{}_", source.to_uppercase().replace('-', "_"));
+    let source_var = (format!("{prefix}SOURCE"), source.to_owned());
+    let mut config: ExternalPriceApiClientConfig = envy::prefixed(&prefix)
+        .from_iter(env::vars().chain([source_var]))
+        .with_context(|| format!("Cannot load config <external_price_api_client_{source}>"))?;
+    // Client-specific configs are loaded with the same prefix, so that e.g. the Uniswap TWAP source
+    // is configured with `EXTERNAL_PRICE_API_CLIENT_UNISWAP_TWAP_POOL_ADDRESS` both for the standalone
+    // and the aggregating client.
+    if source == "forced" {
+        config.forced = envy_load("external_price_api_client_forced", &prefix).ok();
+    }
+    config.uniswap_twap = envy_load("external_price_api_client_uniswap_twap", &prefix).ok();
+    Ok(config)
+}
+
 #[cfg(test)]
 mod tests {
     use zksync_config::configs::external_price_api_client::{
@@ -44,6 +114,8 @@ mod tests {
                 denominator: Some(1),
                 fluctuation: Some(10),
             }),
+            uniswap_twap: None,
+            aggregated: None,
         }
     }
 
@@ -63,4 +135,53 @@ mod tests {
         let actual = ExternalPriceApiClientConfig::from_env().unwrap();
         assert_eq!(actual, expected_external_price_api_client_config());
     }
+
+    #[test]
+    fn from_env_aggregated_price_api_client() {
+        let mut lock = MUTEX.lock();
+        let config = r#"
+            EXTERNAL_PRICE_API_CLIENT_SOURCE=aggregated
+            EXTERNAL_PRICE_API_CLIENT_AGGREGATED_SOURCES=coingecko,uniswap_twap
+            EXTERNAL_PRICE_API_CLIENT_AGGREGATED_MAX_DEVIATION_PERCENT=2.5
+            EXTERNAL_PRICE_API_CLIENT_COINGECKO_BASE_URL=https://pro-api.coingecko.com
+            EXTERNAL_PRICE_API_CLIENT_COINGECKO_API_KEY=qwerty12345
+            EXTERNAL_PRICE_API_CLIENT_UNISWAP_TWAP_CLIENT_TIMEOUT_MS=5000
+            EXTERNAL_PRICE_API_CLIENT_UNISWAP_TWAP_POOL_ADDRESS=0x0000000000000000000000000000000000000123
+            EXTERNAL_PRICE_API_CLIENT_UNISWAP_TWAP_WETH_ADDRESS=0x0000000000000000000000000000000000000456
+            EXTERNAL_PRICE_API_CLIENT_UNISWAP_TWAP_TWAP_INTERVAL_SEC=600
+        "#;
+        lock.set_env(config);
+
+        let actual = ExternalPriceApiClientConfig::from_env().unwrap();
+        let aggregated = actual.aggregated.unwrap();
+        assert_eq!(aggregated.max_deviation_percent, 2.5);
+        assert_eq!(aggregated.min_sources, DEFAULT_MIN_SOURCES);
+        assert_eq!(
+            aggregated.sources,
+            [
+                ExternalPriceApiClientConfig {
+                    source: "coingecko".to_string(),
+                    base_url: Some("https://pro-api.coingecko.com".to_string()),
+                    api_key: Some("qwerty12345".to_string()),
+                    client_timeout_ms: DEFAULT_TIMEOUT_MS,
+                    forced: None,
+                    uniswap_twap: None,
+                    aggregated: None,
+                },
+                ExternalPriceApiClientConfig {
+                    source: "uniswap_twap".to_string(),
+                    base_url: None,
+                    api_key: None,
+                    client_timeout_ms: 5_000,
+                    forced: None,
+                    uniswap_twap: Some(UniswapTwapClientConfig {
+                        pool_address: zksync_basic_types::Address::from_low_u64_be(0x123),
+                        weth_address: zksync_basic_types::Address::from_low_u64_be(0x456),
+                        twap_interval_sec: 600,
+                    }),
+                    aggregated: None,
+                },
+            ]
+        );
+    }
 }
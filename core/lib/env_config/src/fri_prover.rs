@@ -50,6 +50,7 @@ mod tests {
                 },
                 max_retries: 5,
                 local_mirror_path: None,
+                zstd_compression_level: None,
             }),
             public_object_store: Some(ObjectStoreConfig {
                 mode: ObjectStoreMode::GCSWithCredentialFile {
@@ -58,6 +59,7 @@ mod tests {
                 },
                 max_retries: 5,
                 local_mirror_path: None,
+                zstd_compression_level: None,
             }),
             availability_check_interval_in_secs: Some(1_800),
             cloud_type: CloudConnectionMode::GCP,
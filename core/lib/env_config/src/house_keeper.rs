@@ -18,6 +18,8 @@ mod tests {
     fn expected_config() -> HouseKeeperConfig {
         HouseKeeperConfig {
             l1_batch_metrics_reporting_interval_ms: 10_000,
+            object_store_retention_l1_batches: Some(1_000),
+            object_store_retention_interval_ms: Some(600_000),
         }
     }
 
@@ -26,6 +28,8 @@ mod tests {
         let mut lock = MUTEX.lock();
         let config = r#"
             HOUSE_KEEPER_L1_BATCH_METRICS_REPORTING_INTERVAL_MS="10000"
+            HOUSE_KEEPER_OBJECT_STORE_RETENTION_L1_BATCHES="1000"
+            HOUSE_KEEPER_OBJECT_STORE_RETENTION_INTERVAL_MS="600000"
         "#;
         lock.set_env(config);
 
@@ -1,4 +1,4 @@
-use zksync_config::ObjectStoreConfig;
+use zksync_config::{configs::secrets::ObjectStoreSecrets, ObjectStoreConfig};
 
 use crate::{envy_load, FromEnv};
 
@@ -8,6 +8,12 @@ impl FromEnv for ObjectStoreConfig {
     }
 }
 
+impl FromEnv for ObjectStoreSecrets {
+    fn from_env() -> anyhow::Result<Self> {
+        envy_load("object_store_secrets", "OBJECT_STORE_")
+    }
+}
+
 /// Wrapper for `ObjectStoreConfig` that allows loading object store config using `PUBLIC_` prefix.
 #[derive(Debug)]
 pub struct PublicObjectStoreConfig(pub ObjectStoreConfig);
@@ -42,7 +48,10 @@ impl FromEnv for SnapshotsObjectStoreConfig {
 
 #[cfg(test)]
 mod tests {
-    use zksync_config::{configs::object_store::ObjectStoreMode, ObjectStoreConfig};
+    use zksync_config::{
+        configs::{object_store::ObjectStoreMode, secrets::S3SecretAccessKey},
+        ObjectStoreConfig,
+    };
 
     use super::*;
     use crate::test_utils::EnvMutex;
@@ -57,6 +66,7 @@ mod tests {
             },
             max_retries: 5,
             local_mirror_path: Some("/var/cache".to_owned()),
+            zstd_compression_level: None,
         }
     }
 
@@ -69,10 +79,15 @@ mod tests {
             OBJECT_STORE_GCS_CREDENTIAL_FILE_PATH="/path/to/credentials.json"
             OBJECT_STORE_MAX_RETRIES="5"
             OBJECT_STORE_LOCAL_MIRROR_PATH="/var/cache"
+            OBJECT_STORE_ZSTD_COMPRESSION_LEVEL="3"
         "#;
         lock.set_env(config);
         let actual = ObjectStoreConfig::from_env().unwrap();
-        assert_eq!(actual, expected_gcs_config("/base/url"));
+        let expected = ObjectStoreConfig {
+            zstd_compression_level: Some(3),
+            ..expected_gcs_config("/base/url")
+        };
+        assert_eq!(actual, expected);
     }
 
     #[test]
@@ -143,4 +158,35 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn s3_config_from_env() {
+        let mut lock = MUTEX.lock();
+        let config = r#"
+            OBJECT_STORE_MODE="S3"
+            OBJECT_STORE_ENDPOINT="http://localhost:9000"
+            OBJECT_STORE_BUCKET="artifacts"
+            OBJECT_STORE_REGION="us-east-1"
+            OBJECT_STORE_ACCESS_KEY_ID="minioadmin"
+            OBJECT_STORE_REQUEST_TIMEOUT_MS=10000
+            OBJECT_STORE_S3_SECRET_ACCESS_KEY="minioadmin"
+        "#;
+        lock.set_env(config);
+        let actual = ObjectStoreConfig::from_env().unwrap();
+        assert_eq!(
+            actual.mode,
+            ObjectStoreMode::S3 {
+                endpoint: "http://localhost:9000".to_owned(),
+                bucket: "artifacts".to_owned(),
+                region: "us-east-1".to_owned(),
+                access_key_id: Some("minioadmin".to_owned()),
+                request_timeout_ms: Some(10_000),
+            }
+        );
+        let secrets = ObjectStoreSecrets::from_env().unwrap();
+        assert_eq!(
+            secrets.s3_secret_access_key,
+            Some(S3SecretAccessKey("minioadmin".to_owned()))
+        );
+    }
 }
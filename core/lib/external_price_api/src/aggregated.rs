@@ -0,0 +1,276 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use async_trait::async_trait;
+use serde::Serialize;
+use zksync_config::configs::external_price_api_client::AggregatedPriceClientConfig;
+use zksync_health_check::{Health, HealthStatus, HealthUpdater, ReactiveHealthCheck};
+use zksync_types::{base_token_ratio::BaseTokenAPIRatio, Address};
+
+use crate::{
+    metrics::{SourceStatus, METRICS},
+    PriceAPIClient,
+};
+
+/// Health of a single price source as reported in the health check details.
+#[derive(Debug, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+enum SourceHealth {
+    Ok { ratio: f64 },
+    Outlier { ratio: f64, deviation_percent: f64 },
+    Error { error: String },
+}
+
+#[derive(Debug, Serialize)]
+struct AggregatedPriceHealthDetails {
+    /// Median ratio across all sources that returned a price.
+    median_ratio: Option<f64>,
+    sources: BTreeMap<String, SourceHealth>,
+}
+
+/// Price client aggregating prices from multiple sources.
+///
+/// Prices are fetched from all sources concurrently. Prices deviating from the median by more than the configured
+/// percentage are considered outliers and are ignored. If fewer than the configured number of sources provide
+/// a non-outlier price, fetching the ratio fails. Otherwise, the median of non-outlier prices is returned (if there is
+/// an even number of such prices, the lower one of the two middle prices is used).
+///
+/// The health of each source is reported via [`Self::health_check()`].
+#[derive(Debug)]
+pub struct AggregatedPriceClient {
+    sources: Vec<(String, Arc<dyn PriceAPIClient>)>,
+    max_deviation_percent: f64,
+    min_sources: usize,
+    health_updater: HealthUpdater,
+}
+
+impl AggregatedPriceClient {
+    /// Creates a client aggregating prices from the provided named `sources`.
+    pub fn new(
+        config: &AggregatedPriceClientConfig,
+        sources: Vec<(String, Arc<dyn PriceAPIClient>)>,
+    ) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            config.min_sources > 0 && config.min_sources <= sources.len(),
+            "min_sources ({}) must be positive and not exceed the number of price sources ({})",
+            config.min_sources,
+            sources.len()
+        );
+        anyhow::ensure!(
+            config.max_deviation_percent >= 0.0,
+            "max_deviation_percent must be non-negative"
+        );
+
+        let (_, health_updater) = ReactiveHealthCheck::new("aggregated_price_client");
+        Ok(Self {
+            sources,
+            max_deviation_percent: config.max_deviation_percent,
+            min_sources: config.min_sources,
+            health_updater,
+        })
+    }
+
+    /// Returns the health check reporting the health of individual price sources.
+    pub fn health_check(&self) -> ReactiveHealthCheck {
+        self.health_updater.subscribe()
+    }
+}
+
+fn ratio_to_f64(ratio: &BaseTokenAPIRatio) -> f64 {
+    ratio.numerator.get() as f64 / ratio.denominator.get() as f64
+}
+
+fn median(sorted_values: &[f64]) -> f64 {
+    let len = sorted_values.len();
+    if len % 2 == 0 {
+        (sorted_values[len / 2 - 1] + sorted_values[len / 2]) / 2.0
+    } else {
+        sorted_values[len / 2]
+    }
+}
+
+#[async_trait]
+impl PriceAPIClient for AggregatedPriceClient {
+    async fn fetch_ratio(&self, token_address: Address) -> anyhow::Result<BaseTokenAPIRatio> {
+        let fetches = self
+            .sources
+            .iter()
+            .map(|(_, client)| client.fetch_ratio(token_address));
+        let results = futures::future::join_all(fetches).await;
+
+        let mut source_health = BTreeMap::new();
+        let mut prices = vec![];
+        for ((name, _), result) in self.sources.iter().zip(results) {
+            match result {
+                Ok(ratio) => prices.push((name, ratio)),
+                Err(err) => {
+                    tracing::warn!("Failed fetching price from source `{name}`: {err:#}");
+                    METRICS.source_fetches[&(name.clone(), SourceStatus::Error)].inc();
+                    source_health.insert(
+                        name.clone(),
+                        SourceHealth::Error {
+                            error: format!("{err:#}"),
+                        },
+                    );
+                }
+            }
+        }
+
+        let mut sorted_values: Vec<_> = prices
+            .iter()
+            .map(|(_, ratio)| ratio_to_f64(ratio))
+            .collect();
+        sorted_values.sort_unstable_by(f64::total_cmp);
+        let median_ratio = (!sorted_values.is_empty()).then(|| median(&sorted_values));
+
+        let mut accepted = vec![];
+        for (name, ratio) in prices {
+            let value = ratio_to_f64(&ratio);
+            // `median_ratio` is positive since all ratios are positive.
+            let deviation_percent =
+                median_ratio.map_or(0.0, |median| (value - median).abs() / median * 100.0);
+            if deviation_percent > self.max_deviation_percent {
+                tracing::warn!(
+                    "Price from source `{name}` ({value}) deviates from the median ({median_ratio:?}) \
+                     by {deviation_percent:.2}%; ignoring it"
+                );
+                METRICS.source_fetches[&(name.clone(), SourceStatus::Outlier)].inc();
+                source_health.insert(
+                    name.clone(),
+                    SourceHealth::Outlier {
+                        ratio: value,
+                        deviation_percent,
+                    },
+                );
+            } else {
+                METRICS.source_fetches[&(name.clone(), SourceStatus::Ok)].inc();
+                source_health.insert(name.clone(), SourceHealth::Ok { ratio: value });
+                accepted.push(ratio);
+            }
+        }
+        METRICS.accepted_sources.set(accepted.len());
+
+        let all_sources_ok = accepted.len() == self.sources.len();
+        let has_quorum = accepted.len() >= self.min_sources;
+        let status = if !has_quorum {
+            HealthStatus::NotReady
+        } else if all_sources_ok {
+            HealthStatus::Ready
+        } else {
+            HealthStatus::Affected
+        };
+        let details = AggregatedPriceHealthDetails {
+            median_ratio,
+            sources: source_health,
+        };
+        self.health_updater
+            .update(Health::from(status).with_details(details));
+
+        anyhow::ensure!(
+            has_quorum,
+            "only {} price sources out of {} provided a consistent price; at least {} are required",
+            accepted.len(),
+            self.sources.len(),
+            self.min_sources
+        );
+        accepted.sort_unstable_by(|x, y| ratio_to_f64(x).total_cmp(&ratio_to_f64(y)));
+        Ok(accepted[(accepted.len() - 1) / 2])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU64;
+
+    use chrono::Utc;
+    use zksync_health_check::CheckHealth;
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct MockPriceClient(Option<u64>);
+
+    #[async_trait]
+    impl PriceAPIClient for MockPriceClient {
+        async fn fetch_ratio(&self, _token_address: Address) -> anyhow::Result<BaseTokenAPIRatio> {
+            let numerator = self.0.ok_or_else(|| anyhow::anyhow!("source is down"))?;
+            Ok(BaseTokenAPIRatio {
+                numerator: NonZeroU64::new(numerator).unwrap(),
+                denominator: NonZeroU64::new(1).unwrap(),
+                ratio_timestamp: Utc::now(),
+            })
+        }
+    }
+
+    fn create_client(prices: &[Option<u64>], min_sources: usize) -> AggregatedPriceClient {
+        let config = AggregatedPriceClientConfig {
+            sources: vec![],
+            max_deviation_percent: 5.0,
+            min_sources,
+        };
+        let sources = prices
+            .iter()
+            .enumerate()
+            .map(|(i, &price)| {
+                let client: Arc<dyn PriceAPIClient> = Arc::new(MockPriceClient(price));
+                (format!("source{i}"), client)
+            })
+            .collect();
+        AggregatedPriceClient::new(&config, sources).unwrap()
+    }
+
+    async fn fetch_numerator(client: &AggregatedPriceClient) -> anyhow::Result<u64> {
+        let ratio = client.fetch_ratio(Address::zero()).await?;
+        assert_eq!(ratio.denominator.get(), 1);
+        Ok(ratio.numerator.get())
+    }
+
+    #[tokio::test]
+    async fn aggregating_prices() {
+        let client = create_client(&[Some(1_000), Some(1_010), Some(990)], 2);
+        assert_eq!(fetch_numerator(&client).await.unwrap(), 1_000);
+        let health = client.health_check().check_health().await;
+        assert_eq!(health.status(), HealthStatus::Ready);
+
+        // For an even number of prices, the lower middle price is used.
+        let client = create_client(&[Some(1_000), Some(1_010)], 2);
+        assert_eq!(fetch_numerator(&client).await.unwrap(), 1_000);
+    }
+
+    #[tokio::test]
+    async fn ignoring_outliers_and_failed_sources() {
+        let client = create_client(&[Some(1_000), Some(2_000), Some(1_010), None], 2);
+        assert_eq!(fetch_numerator(&client).await.unwrap(), 1_000);
+
+        let health = client.health_check().check_health().await;
+        assert_eq!(health.status(), HealthStatus::Affected);
+        let details = health.details().unwrap();
+        assert_eq!(details["sources"]["source0"]["status"], "ok");
+        assert_eq!(details["sources"]["source1"]["status"], "outlier");
+        assert_eq!(details["sources"]["source2"]["status"], "ok");
+        assert_eq!(details["sources"]["source3"]["status"], "error");
+    }
+
+    #[tokio::test]
+    async fn error_without_quorum() {
+        let client = create_client(&[Some(1_000), Some(2_000), None], 2);
+        let err = fetch_numerator(&client).await.unwrap_err().to_string();
+        assert!(err.contains("only 0 price sources out of 3"), "{err}");
+        let health = client.health_check().check_health().await;
+        assert_eq!(health.status(), HealthStatus::NotReady);
+
+        let client = create_client(&[None, None], 1);
+        fetch_numerator(&client).await.unwrap_err();
+    }
+
+    #[test]
+    fn invalid_config() {
+        let config = AggregatedPriceClientConfig {
+            sources: vec![],
+            max_deviation_percent: 5.0,
+            min_sources: 3,
+        };
+        let source: Arc<dyn PriceAPIClient> = Arc::new(MockPriceClient(Some(1)));
+        let sources = vec![("source".to_owned(), source)];
+        AggregatedPriceClient::new(&config, sources).unwrap_err();
+    }
+}
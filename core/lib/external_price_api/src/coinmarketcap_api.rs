@@ -0,0 +1,325 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest;
+use serde::Deserialize;
+use tokio::sync::Mutex;
+use url::Url;
+use zksync_config::configs::ExternalPriceApiClientConfig;
+use zksync_types::{base_token_ratio::BaseTokenAPIRatio, Address};
+
+use crate::{address_to_string, utils::get_fraction, PriceAPIClient};
+
+#[derive(Debug)]
+pub struct CoinMarketCapPriceAPIClient {
+    base_url: Url,
+    client: reqwest::Client,
+    /// CoinMarketCap identifies tokens by internal IDs; these are resolved once per token address.
+    token_ids: Mutex<HashMap<Address, u64>>,
+}
+
+const DEFAULT_COINMARKETCAP_API_URL: &str = "https://pro-api.coinmarketcap.com";
+const COINMARKETCAP_AUTH_HEADER: &str = "x-cmc_pro_api_key";
+const ETH_SYMBOL: &str = "ETH";
+/// Slug of the Ethereum platform in CoinMarketCap token metadata.
+const ETHEREUM_PLATFORM_SLUG: &str = "ethereum";
+
+impl CoinMarketCapPriceAPIClient {
+    pub fn new(config: ExternalPriceApiClientConfig) -> Self {
+        let mut client_builder = reqwest::Client::builder().timeout(config.client_timeout());
+        if let Some(api_key) = &config.api_key {
+            let mut headers = reqwest::header::HeaderMap::new();
+            headers.insert(
+                reqwest::header::HeaderName::from_static(COINMARKETCAP_AUTH_HEADER),
+                reqwest::header::HeaderValue::from_str(api_key)
+                    .expect("Failed to create header value"),
+            );
+            client_builder = client_builder.default_headers(headers);
+        }
+        let client = client_builder
+            .build()
+            .expect("Failed to build reqwest client");
+
+        let base_url = config
+            .base_url
+            .unwrap_or(DEFAULT_COINMARKETCAP_API_URL.to_string());
+
+        Self {
+            base_url: Url::parse(&base_url).expect("Failed to parse CoinMarketCap URL"),
+            client,
+            token_ids: Mutex::default(),
+        }
+    }
+
+    async fn get<T: serde::de::DeserializeOwned>(
+        &self,
+        path_and_query: &str,
+        address_str: &str,
+    ) -> anyhow::Result<T> {
+        let url = self
+            .base_url
+            .join(path_and_query)
+            .expect("failed to join URL path");
+        let response = self.client.get(url).send().await?;
+        if !response.status().is_success() {
+            return Err(anyhow::anyhow!(
+                "Http error while fetching token price. Status: {}, token_addr: {}, msg: {}",
+                response.status(),
+                address_str,
+                response.text().await.unwrap_or(String::new())
+            ));
+        }
+        Ok(response.json().await?)
+    }
+
+    async fn get_token_id(&self, address: Address) -> anyhow::Result<u64> {
+        let mut token_ids = self.token_ids.lock().await;
+        if let Some(&id) = token_ids.get(&address) {
+            return Ok(id);
+        }
+
+        let address_str = address_to_string(&address);
+        let response: CoinMarketCapResponse<HashMap<String, CoinMarketCapTokenInfo>> = self
+            .get(
+                &format!("/v1/cryptocurrency/info?address={address_str}"),
+                &address_str,
+            )
+            .await?;
+        // The same address may be shared by tokens on different platforms, so we select the token deployed on Ethereum
+        // and fail if the choice is ambiguous.
+        let mut matching_ids = response
+            .data
+            .values()
+            .filter(|info| info.is_ethereum_token(&address_str))
+            .map(|info| info.id);
+        let id = match (matching_ids.next(), matching_ids.next()) {
+            (Some(id), None) => id,
+            (None, _) => anyhow::bail!("Token not found: {address_str}"),
+            (Some(_), Some(_)) => {
+                anyhow::bail!("Multiple Ethereum tokens match address: {address_str}")
+            }
+        };
+        token_ids.insert(address, id);
+        Ok(id)
+    }
+
+    /// Returns token price in ETH by token address together with the quote timestamp.
+    /// Returned value is X such that 1 TOKEN = X ETH.
+    async fn get_token_price_by_address(
+        &self,
+        address: Address,
+    ) -> anyhow::Result<(f64, DateTime<Utc>)> {
+        let id = self.get_token_id(address).await?;
+        let address_str = address_to_string(&address);
+        let response: CoinMarketCapResponse<HashMap<String, CoinMarketCapQuotes>> = self
+            .get(
+                &format!("/v2/cryptocurrency/quotes/latest?id={id}&convert={ETH_SYMBOL}"),
+                &address_str,
+            )
+            .await?;
+
+        let quote = response
+            .data
+            .get(&id.to_string())
+            .and_then(|quotes| quotes.quote.get(ETH_SYMBOL));
+        match quote {
+            Some(quote) => Ok((quote.price, quote.last_updated)),
+            None => Err(anyhow::anyhow!("Price not found for token: {address_str}")),
+        }
+    }
+}
+
+#[async_trait]
+impl PriceAPIClient for CoinMarketCapPriceAPIClient {
+    async fn fetch_ratio(&self, token_address: Address) -> anyhow::Result<BaseTokenAPIRatio> {
+        let (base_token_in_eth, ratio_timestamp) =
+            self.get_token_price_by_address(token_address).await?;
+        let (num_in_eth, denom_in_eth) = get_fraction(base_token_in_eth)?;
+        // take reciprocal of price as returned price is ETH/BaseToken and BaseToken/ETH is needed
+        let (num_in_base, denom_in_base) = (denom_in_eth, num_in_eth);
+
+        Ok(BaseTokenAPIRatio {
+            numerator: num_in_base,
+            denominator: denom_in_base,
+            ratio_timestamp,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct CoinMarketCapResponse<T> {
+    data: T,
+}
+
+#[derive(Debug, Deserialize)]
+struct CoinMarketCapTokenInfo {
+    id: u64,
+    platform: Option<CoinMarketCapPlatform>,
+}
+
+impl CoinMarketCapTokenInfo {
+    fn is_ethereum_token(&self, address_str: &str) -> bool {
+        self.platform.as_ref().map_or(false, |platform| {
+            platform.slug == ETHEREUM_PLATFORM_SLUG
+                && platform.token_address.eq_ignore_ascii_case(address_str)
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct CoinMarketCapPlatform {
+    slug: String,
+    token_address: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct CoinMarketCapQuotes {
+    quote: HashMap<String, CoinMarketCapQuote>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CoinMarketCapQuote {
+    price: f64,
+    last_updated: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod test {
+    use httpmock::MockServer;
+    use zksync_config::configs::external_price_api_client::DEFAULT_TIMEOUT_MS;
+
+    use super::*;
+    use crate::tests::*;
+
+    const TEST_TOKEN_ID: u64 = 7083;
+
+    fn add_mocks(server: &MockServer, address: String, price: Option<f64>, api_key: &str) {
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/v1/cryptocurrency/info")
+                .query_param("address", address.clone())
+                .header(COINMARKETCAP_AUTH_HEADER, api_key);
+            // Include a token with the same address on another platform, which must be ignored.
+            then.status(200).body(format!(
+                r#"{{"data":{{
+                    "1":{{"id":1,"symbol":"UNI","platform":{{"slug":"bnb","token_address":"{address}"}}}},
+                    "{TEST_TOKEN_ID}":{{"id":{TEST_TOKEN_ID},"symbol":"UNI","platform":{{"slug":"ethereum","token_address":"{address}"}}}}
+                }}}}"#
+            ));
+        });
+
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/v2/cryptocurrency/quotes/latest")
+                .query_param("id", TEST_TOKEN_ID.to_string())
+                .query_param("convert", ETH_SYMBOL)
+                .header(COINMARKETCAP_AUTH_HEADER, api_key);
+            if let Some(price) = price {
+                let last_updated = Utc::now().to_rfc3339();
+                then.status(200).body(format!(
+                    r#"{{"data":{{"{TEST_TOKEN_ID}":{{"id":{TEST_TOKEN_ID},"quote":{{"ETH":{{"price":{price},"last_updated":"{last_updated}"}}}}}}}}}}"#
+                ));
+            } else {
+                then.status(200).body(format!(
+                    r#"{{"data":{{"{TEST_TOKEN_ID}":{{"id":{TEST_TOKEN_ID},"quote":{{}}}}}}}}"#
+                ));
+            }
+        });
+    }
+
+    fn get_config(base_url: String) -> ExternalPriceApiClientConfig {
+        ExternalPriceApiClientConfig {
+            base_url: Some(base_url),
+            api_key: Some("test-key".to_string()),
+            source: "coinmarketcap".to_string(),
+            client_timeout_ms: DEFAULT_TIMEOUT_MS,
+            forced: None,
+            uniswap_twap: None,
+            aggregated: None,
+        }
+    }
+
+    fn happy_day_setup(
+        server: &MockServer,
+        address: Address,
+        base_token_price: f64,
+    ) -> SetupResult {
+        add_mocks(
+            server,
+            address_to_string(&address),
+            Some(base_token_price),
+            "test-key",
+        );
+        SetupResult {
+            client: Box::new(CoinMarketCapPriceAPIClient::new(get_config(server.url("")))),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_happy_day() {
+        happy_day_test(happy_day_setup).await
+    }
+
+    fn error_404_setup(
+        server: &MockServer,
+        _address: Address,
+        _base_token_price: f64,
+    ) -> SetupResult {
+        SetupResult {
+            client: Box::new(CoinMarketCapPriceAPIClient::new(get_config(server.url("")))),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_error_404() {
+        let error_string = error_test(error_404_setup).await.to_string();
+        assert!(
+            error_string
+                .starts_with("Http error while fetching token price. Status: 404 Not Found"),
+            "Error was: {}",
+            &error_string
+        )
+    }
+
+    fn error_missing_setup(
+        server: &MockServer,
+        address: Address,
+        _base_token_price: f64,
+    ) -> SetupResult {
+        add_mocks(server, address_to_string(&address), None, "test-key");
+        SetupResult {
+            client: Box::new(CoinMarketCapPriceAPIClient::new(get_config(server.url("")))),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_error_missing() {
+        let error_string = error_test(error_missing_setup).await.to_string();
+        assert!(
+            error_string.starts_with("Price not found for token"),
+            "Error was: {}",
+            error_string
+        )
+    }
+
+    #[tokio::test]
+    async fn client_without_api_key_times_out() {
+        let server = MockServer::start();
+        server.mock(|_, then| {
+            then.status(200)
+                .body("{}")
+                .delay(std::time::Duration::from_secs(5));
+        });
+        let config = ExternalPriceApiClientConfig {
+            api_key: None,
+            client_timeout_ms: 50,
+            ..get_config(server.url(""))
+        };
+        let client = CoinMarketCapPriceAPIClient::new(config);
+
+        let started_at = std::time::Instant::now();
+        client.fetch_ratio(Address::random()).await.unwrap_err();
+        assert!(started_at.elapsed() < std::time::Duration::from_secs(5));
+    }
+}
@@ -1,7 +1,11 @@
+pub mod aggregated;
 pub mod coingecko_api;
+pub mod coinmarketcap_api;
 pub mod forced_price_client;
+mod metrics;
 #[cfg(test)]
 mod tests;
+pub mod uniswap_twap;
 mod utils;
 
 use std::fmt;
@@ -0,0 +1,22 @@
+use vise::{Counter, EncodeLabelValue, Gauge, LabeledFamily, Metrics};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue)]
+#[metrics(rename_all = "snake_case")]
+pub(crate) enum SourceStatus {
+    Ok,
+    Outlier,
+    Error,
+}
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "external_price_api_aggregated")]
+pub(crate) struct AggregatedPriceClientMetrics {
+    /// Number of price fetches from each source, labeled by the source name and the fetch outcome.
+    #[metrics(labels = ["source", "status"])]
+    pub source_fetches: LabeledFamily<(String, SourceStatus), Counter, 2>,
+    /// Number of sources that provided a non-outlier price during the latest aggregation.
+    pub accepted_sources: Gauge<usize>,
+}
+
+#[vise::register]
+pub(crate) static METRICS: vise::Global<AggregatedPriceClientMetrics> = vise::Global::new();
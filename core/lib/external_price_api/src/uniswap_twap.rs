@@ -0,0 +1,336 @@
+use std::sync::OnceLock;
+
+use anyhow::Context as _;
+use async_trait::async_trait;
+use chrono::Utc;
+use zksync_config::configs::external_price_api_client::UniswapTwapClientConfig;
+use zksync_eth_client::{
+    clients::{DynClient, L1},
+    CallFunctionArgs,
+};
+use zksync_types::{
+    base_token_ratio::BaseTokenAPIRatio,
+    ethabi::{self, Token},
+    web3::contract::{Detokenize, Error as TokenError, Tokenize},
+    Address, U256,
+};
+
+use crate::{utils::get_fraction, PriceAPIClient};
+
+/// Subset of the Uniswap V3 pool and ERC-20 ABIs used by the client.
+const ABI: &str = r#"[
+    {
+        "type": "function",
+        "name": "token0",
+        "inputs": [],
+        "outputs": [{ "name": "", "type": "address" }],
+        "stateMutability": "view"
+    },
+    {
+        "type": "function",
+        "name": "token1",
+        "inputs": [],
+        "outputs": [{ "name": "", "type": "address" }],
+        "stateMutability": "view"
+    },
+    {
+        "type": "function",
+        "name": "observe",
+        "inputs": [{ "name": "secondsAgos", "type": "uint32[]" }],
+        "outputs": [
+            { "name": "tickCumulatives", "type": "int56[]" },
+            { "name": "secondsPerLiquidityCumulativeX128s", "type": "uint160[]" }
+        ],
+        "stateMutability": "view"
+    },
+    {
+        "type": "function",
+        "name": "decimals",
+        "inputs": [],
+        "outputs": [{ "name": "", "type": "uint8" }],
+        "stateMutability": "view"
+    }
+]"#;
+
+fn abi() -> &'static ethabi::Contract {
+    static ABI_CONTRACT: OnceLock<ethabi::Contract> = OnceLock::new();
+    ABI_CONTRACT.get_or_init(|| serde_json::from_str(ABI).expect("invalid Uniswap pool ABI"))
+}
+
+/// Number of significant digits retained in the computed ratio. Without rounding, the ratio may not be representable
+/// as a fraction with 64-bit numerator and denominator.
+const SIGNIFICANT_DIGITS: usize = 9;
+
+fn round_to_significant_digits(value: f64) -> f64 {
+    format!("{value:.*e}", SIGNIFICANT_DIGITS - 1)
+        .parse()
+        .unwrap_or(value)
+}
+
+/// Tick cumulatives returned by the `observe()` pool method; the remaining outputs are ignored.
+#[derive(Debug)]
+struct TickCumulatives(Vec<i64>);
+
+impl Detokenize for TickCumulatives {
+    fn from_tokens(tokens: Vec<Token>) -> Result<Self, TokenError> {
+        let Some(Token::Array(ticks)) = tokens.into_iter().next() else {
+            return Err(TokenError::InvalidOutputType(
+                "expected an array of tick cumulatives".to_owned(),
+            ));
+        };
+        ticks
+            .into_iter()
+            .map(|token| match token {
+                // `int56` values are sign-extended to 256 bits, so the lower 64 bits are a valid two's complement.
+                Token::Int(value) => Ok(value.low_u64() as i64),
+                _ => Err(TokenError::InvalidOutputType(format!(
+                    "expected int56, got {token:?}"
+                ))),
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+/// Price client computing the base token price from the time-weighted average price (TWAP) in a Uniswap V3-compatible
+/// pool on L1. The pool must trade the base token against wrapped ETH.
+#[derive(Debug)]
+pub struct UniswapTwapPriceClient {
+    eth_client: Box<DynClient<L1>>,
+    pool_address: Address,
+    weth_address: Address,
+    twap_interval_sec: u32,
+}
+
+impl UniswapTwapPriceClient {
+    pub fn new(config: UniswapTwapClientConfig, eth_client: Box<DynClient<L1>>) -> Self {
+        assert!(
+            config.twap_interval_sec > 0,
+            "TWAP interval must be positive"
+        );
+        Self {
+            eth_client: eth_client.for_component("uniswap_twap_price_client"),
+            pool_address: config.pool_address,
+            weth_address: config.weth_address,
+            twap_interval_sec: config.twap_interval_sec,
+        }
+    }
+
+    async fn call<Res: Detokenize>(
+        &self,
+        contract: Address,
+        function_name: &str,
+        params: impl Tokenize,
+    ) -> anyhow::Result<Res> {
+        CallFunctionArgs::new(function_name, params)
+            .for_contract(contract, abi())
+            .call(&self.eth_client)
+            .await
+            .with_context(|| format!("failed calling `{function_name}()` on {contract:?}"))
+    }
+
+    async fn decimals(&self, token: Address) -> anyhow::Result<i32> {
+        let decimals: U256 = self.call(token, "decimals", ()).await?;
+        anyhow::ensure!(
+            decimals <= U256::from(u8::MAX),
+            "invalid decimals for token {token:?}: {decimals}"
+        );
+        Ok(decimals.as_u32() as i32)
+    }
+
+    /// Returns the time-weighted average tick over the configured interval.
+    async fn average_tick(&self) -> anyhow::Result<f64> {
+        let seconds_agos = Token::Array(vec![
+            Token::Uint(self.twap_interval_sec.into()),
+            Token::Uint(U256::zero()),
+        ]);
+        let TickCumulatives(ticks) = self
+            .call(self.pool_address, "observe", seconds_agos)
+            .await?;
+        let [tick_cumulative_then, tick_cumulative_now] = ticks[..] else {
+            anyhow::bail!("unexpected number of tick cumulatives: {ticks:?}");
+        };
+        let tick_delta = tick_cumulative_now - tick_cumulative_then;
+        Ok(tick_delta as f64 / f64::from(self.twap_interval_sec))
+    }
+}
+
+#[async_trait]
+impl PriceAPIClient for UniswapTwapPriceClient {
+    async fn fetch_ratio(&self, token_address: Address) -> anyhow::Result<BaseTokenAPIRatio> {
+        let token0: Address = self.call(self.pool_address, "token0", ()).await?;
+        let token1: Address = self.call(self.pool_address, "token1", ()).await?;
+        let (base_token_is_token0, quote_token) = if token_address == token0 {
+            (true, token1)
+        } else if token_address == token1 {
+            (false, token0)
+        } else {
+            anyhow::bail!(
+                "pool {:?} doesn't trade token {token_address:?}; pool tokens: {token0:?}, {token1:?}",
+                self.pool_address
+            );
+        };
+        anyhow::ensure!(
+            quote_token == self.weth_address,
+            "pool {:?} trades token {token_address:?} against {quote_token:?} rather than WETH {:?}",
+            self.pool_address,
+            self.weth_address
+        );
+
+        let decimals0 = self.decimals(token0).await?;
+        let decimals1 = self.decimals(token1).await?;
+        let average_tick = self.average_tick().await?;
+        // Price of token0 denominated in token1, adjusted for token decimals.
+        let price = 1.0001_f64.powf(average_tick) * 10_f64.powi(decimals0 - decimals1);
+        // The ratio is the amount of base tokens per 1 ETH.
+        let ratio = if base_token_is_token0 {
+            price.recip()
+        } else {
+            price
+        };
+        let (numerator, denominator) = get_fraction(round_to_significant_digits(ratio))?;
+
+        Ok(BaseTokenAPIRatio {
+            numerator,
+            denominator,
+            ratio_timestamp: Utc::now(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_types::web3;
+    use zksync_web3_decl::client::MockClient;
+
+    use super::*;
+    use crate::tests::*;
+
+    const POOL_ADDRESS: Address = Address::repeat_byte(0x11);
+    const WETH_ADDRESS: Address = Address::repeat_byte(0xee);
+    const TWAP_INTERVAL_SEC: u32 = 1_800;
+
+    fn int_token(value: i64) -> Token {
+        let abs_value = U256::from(value.unsigned_abs());
+        Token::Int(if value >= 0 {
+            abs_value
+        } else {
+            (!abs_value).overflowing_add(U256::one()).0
+        })
+    }
+
+    /// Mocks a pool where `token0` is priced at `price` units of `token1` (adjusted for decimals).
+    fn mock_pool(
+        (token0, decimals0): (Address, u8),
+        (token1, decimals1): (Address, u8),
+        price: f64,
+    ) -> UniswapTwapPriceClient {
+        let raw_price = price * 10_f64.powi(i32::from(decimals1) - i32::from(decimals0));
+        let tick = (raw_price.ln() / 1.0001_f64.ln()).round() as i64;
+        let tick_cumulative_then = 1_000_000_000_i64;
+        let tick_cumulative_now = tick_cumulative_then + tick * i64::from(TWAP_INTERVAL_SEC);
+
+        let selector = |name: &str| abi().function(name).unwrap().short_signature();
+        let eth_client = MockClient::builder(L1::default())
+            .method(
+                "eth_call",
+                move |req: web3::CallRequest, _block: Option<web3::BlockId>| {
+                    let contract = req.to.unwrap();
+                    let data = req.data.unwrap().0;
+                    let output = if data[..4] == selector("token0") {
+                        assert_eq!(contract, POOL_ADDRESS);
+                        vec![Token::Address(token0)]
+                    } else if data[..4] == selector("token1") {
+                        assert_eq!(contract, POOL_ADDRESS);
+                        vec![Token::Address(token1)]
+                    } else if data[..4] == selector("decimals") {
+                        let decimals = if contract == token0 {
+                            decimals0
+                        } else {
+                            assert_eq!(contract, token1);
+                            decimals1
+                        };
+                        vec![Token::Uint(decimals.into())]
+                    } else if data[..4] == selector("observe") {
+                        assert_eq!(contract, POOL_ADDRESS);
+                        vec![
+                            Token::Array(vec![
+                                int_token(tick_cumulative_then),
+                                int_token(tick_cumulative_now),
+                            ]),
+                            Token::Array(vec![Token::Uint(1.into()), Token::Uint(2.into())]),
+                        ]
+                    } else {
+                        panic!("unexpected call: {data:?}");
+                    };
+                    Ok(web3::Bytes(ethabi::encode(&output)))
+                },
+            )
+            .build();
+
+        let config = UniswapTwapClientConfig {
+            pool_address: POOL_ADDRESS,
+            weth_address: WETH_ADDRESS,
+            twap_interval_sec: TWAP_INTERVAL_SEC,
+        };
+        UniswapTwapPriceClient::new(config, Box::new(eth_client))
+    }
+
+    #[tokio::test]
+    async fn base_token_is_token0() {
+        happy_day_test(|_server, address, base_token_price| SetupResult {
+            client: Box::new(mock_pool(
+                (address, 18),
+                (WETH_ADDRESS, 18),
+                base_token_price,
+            )),
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn base_token_is_token1_with_different_decimals() {
+        let base_token = Address::repeat_byte(0xbb);
+        // 1 ETH = 2,500 base tokens
+        let client = mock_pool((WETH_ADDRESS, 18), (base_token, 6), 2_500.0);
+        let ratio = client.fetch_ratio(base_token).await.unwrap();
+        let ratio = ratio.numerator.get() as f64 / ratio.denominator.get() as f64;
+        assert!((ratio - 2_500.0).abs() < 1.0, "{ratio}");
+    }
+
+    #[tokio::test]
+    async fn error_for_token_not_in_pool() {
+        let client = mock_pool((WETH_ADDRESS, 18), (Address::repeat_byte(0xbb), 6), 2_500.0);
+        let err = client
+            .fetch_ratio(Address::repeat_byte(0xcc))
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("doesn't trade token"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn error_for_pool_without_weth() {
+        let base_token = Address::repeat_byte(0xbb);
+        let client = mock_pool((Address::repeat_byte(0xcc), 18), (base_token, 6), 2_500.0);
+        let err = client
+            .fetch_ratio(base_token)
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("rather than WETH"), "{err}");
+    }
+
+    #[test]
+    fn rounding_to_significant_digits() {
+        assert_eq!(
+            round_to_significant_digits(371.742_312_345_678_9),
+            371.742_312
+        );
+        assert_eq!(
+            round_to_significant_digits(1.234_567_890_123e-6),
+            1.234_567_89e-6
+        );
+        get_fraction(round_to_significant_digits(1.234_567_890_123e-6)).unwrap();
+    }
+}
@@ -1,9 +1,10 @@
 mod mempool_store;
+mod metrics;
 #[cfg(test)]
 mod tests;
 mod types;
 
 pub use crate::{
     mempool_store::{MempoolInfo, MempoolStats, MempoolStore},
-    types::L2TxFilter,
+    types::{L2TxFilter, MempoolOrdering},
 };
@@ -4,7 +4,10 @@ use zksync_types::{
     l1::L1Tx, l2::L2Tx, Address, ExecuteTransactionCommon, Nonce, PriorityOpId, Transaction,
 };
 
-use crate::types::{AccountTransactions, L2TxFilter, MempoolScore};
+use crate::{
+    metrics::{EvictionReason, METRICS},
+    types::{AccountTransactions, L2TxFilter, MempoolOrdering, MempoolScore},
+};
 
 #[derive(Debug)]
 pub struct MempoolInfo {
@@ -33,10 +36,20 @@ pub struct MempoolStore {
     /// Number of L2 transactions in the mempool.
     size: u64,
     capacity: u64,
+    ordering: MempoolOrdering,
 }
 
 impl MempoolStore {
     pub fn new(next_priority_id: PriorityOpId, capacity: u64) -> Self {
+        Self::with_ordering(next_priority_id, capacity, MempoolOrdering::default())
+    }
+
+    /// Creates a mempool ordering L2 transactions according to the specified `ordering`.
+    pub fn with_ordering(
+        next_priority_id: PriorityOpId,
+        capacity: u64,
+        ordering: MempoolOrdering,
+    ) -> Self {
         Self {
             l1_transactions: HashMap::new(),
             l2_transactions_per_account: HashMap::new(),
@@ -45,6 +58,7 @@ impl MempoolStore {
             stashed_accounts: vec![],
             size: 0,
             capacity,
+            ordering,
         }
     }
 
@@ -107,7 +121,7 @@ impl MempoolStore {
             hash_map::Entry::Vacant(entry) => {
                 let account_nonce = initial_nonces.get(&account).cloned().unwrap_or(Nonce(0));
                 entry
-                    .insert(AccountTransactions::new(account_nonce))
+                    .insert(AccountTransactions::new(account_nonce, self.ordering))
                     .insert(transaction)
             }
         };
@@ -220,24 +234,67 @@ impl MempoolStore {
         }
     }
 
+    /// Purges accounts if the mempool is full. First, accounts without executable transactions (i.e., ones
+    /// with a nonce gap) are purged. If the mempool is still over capacity, accounts whose next transaction
+    /// has the lowest max fee per gas are evicted until the mempool fits into its capacity.
     fn gc(&mut self) -> Vec<Address> {
-        if self.size >= self.capacity {
-            let index: HashSet<_> = self
-                .l2_priority_queue
-                .iter()
-                .map(|pointer| pointer.account)
-                .collect();
-            let transactions = std::mem::take(&mut self.l2_transactions_per_account);
-            let (kept, drained) = transactions
-                .into_iter()
-                .partition(|(address, _)| index.contains(address));
-            self.l2_transactions_per_account = kept;
-            self.size = self
+        if self.size < self.capacity {
+            return vec![];
+        }
+
+        let index: HashSet<_> = self
+            .l2_priority_queue
+            .iter()
+            .map(|pointer| pointer.account)
+            .collect();
+        let transactions = std::mem::take(&mut self.l2_transactions_per_account);
+        let (kept, drained): (HashMap<_, _>, HashMap<_, _>) = transactions
+            .into_iter()
+            .partition(|(address, _)| index.contains(address));
+        self.l2_transactions_per_account = kept;
+        let evicted_count = drained.values().map(|txs| txs.len() as u64).sum::<u64>();
+        METRICS.evicted_transactions[&EvictionReason::NonceGap].inc_by(evicted_count);
+        self.size = self
+            .l2_transactions_per_account
+            .iter()
+            .fold(0, |agg, (_, tnxs)| agg + tnxs.len() as u64);
+        let mut purged_accounts: Vec<_> = drained.into_keys().collect();
+
+        if self.size > self.capacity {
+            self.evict_low_fee_accounts(&mut purged_accounts);
+        }
+        purged_accounts
+    }
+
+    fn evict_low_fee_accounts(&mut self, purged_accounts: &mut Vec<Address>) {
+        let mut candidates: Vec<_> = self.l2_priority_queue.iter().cloned().collect();
+        // Among transactions with the same fee, the ones with the lower score are evicted first.
+        candidates.sort_by(|a, b| {
+            a.fee_data
+                .max_fee_per_gas
+                .cmp(&b.fee_data.max_fee_per_gas)
+                .then_with(|| a.cmp(b))
+        });
+
+        let mut evicted_count = 0;
+        for score in candidates {
+            if self.size <= self.capacity {
+                break;
+            }
+            self.l2_priority_queue.remove(&score);
+            let removed = self
                 .l2_transactions_per_account
-                .iter()
-                .fold(0, |agg, (_, tnxs)| agg + tnxs.len() as u64);
-            return drained.into_keys().collect();
+                .remove(&score.account)
+                .expect("mempool: dangling pointer in priority queue")
+                .len() as u64;
+            self.size -= removed;
+            evicted_count += removed;
+            purged_accounts.push(score.account);
         }
-        vec![]
+        tracing::info!(
+            "Evicted {evicted_count} low-fee L2 transactions from the mempool; mempool size is {}",
+            self.size
+        );
+        METRICS.evicted_transactions[&EvictionReason::LowFee].inc_by(evicted_count);
     }
 }
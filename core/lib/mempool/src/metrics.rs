@@ -0,0 +1,22 @@
+//! Mempool metrics.
+
+use vise::{Counter, EncodeLabelSet, EncodeLabelValue, Family, Metrics};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelSet, EncodeLabelValue)]
+#[metrics(label = "reason", rename_all = "snake_case")]
+pub(crate) enum EvictionReason {
+    /// Account has no executable transactions because of a nonce gap.
+    NonceGap,
+    /// Account has the lowest-fee next transaction, and the mempool is over capacity.
+    LowFee,
+}
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "server_mempool")]
+pub(crate) struct MempoolMetrics {
+    /// Number of L2 transactions evicted from the mempool when it is full, grouped by the eviction reason.
+    pub evicted_transactions: Family<EvictionReason, Counter>,
+}
+
+#[vise::register]
+pub(crate) static METRICS: vise::Global<MempoolMetrics> = vise::Global::new();
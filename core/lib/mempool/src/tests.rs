@@ -12,7 +12,10 @@ use zksync_types::{
     H256, U256,
 };
 
-use crate::{mempool_store::MempoolStore, types::L2TxFilter};
+use crate::{
+    mempool_store::MempoolStore,
+    types::{L2TxFilter, MempoolOrdering},
+};
 
 #[test]
 fn basic_flow() {
@@ -365,6 +368,123 @@ fn mempool_capacity() {
     );
 }
 
+#[test]
+fn priority_fee_ordering() {
+    let mut mempool =
+        MempoolStore::with_ordering(PriorityOpId(0), 100, MempoolOrdering::PriorityFee);
+    let account0 = Address::random();
+    let account1 = Address::random();
+    let account2 = Address::random();
+    let transactions = vec![
+        gen_l2_tx_with_priority_fee(account0, Nonce(0), 1, 10),
+        gen_l2_tx_with_priority_fee(account0, Nonce(1), 2, 1_000),
+        gen_l2_tx_with_priority_fee(account1, Nonce(0), 3, 100),
+        gen_l2_tx_with_priority_fee(account2, Nonce(0), 4, 10),
+        // Priority fee is capped by the max fee per gas.
+        gen_l2_tx_with_priority_fee(account2, Nonce(1), 5, u64::MAX),
+    ];
+    mempool.insert(transactions, HashMap::new());
+
+    let filter = L2TxFilter::default();
+    // Account nonces must be respected even if a subsequent transaction has a higher fee.
+    assert_eq!(view(mempool.next_transaction(&filter)), (account1, 0));
+    assert_eq!(view(mempool.next_transaction(&filter)), (account0, 0));
+    assert_eq!(view(mempool.next_transaction(&filter)), (account0, 1));
+    assert_eq!(view(mempool.next_transaction(&filter)), (account2, 0));
+    assert_eq!(view(mempool.next_transaction(&filter)), (account2, 1));
+    assert_eq!(mempool.next_transaction(&filter), None);
+}
+
+#[test]
+fn replacing_tx_with_higher_fee() {
+    let mut mempool =
+        MempoolStore::with_ordering(PriorityOpId(0), 100, MempoolOrdering::PriorityFee);
+    let account0 = Address::random();
+    let account1 = Address::random();
+    mempool.insert(
+        vec![
+            gen_l2_tx_with_priority_fee(account0, Nonce(0), 1, 10),
+            gen_l2_tx_with_priority_fee(account1, Nonce(0), 2, 100),
+        ],
+        HashMap::new(),
+    );
+    // Replace the transaction of `account0` with one paying a higher priority fee.
+    mempool.insert(
+        vec![gen_l2_tx_with_priority_fee(account0, Nonce(0), 3, 200)],
+        HashMap::new(),
+    );
+    assert_eq!(mempool.stats().l2_transaction_count, 2);
+    assert_eq!(mempool.stats().l2_priority_queue_size, 2);
+
+    let filter = L2TxFilter::default();
+    let tx = mempool.next_transaction(&filter).unwrap();
+    assert_eq!(tx.initiator_account(), account0);
+    assert_matches_priority_fee(&tx, 200);
+    assert_eq!(view(mempool.next_transaction(&filter)), (account1, 0));
+    assert_eq!(mempool.next_transaction(&filter), None);
+}
+
+#[test]
+fn fifo_ordering_ignores_fees() {
+    let mut mempool = MempoolStore::with_ordering(PriorityOpId(0), 100, MempoolOrdering::Fifo);
+    let account0 = Address::random();
+    let account1 = Address::random();
+    let transactions = vec![
+        gen_l2_tx_with_priority_fee(account0, Nonce(0), 1, 10),
+        gen_l2_tx_with_priority_fee(account1, Nonce(0), 2, 1_000),
+    ];
+    mempool.insert(transactions, HashMap::new());
+
+    let filter = L2TxFilter::default();
+    assert_eq!(view(mempool.next_transaction(&filter)), (account0, 0));
+    assert_eq!(view(mempool.next_transaction(&filter)), (account1, 0));
+}
+
+#[test]
+fn evicting_low_fee_transactions() {
+    fn gen_l2_tx_with_fee(address: Address, nonce: Nonce, max_fee_per_gas: u64) -> Transaction {
+        let mut tx = gen_l2_tx(address, nonce);
+        match &mut tx.common_data {
+            ExecuteTransactionCommon::L2(data) => {
+                data.fee.max_fee_per_gas = U256::from(max_fee_per_gas);
+            }
+            _ => unreachable!(),
+        }
+        tx
+    }
+
+    let mut mempool = MempoolStore::new(PriorityOpId(0), 3);
+    let account0 = Address::random();
+    let account1 = Address::random();
+    let account2 = Address::random();
+    let account3 = Address::random();
+    let transactions = vec![
+        gen_l2_tx_with_fee(account0, Nonce(0), 100),
+        gen_l2_tx_with_fee(account0, Nonce(1), 100),
+        gen_l2_tx_with_fee(account1, Nonce(0), 10),
+        gen_l2_tx_with_fee(account2, Nonce(0), 50),
+        gen_l2_tx_with_fee(account3, Nonce(1), 1_000),
+    ];
+    mempool.insert(transactions, HashMap::new());
+
+    // `account3` is purged because of the nonce gap, and `account1` is evicted as the one with the lowest fee.
+    assert_eq!(
+        HashSet::<_>::from_iter(mempool.get_mempool_info().purged_accounts),
+        HashSet::<_>::from_iter([account1, account3]),
+    );
+    assert_eq!(mempool.stats().l2_transaction_count, 3);
+
+    let filter = L2TxFilter::default();
+    let mut accounts = vec![];
+    while let Some(tx) = mempool.next_transaction(&filter) {
+        accounts.push(tx.initiator_account());
+    }
+    accounts.sort_unstable();
+    let mut expected_accounts = vec![account0, account0, account2];
+    expected_accounts.sort_unstable();
+    assert_eq!(accounts, expected_accounts);
+}
+
 fn gen_l2_tx(address: Address, nonce: Nonce) -> Transaction {
     gen_l2_tx_with_timestamp(address, nonce, unix_timestamp_ms())
 }
@@ -384,6 +504,32 @@ fn gen_l2_tx_with_timestamp(address: Address, nonce: Nonce, received_at_ms: u64)
     txn.into()
 }
 
+fn gen_l2_tx_with_priority_fee(
+    address: Address,
+    nonce: Nonce,
+    received_at_ms: u64,
+    max_priority_fee_per_gas: u64,
+) -> Transaction {
+    let mut tx = gen_l2_tx_with_timestamp(address, nonce, received_at_ms);
+    match &mut tx.common_data {
+        ExecuteTransactionCommon::L2(data) => {
+            data.fee.max_fee_per_gas = U256::from(1_000);
+            data.fee.max_priority_fee_per_gas = U256::from(max_priority_fee_per_gas);
+        }
+        _ => unreachable!(),
+    }
+    tx
+}
+
+fn assert_matches_priority_fee(tx: &Transaction, expected: u64) {
+    match &tx.common_data {
+        ExecuteTransactionCommon::L2(data) => {
+            assert_eq!(data.fee.max_priority_fee_per_gas, U256::from(expected));
+        }
+        _ => unreachable!(),
+    }
+}
+
 fn gen_l1_tx(priority_id: PriorityOpId) -> Transaction {
     let execute = Execute {
         contract_address: Some(Address::repeat_byte(0x11)),
@@ -12,13 +12,16 @@ pub(crate) struct AccountTransactions {
     /// account nonce in mempool
     /// equals to committed nonce in db + number of transactions sent to state keeper
     nonce: Nonce,
+    /// ordering used to score transactions
+    ordering: MempoolOrdering,
 }
 
 impl AccountTransactions {
-    pub fn new(nonce: Nonce) -> Self {
+    pub fn new(nonce: Nonce, ordering: MempoolOrdering) -> Self {
         Self {
             transactions: HashMap::new(),
             nonce,
+            ordering,
         }
     }
 
@@ -30,11 +33,11 @@ impl AccountTransactions {
         if nonce < self.nonce {
             return metadata;
         }
-        let new_score = Self::score_for_transaction(&transaction);
+        let new_score = self.ordering.score(&transaction);
         let previous_score = self
             .transactions
             .insert(nonce, transaction)
-            .map(|tx| Self::score_for_transaction(&tx));
+            .map(|tx| self.ordering.score(&tx));
         metadata.is_new = previous_score.is_none();
         if nonce == self.nonce {
             metadata.new_score = Some(new_score);
@@ -54,7 +57,7 @@ impl AccountTransactions {
         let score = self
             .transactions
             .get(&self.nonce)
-            .map(Self::score_for_transaction);
+            .map(|tx| self.ordering.score(tx));
         (transaction, score)
     }
 
@@ -67,28 +70,54 @@ impl AccountTransactions {
         self.nonce = self.nonce.min(tx_nonce);
         self.transactions
             .get(&(tx_nonce + 1))
-            .map(Self::score_for_transaction)
+            .map(|tx| self.ordering.score(tx))
     }
 
     pub fn len(&self) -> usize {
         self.transactions.len()
     }
+}
+
+/// Policy used to order L2 transactions in the mempool. Regardless of the policy, transactions
+/// of a single account are always returned in the nonce order.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MempoolOrdering {
+    /// Transactions are ordered by the time they were received.
+    #[default]
+    Fifo,
+    /// Transactions with a higher priority fee per gas go first; transactions with equal fees
+    /// are ordered by the time they were received.
+    PriorityFee,
+}
 
-    fn score_for_transaction(transaction: &L2Tx) -> MempoolScore {
+impl MempoolOrdering {
+    pub(crate) fn score(self, transaction: &L2Tx) -> MempoolScore {
+        let fee_data = &transaction.common_data.fee;
+        let priority = match self {
+            Self::Fifo => U256::zero(),
+            // Priority fee cannot exceed the max fee per gas, so we cap it to prevent
+            // transactions from getting priority by specifying a bogus priority fee.
+            Self::PriorityFee => fee_data
+                .max_priority_fee_per_gas
+                .min(fee_data.max_fee_per_gas),
+        };
         MempoolScore {
             account: transaction.initiator_account(),
             received_at_ms: transaction.received_timestamp_ms,
-            fee_data: transaction.common_data.fee.clone(),
+            priority,
+            fee_data: fee_data.clone(),
         }
     }
 }
 
-/// Mempool score of transaction. Used to prioritize L2 transactions in mempool
-/// Currently trivial ordering is used based on received at timestamp
+/// Mempool score of transaction. Used to prioritize L2 transactions in mempool.
+/// Transactions with higher `priority` go first; ties are broken by the received at timestamp.
+/// `priority` is determined by the [`MempoolOrdering`] used by the mempool.
 #[derive(Eq, PartialEq, Clone, Debug, Hash)]
 pub struct MempoolScore {
     pub account: Address,
     pub received_at_ms: u64,
+    pub priority: U256,
     // Not used for actual scoring, but state keeper would request
     // transactions that have acceptable fee values (so transactions
     // with fee too low would be ignored until prices go down).
@@ -105,6 +134,10 @@ impl MempoolScore {
 
 impl Ord for MempoolScore {
     fn cmp(&self, other: &MempoolScore) -> Ordering {
+        match self.priority.cmp(&other.priority) {
+            Ordering::Equal => {}
+            ordering => return ordering,
+        }
         match self.received_at_ms.cmp(&other.received_at_ms).reverse() {
             Ordering::Equal => {}
             ordering => return ordering,
@@ -160,6 +193,7 @@ mod tests {
         let score = MempoolScore {
             account: Address::random(),
             received_at_ms: Default::default(), // Not important
+            priority: Default::default(),       // Not important
             fee_data: Fee {
                 gas_limit: Default::default(), // Not important
                 max_fee_per_gas: U256::from(MAX_FEE_PER_GAS),
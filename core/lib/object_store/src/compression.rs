@@ -0,0 +1,265 @@
+//! Object store wrapper compressing objects and verifying their integrity.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use futures::stream::BoxStream;
+
+use crate::raw::{Bucket, ObjectStore, ObjectStoreError};
+
+/// Magic bytes prepended to objects written by [`CompressingObjectStore`]. Objects without this prefix
+/// are treated as legacy ones and are returned as is. The magic bytes are random, so that a legacy object
+/// (e.g., a bincode-serialized one) cannot realistically start with them.
+const MAGIC: [u8; 16] = [
+    0x40, 0xe0, 0xd9, 0x49, 0x41, 0xce, 0x17, 0xaf, 0x6f, 0xaa, 0x21, 0xc8, 0xef, 0x50, 0x7e, 0x66,
+];
+const FORMAT_VERSION: u8 = 1;
+/// Length of the header: magic, format version, compression kind and CRC32 checksum of the uncompressed data.
+const HEADER_LEN: usize = MAGIC.len() + 2 + 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+enum Compression {
+    None = 0,
+    Zstd = 1,
+}
+
+impl Compression {
+    fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            0 => Some(Self::None),
+            1 => Some(Self::Zstd),
+            _ => None,
+        }
+    }
+}
+
+fn corrupted(message: String) -> ObjectStoreError {
+    ObjectStoreError::Other {
+        source: message.into(),
+        is_retriable: false,
+    }
+}
+
+/// Encodes an object: compresses it with zstd (unless compression doesn't reduce its size) and prepends a header
+/// with a checksum of the original data.
+fn encode(value: &[u8], level: i32) -> Result<Vec<u8>, ObjectStoreError> {
+    let compressed = zstd::bulk::compress(value, level)
+        .map_err(|err| ObjectStoreError::Serialization(err.into()))?;
+    let (compression, payload) = if compressed.len() < value.len() {
+        (Compression::Zstd, compressed.as_slice())
+    } else {
+        (Compression::None, value)
+    };
+
+    let mut encoded = Vec::with_capacity(HEADER_LEN + payload.len());
+    encoded.extend_from_slice(&MAGIC);
+    encoded.push(FORMAT_VERSION);
+    encoded.push(compression as u8);
+    encoded.extend_from_slice(&crc32fast::hash(value).to_le_bytes());
+    encoded.extend_from_slice(payload);
+    Ok(encoded)
+}
+
+/// Decodes an object produced by [`encode()`] verifying its checksum. Objects without a header are returned as is.
+fn decode(bucket: Bucket, key: &str, object: Vec<u8>) -> Result<Vec<u8>, ObjectStoreError> {
+    if !object.starts_with(&MAGIC) {
+        return Ok(object);
+    }
+    if object.len() < HEADER_LEN {
+        return Err(corrupted(format!(
+            "object `{key}` in bucket {bucket} has truncated header"
+        )));
+    }
+
+    let version = object[MAGIC.len()];
+    if version != FORMAT_VERSION {
+        return Err(corrupted(format!(
+            "object `{key}` in bucket {bucket} has unsupported format version {version}"
+        )));
+    }
+    let compression_byte = object[MAGIC.len() + 1];
+    let compression = Compression::from_byte(compression_byte).ok_or_else(|| {
+        corrupted(format!(
+            "object `{key}` in bucket {bucket} has unknown compression {compression_byte}"
+        ))
+    })?;
+    let checksum_bytes = &object[MAGIC.len() + 2..HEADER_LEN];
+    let expected_checksum = u32::from_le_bytes(checksum_bytes.try_into().unwrap());
+
+    let payload = &object[HEADER_LEN..];
+    let value = match compression {
+        Compression::None => payload.to_vec(),
+        Compression::Zstd => zstd::stream::decode_all(payload).map_err(|err| {
+            corrupted(format!(
+                "failed decompressing object `{key}` in bucket {bucket}: {err}"
+            ))
+        })?,
+    };
+    let checksum = crc32fast::hash(&value);
+    if checksum != expected_checksum {
+        return Err(corrupted(format!(
+            "checksum mismatch for object `{key}` in bucket {bucket}: expected {expected_checksum:#010x}, got {checksum:#010x}"
+        )));
+    }
+    Ok(value)
+}
+
+/// [`ObjectStore`] wrapper that transparently compresses stored objects using zstd and verifies their integrity
+/// using a checksum on reads. Objects written without this wrapper are read back unchanged.
+#[derive(Debug)]
+pub(crate) struct CompressingObjectStore {
+    inner: Arc<dyn ObjectStore>,
+    level: i32,
+}
+
+impl CompressingObjectStore {
+    pub fn new(inner: Arc<dyn ObjectStore>, level: i32) -> Self {
+        Self { inner, level }
+    }
+}
+
+#[async_trait]
+impl ObjectStore for CompressingObjectStore {
+    async fn get_raw(&self, bucket: Bucket, key: &str) -> Result<Vec<u8>, ObjectStoreError> {
+        let object = self.inner.get_raw(bucket, key).await?;
+        if !object.starts_with(&MAGIC) {
+            return Ok(object);
+        }
+        // Decompression and checksum computation are CPU-bound, so they're offloaded from the async runtime.
+        let key = key.to_owned();
+        tokio::task::spawn_blocking(move || decode(bucket, &key, object))
+            .await
+            .map_err(|err| ObjectStoreError::Serialization(err.into()))?
+    }
+
+    #[tracing::instrument(
+        name = "CompressingObjectStore::put_raw",
+        skip(self, value),
+        fields(value.len = value.len())
+    )]
+    async fn put_raw(
+        &self,
+        bucket: Bucket,
+        key: &str,
+        value: Vec<u8>,
+    ) -> Result<(), ObjectStoreError> {
+        let level = self.level;
+        let encoded = tokio::task::spawn_blocking(move || encode(&value, level))
+            .await
+            .map_err(|err| ObjectStoreError::Serialization(err.into()))??;
+        tracing::trace!(encoded.len = encoded.len(), "encoded object");
+        self.inner.put_raw(bucket, key, encoded).await
+    }
+
+    async fn remove_raw(&self, bucket: Bucket, key: &str) -> Result<(), ObjectStoreError> {
+        self.inner.remove_raw(bucket, key).await
+    }
+
+    fn list_raw<'a>(
+        &'a self,
+        bucket: Bucket,
+        prefix: &'a str,
+    ) -> BoxStream<'a, Result<String, ObjectStoreError>> {
+        self.inner.list_raw(bucket, prefix)
+    }
+
+    fn storage_prefix_raw(&self, bucket: Bucket) -> String {
+        self.inner.storage_prefix_raw(bucket)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::*;
+    use crate::MockObjectStore;
+
+    #[test]
+    fn encoding_roundtrip() {
+        let value = vec![42_u8; 1_024];
+        let encoded = encode(&value, 3).unwrap();
+        assert!(encoded.starts_with(&MAGIC));
+        assert_eq!(encoded[MAGIC.len() + 1], Compression::Zstd as u8);
+        assert!(encoded.len() < value.len());
+        assert_eq!(decode(Bucket::ProofsFri, "test", encoded).unwrap(), value);
+
+        // Incompressible data is stored uncompressed.
+        let value = vec![1_u8, 2, 3];
+        let encoded = encode(&value, 3).unwrap();
+        assert_eq!(encoded[MAGIC.len() + 1], Compression::None as u8);
+        assert_eq!(encoded.len(), HEADER_LEN + value.len());
+        assert_eq!(decode(Bucket::ProofsFri, "test", encoded).unwrap(), value);
+    }
+
+    #[test]
+    fn decoding_legacy_object() {
+        let value = vec![1_u8, 2, 3, 4, 5];
+        assert_eq!(
+            decode(Bucket::ProofsFri, "test", value.clone()).unwrap(),
+            value
+        );
+
+        // Legacy objects starting with a part of the magic bytes are returned as is as well.
+        let value = MAGIC[..MAGIC.len() - 1].to_vec();
+        assert_eq!(
+            decode(Bucket::ProofsFri, "test", value.clone()).unwrap(),
+            value
+        );
+    }
+
+    #[test]
+    fn detecting_corruption() {
+        let value = vec![1_u8, 2, 3];
+        let mut encoded = encode(&value, 3).unwrap();
+        *encoded.last_mut().unwrap() ^= 1;
+        let err = decode(Bucket::ProofsFri, "test", encoded).unwrap_err();
+        assert!(err.to_string().contains("checksum mismatch"), "{err}");
+        assert!(!err.is_retriable());
+
+        let encoded = encode(&[0_u8; 1_024], 3).unwrap();
+        let err = decode(
+            Bucket::ProofsFri,
+            "test",
+            encoded[..HEADER_LEN + 3].to_vec(),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("decompressing"), "{err}");
+
+        let err = decode(Bucket::ProofsFri, "test", MAGIC.to_vec()).unwrap_err();
+        assert!(err.to_string().contains("truncated"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn compressing_store_basics() {
+        let inner = MockObjectStore::arc();
+        inner
+            .put_raw(Bucket::ProofsFri, "legacy", vec![1, 2, 3])
+            .await
+            .unwrap();
+        let store = CompressingObjectStore::new(inner.clone(), 3);
+
+        let value = vec![42_u8; 1_024];
+        store
+            .put_raw(Bucket::ProofsFri, "test", value.clone())
+            .await
+            .unwrap();
+        let stored = inner.get_raw(Bucket::ProofsFri, "test").await.unwrap();
+        assert!(stored.len() < value.len());
+        assert_eq!(
+            store.get_raw(Bucket::ProofsFri, "test").await.unwrap(),
+            value
+        );
+
+        assert_eq!(
+            store.get_raw(Bucket::ProofsFri, "legacy").await.unwrap(),
+            [1, 2, 3]
+        );
+        let err = store
+            .get_raw(Bucket::ProofsFri, "missing")
+            .await
+            .unwrap_err();
+        assert_matches!(err, ObjectStoreError::KeyNotFound(_));
+    }
+}
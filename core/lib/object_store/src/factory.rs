@@ -1,15 +1,20 @@
-use std::sync::Arc;
+use std::{future, sync::Arc, time::Duration};
 
 use anyhow::Context as _;
 use tokio::sync::OnceCell;
-use zksync_config::configs::object_store::{ObjectStoreConfig, ObjectStoreMode};
+use zksync_config::configs::{
+    object_store::{ObjectStoreConfig, ObjectStoreMode},
+    secrets::ObjectStoreSecrets,
+};
 
 use crate::{
+    compression::CompressingObjectStore,
     file::FileBackedObjectStore,
     gcs::{GoogleCloudStore, GoogleCloudStoreAuthMode},
     mirror::MirroringObjectStore,
     raw::{ObjectStore, ObjectStoreError},
     retries::StoreWithRetries,
+    s3::{S3Credentials, S3Store},
 };
 
 /// Factory of [`ObjectStore`]s that caches the store instance once it's created. Used mainly for legacy reasons.
@@ -19,6 +24,7 @@ use crate::{
 #[derive(Debug)]
 pub struct ObjectStoreFactory {
     config: ObjectStoreConfig,
+    secrets: Option<ObjectStoreSecrets>,
     store: OnceCell<Arc<dyn ObjectStore>>,
 }
 
@@ -27,10 +33,19 @@ impl ObjectStoreFactory {
     pub fn new(config: ObjectStoreConfig) -> Self {
         Self {
             config,
+            secrets: None,
             store: OnceCell::new(),
         }
     }
 
+    /// Sets secrets used by the created store. If secrets are not set, they are read from the standard env variables
+    /// where applicable (e.g., `AWS_SECRET_ACCESS_KEY` for S3 stores).
+    #[must_use]
+    pub fn with_secrets(mut self, secrets: Option<ObjectStoreSecrets>) -> Self {
+        self.secrets = secrets;
+        self
+    }
+
     /// Creates an [`ObjectStore`] or returns a cached store if one was created previously.
     ///
     /// # Errors
@@ -39,7 +54,7 @@ impl ObjectStoreFactory {
     pub async fn create_store(&self) -> anyhow::Result<Arc<dyn ObjectStore>> {
         self.store
             .get_or_try_init(|| async {
-                Self::create_from_config(&self.config)
+                Self::create_from_config(&self.config, self.secrets.as_ref())
                     .await
                     .with_context(|| {
                         format!(
@@ -59,8 +74,21 @@ impl ObjectStoreFactory {
     /// Returns an error if store initialization fails (e.g., because of incorrect configuration).
     async fn create_from_config(
         config: &ObjectStoreConfig,
+        secrets: Option<&ObjectStoreSecrets>,
     ) -> Result<Arc<dyn ObjectStore>, ObjectStoreError> {
         tracing::trace!("Initializing object store with configuration {config:?}");
+        let store = Self::create_store_for_mode(config, secrets).await?;
+        Ok(if let Some(level) = config.zstd_compression_level {
+            Arc::new(CompressingObjectStore::new(store, level))
+        } else {
+            store
+        })
+    }
+
+    async fn create_store_for_mode(
+        config: &ObjectStoreConfig,
+        secrets: Option<&ObjectStoreSecrets>,
+    ) -> Result<Arc<dyn ObjectStore>, ObjectStoreError> {
         match &config.mode {
             ObjectStoreMode::GCS { bucket_base_url } => {
                 let store = StoreWithRetries::try_new(config.max_retries, || {
@@ -97,6 +125,31 @@ impl ObjectStoreFactory {
                 .await?;
                 Self::wrap_mirroring(store, config.local_mirror_path.as_ref()).await
             }
+            ObjectStoreMode::S3 {
+                endpoint,
+                bucket,
+                region,
+                access_key_id,
+                request_timeout_ms,
+            } => {
+                let secret_access_key = secrets
+                    .and_then(|secrets| secrets.s3_secret_access_key.as_ref())
+                    .map(|key| key.0.clone());
+                let credentials = S3Credentials::resolve(access_key_id.clone(), secret_access_key)?;
+                let request_timeout = request_timeout_ms
+                    .map_or(S3Store::DEFAULT_REQUEST_TIMEOUT, Duration::from_millis);
+                let store = StoreWithRetries::try_new(config.max_retries, || {
+                    future::ready(S3Store::new(
+                        endpoint,
+                        bucket.clone(),
+                        region.clone(),
+                        credentials.clone(),
+                        request_timeout,
+                    ))
+                })
+                .await?;
+                Self::wrap_mirroring(store, config.local_mirror_path.as_ref()).await
+            }
 
             ObjectStoreMode::FileBacked {
                 file_backed_base_path,
@@ -1,6 +1,7 @@
 use std::fmt::Debug;
 
 use async_trait::async_trait;
+use futures::stream::{self, BoxStream, StreamExt, TryStreamExt};
 use tokio::{fs, io};
 
 use crate::raw::{Bucket, ObjectStore, ObjectStoreError};
@@ -54,6 +55,29 @@ impl FileBackedObjectStore {
     fn filename(&self, bucket: Bucket, key: &str) -> String {
         format!("{}/{bucket}/{key}", self.base_dir)
     }
+
+    /// Returns the next file in the directory with the name starting with `prefix`.
+    async fn next_key(
+        read_dir: Option<fs::ReadDir>,
+        prefix: &str,
+    ) -> Result<Option<(String, Option<fs::ReadDir>)>, ObjectStoreError> {
+        let Some(mut read_dir) = read_dir else {
+            return Ok(None);
+        };
+        while let Some(entry) = read_dir.next_entry().await? {
+            if !entry.file_type().await?.is_file() {
+                continue;
+            }
+            // Non-UTF-8 filenames cannot be produced by the store, so it's safe to skip them.
+            let Ok(key) = entry.file_name().into_string() else {
+                continue;
+            };
+            if key.starts_with(prefix) {
+                return Ok(Some((key, Some(read_dir))));
+            }
+        }
+        Ok(None)
+    }
 }
 
 #[async_trait]
@@ -78,6 +102,28 @@ impl ObjectStore for FileBackedObjectStore {
         fs::remove_file(filename).await.map_err(From::from)
     }
 
+    fn list_raw<'a>(
+        &'a self,
+        bucket: Bucket,
+        prefix: &'a str,
+    ) -> BoxStream<'a, Result<String, ObjectStoreError>> {
+        let bucket_path = format!("{}/{bucket}", self.base_dir);
+        let read_dir = async move {
+            match fs::read_dir(bucket_path).await {
+                Ok(read_dir) => Ok(Some(read_dir)),
+                // The bucket directory is created lazily for some buckets.
+                Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+                Err(err) => Err(ObjectStoreError::from(err)),
+            }
+        };
+        stream::once(read_dir)
+            .map_ok(move |read_dir| {
+                stream::try_unfold(read_dir, move |read_dir| Self::next_key(read_dir, prefix))
+            })
+            .try_flatten()
+            .boxed()
+    }
+
     fn storage_prefix_raw(&self, bucket: Bucket) -> String {
         format!("{}/{}", self.base_dir, bucket)
     }
@@ -132,4 +178,39 @@ mod test {
             .await
             .unwrap();
     }
+
+    #[tokio::test]
+    async fn test_list() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.into_path().into_os_string().into_string().unwrap();
+        let object_store = FileBackedObjectStore::new(path).await.unwrap();
+        for key in ["test-key-1.bin", "test-key-2.bin", "other-key.bin"] {
+            object_store
+                .put_raw(Bucket::ProverJobs, key, vec![0, 1])
+                .await
+                .unwrap();
+        }
+
+        let mut keys: Vec<_> = object_store
+            .list_raw(Bucket::ProverJobs, "test-key")
+            .try_collect()
+            .await
+            .unwrap();
+        keys.sort_unstable();
+        assert_eq!(keys, ["test-key-1.bin", "test-key-2.bin"]);
+
+        let keys: Vec<_> = object_store
+            .list_raw(Bucket::ProofsFri, "")
+            .try_collect()
+            .await
+            .unwrap();
+        assert!(keys.is_empty());
+        // `DataAvailability` bucket directory is not created in the constructor.
+        let keys: Vec<_> = object_store
+            .list_raw(Bucket::DataAvailability, "")
+            .try_collect()
+            .await
+            .unwrap();
+        assert!(keys.is_empty());
+    }
 }
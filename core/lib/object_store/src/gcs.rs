@@ -3,6 +3,7 @@
 use std::{error::Error as StdError, fmt, io};
 
 use async_trait::async_trait;
+use futures::stream::{self, BoxStream, StreamExt, TryStreamExt};
 use google_cloud_auth::{credentials::CredentialsFile, error::Error as AuthError};
 use google_cloud_storage::{
     client::{Client, ClientConfig},
@@ -11,6 +12,7 @@ use google_cloud_storage::{
             delete::DeleteObjectRequest,
             download::Range,
             get::GetObjectRequest,
+            list::ListObjectsRequest,
             upload::{Media, UploadObjectRequest, UploadType},
         },
         Error as HttpError,
@@ -96,7 +98,7 @@ impl From<AuthError> for ObjectStoreError {
     }
 }
 
-fn is_retriable_http_error(err: &reqwest::Error) -> bool {
+pub(crate) fn is_retriable_http_error(err: &reqwest::Error) -> bool {
     err.is_timeout()
         || err.is_connect()
         // Not all request errors are logically transient, but a significant part of them are (e.g.,
@@ -215,6 +217,47 @@ impl ObjectStore for GoogleCloudStore {
         Ok(())
     }
 
+    fn list_raw<'a>(
+        &'a self,
+        bucket: Bucket,
+        prefix: &'a str,
+    ) -> BoxStream<'a, Result<String, ObjectStoreError>> {
+        let bucket_dir = format!("{}/", bucket.as_str());
+        let full_prefix = Self::filename(bucket.as_str(), prefix);
+        tracing::trace!(
+            "Listing objects in GCS with prefix {full_prefix} from bucket {}",
+            self.bucket_prefix
+        );
+
+        // The stream state is the token for the next page; `None` means that all pages are fetched.
+        let pages = stream::try_unfold(Some(None), move |page_token: Option<Option<String>>| {
+            let request = page_token.map(|page_token| ListObjectsRequest {
+                bucket: self.bucket_prefix.clone(),
+                prefix: Some(full_prefix.clone()),
+                page_token,
+                ..ListObjectsRequest::default()
+            });
+            let bucket_dir = bucket_dir.clone();
+            async move {
+                let Some(request) = request else {
+                    return Ok(None);
+                };
+                let response = self.client.list_objects(&request).await?;
+                let keys: Vec<_> = response
+                    .items
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(|object| Some(object.name.strip_prefix(&bucket_dir)?.to_owned()))
+                    .collect();
+                Ok::<_, ObjectStoreError>(Some((keys, response.next_page_token.map(Some))))
+            }
+        });
+        pages
+            .map_ok(|keys| stream::iter(keys.into_iter().map(Ok)))
+            .try_flatten()
+            .boxed()
+    }
+
     fn storage_prefix_raw(&self, bucket: Bucket) -> String {
         format!(
             "https://storage.googleapis.com/{}/{}",
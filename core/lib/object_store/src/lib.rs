@@ -3,6 +3,7 @@
 //!
 //! - [File-backed store](FileBackedObjectStore) saving blobs as separate files in the local filesystem
 //! - [GCS-based store](GoogleCloudStore)
+//! - [Store for S3-compatible APIs](S3Store) (AWS S3, MinIO etc.)
 //! - [Mock in-memory store](MockObjectStore)
 //!
 //! Normally, these implementations are not used directly. Instead, a store trait object (`Arc<dyn ObjectStore>`)
@@ -23,6 +24,7 @@
     clippy::doc_markdown
 )]
 
+mod compression;
 mod factory;
 mod file;
 mod gcs;
@@ -32,6 +34,7 @@ mod mock;
 mod objects;
 mod raw;
 mod retries;
+mod s3;
 
 // Re-export `bincode` crate so that client binaries can conveniently use it.
 pub use bincode;
@@ -48,4 +51,5 @@ pub use self::{
     mock::MockObjectStore,
     objects::StoredObject,
     raw::{Bucket, ObjectStore, ObjectStoreError},
+    s3::{S3Credentials, S3Store},
 };
@@ -1,6 +1,7 @@
 //! Mirroring object store.
 
 use async_trait::async_trait;
+use futures::stream::BoxStream;
 
 use crate::{file::FileBackedObjectStore, raw::ObjectStore, Bucket, ObjectStoreError};
 
@@ -85,6 +86,15 @@ impl<S: ObjectStore> ObjectStore for MirroringObjectStore<S> {
         Ok(())
     }
 
+    fn list_raw<'a>(
+        &'a self,
+        bucket: Bucket,
+        prefix: &'a str,
+    ) -> BoxStream<'a, Result<String, ObjectStoreError>> {
+        // The mirror may be incomplete, so the underlying store is the source of truth.
+        self.inner.list_raw(bucket, prefix)
+    }
+
     fn storage_prefix_raw(&self, bucket: Bucket) -> String {
         self.inner.storage_prefix_raw(bucket)
     }
@@ -3,6 +3,7 @@
 use std::{collections::HashMap, sync::Arc};
 
 use async_trait::async_trait;
+use futures::stream::{self, BoxStream, StreamExt};
 use tokio::sync::Mutex;
 
 use crate::raw::{Bucket, ObjectStore, ObjectStoreError};
@@ -54,6 +55,26 @@ impl ObjectStore for MockObjectStore {
         Ok(())
     }
 
+    fn list_raw<'a>(
+        &'a self,
+        bucket: Bucket,
+        prefix: &'a str,
+    ) -> BoxStream<'a, Result<String, ObjectStoreError>> {
+        let keys = async move {
+            let lock = self.inner.lock().await;
+            let mut keys: Vec<_> = lock
+                .get(&bucket)
+                .into_iter()
+                .flat_map(HashMap::keys)
+                .filter(|key| key.starts_with(prefix))
+                .cloned()
+                .collect();
+            keys.sort_unstable();
+            stream::iter(keys.into_iter().map(Ok))
+        };
+        stream::once(keys).flatten().boxed()
+    }
+
     fn storage_prefix_raw(&self, bucket: Bucket) -> String {
         bucket.to_string()
     }
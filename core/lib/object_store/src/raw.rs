@@ -1,6 +1,7 @@
 use std::{error, fmt};
 
 use async_trait::async_trait;
+use futures::stream::BoxStream;
 
 /// Bucket for [`ObjectStore`] in which objects can be placed.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -159,5 +160,20 @@ pub trait ObjectStore: 'static + fmt::Debug + Send + Sync {
     /// Returns an error if removal fails.
     async fn remove_raw(&self, bucket: Bucket, key: &str) -> Result<(), ObjectStoreError>;
 
+    /// Lists keys of the objects in the given bucket that start with the specified `prefix`.
+    /// Keys are returned in the same form as accepted by other methods (i.e., relative to the bucket);
+    /// their order is unspecified.
+    ///
+    /// Keys are fetched lazily, so this method can be used for buckets with a large number of objects.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields an error if listing fails. After an error, the stream should not be polled further.
+    fn list_raw<'a>(
+        &'a self,
+        bucket: Bucket,
+        prefix: &'a str,
+    ) -> BoxStream<'a, Result<String, ObjectStoreError>>;
+
     fn storage_prefix_raw(&self, bucket: Bucket) -> String;
 }
@@ -1,6 +1,7 @@
 use std::{any, fmt, future::Future, time::Duration};
 
 use async_trait::async_trait;
+use futures::stream::BoxStream;
 use rand::Rng;
 
 use crate::{
@@ -127,6 +128,16 @@ impl<S: ObjectStore> ObjectStore for StoreWithRetries<S> {
             .await
     }
 
+    fn list_raw<'a>(
+        &'a self,
+        bucket: Bucket,
+        prefix: &'a str,
+    ) -> BoxStream<'a, Result<String, ObjectStoreError>> {
+        // Listing is not retried: a stream cannot be resumed from an arbitrary point after an error,
+        // and restarting it would yield duplicate keys.
+        self.inner.list_raw(bucket, prefix)
+    }
+
     fn storage_prefix_raw(&self, bucket: Bucket) -> String {
         self.inner.storage_prefix_raw(bucket)
     }
@@ -137,6 +137,8 @@ impl ProtoRepr for proto::Web3JsonRpc {
                 .map(|x| x.try_into())
                 .transpose()
                 .context("mempool_cache_size")?,
+            tx_replacement_fee_bump_percentage: self.tx_replacement_fee_bump_percentage,
+            max_pending_txs_per_initiator: self.max_pending_txs_per_initiator,
             whitelisted_tokens_for_aa: self
                 .whitelisted_tokens_for_aa
                 .iter()
@@ -159,6 +161,8 @@ impl ProtoRepr for proto::Web3JsonRpc {
             filters_disabled: Some(this.filters_disabled),
             mempool_cache_update_interval: this.mempool_cache_update_interval,
             mempool_cache_size: this.mempool_cache_size.map(|x| x.try_into().unwrap()),
+            tx_replacement_fee_bump_percentage: this.tx_replacement_fee_bump_percentage,
+            max_pending_txs_per_initiator: this.max_pending_txs_per_initiator,
             filters_limit: this.filters_limit,
             subscriptions_limit: this.subscriptions_limit,
             pubsub_polling_interval: this.pubsub_polling_interval,
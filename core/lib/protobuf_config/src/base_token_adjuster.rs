@@ -45,6 +45,12 @@ impl ProtoRepr for proto::BaseTokenAdjuster {
             l1_update_deviation_percentage: self
                 .l1_update_deviation_percentage
                 .unwrap_or(Self::Type::default_l1_update_deviation_percentage()),
+            max_ratio_change_percentage: self.max_ratio_change_percentage,
+            ratio_change_confirmations: self
+                .ratio_change_confirmations
+                .unwrap_or(Self::Type::default_ratio_change_confirmations()),
+            max_ratio_staleness_ms: self.max_ratio_staleness_ms,
+            halt_on_guardrail_violation: self.halt_on_guardrail_violation.unwrap_or(false),
         })
     }
 
@@ -63,6 +69,10 @@ impl ProtoRepr for proto::BaseTokenAdjuster {
             default_priority_fee_per_gas: Some(this.default_priority_fee_per_gas),
             max_acceptable_priority_fee_in_gwei: Some(this.max_acceptable_priority_fee_in_gwei),
             halt_on_error: Some(this.halt_on_error),
+            max_ratio_change_percentage: this.max_ratio_change_percentage,
+            ratio_change_confirmations: Some(this.ratio_change_confirmations),
+            max_ratio_staleness_ms: this.max_ratio_staleness_ms,
+            halt_on_guardrail_violation: Some(this.halt_on_guardrail_violation),
         }
     }
 }
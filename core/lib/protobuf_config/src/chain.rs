@@ -22,6 +22,24 @@ impl proto::FeeModelVersion {
     }
 }
 
+impl proto::MempoolOrdering {
+    fn new(n: &configs::chain::MempoolOrdering) -> Self {
+        use configs::chain::MempoolOrdering as From;
+        match n {
+            From::Fifo => Self::Fifo,
+            From::PriorityFee => Self::PriorityFee,
+        }
+    }
+
+    fn parse(&self) -> configs::chain::MempoolOrdering {
+        use configs::chain::MempoolOrdering as To;
+        match self {
+            Self::Fifo => To::Fifo,
+            Self::PriorityFee => To::PriorityFee,
+        }
+    }
+}
+
 impl ProtoRepr for proto::StateKeeper {
     type Type = configs::chain::StateKeeperConfig;
     fn read(&self) -> anyhow::Result<Self::Type> {
@@ -81,6 +99,12 @@ impl ProtoRepr for proto::StateKeeper {
             protective_reads_persistence_enabled: self
                 .protective_reads_persistence_enabled
                 .unwrap_or_default(),
+            mempool_ordering: self
+                .mempool_ordering
+                .map(proto::MempoolOrdering::try_from)
+                .transpose()
+                .context("mempool_ordering")?
+                .map_or_else(Default::default, |ordering| ordering.parse()),
 
             // We need these values only for instantiating configs from environmental variables, so it's not
             // needed during the initialization from files
@@ -120,6 +144,7 @@ impl ProtoRepr for proto::StateKeeper {
             save_call_traces: Some(this.save_call_traces),
             max_circuits_per_batch: Some(this.max_circuits_per_batch.try_into().unwrap()),
             protective_reads_persistence_enabled: Some(this.protective_reads_persistence_enabled),
+            mempool_ordering: Some(proto::MempoolOrdering::new(&this.mempool_ordering).into()),
         }
     }
 }
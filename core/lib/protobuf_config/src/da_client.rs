@@ -1,7 +1,10 @@
 use anyhow::Context;
 use zksync_config::{
     configs::{
-        da_client::DAClientConfig::{Avail, ObjectStore},
+        da_client::{
+            multi::MultiDAClientConfig,
+            DAClientConfig::{Avail, Multi, ObjectStore},
+        },
         {self},
     },
     AvailConfig,
@@ -31,6 +34,16 @@ impl ProtoRepr for proto::DataAvailabilityClient {
             proto::data_availability_client::Config::ObjectStore(conf) => {
                 ObjectStore(object_store_proto::ObjectStore::read(conf)?)
             }
+            proto::data_availability_client::Config::Multi(conf) => Multi(MultiDAClientConfig {
+                clients: conf
+                    .clients
+                    .iter()
+                    .enumerate()
+                    .map(|(i, client)| client.read().with_context(|| format!("clients[{i}]")))
+                    .collect::<anyhow::Result<_>>()?,
+                inclusion_quorum: *required(&conf.inclusion_quorum).context("inclusion_quorum")?
+                    as usize,
+            }),
         };
 
         Ok(client)
@@ -54,6 +67,14 @@ impl ProtoRepr for proto::DataAvailabilityClient {
                     object_store_proto::ObjectStore::build(config),
                 )),
             },
+            Multi(config) => Self {
+                config: Some(proto::data_availability_client::Config::Multi(
+                    proto::MultiDaClientConfig {
+                        clients: config.clients.iter().map(ProtoRepr::build).collect(),
+                        inclusion_quorum: Some(config.inclusion_quorum as u64),
+                    },
+                )),
+            },
         }
     }
 }
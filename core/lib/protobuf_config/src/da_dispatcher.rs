@@ -12,6 +12,8 @@ impl ProtoRepr for proto::DataAvailabilityDispatcher {
             max_rows_to_dispatch: self.max_rows_to_dispatch,
             max_retries: self.max_retries.map(|x| x as u16),
             use_dummy_inclusion_data: self.use_dummy_inclusion_data,
+            verification_interval_ms: self.verification_interval_ms,
+            verification_max_attempts: self.verification_max_attempts,
         })
     }
 
@@ -21,6 +23,8 @@ impl ProtoRepr for proto::DataAvailabilityDispatcher {
             max_rows_to_dispatch: this.max_rows_to_dispatch,
             max_retries: this.max_retries.map(Into::into),
             use_dummy_inclusion_data: this.use_dummy_inclusion_data,
+            verification_interval_ms: this.verification_interval_ms,
+            verification_max_attempts: this.verification_max_attempts,
         }
     }
 }
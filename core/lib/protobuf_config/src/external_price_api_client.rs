@@ -1,7 +1,13 @@
-use zksync_config::configs::{self, external_price_api_client::ForcedPriceClientConfig};
-use zksync_protobuf::ProtoRepr;
+use anyhow::Context as _;
+use zksync_config::configs::{
+    self,
+    external_price_api_client::{
+        AggregatedPriceClientConfig, ForcedPriceClientConfig, UniswapTwapClientConfig,
+    },
+};
+use zksync_protobuf::{required, ProtoRepr};
 
-use crate::proto::external_price_api_client as proto;
+use crate::{parse_h160, proto::external_price_api_client as proto};
 
 impl ProtoRepr for proto::ExternalPriceApiClient {
     type Type = configs::external_price_api_client::ExternalPriceApiClientConfig;
@@ -18,6 +24,18 @@ impl ProtoRepr for proto::ExternalPriceApiClient {
                     denominator: self.forced_denominator,
                     fluctuation: self.forced_fluctuation,
                 }),
+                uniswap_twap: self
+                    .uniswap_twap
+                    .as_ref()
+                    .map(ProtoRepr::read)
+                    .transpose()
+                    .context("uniswap_twap")?,
+                aggregated: self
+                    .aggregated
+                    .as_ref()
+                    .map(ProtoRepr::read)
+                    .transpose()
+                    .context("aggregated")?,
             },
         )
     }
@@ -35,6 +53,60 @@ impl ProtoRepr for proto::ExternalPriceApiClient {
             forced_numerator: numerator,
             forced_denominator: denominator,
             forced_fluctuation: fluctuation,
+            uniswap_twap: this.uniswap_twap.as_ref().map(ProtoRepr::build),
+            aggregated: this.aggregated.as_ref().map(ProtoRepr::build),
+        }
+    }
+}
+
+impl ProtoRepr for proto::UniswapTwap {
+    type Type = UniswapTwapClientConfig;
+
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        Ok(UniswapTwapClientConfig {
+            pool_address: required(&self.pool_address)
+                .and_then(|x| parse_h160(x))
+                .context("pool_address")?,
+            weth_address: required(&self.weth_address)
+                .and_then(|x| parse_h160(x))
+                .context("weth_address")?,
+            twap_interval_sec: *required(&self.twap_interval_sec).context("twap_interval_sec")?,
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            pool_address: Some(format!("{:?}", this.pool_address)),
+            weth_address: Some(format!("{:?}", this.weth_address)),
+            twap_interval_sec: Some(this.twap_interval_sec),
+        }
+    }
+}
+
+impl ProtoRepr for proto::Aggregated {
+    type Type = AggregatedPriceClientConfig;
+
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        Ok(AggregatedPriceClientConfig {
+            sources: self
+                .sources
+                .iter()
+                .enumerate()
+                .map(|(i, source)| source.read().with_context(|| format!("sources[{i}]")))
+                .collect::<anyhow::Result<_>>()?,
+            max_deviation_percent: *required(&self.max_deviation_percent)
+                .context("max_deviation_percent")?,
+            min_sources: required(&self.min_sources)
+                .and_then(|x| Ok((*x).try_into()?))
+                .context("min_sources")?,
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            sources: this.sources.iter().map(ProtoRepr::build).collect(),
+            max_deviation_percent: Some(this.max_deviation_percent),
+            min_sources: Some(this.min_sources as u64),
         }
     }
 }
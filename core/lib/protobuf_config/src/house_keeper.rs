@@ -12,6 +12,8 @@ impl ProtoRepr for proto::HouseKeeper {
                 &self.l1_batch_metrics_reporting_interval_ms,
             )
             .context("l1_batch_metrics_reporting_interval_ms")?,
+            object_store_retention_l1_batches: self.object_store_retention_l1_batches,
+            object_store_retention_interval_ms: self.object_store_retention_interval_ms,
         })
     }
 
@@ -20,6 +22,8 @@ impl ProtoRepr for proto::HouseKeeper {
             l1_batch_metrics_reporting_interval_ms: Some(
                 this.l1_batch_metrics_reporting_interval_ms,
             ),
+            object_store_retention_l1_batches: this.object_store_retention_l1_batches,
+            object_store_retention_interval_ms: this.object_store_retention_interval_ms,
         }
     }
 }
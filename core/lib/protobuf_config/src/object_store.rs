@@ -37,6 +37,13 @@ impl ProtoRepr for proto::ObjectStore {
                     .context("file_backed_base_path")?
                     .clone(),
             },
+            proto::object_store::Mode::S3(mode) => ObjectStoreMode::S3 {
+                endpoint: required(&mode.endpoint).context("endpoint")?.clone(),
+                bucket: required(&mode.bucket).context("bucket")?.clone(),
+                region: required(&mode.region).context("region")?.clone(),
+                access_key_id: mode.access_key_id.clone(),
+                request_timeout_ms: mode.request_timeout_ms,
+            },
         };
 
         Ok(Self::Type {
@@ -45,6 +52,7 @@ impl ProtoRepr for proto::ObjectStore {
                 .and_then(|x| Ok((*x).try_into()?))
                 .context("max_retries")?,
             local_mirror_path: self.local_mirror_path.clone(),
+            zstd_compression_level: self.zstd_compression_level,
         })
     }
 
@@ -76,12 +84,26 @@ impl ProtoRepr for proto::ObjectStore {
             } => proto::object_store::Mode::FileBacked(proto::object_store::FileBacked {
                 file_backed_base_path: Some(file_backed_base_path.clone()),
             }),
+            ObjectStoreMode::S3 {
+                endpoint,
+                bucket,
+                region,
+                access_key_id,
+                request_timeout_ms,
+            } => proto::object_store::Mode::S3(proto::object_store::S3 {
+                endpoint: Some(endpoint.clone()),
+                bucket: Some(bucket.clone()),
+                region: Some(region.clone()),
+                access_key_id: access_key_id.clone(),
+                request_timeout_ms: *request_timeout_ms,
+            }),
         };
 
         Self {
             mode: Some(mode),
             max_retries: Some(this.max_retries.into()),
             local_mirror_path: this.local_mirror_path.clone(),
+            zstd_compression_level: this.zstd_compression_level,
         }
     }
 }
@@ -6,7 +6,7 @@ use zksync_basic_types::{seed_phrase::SeedPhrase, url::SensitiveUrl};
 use zksync_config::configs::{
     consensus::{AttesterSecretKey, ConsensusSecrets, NodeSecretKey, ValidatorSecretKey},
     da_client::avail::AvailSecrets,
-    secrets::{DataAvailabilitySecrets, Secrets},
+    secrets::{DataAvailabilitySecrets, ObjectStoreSecrets, S3SecretAccessKey, Secrets},
     DatabaseSecrets, L1Secrets,
 };
 use zksync_protobuf::{required, ProtoRepr};
@@ -28,6 +28,7 @@ impl ProtoRepr for proto::Secrets {
             database: read_optional_repr(&self.database),
             l1: read_optional_repr(&self.l1),
             data_availability: read_optional_repr(&self.da),
+            object_store: read_optional_repr(&self.object_store),
         })
     }
 
@@ -37,6 +38,23 @@ impl ProtoRepr for proto::Secrets {
             l1: this.l1.as_ref().map(ProtoRepr::build),
             consensus: this.consensus.as_ref().map(ProtoRepr::build),
             da: this.data_availability.as_ref().map(ProtoRepr::build),
+            object_store: this.object_store.as_ref().map(ProtoRepr::build),
+        }
+    }
+}
+
+impl ProtoRepr for proto::ObjectStoreSecrets {
+    type Type = ObjectStoreSecrets;
+
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        Ok(Self::Type {
+            s3_secret_access_key: self.s3_secret_access_key.clone().map(S3SecretAccessKey),
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            s3_secret_access_key: this.s3_secret_access_key.as_ref().map(|key| key.0.clone()),
         }
     }
 }
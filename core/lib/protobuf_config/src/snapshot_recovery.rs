@@ -62,26 +62,27 @@ impl ProtoRepr for proto::SnapshotRecovery {
                 .as_ref()
                 .and_then(|experimental| experimental.drop_storage_key_preimages)
                 .unwrap_or_default(),
+            bundle_path: self
+                .experimental
+                .as_ref()
+                .and_then(|experimental| experimental.bundle_path.clone()),
         })
     }
 
     fn build(this: &Self::Type) -> Self {
-        let (tree, experimental) = if this.tree == TreeRecoveryConfig::default() {
-            (None, None)
-        } else {
-            (
-                Some(proto::Tree {
-                    chunk_size: this.tree.chunk_size,
-                }),
-                Some(crate::proto::experimental::SnapshotRecovery {
-                    tree_recovery_parallel_persistence_buffer: this
-                        .tree
-                        .parallel_persistence_buffer
-                        .map(|a| a.get() as u64),
-                    drop_storage_key_preimages: Some(this.drop_storage_key_preimages),
-                }),
-            )
-        };
+        let tree = (this.tree != TreeRecoveryConfig::default()).then(|| proto::Tree {
+            chunk_size: this.tree.chunk_size,
+        });
+        let experimental = (tree.is_some() || this.bundle_path.is_some()).then(|| {
+            crate::proto::experimental::SnapshotRecovery {
+                tree_recovery_parallel_persistence_buffer: this
+                    .tree
+                    .parallel_persistence_buffer
+                    .map(|a| a.get() as u64),
+                drop_storage_key_preimages: Some(this.drop_storage_key_preimages),
+                bundle_path: this.bundle_path.clone(),
+            }
+        });
         let postgres = if this.postgres == PostgresRecoveryConfig::default() {
             None
         } else {
@@ -0,0 +1,127 @@
+//! Self-contained snapshot bundles stored in a local directory.
+
+use std::{path::PathBuf, sync::Arc};
+
+use anyhow::Context as _;
+use async_trait::async_trait;
+use zksync_object_store::{FileBackedObjectStore, ObjectStore};
+use zksync_types::{
+    api,
+    snapshots::{SnapshotBundleMetadata, SnapshotHeader},
+    tokens::TokenInfo,
+    L1BatchNumber, L2BlockNumber,
+};
+use zksync_web3_decl::error::EnrichedClientResult;
+
+use crate::SnapshotsApplierMainNodeClient;
+
+/// Snapshot bundle stored in a local directory (e.g., exported by the snapshot creator). Allows recovering
+/// from a snapshot without a main node: the bundle is used instead of the main node client, and the snapshot data
+/// is read from the [object store](Self::object_store()) backed by the bundle directory.
+#[derive(Debug)]
+pub struct LocalSnapshotBundle {
+    path: PathBuf,
+    metadata: SnapshotBundleMetadata,
+}
+
+impl LocalSnapshotBundle {
+    /// Loads bundle metadata from the specified directory.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the metadata cannot be read or is inconsistent.
+    pub async fn load(path: PathBuf) -> anyhow::Result<Self> {
+        let metadata_path = path.join(SnapshotBundleMetadata::FILE_NAME);
+        let raw_metadata = tokio::fs::read(&metadata_path)
+            .await
+            .with_context(|| format!("failed reading bundle metadata at {metadata_path:?}"))?;
+        let metadata: SnapshotBundleMetadata = serde_json::from_slice(&raw_metadata)
+            .with_context(|| format!("failed parsing bundle metadata at {metadata_path:?}"))?;
+
+        let snapshot = metadata
+            .snapshot()
+            .context("bundle contains no snapshots")?;
+        anyhow::ensure!(
+            snapshot.l1_batch_number == metadata.l1_batch.number,
+            "bundle snapshot L1 batch #{} doesn't match L1 batch details: {:?}",
+            snapshot.l1_batch_number,
+            metadata.l1_batch
+        );
+        anyhow::ensure!(
+            snapshot.l2_block_number == metadata.l2_block.number,
+            "bundle snapshot L2 block #{} doesn't match L2 block details: {:?}",
+            snapshot.l2_block_number,
+            metadata.l2_block
+        );
+        Ok(Self { path, metadata })
+    }
+
+    /// Returns bundle metadata.
+    pub fn metadata(&self) -> &SnapshotBundleMetadata {
+        &self.metadata
+    }
+
+    /// Creates an object store providing snapshot data from the bundle.
+    ///
+    /// # Errors
+    ///
+    /// Propagates I/O errors.
+    pub async fn object_store(&self) -> anyhow::Result<Arc<dyn ObjectStore>> {
+        let path = self
+            .path
+            .to_str()
+            .with_context(|| format!("bundle path {:?} is not UTF-8", self.path))?;
+        let store = FileBackedObjectStore::new(path.to_owned())
+            .await
+            .context("failed initializing bundle object store")?;
+        Ok(Arc::new(store))
+    }
+}
+
+#[async_trait]
+impl SnapshotsApplierMainNodeClient for LocalSnapshotBundle {
+    async fn fetch_l1_batch_details(
+        &self,
+        number: L1BatchNumber,
+    ) -> EnrichedClientResult<Option<api::L1BatchDetails>> {
+        let details = &self.metadata.l1_batch;
+        Ok((details.number == number).then(|| details.clone()))
+    }
+
+    async fn fetch_l2_block_details(
+        &self,
+        number: L2BlockNumber,
+    ) -> EnrichedClientResult<Option<api::BlockDetails>> {
+        let details = &self.metadata.l2_block;
+        Ok((details.number == number).then(|| details.clone()))
+    }
+
+    async fn fetch_newest_snapshot_l1_batch_number(
+        &self,
+    ) -> EnrichedClientResult<Option<L1BatchNumber>> {
+        Ok(self
+            .metadata
+            .snapshot()
+            .map(|snapshot| snapshot.l1_batch_number))
+    }
+
+    async fn fetch_snapshot(
+        &self,
+        l1_batch_number: L1BatchNumber,
+    ) -> EnrichedClientResult<Option<SnapshotHeader>> {
+        let snapshot = self
+            .metadata
+            .snapshots
+            .iter()
+            .find(|snapshot| snapshot.l1_batch_number == l1_batch_number);
+        Ok(snapshot.cloned())
+    }
+
+    async fn fetch_tokens(
+        &self,
+        _at_l2_block: L2BlockNumber,
+    ) -> EnrichedClientResult<Vec<TokenInfo>> {
+        // The bundle only contains tokens as of the snapshot L2 block, which is the only block tokens are requested for.
+        Ok(self.metadata.tokens.clone())
+    }
+}
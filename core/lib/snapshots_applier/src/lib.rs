@@ -1,7 +1,12 @@
 //! Logic for applying application-level snapshots to Postgres storage.
 
 use std::{
-    cmp::Ordering, collections::HashMap, fmt, mem, num::NonZeroUsize, sync::Arc, time::Duration,
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap},
+    fmt, mem,
+    num::NonZeroUsize,
+    sync::Arc,
+    time::Duration,
 };
 
 use anyhow::Context as _;
@@ -28,8 +33,10 @@ use zksync_web3_decl::{
     namespaces::{EnNamespaceClient, SnapshotsNamespaceClient, ZksNamespaceClient},
 };
 
+pub use self::bundle::LocalSnapshotBundle;
 use self::metrics::{InitialStage, StorageLogsChunksStage, METRICS};
 
+mod bundle;
 mod metrics;
 #[cfg(test)]
 mod tests;
@@ -114,7 +121,8 @@ impl From<EnrichedClientError> for SnapshotsApplierError {
     }
 }
 
-/// Main node API used by the [`SnapshotsApplier`].
+/// Main node API used by the [`SnapshotsApplier`]. Besides the main node client, it's implemented by
+/// [`LocalSnapshotBundle`] to recover from a snapshot without a main node.
 #[async_trait]
 pub trait SnapshotsApplierMainNodeClient: fmt::Debug + Send + Sync {
     async fn fetch_l1_batch_details(
@@ -407,13 +415,85 @@ impl SnapshotsApplierTask {
     }
 }
 
+/// Chain of snapshots that need to be applied to recover the node state: a full snapshot optionally followed
+/// by incremental snapshots (see [`SnapshotVersion::Version2`]).
+#[derive(Debug, Clone, PartialEq)]
+struct SnapshotChain {
+    /// Version of the full snapshot at the root of the chain.
+    root_version: SnapshotVersion,
+    /// L1 batches of the snapshots in the chain in the application order, i.e., starting from the full snapshot
+    /// and ending with the snapshot being recovered from.
+    l1_batch_numbers: Vec<L1BatchNumber>,
+}
+
+impl SnapshotChain {
+    /// Resolves the snapshot chain by following base snapshot references starting from `snapshot`.
+    async fn fetch(
+        main_node_client: &dyn SnapshotsApplierMainNodeClient,
+        snapshot: &SnapshotHeader,
+    ) -> Result<Self, SnapshotsApplierError> {
+        let mut l1_batch_numbers = vec![snapshot.l1_batch_number];
+        let chunk_count = snapshot.storage_logs_chunks.len();
+        let mut version = SnapshotRecoveryStrategy::check_snapshot_version(snapshot.version)?;
+        let mut next_base_l1_batch_number = snapshot.base_l1_batch_number;
+
+        while version.is_incremental() {
+            let current_l1_batch_number = *l1_batch_numbers.last().unwrap();
+            let base_l1_batch_number = next_base_l1_batch_number.with_context(|| {
+                format!(
+                    "incremental snapshot for L1 batch #{current_l1_batch_number} doesn't specify its base snapshot"
+                )
+            })?;
+            if base_l1_batch_number >= current_l1_batch_number {
+                let err = anyhow::anyhow!(
+                    "incremental snapshot for L1 batch #{current_l1_batch_number} has base snapshot \
+                     for a non-preceding L1 batch #{base_l1_batch_number}"
+                );
+                return Err(err.into());
+            }
+
+            let base_snapshot = main_node_client
+                .fetch_snapshot(base_l1_batch_number)
+                .await?
+                .with_context(|| {
+                    format!("base snapshot for L1 batch #{base_l1_batch_number} is not present on main node")
+                })?;
+            if base_snapshot.storage_logs_chunks.len() != chunk_count {
+                let err = anyhow::anyhow!(
+                    "base snapshot for L1 batch #{base_l1_batch_number} has {} storage logs chunks, while \
+                     its increments have {chunk_count} chunks",
+                    base_snapshot.storage_logs_chunks.len()
+                );
+                return Err(err.into());
+            }
+            version = SnapshotRecoveryStrategy::check_snapshot_version(base_snapshot.version)?;
+            l1_batch_numbers.push(base_l1_batch_number);
+            next_base_l1_batch_number = base_snapshot.base_l1_batch_number;
+        }
+
+        if l1_batch_numbers.len() > 1 && version != SnapshotVersion::Version1 {
+            let err = anyhow::anyhow!(
+                "incremental snapshots can only be applied on top of a snapshot with version {:?}; \
+                 the root snapshot has version {version:?}",
+                SnapshotVersion::Version1
+            );
+            return Err(err.into());
+        }
+        l1_batch_numbers.reverse();
+        Ok(Self {
+            root_version: version,
+            l1_batch_numbers,
+        })
+    }
+}
+
 /// Strategy determining how snapshot recovery should proceed.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 enum SnapshotRecoveryStrategy {
     /// Snapshot recovery should proceed from scratch with the specified params.
-    New(SnapshotVersion),
+    New(SnapshotChain),
     /// Snapshot recovery should continue with the specified params.
-    Resumed(SnapshotVersion),
+    Resumed(SnapshotChain),
     /// Snapshot recovery has already been completed.
     Completed,
 }
@@ -446,11 +526,11 @@ impl SnapshotRecoveryStrategy {
                 })?;
             // Old snapshots can theoretically be removed by the node, but in this case the snapshot data may be removed as well,
             // so returning an error looks appropriate here.
-            let snapshot_version = Self::check_snapshot_version(snapshot_header.version)?;
+            let snapshot_chain = SnapshotChain::fetch(main_node_client, &snapshot_header).await?;
 
             let latency = latency.observe();
             tracing::info!("Re-initialized snapshots applier after reset/failure in {latency:?}");
-            Ok((Self::Resumed(snapshot_version), applied_snapshot_status))
+            Ok((Self::Resumed(snapshot_chain), applied_snapshot_status))
         } else {
             let is_genesis_needed = storage.blocks_dal().is_genesis_needed().await?;
             if !is_genesis_needed {
@@ -460,7 +540,7 @@ impl SnapshotRecoveryStrategy {
                 return Err(SnapshotsApplierError::Fatal(err));
             }
 
-            let (recovery_status, snapshot_version) =
+            let (recovery_status, snapshot_chain) =
                 Self::create_fresh_recovery_status(main_node_client, snapshot_l1_batch).await?;
 
             let storage_logs_count = storage
@@ -478,14 +558,14 @@ impl SnapshotRecoveryStrategy {
 
             let latency = latency.observe();
             tracing::info!("Initialized fresh snapshots applier in {latency:?}");
-            Ok((Self::New(snapshot_version), recovery_status))
+            Ok((Self::New(snapshot_chain), recovery_status))
         }
     }
 
     async fn create_fresh_recovery_status(
         main_node_client: &dyn SnapshotsApplierMainNodeClient,
         snapshot_l1_batch: Option<L1BatchNumber>,
-    ) -> Result<(SnapshotRecoveryStatus, SnapshotVersion), SnapshotsApplierError> {
+    ) -> Result<(SnapshotRecoveryStatus, SnapshotChain), SnapshotsApplierError> {
         let l1_batch_number = match snapshot_l1_batch {
             Some(num) => num,
             None => main_node_client
@@ -505,7 +585,13 @@ impl SnapshotRecoveryStrategy {
             version = snapshot.version,
             chunk_count = snapshot.storage_logs_chunks.len()
         );
-        let snapshot_version = Self::check_snapshot_version(snapshot.version)?;
+        let snapshot_chain = SnapshotChain::fetch(main_node_client, &snapshot).await?;
+        if snapshot_chain.l1_batch_numbers.len() > 1 {
+            tracing::info!(
+                "Snapshot is incremental; will apply snapshots for L1 batches {:?}",
+                snapshot_chain.l1_batch_numbers
+            );
+        }
 
         let l1_batch = main_node_client
             .fetch_l1_batch_details(l1_batch_number)
@@ -543,7 +629,7 @@ impl SnapshotRecoveryStrategy {
             protocol_version,
             storage_logs_chunks_processed: vec![false; snapshot.storage_logs_chunks.len()],
         };
-        Ok((status, snapshot_version))
+        Ok((status, snapshot_chain))
     }
 
     fn check_snapshot_version(raw_version: u16) -> anyhow::Result<SnapshotVersion> {
@@ -553,9 +639,16 @@ impl SnapshotRecoveryStrategy {
             )
         })?;
         anyhow::ensure!(
-            matches!(version, SnapshotVersion::Version0 | SnapshotVersion::Version1),
+            matches!(
+                version,
+                SnapshotVersion::Version0 | SnapshotVersion::Version1 | SnapshotVersion::Version2
+            ),
             "Cannot recover from a snapshot with version {version:?}; the only supported versions are {:?}",
-            [SnapshotVersion::Version0, SnapshotVersion::Version1]
+            [
+                SnapshotVersion::Version0,
+                SnapshotVersion::Version1,
+                SnapshotVersion::Version2
+            ]
         );
         Ok(version)
     }
@@ -579,13 +672,37 @@ impl StorageLogs {
                 let logs: SnapshotStorageLogsChunk<StorageKey> = blob_store.get(key).await?;
                 Ok(Self::V0(logs.storage_logs))
             }
-            SnapshotVersion::Version1 => {
+            SnapshotVersion::Version1 | SnapshotVersion::Version2 => {
                 let logs: SnapshotStorageLogsChunk = blob_store.get(key).await?;
                 Ok(Self::V1(logs.storage_logs))
             }
         }
     }
 
+    /// Applies logs from a chunk of an incremental snapshot. Logs in the increment override logs for the same keys.
+    fn apply_increment(&mut self, increment: Vec<SnapshotStorageLog>) -> anyhow::Result<()> {
+        let Self::V1(logs) = self else {
+            anyhow::bail!("incremental snapshots can only be applied to version 1 storage logs");
+        };
+        let mut logs_by_key: BTreeMap<_, _> = mem::take(logs)
+            .into_iter()
+            .map(|log| (log.key, log))
+            .collect();
+        for log in increment {
+            if let Some(prev_log) = logs_by_key.get(&log.key) {
+                anyhow::ensure!(
+                    prev_log.enumeration_index == log.enumeration_index
+                        && prev_log.l1_batch_number_of_initial_write
+                            == log.l1_batch_number_of_initial_write,
+                    "incremental storage log {log:?} is inconsistent with the base log {prev_log:?}"
+                );
+            }
+            logs_by_key.insert(log.key, log);
+        }
+        *logs = logs_by_key.into_values().collect();
+        Ok(())
+    }
+
     fn len(&self) -> usize {
         match self {
             Self::V0(logs) => logs.len(),
@@ -651,7 +768,7 @@ struct SnapshotsApplier<'a> {
     blob_store: &'a dyn ObjectStore,
     applied_snapshot_status: SnapshotRecoveryStatus,
     health_updater: &'a HealthUpdater,
-    snapshot_version: SnapshotVersion,
+    snapshot_chain: SnapshotChain,
     max_concurrency: usize,
     drop_storage_key_preimages: bool,
     factory_deps_recovered: bool,
@@ -684,10 +801,10 @@ impl<'a> SnapshotsApplier<'a> {
         )
         .await?;
         tracing::info!("Chosen snapshot recovery strategy: {strategy:?} with status: {applied_snapshot_status:?}");
-        let (created_from_scratch, snapshot_version) = match strategy {
+        let (created_from_scratch, snapshot_chain) = match &strategy {
             SnapshotRecoveryStrategy::Completed => return Ok((strategy, applied_snapshot_status)),
-            SnapshotRecoveryStrategy::New(version) => (true, version),
-            SnapshotRecoveryStrategy::Resumed(version) => (false, version),
+            SnapshotRecoveryStrategy::New(chain) => (true, chain.clone()),
+            SnapshotRecoveryStrategy::Resumed(chain) => (false, chain.clone()),
         };
 
         let mut this = Self {
@@ -696,7 +813,7 @@ impl<'a> SnapshotsApplier<'a> {
             blob_store: task.blob_store.as_ref(),
             applied_snapshot_status,
             health_updater,
-            snapshot_version,
+            snapshot_chain,
             max_concurrency: task.config.max_concurrency.get(),
             drop_storage_key_preimages: task.drop_storage_key_preimages,
             factory_deps_recovered: !created_from_scratch,
@@ -782,35 +899,40 @@ impl<'a> SnapshotsApplier<'a> {
     ) -> Result<(), SnapshotsApplierError> {
         let latency = METRICS.initial_stage_duration[&InitialStage::ApplyFactoryDeps].start();
 
-        tracing::debug!("Fetching factory dependencies from object store");
-        let l1_batch_number = self.applied_snapshot_status.l1_batch_number;
-        let factory_deps: SnapshotFactoryDependencies =
-            self.blob_store.get(l1_batch_number).await.map_err(|err| {
-                let context = format!(
-                    "cannot fetch factory deps for L1 batch #{l1_batch_number} from object store"
-                );
-                SnapshotsApplierError::object_store(err, context)
-            })?;
-        tracing::debug!(
-            "Fetched {} factory dependencies from object store",
-            factory_deps.factory_deps.len()
-        );
+        // Incremental snapshots only contain factory deps added after their base snapshot, so we need to apply
+        // factory deps from all snapshots in the chain.
+        for &l1_batch_number in &self.snapshot_chain.l1_batch_numbers {
+            tracing::debug!(
+                "Fetching factory dependencies for L1 batch #{l1_batch_number} from object store"
+            );
+            let factory_deps: SnapshotFactoryDependencies =
+                self.blob_store.get(l1_batch_number).await.map_err(|err| {
+                    let context = format!(
+                        "cannot fetch factory deps for L1 batch #{l1_batch_number} from object store"
+                    );
+                    SnapshotsApplierError::object_store(err, context)
+                })?;
+            tracing::debug!(
+                "Fetched {} factory dependencies from object store",
+                factory_deps.factory_deps.len()
+            );
 
-        // we cannot insert all factory deps because of field size limit triggered by UNNEST
-        // in underlying query, see `https://www.postgresql.org/docs/current/limits.html`
-        // there were around 100 thousand contracts on mainnet, where this issue first manifested
-        for chunk in factory_deps.factory_deps.chunks(1000) {
-            let chunk_deps_hashmap: HashMap<H256, Vec<u8>> = chunk
-                .iter()
-                .map(|dep| (hash_bytecode(&dep.bytecode.0), dep.bytecode.0.clone()))
-                .collect();
-            storage
-                .factory_deps_dal()
-                .insert_factory_deps(
-                    self.applied_snapshot_status.l2_block_number,
-                    &chunk_deps_hashmap,
-                )
-                .await?;
+            // we cannot insert all factory deps because of field size limit triggered by UNNEST
+            // in underlying query, see `https://www.postgresql.org/docs/current/limits.html`
+            // there were around 100 thousand contracts on mainnet, where this issue first manifested
+            for chunk in factory_deps.factory_deps.chunks(1000) {
+                let chunk_deps_hashmap: HashMap<H256, Vec<u8>> = chunk
+                    .iter()
+                    .map(|dep| (hash_bytecode(&dep.bytecode.0), dep.bytecode.0.clone()))
+                    .collect();
+                storage
+                    .factory_deps_dal()
+                    .insert_factory_deps(
+                        self.applied_snapshot_status.l2_block_number,
+                        &chunk_deps_hashmap,
+                    )
+                    .await?;
+            }
         }
 
         let latency = latency.observe();
@@ -873,18 +995,39 @@ impl<'a> SnapshotsApplier<'a> {
         let latency =
             METRICS.storage_logs_chunks_duration[&StorageLogsChunksStage::LoadFromGcs].start();
 
+        let (&root_l1_batch_number, increment_l1_batch_numbers) = self
+            .snapshot_chain
+            .l1_batch_numbers
+            .split_first()
+            .context("empty snapshot chain")?;
         let storage_key = SnapshotStorageLogsStorageKey {
             chunk_id,
-            l1_batch_number: self.applied_snapshot_status.l1_batch_number,
+            l1_batch_number: root_l1_batch_number,
         };
-        let mut storage_logs =
-            StorageLogs::load(self.blob_store, storage_key, self.snapshot_version)
-                .await
-                .map_err(|err| {
+        let mut storage_logs = StorageLogs::load(
+            self.blob_store,
+            storage_key,
+            self.snapshot_chain.root_version,
+        )
+        .await
+        .map_err(|err| {
+            let context = format!("cannot fetch storage logs {storage_key:?} from object store");
+            SnapshotsApplierError::object_store(err, context)
+        })?;
+
+        for &l1_batch_number in increment_l1_batch_numbers {
+            let storage_key = SnapshotStorageLogsStorageKey {
+                chunk_id,
+                l1_batch_number,
+            };
+            let increment: SnapshotStorageLogsChunk =
+                self.blob_store.get(storage_key).await.map_err(|err| {
                     let context =
                         format!("cannot fetch storage logs {storage_key:?} from object store");
                     SnapshotsApplierError::object_store(err, context)
                 })?;
+            storage_logs.apply_increment(increment.storage_logs)?;
+        }
 
         storage_logs.validate(&self.applied_snapshot_status)?;
         if self.drop_storage_key_preimages {
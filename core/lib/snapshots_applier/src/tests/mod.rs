@@ -17,8 +17,9 @@ use zksync_types::{
 };
 
 use self::utils::{
-    mock_l2_block_header, mock_recovery_status, mock_snapshot_header, mock_tokens, prepare_clients,
-    random_storage_logs, MockMainNodeClient, ObjectStoreWithErrors,
+    add_incremental_snapshot, mock_l2_block_header, mock_recovery_status, mock_snapshot_header,
+    mock_tokens, prepare_bundle, prepare_clients, random_storage_logs, MockMainNodeClient,
+    ObjectStoreWithErrors,
 };
 use super::*;
 use crate::tests::utils::HangingObjectStore;
@@ -243,6 +244,146 @@ async fn applier_recovers_explicitly_specified_snapshot() {
     assert_eq!(all_storage_logs.len(), storage_logs.len());
 }
 
+#[tokio::test]
+async fn applier_recovers_incremental_snapshot() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let base_status = mock_recovery_status();
+    let base_storage_logs = random_storage_logs::<H256>(base_status.l1_batch_number, 200);
+    let (object_store, mut client) = prepare_clients(&base_status, &base_storage_logs).await;
+
+    let expected_status = SnapshotRecoveryStatus {
+        l1_batch_number: base_status.l1_batch_number + 10,
+        l1_batch_root_hash: H256::random(),
+        l2_block_number: base_status.l2_block_number + 20,
+        l2_block_hash: H256::random(),
+        ..base_status.clone()
+    };
+    // Each increment chunk updates some logs from the corresponding base chunk and adds new logs.
+    let base_chunk_size = base_storage_logs.len() / 2;
+    let mut new_storage_logs = random_storage_logs::<H256>(expected_status.l1_batch_number, 50);
+    for (i, log) in new_storage_logs.iter_mut().enumerate() {
+        log.enumeration_index = (base_storage_logs.len() + i + 1) as u64;
+    }
+    let increment_chunks: Vec<Vec<_>> = base_storage_logs
+        .chunks(base_chunk_size)
+        .zip(new_storage_logs.chunks(25))
+        .map(|(base_chunk, new_logs)| {
+            let updated_logs = base_chunk.iter().step_by(5).map(|log| SnapshotStorageLog {
+                value: H256::random(),
+                ..log.clone()
+            });
+            updated_logs.chain(new_logs.iter().cloned()).collect()
+        })
+        .collect();
+    let mut expected_storage_logs: HashMap<_, _> = base_storage_logs
+        .iter()
+        .chain(increment_chunks.iter().flatten())
+        .map(|log| (log.key, log.clone()))
+        .collect();
+    let new_factory_dep = add_incremental_snapshot(
+        &*object_store,
+        &mut client,
+        &expected_status,
+        increment_chunks,
+    )
+    .await;
+
+    let task = SnapshotsApplierTask::new(
+        SnapshotsApplierConfig::for_tests(),
+        pool.clone(),
+        Box::new(client),
+        object_store,
+    );
+    let (_stop_sender, stop_receiver) = watch::channel(false);
+    let stats = task.run(stop_receiver).await.unwrap();
+    assert!(stats.done_work);
+
+    let mut storage = pool.connection().await.unwrap();
+    let status = storage
+        .snapshot_recovery_dal()
+        .get_applied_snapshot_status()
+        .await
+        .unwrap();
+    assert_eq!(status.unwrap(), expected_status);
+
+    let all_storage_logs = storage
+        .storage_logs_dal()
+        .dump_all_storage_logs_for_tests()
+        .await;
+    assert_eq!(all_storage_logs.len(), 250);
+    for db_log in all_storage_logs {
+        let expected_log = expected_storage_logs.remove(&db_log.hashed_key).unwrap();
+        assert_eq!(db_log.value, expected_log.value);
+        assert_eq!(db_log.l2_block_number, expected_status.l2_block_number);
+    }
+
+    // Factory deps from both snapshots must be recovered.
+    let factory_deps = storage
+        .factory_deps_dal()
+        .dump_all_factory_deps_for_tests()
+        .await;
+    assert_eq!(factory_deps.len(), 2);
+    let new_factory_dep_hash = hash_bytecode(&new_factory_dep.bytecode.0);
+    assert_eq!(
+        factory_deps[&new_factory_dep_hash],
+        new_factory_dep.bytecode.0
+    );
+}
+
+#[tokio::test]
+async fn applier_recovers_from_local_bundle() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let expected_status = mock_recovery_status();
+    let mut storage_logs = random_storage_logs(expected_status.l1_batch_number, 200);
+    for token in mock_tokens() {
+        if token.l2_address.is_zero() {
+            continue;
+        }
+        storage_logs.push(SnapshotStorageLog {
+            key: get_code_key(&token.l2_address).hashed_key(),
+            value: H256::random(),
+            l1_batch_number_of_initial_write: expected_status.l1_batch_number,
+            enumeration_index: storage_logs.len() as u64 + 1,
+        });
+    }
+    let bundle_dir = tempfile::TempDir::new().unwrap();
+    prepare_bundle(bundle_dir.path(), &expected_status, &storage_logs).await;
+
+    let bundle = LocalSnapshotBundle::load(bundle_dir.path().to_owned())
+        .await
+        .unwrap();
+    assert_eq!(bundle.metadata().tokens, mock_tokens());
+    let object_store = bundle.object_store().await.unwrap();
+    let task = SnapshotsApplierTask::new(
+        SnapshotsApplierConfig::for_tests(),
+        pool.clone(),
+        Box::new(bundle),
+        object_store,
+    );
+    let (_stop_sender, stop_receiver) = watch::channel(false);
+    let stats = task.run(stop_receiver).await.unwrap();
+    assert!(stats.done_work);
+
+    let mut storage = pool.connection().await.unwrap();
+    let status = storage
+        .snapshot_recovery_dal()
+        .get_applied_snapshot_status()
+        .await
+        .unwrap();
+    assert_eq!(status.unwrap(), expected_status);
+    let all_storage_logs = storage
+        .storage_logs_dal()
+        .dump_all_storage_logs_for_tests()
+        .await;
+    assert_eq!(all_storage_logs.len(), storage_logs.len());
+    let recovered_tokens = storage
+        .tokens_web3_dal()
+        .get_all_tokens(None)
+        .await
+        .unwrap();
+    assert_eq!(recovered_tokens.len(), 2);
+}
+
 #[tokio::test]
 async fn applier_error_for_missing_explicitly_specified_snapshot() {
     let pool = ConnectionPool::<Core>::test_pool().await;
@@ -3,18 +3,22 @@
 use std::{
     collections::HashMap,
     fmt, future,
+    path::Path,
     sync::{Arc, RwLock},
 };
 
 use async_trait::async_trait;
+use futures::stream::BoxStream;
 use tokio::sync::watch;
-use zksync_object_store::{Bucket, MockObjectStore, ObjectStore, ObjectStoreError, StoredObject};
+use zksync_object_store::{
+    Bucket, FileBackedObjectStore, MockObjectStore, ObjectStore, ObjectStoreError, StoredObject,
+};
 use zksync_types::{
     api,
     block::L2BlockHeader,
     snapshots::{
-        SnapshotFactoryDependencies, SnapshotFactoryDependency, SnapshotHeader,
-        SnapshotRecoveryStatus, SnapshotStorageLog, SnapshotStorageLogsChunk,
+        SnapshotBundleMetadata, SnapshotFactoryDependencies, SnapshotFactoryDependency,
+        SnapshotHeader, SnapshotRecoveryStatus, SnapshotStorageLog, SnapshotStorageLogsChunk,
         SnapshotStorageLogsChunkMetadata, SnapshotStorageLogsStorageKey, SnapshotVersion,
     },
     tokens::{TokenInfo, TokenMetadata},
@@ -53,6 +57,8 @@ pub(super) struct MockMainNodeClient {
     pub fetch_l1_batch_responses: HashMap<L1BatchNumber, api::L1BatchDetails>,
     pub fetch_l2_block_responses: HashMap<L2BlockNumber, api::BlockDetails>,
     pub fetch_newest_snapshot_response: Option<SnapshotHeader>,
+    /// Older snapshots (e.g., bases for incremental snapshots) keyed by their L1 batch.
+    pub fetch_snapshot_responses: HashMap<L1BatchNumber, SnapshotHeader>,
     pub tokens_response: Vec<TokenInfo>,
     pub tokens_response_error: Arc<RwLock<Option<EnrichedClientError>>>,
 }
@@ -98,10 +104,14 @@ impl SnapshotsApplierMainNodeClient for MockMainNodeClient {
         &self,
         l1_batch_number: L1BatchNumber,
     ) -> EnrichedClientResult<Option<SnapshotHeader>> {
-        Ok(self
+        let newest_snapshot = self
             .fetch_newest_snapshot_response
             .clone()
-            .filter(|response| response.l1_batch_number == l1_batch_number))
+            .filter(|response| response.l1_batch_number == l1_batch_number);
+        Ok(
+            newest_snapshot
+                .or_else(|| self.fetch_snapshot_responses.get(&l1_batch_number).cloned()),
+        )
     }
 
     async fn fetch_tokens(
@@ -161,6 +171,14 @@ impl ObjectStore for ObjectStoreWithErrors {
         unreachable!("Should not be used in snapshot applier")
     }
 
+    fn list_raw<'a>(
+        &'a self,
+        _bucket: Bucket,
+        _prefix: &'a str,
+    ) -> BoxStream<'a, Result<String, ObjectStoreError>> {
+        unreachable!("Should not be used in snapshot applier")
+    }
+
     fn storage_prefix_raw(&self, bucket: Bucket) -> String {
         self.inner.storage_prefix_raw(bucket)
     }
@@ -291,19 +309,19 @@ pub(super) fn mock_snapshot_header(
             })
             .collect(),
         factory_deps_filepath: "some_filepath".to_string(),
+        base_l1_batch_number: None,
     }
 }
 
-pub(super) async fn prepare_clients<K>(
+/// Puts factory deps and storage logs chunks for a snapshot into the `object_store`.
+async fn put_snapshot_data<K>(
+    object_store: &dyn ObjectStore,
     status: &SnapshotRecoveryStatus,
     logs: &[SnapshotStorageLog<K>],
-) -> (Arc<dyn ObjectStore>, MockMainNodeClient)
-where
+) where
     K: SnapshotLogKey,
     for<'a> SnapshotStorageLogsChunk<K>: StoredObject<Key<'a> = SnapshotStorageLogsStorageKey>,
 {
-    let object_store = MockObjectStore::arc();
-    let mut client = MockMainNodeClient::default();
     let factory_dep_bytes: Vec<u8> = (0..32).collect();
     let factory_deps = SnapshotFactoryDependencies {
         factory_deps: vec![SnapshotFactoryDependency {
@@ -333,6 +351,19 @@ where
             .await
             .unwrap();
     }
+}
+
+pub(super) async fn prepare_clients<K>(
+    status: &SnapshotRecoveryStatus,
+    logs: &[SnapshotStorageLog<K>],
+) -> (Arc<dyn ObjectStore>, MockMainNodeClient)
+where
+    K: SnapshotLogKey,
+    for<'a> SnapshotStorageLogsChunk<K>: StoredObject<Key<'a> = SnapshotStorageLogsStorageKey>,
+{
+    let object_store = MockObjectStore::arc();
+    let mut client = MockMainNodeClient::default();
+    put_snapshot_data(&*object_store, status, logs).await;
 
     client.fetch_newest_snapshot_response = Some(mock_snapshot_header(K::VERSION.into(), status));
     client.fetch_l1_batch_responses.insert(
@@ -350,6 +381,92 @@ where
     (object_store, client)
 }
 
+/// Writes a snapshot bundle with the specified storage logs to `path`.
+pub(super) async fn prepare_bundle(
+    path: &Path,
+    status: &SnapshotRecoveryStatus,
+    logs: &[SnapshotStorageLog],
+) -> SnapshotBundleMetadata {
+    let object_store = FileBackedObjectStore::new(path.to_str().unwrap().to_owned())
+        .await
+        .unwrap();
+    put_snapshot_data(&object_store, status, logs).await;
+
+    let metadata = SnapshotBundleMetadata {
+        snapshots: vec![mock_snapshot_header(
+            SnapshotVersion::Version1.into(),
+            status,
+        )],
+        l1_batch: l1_batch_details(status.l1_batch_number, status.l1_batch_root_hash),
+        l2_block: l2_block_details(
+            status.l2_block_number,
+            status.l1_batch_number,
+            status.l2_block_hash,
+        ),
+        tokens: mock_tokens(),
+    };
+    let raw_metadata = serde_json::to_vec(&metadata).unwrap();
+    std::fs::write(path.join(SnapshotBundleMetadata::FILE_NAME), raw_metadata).unwrap();
+    metadata
+}
+
+/// Adds an incremental snapshot on top of the newest snapshot known to the `client`. `chunks` must contain
+/// storage logs for each chunk of the base snapshot. Returns the factory dependency added in the increment.
+pub(super) async fn add_incremental_snapshot(
+    object_store: &dyn ObjectStore,
+    client: &mut MockMainNodeClient,
+    status: &SnapshotRecoveryStatus,
+    chunks: Vec<Vec<SnapshotStorageLog>>,
+) -> SnapshotFactoryDependency {
+    let base_snapshot = client
+        .fetch_newest_snapshot_response
+        .take()
+        .expect("no base snapshot");
+    assert_eq!(chunks.len(), base_snapshot.storage_logs_chunks.len());
+    assert_eq!(chunks.len(), status.storage_logs_chunks_processed.len());
+
+    let factory_dep = SnapshotFactoryDependency {
+        bytecode: Bytes::from(vec![1_u8; 32]),
+    };
+    let factory_deps = SnapshotFactoryDependencies {
+        factory_deps: vec![factory_dep.clone()],
+    };
+    object_store
+        .put(status.l1_batch_number, &factory_deps)
+        .await
+        .unwrap();
+    for (chunk_id, storage_logs) in chunks.into_iter().enumerate() {
+        let chunk_key = SnapshotStorageLogsStorageKey {
+            l1_batch_number: status.l1_batch_number,
+            chunk_id: chunk_id as u64,
+        };
+        object_store
+            .put(chunk_key, &SnapshotStorageLogsChunk { storage_logs })
+            .await
+            .unwrap();
+    }
+
+    let mut header = mock_snapshot_header(SnapshotVersion::Version2.into(), status);
+    header.base_l1_batch_number = Some(base_snapshot.l1_batch_number);
+    client.fetch_newest_snapshot_response = Some(header);
+    client
+        .fetch_snapshot_responses
+        .insert(base_snapshot.l1_batch_number, base_snapshot);
+    client.fetch_l1_batch_responses.insert(
+        status.l1_batch_number,
+        l1_batch_details(status.l1_batch_number, status.l1_batch_root_hash),
+    );
+    client.fetch_l2_block_responses.insert(
+        status.l2_block_number,
+        l2_block_details(
+            status.l2_block_number,
+            status.l1_batch_number,
+            status.l2_block_hash,
+        ),
+    );
+    factory_dep
+}
+
 /// Object store wrapper that hangs up after processing the specified number of requests.
 /// Used to emulate the snapshot applier being restarted since, if it's configured to have concurrency 1,
 /// the applier will request an object from the store strictly after fully processing all previously requested objects.
@@ -406,6 +523,14 @@ impl ObjectStore for HangingObjectStore {
         unreachable!("Should not be used in snapshot applier")
     }
 
+    fn list_raw<'a>(
+        &'a self,
+        _bucket: Bucket,
+        _prefix: &'a str,
+    ) -> BoxStream<'a, Result<String, ObjectStoreError>> {
+        unreachable!("Should not be used in snapshot applier")
+    }
+
     fn storage_prefix_raw(&self, bucket: Bucket) -> String {
         self.inner.storage_prefix_raw(bucket)
     }
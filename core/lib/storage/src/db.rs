@@ -298,6 +298,9 @@ pub struct RocksDBOptions {
     pub stalled_writes_retries: StalledWritesRetries,
     /// Number of open files that can be used by the DB. Default is None, for no limit.
     pub max_open_files: Option<NonZeroU32>,
+    /// Opens the DB in the read-only mode. All writes to such a DB will fail. The DB must exist
+    /// and contain all column families.
+    pub read_only: bool,
 }
 
 impl Default for RocksDBOptions {
@@ -308,6 +311,7 @@ impl Default for RocksDBOptions {
             large_memtable_capacity: None,
             stalled_writes_retries: StalledWritesRetries::new(Duration::from_secs(10)),
             max_open_files: None,
+            read_only: false,
         }
     }
 }
@@ -402,7 +406,11 @@ impl<CF: NamedColumnFamily> RocksDB<CF> {
             ColumnFamilyDescriptor::new(cf_name, cf_options)
         });
 
-        let db = DB::open_cf_descriptors(&db_options, path, cfs)?;
+        let db = if options.read_only {
+            DB::open_cf_descriptors_read_only(&db_options, path, cfs, false)?
+        } else {
+            DB::open_cf_descriptors(&db_options, path, cfs)?
+        };
         let inner = Arc::new(RocksDBInner {
             db,
             db_name: CF::DB_NAME,
@@ -418,7 +426,9 @@ impl<CF: NamedColumnFamily> RocksDB<CF> {
             path.display()
         );
 
-        inner.wait_for_writes_to_resume(&options.stalled_writes_retries);
+        if !options.read_only {
+            inner.wait_for_writes_to_resume(&options.stalled_writes_retries);
+        }
         Ok(Self {
             inner,
             sync_writes: false,
@@ -1034,6 +1044,33 @@ mod tests {
         assert_eq!(value, b"value2");
     }
 
+    #[test]
+    fn opening_db_in_read_only_mode() {
+        let temp_dir = TempDir::new().unwrap();
+        let db = RocksDB::<NewColumnFamilies>::new(temp_dir.path())
+            .unwrap()
+            .with_sync_writes();
+        let mut batch = db.new_write_batch();
+        batch.put_cf(NewColumnFamilies::Default, b"test", b"value");
+        db.write(batch).unwrap();
+
+        let options = RocksDBOptions {
+            read_only: true,
+            ..RocksDBOptions::default()
+        };
+        // Read-only instances can coexist with a read-write one.
+        let read_only_db =
+            RocksDB::<NewColumnFamilies>::with_options(temp_dir.path(), options).unwrap();
+        let value = read_only_db
+            .get_cf(NewColumnFamilies::Default, b"test")
+            .unwrap();
+        assert_eq!(value.unwrap(), b"value");
+
+        let mut batch = read_only_db.new_write_batch();
+        batch.put_cf(NewColumnFamilies::Other, b"test", b"value");
+        read_only_db.write(batch).unwrap_err();
+    }
+
     #[test]
     fn profiling_basics() {
         let temp_dir = TempDir::new().unwrap();
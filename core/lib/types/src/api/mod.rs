@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use chrono::{DateTime, Utc};
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
@@ -903,6 +905,56 @@ pub struct TransactionExecutionInfo {
     pub execution_info: Value,
 }
 
+/// Mempool transactions grouped by the initiator address and nonce, as returned by the `txpool_content`
+/// and `txpool_inspect` calls. `pending` transactions are executable, i.e. their nonces follow the account nonce
+/// without gaps; other transactions are `queued`. The response shape matches the one used by Geth,
+/// with the addition of the `truncated` flag.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct TxpoolContent<T = Transaction> {
+    pub pending: BTreeMap<Address, BTreeMap<u64, T>>,
+    pub queued: BTreeMap<Address, BTreeMap<u64, T>>,
+    /// Set if the returned transactions were truncated because of the entity limit configured for the node.
+    #[serde(default)]
+    pub truncated: bool,
+}
+
+impl<T> Default for TxpoolContent<T> {
+    fn default() -> Self {
+        Self {
+            pending: BTreeMap::new(),
+            queued: BTreeMap::new(),
+            truncated: false,
+        }
+    }
+}
+
+/// Mempool transactions of a single account grouped by nonce, as returned by the `txpool_contentFrom` call.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct TxpoolContentFrom<T = Transaction> {
+    pub pending: BTreeMap<u64, T>,
+    pub queued: BTreeMap<u64, T>,
+    /// Set if the returned transactions were truncated because of the entity limit configured for the node.
+    #[serde(default)]
+    pub truncated: bool,
+}
+
+impl<T> Default for TxpoolContentFrom<T> {
+    fn default() -> Self {
+        Self {
+            pending: BTreeMap::new(),
+            queued: BTreeMap::new(),
+            truncated: false,
+        }
+    }
+}
+
+/// Number of mempool transactions by their status, as returned by the `txpool_status` call.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize, Serialize)]
+pub struct TxpoolStatus {
+    pub pending: U64,
+    pub queued: U64,
+}
+
 /// The fee history type returned from `eth_feeHistory` call.
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
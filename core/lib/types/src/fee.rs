@@ -29,6 +29,52 @@ impl Fee {
     }
 }
 
+/// Priority fee charged from executed L2 transactions, together with the amount of gas used by them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TxPriorityFee {
+    /// Priority fee per gas actually charged, i.e. the effective gas price minus the base fee of the L2 block.
+    /// Since only the base fee is charged for now (see [`Fee::get_effective_gas_price()`]), this is always zero.
+    pub priority_fee_per_gas: U256,
+    /// Gas used by the transactions (i.e., gas limit minus refunded gas).
+    pub gas_used: u64,
+}
+
+/// Fee statistics of an L2 block persisted when the block is sealed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct L2BlockFeeStats {
+    /// Gas used by all transactions in the block.
+    pub gas_used: u64,
+    /// Priority fees charged from L2 transactions in the block. Transactions paying the same fee are merged;
+    /// fees are sorted in the ascending order.
+    pub priority_fees: Vec<TxPriorityFee>,
+}
+
+impl L2BlockFeeStats {
+    /// Accounts for an executed transaction. `priority_fee_per_gas` must be `None` for L1 and upgrade transactions.
+    pub fn push_tx(&mut self, priority_fee_per_gas: Option<U256>, gas_used: u64) {
+        self.gas_used = self.gas_used.saturating_add(gas_used);
+        let Some(priority_fee_per_gas) = priority_fee_per_gas else {
+            return;
+        };
+        match self
+            .priority_fees
+            .binary_search_by_key(&priority_fee_per_gas, |fee| fee.priority_fee_per_gas)
+        {
+            Ok(idx) => {
+                let fee = &mut self.priority_fees[idx];
+                fee.gas_used = fee.gas_used.saturating_add(gas_used);
+            }
+            Err(idx) => self.priority_fees.insert(
+                idx,
+                TxPriorityFee {
+                    priority_fee_per_gas,
+                    gas_used,
+                },
+            ),
+        }
+    }
+}
+
 /// Returns how many slots would ABI-encoding of the transaction with such parameters take
 pub fn encoding_len(
     data_len: u64,
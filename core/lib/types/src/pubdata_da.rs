@@ -1,9 +1,16 @@
+use anyhow::Context as _;
 use chrono::{DateTime, Utc};
 use num_enum::TryFromPrimitive;
 use serde::{Deserialize, Serialize};
 use zksync_basic_types::L1BatchNumber;
 use zksync_config::configs::eth_sender::PubdataSendingMode;
 
+use crate::{
+    commitment::SerializeCommitment,
+    l2_to_l1_log::L2ToL1Log,
+    writes::{decompress_state_diffs, DecompressedStateDiff},
+};
+
 /// Enum holding the current values used for DA Layers.
 #[repr(u8)]
 #[derive(Debug, Clone, Copy, Deserialize, PartialEq, Serialize)]
@@ -38,3 +45,156 @@ pub struct DataAvailabilityBlob {
     pub inclusion_data: Option<Vec<u8>>,
     pub sent_at: DateTime<Utc>,
 }
+
+/// Represents a part of the L1 batch pubdata dispatched as a separate blob. Used if the pubdata
+/// doesn't fit into a single blob of the data availability layer.
+#[derive(Debug, Clone)]
+pub struct DataAvailabilityChunk {
+    pub l1_batch_number: L1BatchNumber,
+    pub chunk_index: usize,
+    pub blob_id: String,
+    pub inclusion_data: Option<Vec<u8>>,
+    pub sent_at: DateTime<Utc>,
+}
+
+/// L1 batch pubdata decoded into its components. This is the inverse of the pubdata encoding performed by the VM
+/// (without uncompressed state diffs), i.e. the format of the data posted to DA layers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedPubdata {
+    pub user_logs: Vec<L2ToL1Log>,
+    pub l2_to_l1_messages: Vec<Vec<u8>>,
+    pub published_bytecodes: Vec<Vec<u8>>,
+    pub state_diffs: Vec<DecompressedStateDiff>,
+}
+
+impl ParsedPubdata {
+    /// Parses the pubdata. Fails if the pubdata is malformed or has trailing bytes.
+    pub fn parse(pubdata: &[u8]) -> anyhow::Result<Self> {
+        let mut reader = PubdataReader(pubdata);
+
+        let logs_count = reader
+            .read_u32()
+            .context("failed reading user logs count")?;
+        let user_logs = (0..logs_count)
+            .map(|i| {
+                let log = reader
+                    .read_bytes(L2ToL1Log::SERIALIZED_SIZE)
+                    .with_context(|| format!("failed reading user log #{i}"))?;
+                Ok(L2ToL1Log::from_slice(log))
+            })
+            .collect::<anyhow::Result<_>>()?;
+        let l2_to_l1_messages = reader
+            .read_byte_arrays()
+            .context("failed reading L2-to-L1 messages")?;
+        let published_bytecodes = reader
+            .read_byte_arrays()
+            .context("failed reading published bytecodes")?;
+
+        let (state_diffs, consumed) =
+            decompress_state_diffs(reader.0).context("failed decompressing state diffs")?;
+        let trailing_len = reader.0.len() - consumed;
+        anyhow::ensure!(
+            trailing_len == 0,
+            "pubdata has {trailing_len} unexpected trailing bytes"
+        );
+
+        Ok(Self {
+            user_logs,
+            l2_to_l1_messages,
+            published_bytecodes,
+            state_diffs,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct PubdataReader<'a>(&'a [u8]);
+
+impl<'a> PubdataReader<'a> {
+    fn read_bytes(&mut self, len: usize) -> anyhow::Result<&'a [u8]> {
+        anyhow::ensure!(
+            self.0.len() >= len,
+            "unexpected end of pubdata: expected {len} bytes, got {}",
+            self.0.len()
+        );
+        let (bytes, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(bytes)
+    }
+
+    fn read_u32(&mut self) -> anyhow::Result<u32> {
+        let bytes = self.read_bytes(4)?;
+        Ok(u32::from_be_bytes(bytes.try_into().unwrap()))
+    }
+
+    /// Reads a `u32`-prefixed list of `u32`-prefixed byte arrays.
+    fn read_byte_arrays(&mut self) -> anyhow::Result<Vec<Vec<u8>>> {
+        let count = self.read_u32()?;
+        (0..count)
+            .map(|_| {
+                let len = self.read_u32()? as usize;
+                Ok(self.read_bytes(len)?.to_vec())
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        writes::{compress_state_diffs, StateDiffKey, StateDiffRecord},
+        Address, H256, U256,
+    };
+
+    #[test]
+    fn parsing_pubdata() {
+        let log = L2ToL1Log {
+            shard_id: 0,
+            is_service: true,
+            tx_number_in_block: 3,
+            sender: Address::repeat_byte(1),
+            key: H256::repeat_byte(2),
+            value: H256::repeat_byte(3),
+        };
+        let state_diff = StateDiffRecord {
+            address: Address::repeat_byte(4),
+            key: U256::from(5),
+            derived_key: [6; 32],
+            enumeration_index: 0,
+            initial_value: U256::zero(),
+            final_value: U256::from(7),
+        };
+
+        let mut pubdata = vec![];
+        pubdata.extend(1_u32.to_be_bytes());
+        pubdata.extend(log.packed_encoding());
+        pubdata.extend(2_u32.to_be_bytes());
+        for message in [&b"hello"[..], &b""[..]] {
+            pubdata.extend((message.len() as u32).to_be_bytes());
+            pubdata.extend(message);
+        }
+        pubdata.extend(1_u32.to_be_bytes());
+        pubdata.extend(32_u32.to_be_bytes());
+        pubdata.extend([0xaa; 32]);
+        pubdata.extend(compress_state_diffs(vec![state_diff]));
+
+        let parsed = ParsedPubdata::parse(&pubdata).unwrap();
+        assert_eq!(parsed.user_logs, [log]);
+        assert_eq!(parsed.l2_to_l1_messages, [b"hello".to_vec(), vec![]]);
+        assert_eq!(parsed.published_bytecodes, [vec![0xaa; 32]]);
+        assert_eq!(parsed.state_diffs.len(), 1);
+        assert_eq!(
+            parsed.state_diffs[0].key,
+            StateDiffKey::Initial(H256::repeat_byte(6))
+        );
+        assert_eq!(
+            parsed.state_diffs[0].value.decompress(U256::zero()),
+            7.into()
+        );
+
+        ParsedPubdata::parse(&pubdata[..pubdata.len() - 1]).unwrap_err();
+        pubdata.push(0);
+        ParsedPubdata::parse(&pubdata).unwrap_err();
+    }
+}
@@ -7,7 +7,9 @@ use zksync_basic_types::{AccountTreeId, L1BatchNumber, L2BlockNumber, H256};
 use zksync_protobuf::{required, ProtoFmt};
 use zksync_utils::u256_to_h256;
 
-use crate::{utils, web3::Bytes, ProtocolVersionId, StorageKey, StorageValue, U256};
+use crate::{
+    api, tokens::TokenInfo, utils, web3::Bytes, ProtocolVersionId, StorageKey, StorageValue, U256,
+};
 
 /// Information about all snapshots persisted by the node.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -28,6 +30,17 @@ pub enum SnapshotVersion {
     /// Snapshot version made compatible with L1 recovery. Differs from `Version0` by including
     /// hashed keys in storage logs instead of `(address, key)` pairs.
     Version1 = 1,
+    /// Incremental snapshot on top of a base snapshot. Uses the same storage log format as `Version1`, but contains
+    /// only storage logs and factory deps changed after the base snapshot L1 batch. To recover from such a snapshot,
+    /// it must be applied on top of its base snapshot (which may be incremental itself).
+    Version2 = 2,
+}
+
+impl SnapshotVersion {
+    /// Checks whether this version corresponds to an incremental snapshot.
+    pub fn is_incremental(self) -> bool {
+        matches!(self, Self::Version2)
+    }
 }
 
 /// Storage snapshot metadata. Used in DAL to fetch certain snapshot data.
@@ -41,6 +54,8 @@ pub struct SnapshotMetadata {
     /// Paths to the storage log blobs. Ordered by the chunk ID. If a certain chunk is not produced yet,
     /// the corresponding path is `None`.
     pub storage_logs_filepaths: Vec<Option<String>>,
+    /// L1 batch of the base snapshot. Only set for incremental snapshots.
+    pub base_l1_batch_number: Option<L1BatchNumber>,
 }
 
 impl SnapshotMetadata {
@@ -64,6 +79,37 @@ pub struct SnapshotHeader {
     /// Ordered by chunk IDs.
     pub storage_logs_chunks: Vec<SnapshotStorageLogsChunkMetadata>,
     pub factory_deps_filepath: String,
+    /// L1 batch of the base snapshot. Only set for incremental snapshots; the base snapshot header can be requested
+    /// separately.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_l1_batch_number: Option<L1BatchNumber>,
+}
+
+/// Metadata of a self-contained snapshot bundle stored in a local directory. Contains all data necessary to recover
+/// from a snapshot without a main node. Besides this metadata, the bundle directory contains factory deps
+/// and storage logs chunks of the snapshot(s) laid out in the same way as in a file-backed object store.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotBundleMetadata {
+    /// Headers of all snapshots in the bundle ordered by L1 batch number. The last header corresponds
+    /// to the snapshot to recover from; other headers (if any) correspond to its base snapshots.
+    pub snapshots: Vec<SnapshotHeader>,
+    /// Details of the snapshot L1 batch.
+    pub l1_batch: api::L1BatchDetails,
+    /// Details of the snapshot L2 block, i.e., the last L2 block in the snapshot L1 batch.
+    pub l2_block: api::BlockDetails,
+    /// Tokens as of the snapshot L2 block.
+    pub tokens: Vec<TokenInfo>,
+}
+
+impl SnapshotBundleMetadata {
+    /// Name of the metadata file in the bundle directory.
+    pub const FILE_NAME: &'static str = "snapshot_bundle.json";
+
+    /// Returns the header of the snapshot to recover from.
+    pub fn snapshot(&self) -> Option<&SnapshotHeader> {
+        self.snapshots.last()
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -185,6 +185,56 @@ pub fn compress_with_best_strategy(prev_value: U256, new_value: U256) -> Vec<u8>
         })
 }
 
+/// Storage value compressed by [`compress_with_best_strategy()`] and decoded from its extended form
+/// (the metadata byte followed by the compressed value).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompressedValue {
+    /// Id of the compression operation.
+    pub operation_id: u8,
+    /// Diff or the full value, depending on the operation.
+    pub value: U256,
+}
+
+impl CompressedValue {
+    /// Decodes a compressed value from the start of `data`. Returns the value and the number of consumed bytes.
+    pub fn decode(data: &[u8]) -> anyhow::Result<(Self, usize)> {
+        let metadata = *data
+            .first()
+            .ok_or_else(|| anyhow::anyhow!("missing compressed value metadata"))?;
+        let operation_id = metadata & 7;
+        let size = match operation_id {
+            // `CompressionByteNone` always outputs the full value with the `0x00` metadata byte.
+            0 => 32,
+            1..=3 => usize::from(metadata >> 3),
+            _ => anyhow::bail!("unknown compression operation id: {operation_id}"),
+        };
+        let value = data.get(1..=size).ok_or_else(|| {
+            anyhow::anyhow!("compressed value is truncated: expected {size} bytes")
+        })?;
+
+        let value = Self {
+            operation_id,
+            value: U256::from_big_endian(value),
+        };
+        Ok((value, size + 1))
+    }
+
+    /// Computes the new value of the storage slot given its previous value. The previous value is
+    /// only used by additive and subtractive compression.
+    pub fn decompress(&self, prev_value: U256) -> U256 {
+        match self.operation_id {
+            1 => prev_value.overflowing_add(self.value).0,
+            2 => prev_value.overflowing_sub(self.value).0,
+            _ => self.value,
+        }
+    }
+
+    /// Checks whether the new value can be computed without knowing the previous value.
+    pub fn is_standalone(&self) -> bool {
+        !matches!(self.operation_id, 1 | 2)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::ops::{Add, BitAnd, Shr, Sub};
@@ -369,4 +419,28 @@ mod tests {
         assert!(compression_sub_strategy.compress_value_only().is_none());
         assert!(compression_sub_strategy.compress_extended().is_none());
     }
+
+    #[test]
+    fn decompressing_values() {
+        let values = [
+            (U256::from(255438218), U256::from(255438638)),
+            (U256::from(580481589), U256::from(229496100)),
+            (U256::from(580481589), U256::from(1337)),
+            (U256::from(580481589), U256::zero()),
+            (U256::MAX, U256::one()),
+            (U256::zero(), U256::MAX),
+            // No compression is applicable
+            (U256::zero(), (U256::one() << 255) + 1),
+        ];
+        for (initial_val, final_val) in values {
+            let compressed = compress_with_best_strategy(initial_val, final_val);
+            let (value, consumed) = CompressedValue::decode(&compressed).unwrap();
+            assert_eq!(consumed, compressed.len());
+            assert_eq!(value.decompress(initial_val), final_val);
+        }
+
+        CompressedValue::decode(&[]).unwrap_err();
+        CompressedValue::decode(&[17, 1]).unwrap_err();
+        CompressedValue::decode(&[4]).unwrap_err();
+    }
 }
@@ -1,8 +1,10 @@
 use std::{convert::TryInto, fmt};
 
+use anyhow::Context as _;
 use serde::{de, ser::SerializeTuple, Deserialize, Deserializer, Serialize, Serializer};
 use zksync_basic_types::{Address, U256};
 
+use self::compression::CompressedValue;
 pub(crate) use self::compression::{compress_with_best_strategy, COMPRESSION_VERSION_NUMBER};
 use crate::H256;
 
@@ -189,6 +191,83 @@ fn prepend_header(compressed_state_diffs: Vec<u8>) -> Vec<u8> {
     res.to_vec()
 }
 
+/// Key of a state diff decoded from the compressed state diffs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StateDiffKey {
+    /// Initial write identified by the derived key, i.e. `blake2s(bytes32(address), key)`.
+    Initial(H256),
+    /// Repeated write identified by the enumeration index of the slot in the Merkle tree.
+    Repeated(u64),
+}
+
+/// State diff decoded from the compressed state diffs published as a part of the L1 batch pubdata.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecompressedStateDiff {
+    pub key: StateDiffKey,
+    pub value: CompressedValue,
+}
+
+/// Decodes state diffs compressed by [`compress_state_diffs()`] (including the header). Returns decoded diffs
+/// in the order of their publication and the number of consumed bytes; trailing data is ignored.
+pub fn decompress_state_diffs(data: &[u8]) -> anyhow::Result<(Vec<DecompressedStateDiff>, usize)> {
+    anyhow::ensure!(
+        data.len() >= 5,
+        "compressed state diffs header is truncated"
+    );
+    anyhow::ensure!(
+        data[0] == COMPRESSION_VERSION_NUMBER,
+        "unsupported state diffs compression version: {}",
+        data[0]
+    );
+    let len = u32::from_be_bytes([0, data[1], data[2], data[3]]) as usize;
+    let enumeration_index_size = usize::from(data[4]);
+    anyhow::ensure!(
+        (1..=8).contains(&enumeration_index_size),
+        "invalid enumeration index size: {enumeration_index_size}"
+    );
+    let mut body = data.get(5..5 + len).ok_or_else(|| {
+        anyhow::anyhow!("compressed state diffs are truncated: expected {len} bytes")
+    })?;
+
+    anyhow::ensure!(body.len() >= 2, "number of initial writes is missing");
+    let initial_writes_count = u16::from_be_bytes([body[0], body[1]]);
+    body = &body[2..];
+
+    let mut state_diffs = vec![];
+    for i in 0..initial_writes_count {
+        anyhow::ensure!(
+            body.len() >= usize::from(BYTES_PER_DERIVED_KEY),
+            "derived key for initial write #{i} is truncated"
+        );
+        let (derived_key, rest) = body.split_at(usize::from(BYTES_PER_DERIVED_KEY));
+        let (value, consumed) = CompressedValue::decode(rest)
+            .with_context(|| format!("failed decoding initial write #{i}"))?;
+        state_diffs.push(DecompressedStateDiff {
+            key: StateDiffKey::Initial(H256::from_slice(derived_key)),
+            value,
+        });
+        body = &rest[consumed..];
+    }
+
+    while !body.is_empty() {
+        anyhow::ensure!(
+            body.len() >= enumeration_index_size,
+            "enumeration index for repeated write is truncated"
+        );
+        let (index, rest) = body.split_at(enumeration_index_size);
+        let mut index_bytes = [0_u8; 8];
+        index_bytes[8 - enumeration_index_size..].copy_from_slice(index);
+        let (value, consumed) =
+            CompressedValue::decode(rest).context("failed decoding repeated write")?;
+        state_diffs.push(DecompressedStateDiff {
+            key: StateDiffKey::Repeated(u64::from_be_bytes(index_bytes)),
+            value,
+        });
+        body = &rest[consumed..];
+    }
+    Ok((state_diffs, 5 + len))
+}
+
 /// Struct for storing tree writes in DB.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct TreeWrite {
@@ -532,6 +611,60 @@ mod tests {
         assert!(compressed_state_diffs.is_empty());
     }
 
+    #[test]
+    fn decompressing_state_diffs() {
+        let storage_diffs = vec![
+            StateDiffRecord {
+                address: Address::repeat_byte(1),
+                key: U256::from(1u8),
+                derived_key: [1u8; 32],
+                enumeration_index: 0,
+                initial_value: U256::default(),
+                final_value: U256::from(64u8),
+            },
+            StateDiffRecord {
+                address: Address::repeat_byte(2),
+                key: U256::from(2u8),
+                derived_key: [2u8; 32],
+                enumeration_index: 2,
+                initial_value: U256::from(64u8),
+                final_value: U256::from(20u8),
+            },
+            StateDiffRecord {
+                address: Address::repeat_byte(3),
+                key: U256::from(3u8),
+                derived_key: [3u8; 32],
+                enumeration_index: u64::from(u32::MAX),
+                initial_value: U256::MAX / 2,
+                final_value: U256::MAX,
+            },
+        ];
+        let mut compressed = compress_state_diffs(storage_diffs.clone());
+        let compressed_len = compressed.len();
+        compressed.extend_from_slice(&[0xff; 10]); // trailing data must be ignored
+
+        let (state_diffs, consumed) = decompress_state_diffs(&compressed).unwrap();
+        assert_eq!(consumed, compressed_len);
+        assert_eq!(state_diffs.len(), storage_diffs.len());
+        for (diff, expected) in state_diffs.iter().zip(&storage_diffs) {
+            let expected_key = if expected.is_write_initial() {
+                StateDiffKey::Initial(H256(expected.derived_key))
+            } else {
+                StateDiffKey::Repeated(expected.enumeration_index)
+            };
+            assert_eq!(diff.key, expected_key);
+            assert_eq!(
+                diff.value.decompress(expected.initial_value),
+                expected.final_value
+            );
+        }
+
+        decompress_state_diffs(&compressed[..compressed_len - 1]).unwrap_err();
+        let mut invalid_version = compressed.clone();
+        invalid_version[0] = COMPRESSION_VERSION_NUMBER + 1;
+        decompress_state_diffs(&invalid_version).unwrap_err();
+    }
+
     #[test]
     fn test_encoding() {
         let state_diff = StateDiffRecord {
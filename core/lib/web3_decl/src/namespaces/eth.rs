@@ -184,13 +184,16 @@ pub trait EthNamespace {
         newest_block: BlockNumber,
         reward_percentiles: Vec<f32>,
     ) -> RpcResult<FeeHistory>;
+
+    #[method(name = "maxPriorityFeePerGas")]
+    async fn max_priority_fee_per_gas(&self) -> RpcResult<U256>;
 }
 
 #[cfg(feature = "server")]
 mod pub_sub {
     use jsonrpsee::{core::SubscriptionResult, proc_macros::rpc};
 
-    use crate::types::PubSubFilter;
+    use crate::types::PubSubParams;
 
     #[rpc(server, namespace = "eth")]
     pub trait EthPubSub {
@@ -198,7 +201,7 @@ mod pub_sub {
         async fn subscribe(
             &self,
             sub_type: String,
-            filter: Option<PubSubFilter>,
+            params: Option<PubSubParams>,
         ) -> SubscriptionResult;
     }
 }
@@ -0,0 +1,32 @@
+#[cfg_attr(not(feature = "server"), allow(unused_imports))]
+use jsonrpsee::core::RpcResult;
+use jsonrpsee::proc_macros::rpc;
+use zksync_types::{
+    api::{TxpoolContent, TxpoolContentFrom, TxpoolStatus},
+    Address,
+};
+
+use crate::client::{ForWeb3Network, L2};
+
+/// Mempool inspection methods. Response shapes match the ones used by Geth.
+#[cfg_attr(
+    feature = "server",
+    rpc(server, client, namespace = "txpool", client_bounds(Self: ForWeb3Network<Net = L2>))
+)]
+#[cfg_attr(
+    not(feature = "server"),
+    rpc(client, namespace = "txpool", client_bounds(Self: ForWeb3Network<Net = L2>))
+)]
+pub trait TxpoolNamespace {
+    #[method(name = "content")]
+    async fn content(&self) -> RpcResult<TxpoolContent>;
+
+    #[method(name = "contentFrom")]
+    async fn content_from(&self, address: Address) -> RpcResult<TxpoolContentFrom>;
+
+    #[method(name = "status")]
+    async fn status(&self) -> RpcResult<TxpoolStatus>;
+
+    #[method(name = "inspect")]
+    async fn inspect(&self) -> RpcResult<TxpoolContent<String>>;
+}
@@ -13,6 +13,7 @@ use core::{
 
 use rlp::Rlp;
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use zksync_types::{api, L1BatchNumber};
 pub use zksync_types::{
     api::{Block, BlockNumber, Log, TransactionReceipt, TransactionRequest},
     ethabi,
@@ -333,6 +334,64 @@ fn topic_to_option<T>(topic: ethabi::Topic<T>) -> Option<Vec<T>> {
     }
 }
 
+/// Parameters of an `eth_subscribe` call following the subscription type. Depending on the subscription type,
+/// this is either a filter for `logs`, or a flag for `newPendingTransactions` specifying whether full transactions
+/// should be returned instead of transaction hashes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PubSubParams {
+    FullTransactions(bool),
+    Filter(PubSubFilter),
+}
+
+impl From<PubSubFilter> for PubSubParams {
+    fn from(filter: PubSubFilter) -> Self {
+        Self::Filter(filter)
+    }
+}
+
+/// Sync progress reported to `syncing` subscribers. Uses the same key format as Geth.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PubSubSyncProgress {
+    /// The block at which import began.
+    pub starting_block: U256,
+    /// The highest currently synced block.
+    pub current_block: U256,
+    /// The estimated highest block.
+    pub highest_block: U256,
+}
+
+/// Notification sent to `syncing` subscribers while the node is syncing. Once the node is synced,
+/// `false` is sent instead.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PubSubSyncStatus {
+    pub syncing: bool,
+    pub status: PubSubSyncProgress,
+}
+
+/// L1 batch status reported to `zks_l1BatchStatus` subscribers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum L1BatchStatus {
+    /// The batch is sealed by the state keeper.
+    Sealed,
+    /// The batch commitment transaction is confirmed on L1.
+    Committed,
+    /// The batch proof transaction is confirmed on L1.
+    Proven,
+    /// The batch execution transaction is confirmed on L1.
+    Executed,
+}
+
+/// Notification about an L1 batch reaching a certain status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct L1BatchStatusUpdate {
+    pub l1_batch_number: L1BatchNumber,
+    pub status: L1BatchStatus,
+}
+
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -341,10 +400,14 @@ pub enum PubSubResult {
     Log(Log),
     TxHash(H256),
     Syncing(bool),
+    FullTx(api::Transaction),
+    SyncStatus(PubSubSyncStatus),
+    L1BatchStatus(L1BatchStatusUpdate),
 }
 
 #[cfg(test)]
 mod tests {
+    use assert_matches::assert_matches;
     use zksync_types::api::{BlockId, BlockIdVariant};
 
     use super::*;
@@ -437,4 +500,58 @@ mod tests {
         let restored_value: ValueOrArray<Address> = serde_json::from_value(json).unwrap();
         assert_eq!(restored_value, value);
     }
+
+    #[test]
+    fn pub_sub_params_deserialization() {
+        let params: PubSubParams = serde_json::from_str("true").unwrap();
+        assert_eq!(params, PubSubParams::FullTransactions(true));
+
+        let params: PubSubParams =
+            serde_json::from_str(r#"{ "address": "0x0000000000000000000000000000000000000001" }"#)
+                .unwrap();
+        let PubSubParams::Filter(filter) = params else {
+            panic!("unexpected params: {params:?}");
+        };
+        assert_eq!(
+            filter.address,
+            Some(ValueOrArray(vec![Address::from_low_u64_be(1)]))
+        );
+    }
+
+    #[test]
+    fn serializing_pub_sub_results() {
+        let sync_status = PubSubResult::SyncStatus(PubSubSyncStatus {
+            syncing: true,
+            status: PubSubSyncProgress {
+                starting_block: 0.into(),
+                current_block: 10.into(),
+                highest_block: 20.into(),
+            },
+        });
+        let json = serde_json::to_value(sync_status).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "syncing": true,
+                "status": {
+                    "StartingBlock": "0x0",
+                    "CurrentBlock": "0xa",
+                    "HighestBlock": "0x14",
+                },
+            })
+        );
+        // Check compatibility with the `web3` type.
+        let sync_state: SyncState = serde_json::from_value(json).unwrap();
+        assert_matches!(sync_state, SyncState::Syncing(_));
+
+        let update = PubSubResult::L1BatchStatus(L1BatchStatusUpdate {
+            l1_batch_number: L1BatchNumber(5),
+            status: L1BatchStatus::Committed,
+        });
+        let json = serde_json::to_value(update).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "l1BatchNumber": 5, "status": "committed" })
+        );
+    }
 }
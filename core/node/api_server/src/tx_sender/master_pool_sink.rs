@@ -1,28 +1,88 @@
 use std::collections::hash_map::{Entry, HashMap};
 
 use tokio::sync::Mutex;
-use zksync_dal::{transactions_dal::L2TxSubmissionResult, ConnectionPool, Core, CoreDal};
+use zksync_dal::{
+    transactions_dal::L2TxSubmissionResult, Connection, ConnectionPool, Core, CoreDal,
+};
 use zksync_multivm::interface::TransactionExecutionMetrics;
 use zksync_shared_metrics::{TxStage, APP_METRICS};
-use zksync_types::{l2::L2Tx, Address, Nonce, H256};
+use zksync_types::{fee::Fee, l2::L2Tx, Address, Nonce, H256, U256};
 
 use super::{tx_sink::TxSink, SubmitTxError};
 use crate::web3::metrics::API_METRICS;
 
 /// Wrapper for the master DB pool that allows to submit transactions to the mempool.
+///
+/// A transaction with the same initiator and nonce as a pending transaction replaces the latter only if
+/// both `max_fee_per_gas` and `max_priority_fee_per_gas` are increased by at least the configured percentage.
 #[derive(Debug)]
 pub struct MasterPoolSink {
     master_pool: ConnectionPool<Core>,
     inflight_requests: Mutex<HashMap<(Address, Nonce), H256>>,
+    replacement_fee_bump_percentage: u32,
 }
 
 impl MasterPoolSink {
-    pub fn new(master_pool: ConnectionPool<Core>) -> Self {
+    pub fn new(master_pool: ConnectionPool<Core>, replacement_fee_bump_percentage: u32) -> Self {
         Self {
             master_pool,
             inflight_requests: Mutex::new(HashMap::new()),
+            replacement_fee_bump_percentage,
         }
     }
+
+    async fn insert_tx(
+        &self,
+        connection: &mut Connection<'_, Core>,
+        tx: &L2Tx,
+        execution_metrics: TransactionExecutionMetrics,
+    ) -> Result<L2TxSubmissionResult, SubmitTxError> {
+        let pending_tx = connection
+            .transactions_dal()
+            .get_pending_l2_tx_fee(tx.initiator_account(), tx.nonce())
+            .await
+            .map_err(|err| err.generalize())?;
+        if let Some((pending_tx_hash, pending_fee)) = pending_tx {
+            // Duplicates are handled by the DAL.
+            let is_replacement = pending_tx_hash != tx.hash();
+            if is_replacement
+                && !is_sufficient_fee_bump(
+                    &pending_fee,
+                    &tx.common_data.fee,
+                    self.replacement_fee_bump_percentage,
+                )
+            {
+                tracing::debug!(
+                    "Rejected replacement of pending transaction {pending_tx_hash:?} by {:?}: insufficient fee bump",
+                    tx.hash()
+                );
+                return Err(SubmitTxError::ReplacementUnderpriced(
+                    self.replacement_fee_bump_percentage,
+                ));
+            }
+        }
+
+        let submission_res_handle = connection
+            .transactions_dal()
+            .insert_transaction_l2(tx, execution_metrics)
+            .await
+            .map_err(|err| err.generalize())?;
+        APP_METRICS.processed_txs[&TxStage::Mempool(submission_res_handle)].inc();
+        Ok(submission_res_handle)
+    }
+}
+
+/// Checks whether both the max fee and the priority fee of a replacement transaction are increased
+/// by at least `bump_percentage` compared to the replaced transaction.
+fn is_sufficient_fee_bump(pending_fee: &Fee, new_fee: &Fee, bump_percentage: u32) -> bool {
+    let min_value = |pending_value: U256| {
+        let bumped =
+            pending_value.saturating_mul(U256::from(100_u32.saturating_add(bump_percentage)));
+        // Round up, so that the bump is enforced for small values as well.
+        bumped.saturating_add(99.into()) / 100
+    };
+    new_fee.max_fee_per_gas >= min_value(pending_fee.max_fee_per_gas)
+        && new_fee.max_priority_fee_per_gas >= min_value(pending_fee.max_priority_fee_per_gas)
 }
 
 #[async_trait::async_trait]
@@ -53,14 +113,7 @@ impl TxSink for MasterPoolSink {
         drop(lock);
 
         let result = match self.master_pool.connection_tagged("api").await {
-            Ok(mut connection) => connection
-                .transactions_dal()
-                .insert_transaction_l2(tx, execution_metrics)
-                .await
-                .inspect(|submission_res_handle| {
-                    APP_METRICS.processed_txs[&TxStage::Mempool(*submission_res_handle)].inc();
-                })
-                .map_err(|err| err.generalize().into()),
+            Ok(mut connection) => self.insert_tx(&mut connection, tx, execution_metrics).await,
             Err(err) => Err(err.generalize().into()),
         };
 
@@ -73,3 +126,75 @@ impl TxSink for MasterPoolSink {
         result
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use zksync_node_test_utils::create_l2_transaction;
+
+    use super::*;
+
+    fn fee(max_fee_per_gas: u64, max_priority_fee_per_gas: u64) -> Fee {
+        Fee {
+            gas_limit: 1_000_000.into(),
+            max_fee_per_gas: max_fee_per_gas.into(),
+            max_priority_fee_per_gas: max_priority_fee_per_gas.into(),
+            gas_per_pubdata_limit: 800.into(),
+        }
+    }
+
+    #[test]
+    fn checking_fee_bump() {
+        let pending_fee = fee(1_000, 100);
+        assert!(is_sufficient_fee_bump(&pending_fee, &fee(1_100, 110), 10));
+        assert!(is_sufficient_fee_bump(&pending_fee, &fee(2_000, 200), 10));
+        assert!(!is_sufficient_fee_bump(&pending_fee, &fee(1_099, 110), 10));
+        assert!(!is_sufficient_fee_bump(&pending_fee, &fee(1_100, 109), 10));
+        assert!(!is_sufficient_fee_bump(&pending_fee, &pending_fee, 10));
+        assert!(is_sufficient_fee_bump(&pending_fee, &pending_fee, 0));
+
+        // The bump should be enforced for small values as well.
+        assert!(!is_sufficient_fee_bump(&fee(1, 1), &fee(1, 1), 10));
+        assert!(is_sufficient_fee_bump(&fee(1, 0), &fee(2, 0), 10));
+    }
+
+    #[tokio::test]
+    async fn replacing_pending_transaction() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let sink = MasterPoolSink::new(pool.clone(), 10);
+        let tx = create_l2_transaction(1_000, 800);
+        let submission_result = sink
+            .submit_tx(&tx, TransactionExecutionMetrics::default())
+            .await
+            .unwrap();
+        assert_matches!(submission_result, L2TxSubmissionResult::Added);
+
+        let mut underpriced_tx = tx.clone();
+        underpriced_tx.common_data.fee.max_fee_per_gas = 1_050.into();
+        underpriced_tx.set_input(H256::random().0.to_vec(), H256::random());
+        let err = sink
+            .submit_tx(&underpriced_tx, TransactionExecutionMetrics::default())
+            .await
+            .unwrap_err();
+        assert_matches!(err, SubmitTxError::ReplacementUnderpriced(10));
+
+        let mut replacement_tx = tx.clone();
+        replacement_tx.common_data.fee.max_fee_per_gas = 1_100.into();
+        replacement_tx.set_input(H256::random().0.to_vec(), H256::random());
+        let submission_result = sink
+            .submit_tx(&replacement_tx, TransactionExecutionMetrics::default())
+            .await
+            .unwrap();
+        assert_matches!(submission_result, L2TxSubmissionResult::Replaced);
+
+        let mut storage = pool.connection().await.unwrap();
+        let (pending_tx_hash, pending_fee) = storage
+            .transactions_dal()
+            .get_pending_l2_tx_fee(tx.initiator_account(), tx.nonce())
+            .await
+            .unwrap()
+            .expect("no pending transaction");
+        assert_eq!(pending_tx_hash, replacement_tx.hash());
+        assert_eq!(pending_fee, replacement_tx.common_data.fee);
+    }
+}
@@ -56,7 +56,10 @@ pub async fn build_tx_sender(
     storage_caches: PostgresStorageCaches,
 ) -> anyhow::Result<(TxSender, VmConcurrencyBarrier)> {
     let sequencer_sealer = SequencerSealer::new(state_keeper_config.clone());
-    let master_pool_sink = MasterPoolSink::new(master_pool);
+    let master_pool_sink = MasterPoolSink::new(
+        master_pool,
+        web3_json_config.tx_replacement_fee_bump_percentage(),
+    );
     let tx_sender_builder = TxSenderBuilder::new(
         tx_sender_config.clone(),
         replica_pool.clone(),
@@ -206,6 +209,7 @@ pub struct TxSenderConfig {
     pub fee_account_addr: Address,
     pub gas_price_scale_factor: f64,
     pub max_nonce_ahead: u32,
+    pub max_pending_txs_per_initiator: Option<u32>,
     pub max_allowed_l2_tx_gas_limit: u64,
     pub vm_execution_cache_misses_limit: Option<usize>,
     pub validation_computational_gas_limit: u32,
@@ -224,6 +228,7 @@ impl TxSenderConfig {
             fee_account_addr,
             gas_price_scale_factor: web3_json_config.gas_price_scale_factor,
             max_nonce_ahead: web3_json_config.max_nonce_ahead,
+            max_pending_txs_per_initiator: web3_json_config.max_pending_txs_per_initiator,
             max_allowed_l2_tx_gas_limit: state_keeper_config.max_allowed_l2_tx_gas_limit,
             vm_execution_cache_misses_limit: web3_json_config.vm_execution_cache_misses_limit,
             validation_computational_gas_limit: state_keeper_config
@@ -454,6 +459,7 @@ impl TxSender {
         // We still double-check the nonce manually
         // to make sure that only the correct nonce is submitted and the transaction's hashes never repeat
         self.validate_account_nonce(tx).await?;
+        self.validate_pending_txs_limit(tx).await?;
         // Even though without enough balance the tx will not pass anyway
         // we check the user for enough balance explicitly here for better DevEx.
         self.validate_enough_balance(tx).await?;
@@ -491,6 +497,30 @@ impl TxSender {
         }
     }
 
+    async fn validate_pending_txs_limit(&self, tx: &L2Tx) -> Result<(), SubmitTxError> {
+        let Some(limit) = self.0.sender_config.max_pending_txs_per_initiator else {
+            return Ok(());
+        };
+        let initiator_account = tx.initiator_account();
+        let mut storage = self.acquire_replica_connection().await?;
+        // The transaction with the same nonce is not counted since it would be replaced.
+        let pending_txs_count = storage
+            .transactions_dal()
+            .get_pending_l2_txs_count(initiator_account, tx.nonce())
+            .await
+            .with_context(|| {
+                format!("failed getting pending transactions count for {initiator_account:?}")
+            })?;
+        if pending_txs_count >= limit as usize {
+            tracing::info!(
+                "Submitted Tx {:?} is rejected because initiator {initiator_account:?} has {pending_txs_count} pending transactions",
+                tx.hash()
+            );
+            return Err(SubmitTxError::TooManyPendingTransactions(limit));
+        }
+        Ok(())
+    }
+
     async fn get_expected_nonce(&self, initiator_account: Address) -> anyhow::Result<Nonce> {
         let mut storage = self.acquire_replica_connection().await?;
         let latest_block_number = storage
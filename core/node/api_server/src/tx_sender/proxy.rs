@@ -143,6 +143,20 @@ impl TxCache {
         }
     }
 
+    /// Returns cached transactions ordered by initiator address and nonce.
+    async fn get_all(&self, initiator_address: Option<Address>, limit: usize) -> Vec<L2Tx> {
+        let inner = self.inner.read().await;
+        let mut txs: Vec<_> = inner
+            .transactions_by_hash
+            .values()
+            .filter(|tx| {
+                initiator_address.map_or(true, |address| tx.initiator_account() == address)
+            })
+            .collect();
+        txs.sort_unstable_by_key(|tx| (tx.initiator_account(), tx.nonce()));
+        txs.into_iter().take(limit).cloned().collect()
+    }
+
     async fn get_nonces_for_account(&self, account_address: Address) -> BTreeSet<Nonce> {
         let inner = self.inner.read().await;
         if let Some(nonces) = inner.nonces_by_account.get(&account_address) {
@@ -379,6 +393,17 @@ impl TxSink for TxProxy {
         }
         Ok(None)
     }
+
+    async fn lookup_pending_txs(
+        &self,
+        initiator_address: Option<Address>,
+        limit: usize,
+    ) -> Result<Option<Vec<api::Transaction>>, Web3Error> {
+        // We don't have mempool in EN; the proxy cache contains all transactions submitted via this node
+        // that aren't synced back from the main node yet.
+        let txs = self.tx_cache.get_all(initiator_address, limit).await;
+        Ok(Some(txs.into_iter().map(Into::into).collect()))
+    }
 }
 
 #[cfg(test)]
@@ -443,6 +468,20 @@ mod tests {
             .unwrap()
             .expect("no transaction");
         assert_eq!(tx_details.initiator_address, tx.initiator_account());
+
+        let pending_txs = proxy
+            .lookup_pending_txs(Some(tx.initiator_account()), 10)
+            .await
+            .unwrap()
+            .expect("no pending transactions");
+        assert_eq!(pending_txs.len(), 1);
+        assert_eq!(pending_txs[0].hash, tx.hash());
+        let pending_txs = proxy
+            .lookup_pending_txs(Some(Address::repeat_byte(0xff)), 10)
+            .await
+            .unwrap()
+            .expect("no pending transactions");
+        assert!(pending_txs.is_empty());
     }
 
     #[tokio::test]
@@ -106,6 +106,45 @@ async fn nonce_validation_errors() {
     );
 }
 
+#[tokio::test]
+async fn pending_txs_limit_validation() {
+    let pool = ConnectionPool::<Core>::constrained_test_pool(1).await;
+    let mut storage = pool.connection().await.unwrap();
+    insert_genesis_batch(&mut storage, &GenesisParams::mock())
+        .await
+        .unwrap();
+    let tx = create_l2_transaction(55, 555);
+    storage
+        .transactions_dal()
+        .insert_transaction_l2(&tx, TransactionExecutionMetrics::default())
+        .await
+        .unwrap();
+    drop(storage);
+
+    let l2_chain_id = L2ChainId::default();
+    let tx_executor = SandboxExecutor::mock(MockOneshotExecutor::default()).await;
+    let (mut tx_sender, _) = create_test_tx_sender(pool.clone(), l2_chain_id, tx_executor).await;
+    // No limit is set by default.
+    let mut next_tx = tx.clone();
+    next_tx.common_data.nonce = Nonce(1);
+    tx_sender
+        .validate_pending_txs_limit(&next_tx)
+        .await
+        .unwrap();
+
+    Arc::get_mut(&mut tx_sender.0)
+        .unwrap()
+        .sender_config
+        .max_pending_txs_per_initiator = Some(1);
+    // The pending transaction itself may be replaced.
+    tx_sender.validate_pending_txs_limit(&tx).await.unwrap();
+    let err = tx_sender
+        .validate_pending_txs_limit(&next_tx)
+        .await
+        .unwrap_err();
+    assert_matches!(err, SubmitTxError::TooManyPendingTransactions(1));
+}
+
 #[tokio::test]
 async fn fee_validation_errors() {
     let pool = ConnectionPool::<Core>::constrained_test_pool(1).await;
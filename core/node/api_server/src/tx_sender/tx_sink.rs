@@ -59,4 +59,15 @@ pub trait TxSink: std::fmt::Debug + Send + Sync + 'static {
     ) -> Result<Option<TransactionDetails>, Web3Error> {
         Ok(None)
     }
+
+    /// Attempts to look up pending transactions (optionally, only ones initiated by the specified account)
+    /// in the sink-specific storage. Returns at most `limit` transactions.
+    /// By default, returns `Ok(None)`, meaning that pending transactions should be looked up in Postgres.
+    async fn lookup_pending_txs(
+        &self,
+        _initiator_address: Option<Address>,
+        _limit: usize,
+    ) -> Result<Option<Vec<Transaction>>, Web3Error> {
+        Ok(None)
+    }
 }
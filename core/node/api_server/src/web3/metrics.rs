@@ -170,6 +170,7 @@ enum Web3ErrorKind {
     FilterNotFound,
     LogsLimitExceeded,
     InvalidFilterBlockHash,
+    InvalidRewardPercentiles,
     TreeApiUnavailable,
     Internal,
 }
@@ -186,6 +187,7 @@ impl Web3ErrorKind {
             Web3Error::FilterNotFound => Self::FilterNotFound,
             Web3Error::LogsLimitExceeded(..) => Self::LogsLimitExceeded,
             Web3Error::InvalidFilterBlockHash => Self::InvalidFilterBlockHash,
+            Web3Error::InvalidRewardPercentiles => Self::InvalidRewardPercentiles,
             Web3Error::TreeApiUnavailable => Self::TreeApiUnavailable,
             Web3Error::InternalError(_) | Web3Error::MethodNotImplemented => Self::Internal,
         }
@@ -420,7 +422,10 @@ pub(crate) static API_METRICS: vise::Global<ApiMetrics> = vise::Global::new();
 pub enum SubscriptionType {
     Blocks,
     Txs,
+    FullTxs,
     Logs,
+    Syncing,
+    L1BatchStatus,
 }
 
 #[derive(Debug, Metrics)]
@@ -447,6 +452,9 @@ pub(super) struct PubSubMetrics {
     pub skipped_broadcast_messages: Family<SubscriptionType, Histogram<u64>>,
     /// Number of subscribers dropped because of a send timeout.
     pub subscriber_send_timeouts: Family<SubscriptionType, Counter>,
+    /// Whether push notifications about new L2 blocks from Postgres are available (1) or notifiers
+    /// fall back to polling (0).
+    pub l2_block_notifications_available: Gauge,
 }
 
 #[vise::register]
@@ -1,5 +1,6 @@
 use anyhow::Context as _;
 use zksync_dal::{CoreDal, DalError};
+use zksync_node_fee_model::priority_fee;
 use zksync_system_constants::DEFAULT_L2_TX_GAS_PER_PUBDATA_BYTE;
 use zksync_types::{
     api::{
@@ -27,6 +28,8 @@ use crate::{
 
 pub const EVENT_TOPIC_NUMBER_LIMIT: usize = 4;
 pub const PROTOCOL_VERSION: &str = "zks/1";
+/// Number of recent L2 blocks used to suggest a priority fee in `eth_maxPriorityFeePerGas`.
+const PRIORITY_FEE_HISTORY_BLOCKS: u32 = 20;
 
 #[derive(Debug)]
 pub(crate) struct EthNamespace {
@@ -655,7 +658,7 @@ impl EthNamespace {
                 SyncState::NotSyncing
             } else {
                 SyncState::Syncing(SyncInfo {
-                    starting_block: 0u64.into(), // We always start syncing from genesis right now.
+                    starting_block: state.get_starting_block().0.into(),
                     current_block: state.get_local_block().0.into(),
                     highest_block: state.get_main_node_block().0.into(),
                 })
@@ -674,6 +677,9 @@ impl EthNamespace {
     ) -> Result<FeeHistory, Web3Error> {
         self.current_method()
             .set_block_id(BlockId::Number(newest_block));
+        if !priority_fee::validate_reward_percentiles(&reward_percentiles) {
+            return Err(Web3Error::InvalidRewardPercentiles);
+        }
 
         // Limit `block_count`.
         let block_count = block_count
@@ -688,24 +694,34 @@ impl EthNamespace {
             .await?;
         self.set_block_diff(newest_l2_block);
 
-        let (mut base_fee_per_gas, mut effective_pubdata_price_history) = connection
-            .blocks_web3_dal()
-            .get_fee_history(newest_l2_block, block_count)
-            .await
-            .map_err(DalError::generalize)?;
+        let (mut base_fee_per_gas, mut effective_pubdata_price_history, mut gas_used_ratio) =
+            connection
+                .blocks_web3_dal()
+                .get_fee_history(newest_l2_block, block_count)
+                .await
+                .map_err(DalError::generalize)?;
 
         // DAL method returns fees in DESC order while we need ASC.
         base_fee_per_gas.reverse();
         effective_pubdata_price_history.reverse();
+        gas_used_ratio.reverse();
 
         let oldest_block = newest_l2_block.0 + 1 - base_fee_per_gas.len() as u32;
-        // We do not store gas used ratio for blocks, returns array of zeroes as a placeholder.
-        let gas_used_ratio = vec![0.0; base_fee_per_gas.len()];
-        // Effective priority gas price is currently 0.
-        let reward = Some(vec![
-            vec![U256::zero(); reward_percentiles.len()];
-            base_fee_per_gas.len()
-        ]);
+
+        let mut priority_fees = connection
+            .blocks_web3_dal()
+            .get_priority_fees(L2BlockNumber(oldest_block)..=newest_l2_block)
+            .await
+            .map_err(DalError::generalize)?;
+        let reward = (oldest_block..=newest_l2_block.0)
+            .map(|number| {
+                let fees = priority_fees
+                    .remove(&L2BlockNumber(number))
+                    .unwrap_or_default();
+                priority_fee::compute_rewards(fees, &reward_percentiles)
+            })
+            .collect();
+        let reward = Some(reward);
 
         // `base_fee_per_gas` for next L2 block cannot be calculated, appending last fee as a placeholder.
         base_fee_per_gas.push(*base_fee_per_gas.last().unwrap());
@@ -727,6 +743,27 @@ impl EthNamespace {
         })
     }
 
+    pub async fn max_priority_fee_per_gas_impl(&self) -> Result<U256, Web3Error> {
+        let mut connection = self.state.acquire_connection().await?;
+        let newest_l2_block = self
+            .state
+            .resolve_block(&mut connection, BlockId::Number(BlockNumber::Latest))
+            .await?;
+        let oldest_l2_block = L2BlockNumber(
+            newest_l2_block
+                .0
+                .saturating_sub(PRIORITY_FEE_HISTORY_BLOCKS - 1),
+        );
+        let priority_fees = connection
+            .blocks_web3_dal()
+            .get_priority_fees(oldest_l2_block..=newest_l2_block)
+            .await
+            .map_err(DalError::generalize)?;
+        Ok(priority_fee::suggest_max_priority_fee(
+            priority_fees.into_values(),
+        ))
+    }
+
     async fn filter_changes(
         &self,
         typed_filter: &mut TypedFilter,
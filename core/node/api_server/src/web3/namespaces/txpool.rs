@@ -0,0 +1,292 @@
+use std::collections::BTreeMap;
+
+use zksync_dal::{Connection, Core, CoreDal, DalError};
+use zksync_types::{
+    api::{Transaction, TxpoolContent, TxpoolContentFrom, TxpoolStatus},
+    Address, Nonce,
+};
+use zksync_web3_decl::error::Web3Error;
+
+use crate::web3::{backend_jsonrpsee::MethodTracer, state::RpcState};
+
+/// Splits transactions of a single account into pending (executable) and queued ones. `txs` must be sorted by nonce.
+fn split_account_txs(
+    txs: Vec<Transaction>,
+    stored_nonce: Nonce,
+) -> (BTreeMap<u64, Transaction>, BTreeMap<u64, Transaction>) {
+    let mut pending = BTreeMap::new();
+    let mut queued = BTreeMap::new();
+    let mut next_nonce = u64::from(stored_nonce.0);
+    for tx in txs {
+        let nonce = tx.nonce.as_u64();
+        if nonce < next_nonce {
+            // The transaction is either already executed, or replaces a pending transaction. Both cases may happen
+            // for the transaction cache on EN.
+            if let Some(pending_tx) = pending.get_mut(&nonce) {
+                *pending_tx = tx;
+            }
+        } else if nonce == next_nonce {
+            pending.insert(nonce, tx);
+            next_nonce += 1;
+        } else {
+            queued.insert(nonce, tx);
+        }
+    }
+    (pending, queued)
+}
+
+/// Formats a transaction summary the same way as Geth does for `txpool_inspect`.
+fn inspect_tx(tx: &Transaction) -> String {
+    let gas_price = tx.gas_price.unwrap_or_default();
+    match tx.to {
+        Some(to) => format!(
+            "{to:?}: {} wei + {} gas × {gas_price} wei",
+            tx.value, tx.gas
+        ),
+        None => format!(
+            "contract creation: {} wei + {} gas × {gas_price} wei",
+            tx.value, tx.gas
+        ),
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct TxpoolNamespace {
+    state: RpcState,
+}
+
+impl TxpoolNamespace {
+    pub fn new(state: RpcState) -> Self {
+        Self { state }
+    }
+
+    pub(crate) fn current_method(&self) -> &MethodTracer {
+        &self.state.current_method
+    }
+
+    /// Loads pending transactions from the transaction sink (e.g., the transaction cache on EN) or, if the sink
+    /// doesn't store transactions, from Postgres. The number of loaded transactions is capped by the entity limit
+    /// from the API config; if the cap is hit, the returned content is marked as truncated.
+    async fn pending_transactions(
+        &self,
+        initiator_address: Option<Address>,
+    ) -> Result<TxpoolContent, Web3Error> {
+        let limit = self.state.api_config.req_entities_limit;
+        // Request an extra transaction to detect truncation.
+        let request_limit = limit.saturating_add(1);
+        let mut storage = self.state.acquire_connection().await?;
+        let txs = self
+            .state
+            .tx_sink()
+            .lookup_pending_txs(initiator_address, request_limit)
+            .await?;
+        let mut txs = if let Some(txs) = txs {
+            txs
+        } else {
+            storage
+                .transactions_web3_dal()
+                .get_pending_transactions(
+                    initiator_address,
+                    request_limit,
+                    self.state.api_config.l2_chain_id,
+                )
+                .await
+                .map_err(DalError::generalize)?
+        };
+        let truncated = txs.len() > limit;
+        txs.truncate(limit);
+
+        let mut content = Self::split_transactions(&mut storage, txs).await?;
+        content.truncated = truncated;
+        Ok(content)
+    }
+
+    async fn split_transactions(
+        storage: &mut Connection<'_, Core>,
+        txs: Vec<Transaction>,
+    ) -> Result<TxpoolContent, Web3Error> {
+        let mut txs_by_account = BTreeMap::<_, Vec<_>>::new();
+        for tx in txs {
+            // `from` is always set for pending transactions.
+            let initiator_address = tx.from.unwrap_or_default();
+            txs_by_account
+                .entry(initiator_address)
+                .or_default()
+                .push(tx);
+        }
+        let addresses: Vec<_> = txs_by_account.keys().copied().collect();
+        let stored_nonces = storage
+            .storage_web3_dal()
+            .get_nonces_for_addresses(&addresses)
+            .await
+            .map_err(DalError::generalize)?;
+
+        let mut content = TxpoolContent::default();
+        for (address, txs) in txs_by_account {
+            let stored_nonce = stored_nonces.get(&address).copied().unwrap_or(Nonce(0));
+            let (pending, queued) = split_account_txs(txs, stored_nonce);
+            if !pending.is_empty() {
+                content.pending.insert(address, pending);
+            }
+            if !queued.is_empty() {
+                content.queued.insert(address, queued);
+            }
+        }
+        Ok(content)
+    }
+
+    pub async fn content_impl(&self) -> Result<TxpoolContent, Web3Error> {
+        self.pending_transactions(None).await
+    }
+
+    pub async fn content_from_impl(
+        &self,
+        address: Address,
+    ) -> Result<TxpoolContentFrom, Web3Error> {
+        let mut content = self.pending_transactions(Some(address)).await?;
+        Ok(TxpoolContentFrom {
+            pending: content.pending.remove(&address).unwrap_or_default(),
+            queued: content.queued.remove(&address).unwrap_or_default(),
+            truncated: content.truncated,
+        })
+    }
+
+    /// Counts pending and queued transactions. Unlike other methods, counts are not capped by the entity limit.
+    /// If the transaction sink stores transactions (e.g., on EN), they are counted in memory; otherwise,
+    /// transactions are aggregated by Postgres without loading them. In the latter case, transactions
+    /// with nonces below the stored account nonce (which should not normally be present) are counted as queued.
+    pub async fn status_impl(&self) -> Result<TxpoolStatus, Web3Error> {
+        let mut storage = self.state.acquire_connection().await?;
+        let sink_txs = self
+            .state
+            .tx_sink()
+            .lookup_pending_txs(None, usize::MAX)
+            .await?;
+        if let Some(txs) = sink_txs {
+            let content = Self::split_transactions(&mut storage, txs).await?;
+            let count = |txs: &BTreeMap<Address, BTreeMap<u64, Transaction>>| {
+                txs.values().map(BTreeMap::len).sum::<usize>()
+            };
+            return Ok(TxpoolStatus {
+                pending: count(&content.pending).into(),
+                queued: count(&content.queued).into(),
+            });
+        }
+
+        let txs_by_account = storage
+            .transactions_web3_dal()
+            .get_pending_transactions_by_account()
+            .await
+            .map_err(DalError::generalize)?;
+        let addresses: Vec<_> = txs_by_account.keys().copied().collect();
+        let stored_nonces = storage
+            .storage_web3_dal()
+            .get_nonces_for_addresses(&addresses)
+            .await
+            .map_err(DalError::generalize)?;
+        drop(storage);
+
+        let (mut pending, mut queued) = (0_u64, 0_u64);
+        for (address, txs) in txs_by_account {
+            let stored_nonce = stored_nonces.get(&address).copied().unwrap_or(Nonce(0));
+            if txs.min_nonce == stored_nonce {
+                pending += txs.contiguous_count;
+                queued += txs.total_count - txs.contiguous_count;
+            } else {
+                queued += txs.total_count;
+            }
+        }
+        Ok(TxpoolStatus {
+            pending: pending.into(),
+            queued: queued.into(),
+        })
+    }
+
+    pub async fn inspect_impl(&self) -> Result<TxpoolContent<String>, Web3Error> {
+        let content = self.pending_transactions(None).await?;
+        let inspect = |txs: BTreeMap<Address, BTreeMap<u64, Transaction>>| -> BTreeMap<_, _> {
+            txs.into_iter()
+                .map(|(address, txs)| {
+                    let summaries = txs
+                        .iter()
+                        .map(|(&nonce, tx)| (nonce, inspect_tx(tx)))
+                        .collect();
+                    (address, summaries)
+                })
+                .collect()
+        };
+        Ok(TxpoolContent {
+            pending: inspect(content.pending),
+            queued: inspect(content.queued),
+            truncated: content.truncated,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use zksync_types::{H256, U256};
+
+    use super::*;
+
+    fn mock_tx(nonce: u64) -> Transaction {
+        Transaction {
+            hash: H256::from_low_u64_be(nonce),
+            nonce: nonce.into(),
+            ..Transaction::default()
+        }
+    }
+
+    fn nonces(txs: &BTreeMap<u64, Transaction>) -> HashSet<u64> {
+        txs.keys().copied().collect()
+    }
+
+    #[test]
+    fn splitting_account_transactions() {
+        let txs = [1, 2, 3, 5, 6].map(mock_tx).to_vec();
+        let (pending, queued) = split_account_txs(txs.clone(), Nonce(1));
+        assert_eq!(nonces(&pending), HashSet::from([1, 2, 3]));
+        assert_eq!(nonces(&queued), HashSet::from([5, 6]));
+
+        let (pending, queued) = split_account_txs(txs.clone(), Nonce(0));
+        assert!(pending.is_empty());
+        assert_eq!(nonces(&queued), HashSet::from([1, 2, 3, 5, 6]));
+
+        // Transactions with past nonces are ignored.
+        let (pending, queued) = split_account_txs(txs, Nonce(3));
+        assert_eq!(nonces(&pending), HashSet::from([3]));
+        assert_eq!(nonces(&queued), HashSet::from([5, 6]));
+    }
+
+    #[test]
+    fn splitting_account_transactions_with_replacements() {
+        let mut replacement_tx = mock_tx(1);
+        replacement_tx.hash = H256::repeat_byte(0xff);
+        let txs = vec![mock_tx(0), mock_tx(1), replacement_tx];
+        let (pending, queued) = split_account_txs(txs, Nonce(0));
+        assert_eq!(nonces(&pending), HashSet::from([0, 1]));
+        assert_eq!(pending[&1].hash, H256::repeat_byte(0xff));
+        assert!(queued.is_empty());
+    }
+
+    #[test]
+    fn inspecting_transactions() {
+        let mut tx = mock_tx(0);
+        tx.to = Some(Address::repeat_byte(0x11));
+        tx.value = 1_000.into();
+        tx.gas = 21_000.into();
+        tx.gas_price = Some(U256::from(250_000_000));
+        assert_eq!(
+            inspect_tx(&tx),
+            "0x1111111111111111111111111111111111111111: 1000 wei + 21000 gas × 250000000 wei"
+        );
+
+        tx.to = None;
+        assert_eq!(
+            inspect_tx(&tx),
+            "contract creation: 1000 wei + 21000 gas × 250000000 wei"
+        );
+    }
+}
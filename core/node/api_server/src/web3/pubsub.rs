@@ -1,15 +1,18 @@
 //! (Largely) backend-agnostic logic for dealing with Web3 subscriptions.
 
+use std::collections::HashMap;
+
 use chrono::NaiveDateTime;
 use futures::FutureExt;
 use tokio::{
     sync::{broadcast, mpsc, watch},
     task::JoinHandle,
-    time::{interval, Duration},
+    time::{interval, Duration, Interval},
 };
 use tracing::Instrument as _;
-use zksync_dal::{ConnectionPool, Core, CoreDal};
-use zksync_types::{L2BlockNumber, H128, H256};
+use zksync_dal::{notifications::L2BlockListener, ConnectionPool, Core, CoreDal};
+use zksync_node_sync::SyncState;
+use zksync_types::{api, L1BatchNumber, L2BlockNumber, L2ChainId, H128, H256};
 use zksync_web3_decl::{
     jsonrpsee::{
         core::{server::SubscriptionMessage, SubscriptionResult},
@@ -18,7 +21,10 @@ use zksync_web3_decl::{
         PendingSubscriptionSink, SendTimeoutError, SubscriptionSink,
     },
     namespaces::EthPubSubServer,
-    types::{BlockHeader, Log, PubSubFilter, PubSubResult},
+    types::{
+        BlockHeader, L1BatchStatus, L1BatchStatusUpdate, Log, PubSubFilter, PubSubParams,
+        PubSubResult, PubSubSyncProgress, PubSubSyncStatus,
+    },
 };
 
 use super::{
@@ -28,6 +34,67 @@ use super::{
 
 const BROADCAST_CHANNEL_CAPACITY: usize = 1024;
 const SUBSCRIPTION_SINK_SEND_TIMEOUT: Duration = Duration::from_secs(1);
+/// Multiplier for the polling interval of L2 block-based notifiers used while push notifications about new L2 blocks
+/// are available. Polling is still performed in this case as a safety net, e.g. for data committed to Postgres
+/// after the L2 block header.
+const PUSH_MODE_POLLING_INTERVAL_MULTIPLIER: u32 = 10;
+/// Interval between attempts to (re)connect the listener of L2 block notifications.
+const L2_BLOCK_LISTENER_RETRY_INTERVAL: Duration = Duration::from_secs(5);
+/// L1 batch statuses reported to `zks_l1BatchStatus` subscribers, in the order of their progression.
+const L1_BATCH_STATUSES: [L1BatchStatus; 4] = [
+    L1BatchStatus::Sealed,
+    L1BatchStatus::Committed,
+    L1BatchStatus::Proven,
+    L1BatchStatus::Executed,
+];
+
+/// Returns the current sync status in the format used for `syncing` subscriptions.
+fn sync_status(sync_state: &SyncState) -> PubSubResult {
+    if sync_state.is_synced() {
+        PubSubResult::Syncing(false)
+    } else {
+        PubSubResult::SyncStatus(PubSubSyncStatus {
+            syncing: true,
+            status: PubSubSyncProgress {
+                starting_block: sync_state.get_starting_block().0.into(),
+                current_block: sync_state.get_local_block().0.into(),
+                highest_block: sync_state.get_main_node_block().0.into(),
+            },
+        })
+    }
+}
+
+/// Computes L1 batch status updates between the `prev` and `new` last L1 batch numbers for each status
+/// in [`L1_BATCH_STATUSES`]. `first_l1_batch` is the earliest L1 batch in storage; it's used as the start
+/// of the updated range for statuses that no batch had previously.
+fn l1_batch_status_updates(
+    prev: &[Option<L1BatchNumber>; 4],
+    new: &[Option<L1BatchNumber>; 4],
+    first_l1_batch: L1BatchNumber,
+) -> Vec<L1BatchStatusUpdate> {
+    let mut updates = vec![];
+    for ((&status, &prev), &new) in L1_BATCH_STATUSES.iter().zip(prev).zip(new) {
+        let numbers = match (prev, new) {
+            (Some(prev), Some(new)) if new > prev => prev.0 + 1..=new.0,
+            (None, Some(new)) => {
+                // The genesis L1 batch is never committed to L1, so it only gets the sealed status.
+                let first_l1_batch = if status == L1BatchStatus::Sealed {
+                    first_l1_batch
+                } else {
+                    first_l1_batch.max(L1BatchNumber(1))
+                };
+                first_l1_batch.0..=new.0
+            }
+            // No progress or a revert; in the latter case, updates will be sent once batches are re-processed.
+            _ => continue,
+        };
+        updates.extend(numbers.map(|number| L1BatchStatusUpdate {
+            l1_batch_number: L1BatchNumber(number),
+            status,
+        }));
+    }
+    updates
+}
 
 #[derive(Debug, Clone, Copy)]
 pub struct EthSubscriptionIdProvider;
@@ -45,6 +112,88 @@ pub enum PubSubEvent {
     Subscribed(SubscriptionType),
     NotifyIterationFinished(SubscriptionType),
     L2BlockAdvanced(SubscriptionType, L2BlockNumber),
+    L2BlockNotificationsAvailable,
+}
+
+/// State of push notifications about new L2 blocks. Each notification is signalled by re-sending
+/// [`Self::Available`] to the watch channel.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum L2BlockNotifications {
+    /// Notifications are unavailable; notifiers should poll Postgres.
+    Unavailable,
+    /// Notifications are available.
+    Available,
+}
+
+/// Listens to Postgres notifications about inserted L2 blocks and forwards them to L2 block-based notifiers.
+/// While the listener is disconnected, notifiers fall back to polling.
+async fn listen_to_l2_blocks(
+    connection_pool: ConnectionPool<Core>,
+    sender: watch::Sender<L2BlockNotifications>,
+    events_sender: Option<mpsc::UnboundedSender<PubSubEvent>>,
+    mut stop_receiver: watch::Receiver<bool>,
+) -> anyhow::Result<()> {
+    while !*stop_receiver.borrow_and_update() {
+        match L2BlockListener::new(&connection_pool).await {
+            Ok(mut listener) => {
+                tracing::info!("Listening to Postgres notifications about new L2 blocks");
+                sender.send_replace(L2BlockNotifications::Available);
+                PUB_SUB_METRICS.l2_block_notifications_available.set(1);
+                if let Some(events_sender) = &events_sender {
+                    events_sender
+                        .send(PubSubEvent::L2BlockNotificationsAvailable)
+                        .ok();
+                }
+
+                loop {
+                    let recv_result = tokio::select! {
+                        res = listener.recv() => Some(res),
+                        _ = stop_receiver.changed() => None,
+                    };
+                    let Some(recv_result) = recv_result else {
+                        tracing::info!(
+                            "Stop signal received, pubsub_l2_block_listener is shutting down"
+                        );
+                        return Ok(());
+                    };
+                    match recv_result {
+                        Ok(Some(number)) => {
+                            tracing::trace!("Received notification about L2 block #{number}");
+                            sender.send_replace(L2BlockNotifications::Available);
+                        }
+                        Ok(None) => {
+                            tracing::warn!(
+                                "Lost connection for L2 block notifications; falling back to polling"
+                            );
+                            break;
+                        }
+                        Err(err) => {
+                            tracing::warn!(
+                                "Failed receiving L2 block notifications; falling back to polling: {err:#}"
+                            );
+                            break;
+                        }
+                    }
+                }
+                sender.send_replace(L2BlockNotifications::Unavailable);
+                PUB_SUB_METRICS.l2_block_notifications_available.set(0);
+            }
+            Err(err) => {
+                tracing::warn!(
+                    "Failed listening to L2 block notifications; falling back to polling: {err:#}"
+                );
+            }
+        }
+
+        if tokio::time::timeout(L2_BLOCK_LISTENER_RETRY_INTERVAL, stop_receiver.changed())
+            .await
+            .is_ok()
+        {
+            break;
+        }
+    }
+    tracing::info!("Stop signal received, pubsub_l2_block_listener is shutting down");
+    Ok(())
 }
 
 /// Manager of notifications for a certain type of subscriptions.
@@ -54,6 +203,8 @@ struct PubSubNotifier {
     connection_pool: ConnectionPool<Core>,
     polling_interval: Duration,
     events_sender: Option<mpsc::UnboundedSender<PubSubEvent>>,
+    /// Push notifications about new L2 blocks. Only set for notifiers depending on new L2 blocks.
+    l2_block_notifications: Option<watch::Receiver<L2BlockNotifications>>,
 }
 
 impl PubSubNotifier {
@@ -81,6 +232,44 @@ impl PubSubNotifier {
         Ok(None) // we can only break from the loop if we've received a stop signal
     }
 
+    /// Waits until the next poll. While push notifications about new L2 blocks are available, the notifier
+    /// is woken up by them, and polls at an extended interval as a fallback. Otherwise, the notifier polls
+    /// at the configured interval.
+    async fn wait_for_next_poll(
+        &mut self,
+        timer: &mut Interval,
+        stop_receiver: &mut watch::Receiver<bool>,
+    ) {
+        let Some(notifications) = &mut self.l2_block_notifications else {
+            timer.tick().await;
+            return;
+        };
+
+        let push_available = *notifications.borrow() != L2BlockNotifications::Unavailable;
+        let listener_terminated = if push_available {
+            let extended_interval = self.polling_interval * PUSH_MODE_POLLING_INTERVAL_MULTIPLIER;
+            let listener_terminated = tokio::select! {
+                res = notifications.changed() => res.is_err(),
+                () = tokio::time::sleep(extended_interval) => false,
+                _ = stop_receiver.changed() => false,
+            };
+            timer.reset();
+            listener_terminated
+        } else {
+            // Changes in notifications (e.g., the listener getting connected) wake up the notifier as well.
+            tokio::select! {
+                res = notifications.changed() => res.is_err(),
+                _ = timer.tick() => false,
+                _ = stop_receiver.changed() => false,
+            }
+        };
+
+        if listener_terminated {
+            tracing::info!("L2 block listener has terminated; falling back to polling");
+            self.l2_block_notifications = None;
+        }
+    }
+
     fn emit_event(&self, event: PubSubEvent) {
         if let Some(sender) = &self.events_sender {
             sender.send(event).ok();
@@ -89,7 +278,10 @@ impl PubSubNotifier {
 }
 
 impl PubSubNotifier {
-    async fn notify_blocks(self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+    async fn notify_blocks(
+        mut self,
+        mut stop_receiver: watch::Receiver<bool>,
+    ) -> anyhow::Result<()> {
         let Some(mut last_block_number) = self
             .get_starting_l2_block_number(&mut stop_receiver)
             .await?
@@ -104,7 +296,11 @@ impl PubSubNotifier {
                 tracing::info!("Stop signal received, pubsub_block_notifier is shutting down");
                 break;
             }
-            timer.tick().await;
+            self.wait_for_next_poll(&mut timer, &mut stop_receiver)
+                .await;
+            if *stop_receiver.borrow() {
+                continue;
+            }
 
             let db_latency = PUB_SUB_METRICS.db_poll_latency[&SubscriptionType::Blocks].start();
             let new_blocks = self.new_blocks(last_block_number).await?;
@@ -145,7 +341,12 @@ impl PubSubNotifier {
             .map_err(Into::into)
     }
 
-    async fn notify_txs(self, stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+    async fn notify_txs(
+        self,
+        full_txs_sender: broadcast::Sender<Vec<PubSubResult>>,
+        chain_id: L2ChainId,
+        stop_receiver: watch::Receiver<bool>,
+    ) -> anyhow::Result<()> {
         let mut last_time = chrono::Utc::now().naive_utc();
         let mut timer = interval(self.polling_interval);
         loop {
@@ -161,10 +362,21 @@ impl PubSubNotifier {
 
             if let Some((new_last_time, _)) = new_txs.last() {
                 last_time = *new_last_time;
-                let new_txs = new_txs
-                    .into_iter()
-                    .map(|(_, tx_hash)| PubSubResult::TxHash(tx_hash))
-                    .collect();
+                let tx_hashes: Vec<_> = new_txs.into_iter().map(|(_, tx_hash)| tx_hash).collect();
+                // Only load transaction bodies if there's anyone to send them to.
+                if full_txs_sender.receiver_count() > 0 {
+                    let db_latency =
+                        PUB_SUB_METRICS.db_poll_latency[&SubscriptionType::FullTxs].start();
+                    let full_txs = self.full_txs(&tx_hashes, chain_id).await?;
+                    db_latency.observe();
+
+                    let full_txs = full_txs.into_iter().map(PubSubResult::FullTx).collect();
+                    full_txs_sender.send(full_txs).ok();
+                    PUB_SUB_METRICS.broadcast_channel_len[&SubscriptionType::FullTxs]
+                        .set(full_txs_sender.len());
+                }
+
+                let new_txs = tx_hashes.into_iter().map(PubSubResult::TxHash).collect();
                 self.send_pub_sub_results(new_txs, SubscriptionType::Txs);
             }
             self.emit_event(PubSubEvent::NotifyIterationFinished(SubscriptionType::Txs));
@@ -185,7 +397,30 @@ impl PubSubNotifier {
             .map_err(Into::into)
     }
 
-    async fn notify_logs(self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+    /// Loads transactions with the specified hashes, preserving the order of hashes.
+    async fn full_txs(
+        &self,
+        tx_hashes: &[H256],
+        chain_id: L2ChainId,
+    ) -> anyhow::Result<Vec<api::Transaction>> {
+        let mut txs = self
+            .connection_pool
+            .connection_tagged("api")
+            .await?
+            .transactions_web3_dal()
+            .get_transactions(tx_hashes, chain_id)
+            .await?;
+        let positions: HashMap<_, _> = tx_hashes
+            .iter()
+            .enumerate()
+            .map(|(i, hash)| (*hash, i))
+            .collect();
+        // Transactions not found in the DB (e.g., rejected in the meantime) are filtered out by the DAL.
+        txs.sort_unstable_by_key(|tx| positions.get(&tx.hash).copied());
+        Ok(txs)
+    }
+
+    async fn notify_logs(mut self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
         let Some(mut last_block_number) = self
             .get_starting_l2_block_number(&mut stop_receiver)
             .await?
@@ -200,7 +435,11 @@ impl PubSubNotifier {
                 tracing::info!("Stop signal received, pubsub_logs_notifier is shutting down");
                 break;
             }
-            timer.tick().await;
+            self.wait_for_next_poll(&mut timer, &mut stop_receiver)
+                .await;
+            if *stop_receiver.borrow() {
+                continue;
+            }
 
             let db_latency = PUB_SUB_METRICS.db_poll_latency[&SubscriptionType::Logs].start();
             let new_logs = self.new_logs(last_block_number).await?;
@@ -229,26 +468,147 @@ impl PubSubNotifier {
             .await
             .map_err(Into::into)
     }
+
+    /// Broadcasts sync status changes. Unlike other notifiers, this one doesn't access Postgres.
+    async fn notify_syncing(
+        self,
+        sync_state: SyncState,
+        stop_receiver: watch::Receiver<bool>,
+    ) -> anyhow::Result<()> {
+        // Subscribers receive the current status on subscription, so the initial status isn't broadcast.
+        let mut was_synced = sync_state.is_synced();
+        let mut timer = interval(self.polling_interval);
+        loop {
+            if *stop_receiver.borrow() {
+                tracing::info!("Stop signal received, pubsub_syncing_notifier is shutting down");
+                break;
+            }
+            timer.tick().await;
+
+            let status = sync_status(&sync_state);
+            let is_synced = matches!(status, PubSubResult::Syncing(false));
+            if is_synced != was_synced {
+                was_synced = is_synced;
+                self.send_pub_sub_results(vec![status], SubscriptionType::Syncing);
+            }
+            self.emit_event(PubSubEvent::NotifyIterationFinished(
+                SubscriptionType::Syncing,
+            ));
+        }
+        Ok(())
+    }
+
+    async fn notify_l1_batch_statuses(
+        self,
+        stop_receiver: watch::Receiver<bool>,
+    ) -> anyhow::Result<()> {
+        let mut last_numbers = None;
+        let mut timer = interval(self.polling_interval);
+        loop {
+            if *stop_receiver.borrow() {
+                tracing::info!(
+                    "Stop signal received, pubsub_l1_batch_status_notifier is shutting down"
+                );
+                break;
+            }
+            timer.tick().await;
+
+            if self.sender.receiver_count() == 0 {
+                // Don't poll Postgres if there are no subscribers. Once a subscriber appears,
+                // notifications will start from the then-current state.
+                last_numbers = None;
+            } else {
+                let db_latency =
+                    PUB_SUB_METRICS.db_poll_latency[&SubscriptionType::L1BatchStatus].start();
+                let new_numbers = self.last_l1_batch_numbers().await?;
+                db_latency.observe();
+
+                if let Some(last_numbers) = &last_numbers {
+                    let has_new_statuses = last_numbers
+                        .iter()
+                        .zip(&new_numbers)
+                        .any(|(prev, new)| prev.is_none() && new.is_some());
+                    let first_l1_batch = if has_new_statuses {
+                        self.earliest_l1_batch_number().await?
+                    } else {
+                        L1BatchNumber(0) // Not used
+                    };
+                    let updates =
+                        l1_batch_status_updates(last_numbers, &new_numbers, first_l1_batch);
+                    if !updates.is_empty() {
+                        let updates = updates
+                            .into_iter()
+                            .map(PubSubResult::L1BatchStatus)
+                            .collect();
+                        self.send_pub_sub_results(updates, SubscriptionType::L1BatchStatus);
+                    }
+                }
+                last_numbers = Some(new_numbers);
+            }
+            self.emit_event(PubSubEvent::NotifyIterationFinished(
+                SubscriptionType::L1BatchStatus,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns the last L1 batch numbers for each status in [`L1_BATCH_STATUSES`].
+    async fn last_l1_batch_numbers(&self) -> anyhow::Result<[Option<L1BatchNumber>; 4]> {
+        let mut storage = self.connection_pool.connection_tagged("api").await?;
+        let mut blocks_dal = storage.blocks_dal();
+        Ok([
+            blocks_dal.get_sealed_l1_batch_number().await?,
+            blocks_dal
+                .get_number_of_last_l1_batch_committed_on_eth()
+                .await?,
+            blocks_dal
+                .get_number_of_last_l1_batch_proven_on_eth()
+                .await?,
+            blocks_dal
+                .get_number_of_last_l1_batch_executed_on_eth()
+                .await?,
+        ])
+    }
+
+    /// Returns the earliest L1 batch number in storage, which is non-zero after snapshot recovery or pruning.
+    async fn earliest_l1_batch_number(&self) -> anyhow::Result<L1BatchNumber> {
+        let mut storage = self.connection_pool.connection_tagged("api").await?;
+        let earliest = storage.blocks_dal().get_earliest_l1_batch_number().await?;
+        Ok(earliest.unwrap_or(L1BatchNumber(0)))
+    }
 }
 
 /// Subscription support for Web3 APIs.
 pub(super) struct EthSubscribe {
+    l2_chain_id: L2ChainId,
     blocks: broadcast::Sender<Vec<PubSubResult>>,
     transactions: broadcast::Sender<Vec<PubSubResult>>,
+    full_transactions: broadcast::Sender<Vec<PubSubResult>>,
     logs: broadcast::Sender<Vec<PubSubResult>>,
+    syncing: broadcast::Sender<Vec<PubSubResult>>,
+    l1_batch_statuses: broadcast::Sender<Vec<PubSubResult>>,
+    sync_state: Option<SyncState>,
     events_sender: Option<mpsc::UnboundedSender<PubSubEvent>>,
 }
 
 impl EthSubscribe {
-    pub fn new() -> Self {
+    pub fn new(l2_chain_id: L2ChainId) -> Self {
         let (blocks, _) = broadcast::channel(BROADCAST_CHANNEL_CAPACITY);
         let (transactions, _) = broadcast::channel(BROADCAST_CHANNEL_CAPACITY);
+        let (full_transactions, _) = broadcast::channel(BROADCAST_CHANNEL_CAPACITY);
         let (logs, _) = broadcast::channel(BROADCAST_CHANNEL_CAPACITY);
+        let (syncing, _) = broadcast::channel(BROADCAST_CHANNEL_CAPACITY);
+        let (l1_batch_statuses, _) = broadcast::channel(BROADCAST_CHANNEL_CAPACITY);
 
         Self {
+            l2_chain_id,
             blocks,
             transactions,
+            full_transactions,
             logs,
+            syncing,
+            l1_batch_statuses,
+            sync_state: None,
             events_sender: None,
         }
     }
@@ -257,6 +617,12 @@ impl EthSubscribe {
         self.events_sender = Some(sender);
     }
 
+    /// Sets the sync state used for `syncing` subscriptions. If not set (e.g., on the main node),
+    /// the node is always considered synced.
+    pub fn set_sync_state(&mut self, sync_state: SyncState) {
+        self.sync_state = Some(sync_state);
+    }
+
     async fn reject(sink: PendingSubscriptionSink) {
         sink.reject(ErrorObject::borrowed(
             ErrorCode::InvalidParams.code(),
@@ -350,7 +716,7 @@ impl EthSubscribe {
         &self,
         pending_sink: PendingSubscriptionSink,
         sub_type: String,
-        params: Option<PubSubFilter>,
+        params: Option<PubSubParams>,
     ) {
         let sub_type = match sub_type.as_str() {
             "newHeads" => {
@@ -369,15 +735,30 @@ impl EthSubscribe {
                 let Ok(sink) = pending_sink.accept().await else {
                     return;
                 };
-                let transactions_rx = self.transactions.subscribe();
+                let full_transactions =
+                    matches!(params, Some(PubSubParams::FullTransactions(true)));
+                let (sub_type, transactions_rx) = if full_transactions {
+                    (
+                        SubscriptionType::FullTxs,
+                        self.full_transactions.subscribe(),
+                    )
+                } else {
+                    (SubscriptionType::Txs, self.transactions.subscribe())
+                };
                 tokio::spawn(
-                    Self::run_subscriber(sink, SubscriptionType::Txs, transactions_rx, None)
-                        .in_current_span(),
+                    Self::run_subscriber(sink, sub_type, transactions_rx, None).in_current_span(),
                 );
-                Some(SubscriptionType::Txs)
+                Some(sub_type)
             }
             "logs" => {
-                let filter = params.unwrap_or_default();
+                let filter = match params {
+                    None => PubSubFilter::default(),
+                    Some(PubSubParams::Filter(filter)) => filter,
+                    Some(PubSubParams::FullTransactions(_)) => {
+                        Self::reject(pending_sink).await;
+                        return;
+                    }
+                };
                 let topic_count = filter.topics.as_ref().map_or(0, Vec::len);
 
                 if topic_count > EVENT_TOPIC_NUMBER_LIMIT {
@@ -400,14 +781,47 @@ impl EthSubscribe {
                     return;
                 };
 
-                tokio::spawn(async move {
-                    sink.send_timeout(
-                        SubscriptionMessage::from_json(&PubSubResult::Syncing(false)).unwrap(),
-                        SUBSCRIPTION_SINK_SEND_TIMEOUT,
-                    )
-                    .await
-                });
-                None
+                if let Some(sync_state) = &self.sync_state {
+                    // Subscribe before getting the current status so that no status changes are missed.
+                    let syncing_rx = self.syncing.subscribe();
+                    let current_status = sync_status(sync_state);
+                    tokio::spawn(
+                        async move {
+                            let sub_type = SubscriptionType::Syncing;
+                            let send_result =
+                                Self::handle_new_items(&sink, sub_type, vec![current_status], None)
+                                    .await;
+                            if send_result.is_err() {
+                                PUB_SUB_METRICS.subscriber_send_timeouts[&sub_type].inc();
+                                return;
+                            }
+                            Self::run_subscriber(sink, sub_type, syncing_rx, None).await;
+                        }
+                        .in_current_span(),
+                    );
+                    Some(SubscriptionType::Syncing)
+                } else {
+                    // The node is the main node, which is always synced.
+                    tokio::spawn(async move {
+                        sink.send_timeout(
+                            SubscriptionMessage::from_json(&PubSubResult::Syncing(false)).unwrap(),
+                            SUBSCRIPTION_SINK_SEND_TIMEOUT,
+                        )
+                        .await
+                    });
+                    None
+                }
+            }
+            "zks_l1BatchStatus" => {
+                let Ok(sink) = pending_sink.accept().await else {
+                    return;
+                };
+                let statuses_rx = self.l1_batch_statuses.subscribe();
+                tokio::spawn(
+                    Self::run_subscriber(sink, SubscriptionType::L1BatchStatus, statuses_rx, None)
+                        .in_current_span(),
+                );
+                Some(SubscriptionType::L1BatchStatus)
             }
             _ => {
                 Self::reject(pending_sink).await;
@@ -429,13 +843,24 @@ impl EthSubscribe {
         polling_interval: Duration,
         stop_receiver: watch::Receiver<bool>,
     ) -> Vec<JoinHandle<anyhow::Result<()>>> {
-        let mut notifier_tasks = Vec::with_capacity(3);
+        let mut notifier_tasks = Vec::with_capacity(6);
+
+        let (l2_block_notifications_sender, l2_block_notifications) =
+            watch::channel(L2BlockNotifications::Unavailable);
+        let listener_task = tokio::spawn(listen_to_l2_blocks(
+            connection_pool.clone(),
+            l2_block_notifications_sender,
+            self.events_sender.clone(),
+            stop_receiver.clone(),
+        ));
+        notifier_tasks.push(listener_task);
 
         let notifier = PubSubNotifier {
             sender: self.blocks.clone(),
             connection_pool: connection_pool.clone(),
             polling_interval,
             events_sender: self.events_sender.clone(),
+            l2_block_notifications: Some(l2_block_notifications.clone()),
         };
         let notifier_task = tokio::spawn(notifier.notify_blocks(stop_receiver.clone()));
         notifier_tasks.push(notifier_task);
@@ -445,17 +870,46 @@ impl EthSubscribe {
             connection_pool: connection_pool.clone(),
             polling_interval,
             events_sender: self.events_sender.clone(),
+            l2_block_notifications: None,
         };
-        let notifier_task = tokio::spawn(notifier.notify_txs(stop_receiver.clone()));
+        let notifier_task = tokio::spawn(notifier.notify_txs(
+            self.full_transactions.clone(),
+            self.l2_chain_id,
+            stop_receiver.clone(),
+        ));
         notifier_tasks.push(notifier_task);
 
         let notifier = PubSubNotifier {
             sender: self.logs.clone(),
+            connection_pool: connection_pool.clone(),
+            polling_interval,
+            events_sender: self.events_sender.clone(),
+            l2_block_notifications: Some(l2_block_notifications),
+        };
+        let notifier_task = tokio::spawn(notifier.notify_logs(stop_receiver.clone()));
+        notifier_tasks.push(notifier_task);
+
+        if let Some(sync_state) = self.sync_state.clone() {
+            let notifier = PubSubNotifier {
+                sender: self.syncing.clone(),
+                connection_pool: connection_pool.clone(),
+                polling_interval,
+                events_sender: self.events_sender.clone(),
+                l2_block_notifications: None,
+            };
+            let notifier_task =
+                tokio::spawn(notifier.notify_syncing(sync_state, stop_receiver.clone()));
+            notifier_tasks.push(notifier_task);
+        }
+
+        let notifier = PubSubNotifier {
+            sender: self.l1_batch_statuses.clone(),
             connection_pool,
             polling_interval,
             events_sender: self.events_sender.clone(),
+            l2_block_notifications: None,
         };
-        let notifier_task = tokio::spawn(notifier.notify_logs(stop_receiver));
+        let notifier_task = tokio::spawn(notifier.notify_l1_batch_statuses(stop_receiver));
 
         notifier_tasks.push(notifier_task);
         notifier_tasks
@@ -468,9 +922,72 @@ impl EthPubSubServer for EthSubscribe {
         &self,
         pending: PendingSubscriptionSink,
         sub_type: String,
-        filter: Option<PubSubFilter>,
+        params: Option<PubSubParams>,
     ) -> SubscriptionResult {
-        self.sub(pending, sub_type, filter).await;
+        self.sub(pending, sub_type, params).await;
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn updates(numbers: &[(u32, L1BatchStatus)]) -> Vec<L1BatchStatusUpdate> {
+        numbers
+            .iter()
+            .map(|&(number, status)| L1BatchStatusUpdate {
+                l1_batch_number: L1BatchNumber(number),
+                status,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn computing_l1_batch_status_updates() {
+        let prev = [Some(L1BatchNumber(3)), Some(L1BatchNumber(1)), None, None];
+        let new = [Some(L1BatchNumber(5)), Some(L1BatchNumber(3)), None, None];
+        assert_eq!(
+            l1_batch_status_updates(&prev, &new, L1BatchNumber(0)),
+            updates(&[
+                (4, L1BatchStatus::Sealed),
+                (5, L1BatchStatus::Sealed),
+                (2, L1BatchStatus::Committed),
+                (3, L1BatchStatus::Committed),
+            ])
+        );
+
+        // Reverts and lack of progress don't produce updates.
+        let reverted = [Some(L1BatchNumber(2)), Some(L1BatchNumber(1)), None, None];
+        assert!(l1_batch_status_updates(&prev, &reverted, L1BatchNumber(0)).is_empty());
+        assert!(l1_batch_status_updates(&prev, &prev, L1BatchNumber(0)).is_empty());
+    }
+
+    #[test]
+    fn l1_batch_status_updates_for_first_batches_with_status() {
+        let prev = [Some(L1BatchNumber(3)), Some(L1BatchNumber(2)), None, None];
+        let new = [
+            Some(L1BatchNumber(3)),
+            Some(L1BatchNumber(2)),
+            Some(L1BatchNumber(2)),
+            None,
+        ];
+        // All batches in the window must be reported, excluding the genesis batch.
+        assert_eq!(
+            l1_batch_status_updates(&prev, &new, L1BatchNumber(0)),
+            updates(&[(1, L1BatchStatus::Proven), (2, L1BatchStatus::Proven)])
+        );
+        // After snapshot recovery, the window starts from the earliest batch in storage.
+        assert_eq!(
+            l1_batch_status_updates(&prev, &new, L1BatchNumber(2)),
+            updates(&[(2, L1BatchStatus::Proven)])
+        );
+
+        let prev = [None; 4];
+        let new = [Some(L1BatchNumber(0)), None, None, None];
+        assert_eq!(
+            l1_batch_status_updates(&prev, &new, L1BatchNumber(0)),
+            updates(&[(0, L1BatchStatus::Sealed)])
+        );
+    }
+}
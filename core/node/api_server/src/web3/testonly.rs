@@ -104,6 +104,7 @@ pub struct TestServerBuilder {
     api_config: InternalApiConfig,
     tx_executor: MockOneshotExecutor,
     method_tracer: Arc<MethodTracer>,
+    sync_state: Option<SyncState>,
 }
 
 impl TestServerBuilder {
@@ -114,6 +115,7 @@ impl TestServerBuilder {
             pool,
             tx_executor: MockOneshotExecutor::default(),
             method_tracer: Arc::default(),
+            sync_state: None,
         }
     }
 
@@ -131,6 +133,13 @@ impl TestServerBuilder {
         self
     }
 
+    /// Sets the sync state for this builder, which emulates the server running on an external node.
+    #[must_use]
+    pub fn with_sync_state(mut self, sync_state: SyncState) -> Self {
+        self.sync_state = Some(sync_state);
+        self
+    }
+
     /// Builds an HTTP server.
     pub async fn build_http(self, stop_receiver: watch::Receiver<bool>) -> ApiServerHandles {
         spawn_server(
@@ -140,6 +149,7 @@ impl TestServerBuilder {
             None,
             self.tx_executor,
             self.method_tracer,
+            self.sync_state,
             stop_receiver,
         )
         .await
@@ -159,6 +169,7 @@ impl TestServerBuilder {
             websocket_requests_per_minute_limit,
             self.tx_executor,
             self.method_tracer,
+            self.sync_state,
             stop_receiver,
         )
         .await
@@ -172,6 +183,7 @@ async fn spawn_server(
     websocket_requests_per_minute_limit: Option<NonZeroU32>,
     tx_executor: MockOneshotExecutor,
     method_tracer: Arc<MethodTracer>,
+    sync_state: Option<SyncState>,
     stop_receiver: watch::Receiver<bool>,
 ) -> (ApiServerHandles, mpsc::UnboundedReceiver<PubSubEvent>) {
     let tx_executor = SandboxExecutor::mock(tx_executor).await;
@@ -180,11 +192,16 @@ async fn spawn_server(
     let (pub_sub_events_sender, pub_sub_events_receiver) = mpsc::unbounded_channel();
 
     let mut namespaces = Namespace::DEFAULT.to_vec();
-    namespaces.extend([Namespace::Debug, Namespace::Snapshots, Namespace::Unstable]);
+    namespaces.extend([
+        Namespace::Debug,
+        Namespace::Snapshots,
+        Namespace::Unstable,
+        Namespace::Txpool,
+    ]);
     let sealed_l2_block_handle = SealedL2BlockNumber::default();
     let bridge_addresses_handle = BridgeAddressesHandle::new(api_config.bridge_addresses.clone());
 
-    let server_builder = match transport {
+    let mut server_builder = match transport {
         ApiTransportLabel::Http => ApiBuilder::jsonrpsee_backend(api_config, pool).http(0),
         ApiTransportLabel::Ws => {
             let mut builder = ApiBuilder::jsonrpsee_backend(api_config, pool)
@@ -197,6 +214,9 @@ async fn spawn_server(
             builder
         }
     };
+    if let Some(sync_state) = sync_state {
+        server_builder = server_builder.with_sync_state(sync_state);
+    }
     let server_handles = server_builder
         .with_polling_interval(POLL_INTERVAL)
         .with_tx_sender(tx_sender)
@@ -32,14 +32,15 @@ use zksync_system_constants::{
 use zksync_types::{
     api,
     block::{pack_block_info, L2BlockHeader},
+    fee::L2BlockFeeStats,
     get_nonce_key,
     l2::L2Tx,
     storage::get_code_key,
     tokens::{TokenInfo, TokenMetadata},
     tx::IncludedTxLocation,
     utils::{storage_key_for_eth_balance, storage_key_for_standard_token_balance},
-    AccountTreeId, Address, L1BatchNumber, Nonce, ProtocolVersionId, StorageKey, StorageLog, H256,
-    U256, U64,
+    web3, AccountTreeId, Address, L1BatchNumber, Nonce, ProtocolVersionId, StorageKey, StorageLog,
+    H256, U256, U64,
 };
 use zksync_utils::u256_to_h256;
 use zksync_vm_executor::oneshot::MockOneshotExecutor;
@@ -63,6 +64,7 @@ use crate::web3::testonly::TestServerBuilder;
 mod debug;
 mod filters;
 mod snapshots;
+mod txpool;
 mod unstable;
 mod vm;
 mod ws;
@@ -969,6 +971,66 @@ async fn getting_all_account_balances() {
     test_http_server(AllAccountBalancesTest).await;
 }
 
+#[derive(Debug)]
+struct FeeHistoryTest;
+
+#[async_trait]
+impl HttpTest for FeeHistoryTest {
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let max_priority_fee = client.max_priority_fee_per_gas().await?;
+        assert_eq!(max_priority_fee, U256::zero());
+
+        let mut storage = pool.connection().await?;
+        let mut header = create_l2_block(1);
+        header.gas_limit = 1_000_000;
+        store_custom_l2_block(&mut storage, &header, &[]).await?;
+        // Fee stats are persisted by the state keeper; priority fees are never charged by it at the moment,
+        // so we use mock values.
+        let mut stats = L2BlockFeeStats::default();
+        stats.push_tx(Some(30.into()), 100_000);
+        stats.push_tx(Some(10.into()), 200_000);
+        stats.push_tx(None, 100_000);
+        storage
+            .blocks_dal()
+            .insert_l2_block_fee_stats(L2BlockNumber(1), &stats)
+            .await?;
+
+        let history = client
+            .fee_history(2.into(), api::BlockNumber::Latest, vec![0.0, 50.0, 100.0])
+            .await?;
+        assert_eq!(
+            history.inner.oldest_block,
+            web3::BlockNumber::Number(0.into())
+        );
+        let expected_rewards = vec![vec![U256::zero(); 3], vec![10.into(), 10.into(), 30.into()]];
+        assert_eq!(history.inner.reward, Some(expected_rewards));
+        assert_eq!(history.inner.gas_used_ratio, [0.0, 0.4]);
+
+        let max_priority_fee = client.max_priority_fee_per_gas().await?;
+        assert_eq!(max_priority_fee, 10.into());
+
+        let err = client
+            .fee_history(2.into(), api::BlockNumber::Latest, vec![50.0, 10.0])
+            .await
+            .unwrap_err();
+        if let ClientError::Call(err) = err {
+            assert_eq!(err.code(), ErrorCode::InvalidParams.code());
+        } else {
+            panic!("Unexpected error: {err:?}");
+        }
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn getting_fee_history() {
+    test_http_server(FeeHistoryTest).await;
+}
+
 #[derive(Debug, Default)]
 struct RpcCallsTracingTest {
     tracer: Arc<MethodTracer>,
@@ -0,0 +1,92 @@
+//! Tests for the `txpool` Web3 namespace.
+
+use zksync_web3_decl::namespaces::TxpoolNamespaceClient;
+
+use super::*;
+
+#[derive(Debug)]
+struct TxpoolTest;
+
+#[async_trait]
+impl HttpTest for TxpoolTest {
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let content = client.content().await?;
+        assert!(content.pending.is_empty() && content.queued.is_empty());
+        let status = client.status().await?;
+        assert_eq!(status, api::TxpoolStatus::default());
+
+        let test_address = Address::repeat_byte(11);
+        let mut storage = pool.connection().await?;
+        // Emulate the account having sent a single transaction.
+        let nonce_log =
+            StorageLog::new_write_log(get_nonce_key(&test_address), H256::from_low_u64_be(1));
+        storage
+            .storage_logs_dal()
+            .append_storage_logs(L2BlockNumber(0), &[nonce_log])
+            .await?;
+
+        let mut txs = HashMap::new();
+        for nonce in [1, 2, 4] {
+            let mut tx = create_l2_transaction(10, 200);
+            tx.common_data.initiator_address = test_address;
+            tx.common_data.nonce = Nonce(nonce);
+            storage
+                .transactions_dal()
+                .insert_transaction_l2(&tx, TransactionExecutionMetrics::default())
+                .await?;
+            txs.insert(u64::from(nonce), tx);
+        }
+        let other_tx = create_l2_transaction(10, 200);
+        storage
+            .transactions_dal()
+            .insert_transaction_l2(&other_tx, TransactionExecutionMetrics::default())
+            .await?;
+
+        let content = client.content().await?;
+        assert!(!content.truncated);
+        assert_eq!(content.pending.len(), 2);
+        let pending_hashes: HashMap<_, _> = content.pending[&test_address]
+            .iter()
+            .map(|(&nonce, tx)| (nonce, tx.hash))
+            .collect();
+        assert_eq!(
+            pending_hashes,
+            HashMap::from([(1, txs[&1].hash()), (2, txs[&2].hash())])
+        );
+        assert_eq!(
+            content.pending[&other_tx.initiator_account()][&0].hash,
+            other_tx.hash()
+        );
+        assert_eq!(content.queued.len(), 1);
+        let queued_tx = &content.queued[&test_address][&4];
+        assert_eq!(queued_tx.hash, txs[&4].hash());
+        assert_eq!(queued_tx.from, Some(test_address));
+        assert_eq!(queued_tx.block_number, None);
+
+        let content = client.content_from(test_address).await?;
+        assert_eq!(content.pending.keys().copied().collect::<Vec<_>>(), [1, 2]);
+        assert_eq!(content.queued.keys().copied().collect::<Vec<_>>(), [4]);
+        let content = client.content_from(Address::repeat_byte(0xff)).await?;
+        assert!(content.pending.is_empty() && content.queued.is_empty());
+
+        let status = client.status().await?;
+        assert_eq!(status.pending, 3.into());
+        assert_eq!(status.queued, 1.into());
+
+        let inspect = client.inspect().await?;
+        let summary = &inspect.queued[&test_address][&4];
+        let expected_recipient = format!("{:?}", txs[&4].recipient_account().unwrap());
+        assert!(summary.starts_with(&expected_recipient), "{summary}");
+        assert!(summary.ends_with("gas × 10 wei"), "{summary}");
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn txpool_methods() {
+    test_http_server(TxpoolTest).await;
+}
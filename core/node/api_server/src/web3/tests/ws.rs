@@ -8,9 +8,9 @@ use http::StatusCode;
 use tokio::sync::watch;
 use zksync_config::configs::chain::NetworkConfig;
 use zksync_dal::ConnectionPool;
-use zksync_types::{api, Address, Bloom, L1BatchNumber, H160, H256, U64};
+use zksync_types::{api, Address, Bloom, L1BatchNumber, L2ChainId, H160, H256, U64};
 use zksync_web3_decl::{
-    client::{WsClient, L2},
+    client::{MockClient, WsClient, L2},
     jsonrpsee::{
         core::{
             client::{ClientT, Subscription, SubscriptionClientT},
@@ -20,7 +20,7 @@ use zksync_web3_decl::{
         rpc_params,
     },
     namespaces::{EthNamespaceClient, ZksNamespaceClient},
-    types::{BlockHeader, Bytes, PubSubFilter},
+    types::{BlockHeader, Bytes, L1BatchStatus, L1BatchStatusUpdate, PubSubFilter},
 };
 
 use super::*;
@@ -109,7 +109,7 @@ async fn notifiers_start_after_snapshot_recovery() {
 
     let (stop_sender, stop_receiver) = watch::channel(false);
     let (events_sender, mut events_receiver) = mpsc::unbounded_channel();
-    let mut subscribe_logic = EthSubscribe::new();
+    let mut subscribe_logic = EthSubscribe::new(L2ChainId::default());
     subscribe_logic.set_events_sender(events_sender);
     let notifier_handles =
         subscribe_logic.spawn_notifiers(pool.clone(), POLL_INTERVAL, stop_receiver);
@@ -143,6 +143,53 @@ async fn notifiers_start_after_snapshot_recovery() {
     }
 }
 
+#[tokio::test]
+async fn notifiers_are_woken_up_by_l2_block_notifications() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    prepare_recovery_snapshot(
+        &mut storage,
+        StorageInitialization::SNAPSHOT_RECOVERY_BATCH,
+        StorageInitialization::SNAPSHOT_RECOVERY_BLOCK,
+        &[],
+    )
+    .await;
+
+    let (stop_sender, stop_receiver) = watch::channel(false);
+    let (events_sender, mut events_receiver) = mpsc::unbounded_channel();
+    let mut subscribe_logic = EthSubscribe::new(L2ChainId::default());
+    subscribe_logic.set_events_sender(events_sender);
+    // The polling interval is large enough for notifiers to only be woken up by L2 block notifications.
+    let notifier_handles =
+        subscribe_logic.spawn_notifiers(pool.clone(), Duration::from_secs(3_600), stop_receiver);
+
+    tokio::time::timeout(TEST_TIMEOUT, async {
+        loop {
+            let event = events_receiver
+                .recv()
+                .await
+                .expect("Events emitter unexpectedly dropped");
+            if matches!(event, PubSubEvent::L2BlockNotificationsAvailable) {
+                break;
+            }
+        }
+    })
+    .await
+    .expect("Timed out waiting for L2 block notifications");
+
+    let new_l2_block = StorageInitialization::SNAPSHOT_RECOVERY_BLOCK + 1;
+    store_l2_block(&mut storage, new_l2_block, &[])
+        .await
+        .unwrap();
+    wait_for_notifier_l2_block(&mut events_receiver, SubscriptionType::Blocks, new_l2_block).await;
+
+    stop_sender.send_replace(true);
+    // Notifiers not depending on L2 blocks only check the stop signal once per polling interval.
+    for handle in notifier_handles {
+        handle.abort();
+    }
+}
+
 #[async_trait]
 trait WsTest: Send + Sync {
     /// Prepares the storage before the server is started. The default implementation performs genesis.
@@ -160,6 +207,11 @@ trait WsTest: Send + Sync {
     fn websocket_requests_per_minute_limit(&self) -> Option<NonZeroU32> {
         None
     }
+
+    /// Returns the sync state for the server. If set, the server emulates running on an external node.
+    fn sync_state(&self) -> Option<SyncState> {
+        None
+    }
 }
 
 async fn test_ws_server(test: impl WsTest) {
@@ -177,7 +229,11 @@ async fn test_ws_server(test: impl WsTest) {
     drop(storage);
 
     let (stop_sender, stop_receiver) = watch::channel(false);
-    let (mut server_handles, pub_sub_events) = TestServerBuilder::new(pool.clone(), api_config)
+    let mut server_builder = TestServerBuilder::new(pool.clone(), api_config);
+    if let Some(sync_state) = test.sync_state() {
+        server_builder = server_builder.with_sync_state(sync_state);
+    }
+    let (mut server_handles, pub_sub_events) = server_builder
         .build_ws(test.websocket_requests_per_minute_limit(), stop_receiver)
         .await;
 
@@ -344,6 +400,174 @@ async fn basic_subscriptions_after_snapshot_recovery() {
     .await;
 }
 
+#[derive(Debug)]
+struct FullPendingTransactionsSubscriptionTest;
+
+#[async_trait]
+impl WsTest for FullPendingTransactionsSubscriptionTest {
+    async fn test(
+        &self,
+        client: &WsClient<L2>,
+        pool: &ConnectionPool<Core>,
+        mut pub_sub_events: mpsc::UnboundedReceiver<PubSubEvent>,
+    ) -> anyhow::Result<()> {
+        wait_for_notifiers(&mut pub_sub_events, &[SubscriptionType::Txs]).await;
+
+        let params = rpc_params!["newPendingTransactions", true];
+        let mut txs_subscription = client
+            .subscribe::<api::Transaction, _>("eth_subscribe", params, "eth_unsubscribe")
+            .await?;
+        wait_for_subscription(&mut pub_sub_events, SubscriptionType::FullTxs).await;
+
+        let mut storage = pool.connection().await?;
+        let tx = create_l2_transaction(1, 2);
+        let tx_result = execute_l2_transaction(tx.clone());
+        store_l2_block(&mut storage, L2BlockNumber(1), &[tx_result]).await?;
+        drop(storage);
+
+        let received_tx = tokio::time::timeout(TEST_TIMEOUT, txs_subscription.next())
+            .await
+            .context("Timed out waiting for new tx")?
+            .context("Pending txs subscription terminated")??;
+        assert_eq!(received_tx.hash, tx.hash());
+        assert_eq!(received_tx.from, Some(tx.initiator_account()));
+        assert_eq!(received_tx.nonce, tx.nonce().0.into());
+
+        txs_subscription.unsubscribe().await?;
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn full_pending_transactions_subscription() {
+    test_ws_server(FullPendingTransactionsSubscriptionTest).await;
+}
+
+#[derive(Debug)]
+struct SyncingSubscriptionTest {
+    sync_state: SyncState,
+}
+
+#[async_trait]
+impl WsTest for SyncingSubscriptionTest {
+    fn sync_state(&self) -> Option<SyncState> {
+        Some(self.sync_state.clone())
+    }
+
+    async fn test(
+        &self,
+        client: &WsClient<L2>,
+        pool: &ConnectionPool<Core>,
+        mut pub_sub_events: mpsc::UnboundedReceiver<PubSubEvent>,
+    ) -> anyhow::Result<()> {
+        wait_for_notifiers(&mut pub_sub_events, &[SubscriptionType::Syncing]).await;
+
+        let params = rpc_params!["syncing"];
+        let mut syncing_subscription = client
+            .subscribe::<serde_json::Value, _>("eth_subscribe", params, "eth_unsubscribe")
+            .await?;
+        wait_for_subscription(&mut pub_sub_events, SubscriptionType::Syncing).await;
+
+        // The sync state isn't initialized, so the node is considered to be syncing.
+        let status = tokio::time::timeout(TEST_TIMEOUT, syncing_subscription.next())
+            .await
+            .context("Timed out waiting for sync status")?
+            .context("Syncing subscription terminated")??;
+        assert_eq!(
+            status,
+            serde_json::json!({
+                "syncing": true,
+                "status": {
+                    "StartingBlock": "0x0",
+                    "CurrentBlock": "0x0",
+                    "HighestBlock": "0x0",
+                },
+            })
+        );
+
+        let main_node_client = MockClient::builder(L2::default())
+            .method("eth_blockNumber", || Ok(U64::zero()))
+            .build();
+        let (_stop_sender, stop_receiver) = watch::channel(false);
+        let updater_task = tokio::spawn(self.sync_state.clone().run_updater(
+            pool.clone(),
+            Box::new(main_node_client),
+            stop_receiver,
+        ));
+
+        let status = tokio::time::timeout(TEST_TIMEOUT, syncing_subscription.next())
+            .await
+            .context("Timed out waiting for sync status")?
+            .context("Syncing subscription terminated")??;
+        assert_eq!(status, serde_json::json!(false));
+        assert!(self.sync_state.is_synced());
+
+        updater_task.abort();
+        syncing_subscription.unsubscribe().await?;
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn syncing_subscription() {
+    test_ws_server(SyncingSubscriptionTest {
+        sync_state: SyncState::default(),
+    })
+    .await;
+}
+
+#[derive(Debug)]
+struct L1BatchStatusSubscriptionTest;
+
+#[async_trait]
+impl WsTest for L1BatchStatusSubscriptionTest {
+    async fn test(
+        &self,
+        client: &WsClient<L2>,
+        pool: &ConnectionPool<Core>,
+        mut pub_sub_events: mpsc::UnboundedReceiver<PubSubEvent>,
+    ) -> anyhow::Result<()> {
+        let params = rpc_params!["zks_l1BatchStatus"];
+        let mut statuses_subscription = client
+            .subscribe::<L1BatchStatusUpdate, _>("eth_subscribe", params, "eth_unsubscribe")
+            .await?;
+        wait_for_subscription(&mut pub_sub_events, SubscriptionType::L1BatchStatus).await;
+        // Wait until the notifier loads the current L1 batch statuses, so that it reacts to new batches.
+        for _ in 0..2 {
+            wait_for_notifiers(&mut pub_sub_events, &[SubscriptionType::L1BatchStatus]).await;
+        }
+
+        let mut storage = pool.connection().await?;
+        for number in 1..=2 {
+            store_l2_block(&mut storage, L2BlockNumber(number), &[]).await?;
+            seal_l1_batch(&mut storage, L1BatchNumber(number)).await?;
+        }
+        drop(storage);
+
+        for number in 1..=2 {
+            let update = tokio::time::timeout(TEST_TIMEOUT, statuses_subscription.next())
+                .await
+                .context("Timed out waiting for L1 batch status")?
+                .context("L1 batch status subscription terminated")??;
+            assert_eq!(
+                update,
+                L1BatchStatusUpdate {
+                    l1_batch_number: L1BatchNumber(number),
+                    status: L1BatchStatus::Sealed,
+                }
+            );
+        }
+
+        statuses_subscription.unsubscribe().await?;
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn l1_batch_status_subscription() {
+    test_ws_server(L1BatchStatusSubscriptionTest).await;
+}
+
 #[derive(Debug)]
 struct LogSubscriptionsTest {
     snapshot_recovery: bool,
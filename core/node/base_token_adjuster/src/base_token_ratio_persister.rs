@@ -1,24 +1,40 @@
 use std::{fmt::Debug, sync::Arc, time::Instant};
 
 use anyhow::Context as _;
+use chrono::Utc;
 use tokio::{sync::watch, time::sleep};
 use zksync_config::configs::base_token_adjuster::BaseTokenAdjusterConfig;
 use zksync_dal::{ConnectionPool, Core, CoreDal};
 use zksync_external_price_api::PriceAPIClient;
+use zksync_health_check::{Health, HealthStatus, HealthUpdater, ReactiveHealthCheck};
 use zksync_types::{base_token_ratio::BaseTokenAPIRatio, Address};
 
 use crate::{
     base_token_l1_behaviour::BaseTokenL1Behaviour,
+    guardrails::{BaseTokenRatioGuardrailChecker, GuardrailViolation, RatioGuardrails},
     metrics::{OperationResult, OperationResultLabels, METRICS},
 };
 
-#[derive(Debug, Clone)]
+/// Persists base token ratios fetched from an external price API and (optionally) propagates them to L1.
+///
+/// Fetched ratios are checked against the configured guardrails (the maximum change compared to the previously
+/// persisted ratio, and the maximum quote staleness). If a ratio violates the guardrails, it is ignored, so that
+/// the previous ratio remains in effect; the violation is reported via metrics and the health check.
+/// A ratio exceeding the change limit is accepted once the configured number of subsequent fetched ratios confirm it
+/// (i.e., are within the limit from it); only changes breaking the confirmation sequence and stale quotes are reported
+/// to the circuit breaker obtainable via [`Self::guardrail_checker()`]. The reported violation is cleared once
+/// a fetched ratio passes the guardrails.
+#[derive(Debug)]
 pub struct BaseTokenRatioPersister {
     pool: ConnectionPool<Core>,
     config: BaseTokenAdjusterConfig,
     base_token_address: Address,
     price_api_client: Arc<dyn PriceAPIClient>,
     l1_behaviour: BaseTokenL1Behaviour,
+    guardrails: RatioGuardrails,
+    health_updater: HealthUpdater,
+    /// First guardrail violation since the last ratio passing the guardrails.
+    violation_sender: watch::Sender<Option<GuardrailViolation>>,
 }
 
 impl BaseTokenRatioPersister {
@@ -30,12 +46,28 @@ impl BaseTokenRatioPersister {
         price_api_client: Arc<dyn PriceAPIClient>,
         l1_behaviour: BaseTokenL1Behaviour,
     ) -> Self {
+        let guardrails = RatioGuardrails::new(&config);
         Self {
             pool,
             config,
             base_token_address,
             price_api_client,
             l1_behaviour,
+            guardrails,
+            health_updater: ReactiveHealthCheck::new("base_token_ratio_persister").1,
+            violation_sender: watch::channel(None).0,
+        }
+    }
+
+    /// Returns the health check for this persister.
+    pub fn health_check(&self) -> ReactiveHealthCheck {
+        self.health_updater.subscribe()
+    }
+
+    /// Returns a circuit breaker that trips once a fetched ratio violates the configured guardrails.
+    pub fn guardrail_checker(&self) -> BaseTokenRatioGuardrailChecker {
+        BaseTokenRatioGuardrailChecker {
+            violation_receiver: self.violation_sender.subscribe(),
         }
     }
 
@@ -69,10 +101,57 @@ impl BaseTokenRatioPersister {
     async fn loop_iteration(&mut self) -> anyhow::Result<()> {
         // TODO(PE-148): Consider shifting retry upon adding external API redundancy.
         let new_ratio = self.retry_fetch_ratio().await?;
+        if let Err(violation) = self.check_guardrails(&new_ratio).await? {
+            tracing::error!(
+                "Fetched base token ratio {new_ratio:?} violates guardrails: {violation}; keeping the previous ratio"
+            );
+            METRICS.guardrail_violations[&violation.kind()].inc();
+            let health = Health::from(HealthStatus::Affected).with_details(&violation);
+            self.health_updater.update(health);
+
+            // A change violation may be caused by a genuine market move, which can be confirmed by subsequent fetched ratios.
+            if !violation.is_awaiting_confirmation() {
+                self.violation_sender.send_if_modified(|current| {
+                    let is_first = current.is_none();
+                    if is_first {
+                        *current = Some(violation);
+                    }
+                    is_first
+                });
+            }
+            return Ok(());
+        }
+
+        let had_violation = self
+            .violation_sender
+            .send_if_modified(|current| current.take().is_some());
+        if had_violation {
+            tracing::info!("Fetched base token ratio {new_ratio:?} passes guardrails; clearing the guardrail violation");
+        }
         self.persist_ratio(new_ratio).await?;
+        self.health_updater.update(HealthStatus::Ready.into());
         self.l1_behaviour.update_l1(new_ratio).await
     }
 
+    async fn check_guardrails(
+        &mut self,
+        new_ratio: &BaseTokenAPIRatio,
+    ) -> anyhow::Result<Result<(), GuardrailViolation>> {
+        let mut conn = self
+            .pool
+            .connection_tagged("base_token_ratio_persister")
+            .await
+            .context("Failed to obtain connection to the database")?;
+        let previous_ratio = conn
+            .base_token_dal()
+            .get_latest_ratio()
+            .await
+            .context("Failed to get the latest base token ratio")?;
+        Ok(self
+            .guardrails
+            .check(previous_ratio.as_ref(), new_ratio, Utc::now()))
+    }
+
     async fn retry_fetch_ratio(&self) -> anyhow::Result<BaseTokenAPIRatio> {
         let sleep_duration = self.config.price_fetching_sleep_duration();
         let max_retries = self.config.price_fetching_max_attempts;
@@ -0,0 +1,393 @@
+use std::{fmt, num::NonZeroU64, time::Duration};
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use tokio::sync::watch;
+use zksync_circuit_breaker::{CircuitBreaker, CircuitBreakerError};
+use zksync_config::configs::base_token_adjuster::BaseTokenAdjusterConfig;
+use zksync_types::base_token_ratio::{BaseTokenAPIRatio, BaseTokenRatio};
+
+use crate::metrics::GuardrailViolationKind;
+
+/// Reason for rejecting a fetched base token ratio.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub(crate) enum GuardrailViolation {
+    /// Ratio has changed too much compared to the previously persisted ratio, and the change isn't confirmed
+    /// by enough fetched ratios yet.
+    Change {
+        previous_ratio: f64,
+        new_ratio: f64,
+        change_percentage: f64,
+        /// Whether the change may still be confirmed by subsequent fetched ratios.
+        awaiting_confirmation: bool,
+    },
+    /// Ratio is based on a stale price quote. Only detected for price sources reporting the quote timestamp.
+    Staleness {
+        ratio_timestamp: DateTime<Utc>,
+        age_ms: u64,
+    },
+}
+
+impl fmt::Display for GuardrailViolation {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Change {
+                previous_ratio,
+                new_ratio,
+                change_percentage,
+                awaiting_confirmation,
+            } => {
+                write!(
+                    formatter,
+                    "ratio changed by {change_percentage:.2}% (from {previous_ratio} to {new_ratio})"
+                )?;
+                if *awaiting_confirmation {
+                    formatter.write_str("; awaiting confirmation")?;
+                }
+                Ok(())
+            }
+            Self::Staleness {
+                ratio_timestamp,
+                age_ms,
+            } => write!(
+                formatter,
+                "ratio is based on a stale quote from {ratio_timestamp} ({age_ms}ms old)"
+            ),
+        }
+    }
+}
+
+impl GuardrailViolation {
+    pub(crate) fn kind(&self) -> GuardrailViolationKind {
+        match self {
+            Self::Change { .. } => GuardrailViolationKind::Change,
+            Self::Staleness { .. } => GuardrailViolationKind::Staleness,
+        }
+    }
+
+    /// Returns `true` if this violation may be resolved by subsequent fetched ratios, i.e., it shouldn't trip
+    /// the circuit breaker.
+    pub(crate) fn is_awaiting_confirmation(&self) -> bool {
+        matches!(
+            self,
+            Self::Change {
+                awaiting_confirmation: true,
+                ..
+            }
+        )
+    }
+}
+
+/// Change exceeding the limit that is being confirmed by fetched ratios.
+#[derive(Debug, Clone, Copy)]
+struct UnconfirmedChange {
+    /// First fetched ratio exceeding the limit.
+    ratio: BaseTokenAPIRatio,
+    /// Number of subsequent fetched ratios within the limit from `ratio`.
+    confirmations: u32,
+}
+
+/// Limits on fetched base token ratios.
+#[derive(Debug, Default)]
+pub(crate) struct RatioGuardrails {
+    max_change_percentage: Option<u32>,
+    /// Number of fetched ratios required to confirm a change exceeding `max_change_percentage`.
+    /// If 0, such changes are never accepted.
+    change_confirmations: u32,
+    max_staleness: Option<Duration>,
+    unconfirmed_change: Option<UnconfirmedChange>,
+}
+
+impl RatioGuardrails {
+    pub(crate) fn new(config: &BaseTokenAdjusterConfig) -> Self {
+        Self {
+            max_change_percentage: config.max_ratio_change_percentage,
+            change_confirmations: config.ratio_change_confirmations,
+            max_staleness: config.max_ratio_staleness(),
+            unconfirmed_change: None,
+        }
+    }
+
+    /// Checks a `new_ratio` against the limits. `previous_ratio` is the latest persisted ratio, if any.
+    ///
+    /// A ratio changing too much compared to `previous_ratio` is still accepted if the change is confirmed by
+    /// the configured number of consecutive fetched ratios, each within the limit from the first ratio exceeding it.
+    /// Otherwise, a single genuine market move exceeding the limit would block all subsequent ratios.
+    /// A change violation breaking the confirmation sequence starts a new one and is not awaiting confirmation.
+    pub(crate) fn check(
+        &mut self,
+        previous_ratio: Option<&BaseTokenRatio>,
+        new_ratio: &BaseTokenAPIRatio,
+        now: DateTime<Utc>,
+    ) -> Result<(), GuardrailViolation> {
+        let unconfirmed_change = self.unconfirmed_change.take();
+        if let Some(max_staleness) = self.max_staleness {
+            // Quotes with a timestamp in the future are not considered stale.
+            let age = (now - new_ratio.ratio_timestamp)
+                .to_std()
+                .unwrap_or_default();
+            if age > max_staleness {
+                return Err(GuardrailViolation::Staleness {
+                    ratio_timestamp: new_ratio.ratio_timestamp,
+                    age_ms: age.as_millis() as u64,
+                });
+            }
+        }
+
+        let (Some(max_change_percentage), Some(previous_ratio)) =
+            (self.max_change_percentage, previous_ratio)
+        else {
+            return Ok(());
+        };
+        let max_change_percentage = f64::from(max_change_percentage);
+        let previous_value = ratio_to_f64(previous_ratio.numerator, previous_ratio.denominator);
+        let new_value = ratio_to_f64(new_ratio.numerator, new_ratio.denominator);
+        let change_percentage = change_percentage(previous_value, new_value);
+        if change_percentage <= max_change_percentage {
+            return Ok(());
+        }
+
+        let confirmed_change = unconfirmed_change.filter(|change| {
+            let unconfirmed_value = ratio_to_f64(change.ratio.numerator, change.ratio.denominator);
+            change_percentage(unconfirmed_value, new_value) <= max_change_percentage
+        });
+        let awaiting_confirmation = if let Some(mut change) = confirmed_change {
+            change.confirmations += 1;
+            if change.confirmations >= self.change_confirmations {
+                return Ok(());
+            }
+            self.unconfirmed_change = Some(change);
+            true
+        } else {
+            if self.change_confirmations > 0 {
+                self.unconfirmed_change = Some(UnconfirmedChange {
+                    ratio: *new_ratio,
+                    confirmations: 0,
+                });
+            }
+            // The change is awaiting confirmation only if it doesn't break a previous confirmation sequence.
+            self.change_confirmations > 0 && unconfirmed_change.is_none()
+        };
+        Err(GuardrailViolation::Change {
+            previous_ratio: previous_value,
+            new_ratio: new_value,
+            change_percentage,
+            awaiting_confirmation,
+        })
+    }
+}
+
+fn ratio_to_f64(numerator: NonZeroU64, denominator: NonZeroU64) -> f64 {
+    numerator.get() as f64 / denominator.get() as f64
+}
+
+fn change_percentage(previous_value: f64, new_value: f64) -> f64 {
+    (new_value - previous_value).abs() / previous_value * 100.0
+}
+
+/// Circuit breaker tripping once a fetched base token ratio violates the configured guardrails.
+/// Can be obtained from [`BaseTokenRatioPersister`](crate::BaseTokenRatioPersister).
+#[derive(Debug)]
+pub struct BaseTokenRatioGuardrailChecker {
+    pub(crate) violation_receiver: watch::Receiver<Option<GuardrailViolation>>,
+}
+
+#[async_trait::async_trait]
+impl CircuitBreaker for BaseTokenRatioGuardrailChecker {
+    fn name(&self) -> &'static str {
+        "base_token_ratio_guardrail"
+    }
+
+    async fn check(&self) -> Result<(), CircuitBreakerError> {
+        if let Some(violation) = &*self.violation_receiver.borrow() {
+            return Err(CircuitBreakerError::BaseTokenRatioGuardrail(
+                violation.to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn api_ratio(numerator: u64, ratio_timestamp: DateTime<Utc>) -> BaseTokenAPIRatio {
+        BaseTokenAPIRatio {
+            numerator: NonZeroU64::new(numerator).unwrap(),
+            denominator: NonZeroU64::new(1).unwrap(),
+            ratio_timestamp,
+        }
+    }
+
+    fn persisted_ratio(numerator: u64) -> BaseTokenRatio {
+        BaseTokenRatio {
+            id: 1,
+            ratio_timestamp: Utc::now(),
+            numerator: NonZeroU64::new(numerator).unwrap(),
+            denominator: NonZeroU64::new(1).unwrap(),
+            used_in_l1: false,
+        }
+    }
+
+    fn change_guardrails(change_confirmations: u32) -> RatioGuardrails {
+        RatioGuardrails {
+            max_change_percentage: Some(10),
+            change_confirmations,
+            ..RatioGuardrails::default()
+        }
+    }
+
+    fn assert_change_violation(
+        result: Result<(), GuardrailViolation>,
+        expected_awaiting_confirmation: bool,
+    ) {
+        let violation = result.unwrap_err();
+        assert_eq!(violation.kind(), GuardrailViolationKind::Change);
+        assert_eq!(
+            violation.is_awaiting_confirmation(),
+            expected_awaiting_confirmation,
+            "{violation}"
+        );
+    }
+
+    #[test]
+    fn checking_ratio_change() {
+        let now = Utc::now();
+        let previous_ratio = persisted_ratio(100);
+
+        change_guardrails(1)
+            .check(None, &api_ratio(1_000, now), now)
+            .unwrap();
+        for numerator in [90, 100, 110] {
+            change_guardrails(1)
+                .check(Some(&previous_ratio), &api_ratio(numerator, now), now)
+                .unwrap();
+        }
+        for numerator in [89, 111, 1_000] {
+            let result =
+                change_guardrails(1).check(Some(&previous_ratio), &api_ratio(numerator, now), now);
+            assert_change_violation(result, true);
+        }
+
+        // Without limits, any change is allowed.
+        RatioGuardrails::default()
+            .check(Some(&previous_ratio), &api_ratio(1_000, now), now)
+            .unwrap();
+    }
+
+    #[test]
+    fn confirming_ratio_change() {
+        let now = Utc::now();
+        let previous_ratio = persisted_ratio(100);
+
+        // The change is confirmed by the next fetched ratio.
+        for numerator in [200, 181, 219] {
+            let mut guardrails = change_guardrails(1);
+            let result = guardrails.check(Some(&previous_ratio), &api_ratio(200, now), now);
+            assert_change_violation(result, true);
+            guardrails
+                .check(Some(&previous_ratio), &api_ratio(numerator, now), now)
+                .unwrap();
+        }
+        // The change is not confirmed; the unconfirmed ratio starts a new confirmation sequence.
+        for numerator in [150, 300] {
+            let mut guardrails = change_guardrails(1);
+            let result = guardrails.check(Some(&previous_ratio), &api_ratio(200, now), now);
+            assert_change_violation(result, true);
+            let result = guardrails.check(Some(&previous_ratio), &api_ratio(numerator, now), now);
+            assert_change_violation(result, false);
+            guardrails
+                .check(Some(&previous_ratio), &api_ratio(numerator, now), now)
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn confirming_ratio_change_with_multiple_confirmations() {
+        let now = Utc::now();
+        let previous_ratio = persisted_ratio(100);
+        let mut guardrails = change_guardrails(3);
+
+        for numerator in [200, 190, 210] {
+            let result = guardrails.check(Some(&previous_ratio), &api_ratio(numerator, now), now);
+            assert_change_violation(result, true);
+        }
+        guardrails
+            .check(Some(&previous_ratio), &api_ratio(200, now), now)
+            .unwrap();
+
+        // A ratio within the limit resets confirmations.
+        for numerator in [200, 190, 105, 210, 200] {
+            let result = guardrails.check(Some(&previous_ratio), &api_ratio(numerator, now), now);
+            if numerator == 105 {
+                result.unwrap();
+            } else {
+                assert_change_violation(result, true);
+            }
+        }
+    }
+
+    #[test]
+    fn ratio_change_without_confirmations() {
+        let now = Utc::now();
+        let previous_ratio = persisted_ratio(100);
+        let mut guardrails = change_guardrails(0);
+
+        for _ in 0..10 {
+            let result = guardrails.check(Some(&previous_ratio), &api_ratio(200, now), now);
+            assert_change_violation(result, false);
+        }
+    }
+
+    #[test]
+    fn checking_ratio_staleness() {
+        let mut guardrails = RatioGuardrails {
+            max_staleness: Some(Duration::from_secs(60)),
+            ..RatioGuardrails::default()
+        };
+        let now = Utc::now();
+
+        guardrails.check(None, &api_ratio(1, now), now).unwrap();
+        let recent_timestamp = now - chrono::Duration::seconds(30);
+        guardrails
+            .check(None, &api_ratio(1, recent_timestamp), now)
+            .unwrap();
+        let future_timestamp = now + chrono::Duration::seconds(30);
+        guardrails
+            .check(None, &api_ratio(1, future_timestamp), now)
+            .unwrap();
+
+        let stale_timestamp = now - chrono::Duration::seconds(61);
+        let violation = guardrails
+            .check(None, &api_ratio(1, stale_timestamp), now)
+            .unwrap_err();
+        assert_eq!(
+            violation,
+            GuardrailViolation::Staleness {
+                ratio_timestamp: stale_timestamp,
+                age_ms: 61_000,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn guardrail_circuit_breaker() {
+        let (violation_sender, violation_receiver) = watch::channel(None);
+        let checker = BaseTokenRatioGuardrailChecker { violation_receiver };
+        checker.check().await.unwrap();
+
+        violation_sender.send_replace(Some(GuardrailViolation::Change {
+            previous_ratio: 1.0,
+            new_ratio: 2.0,
+            change_percentage: 100.0,
+            awaiting_confirmation: false,
+        }));
+        let err = checker.check().await.unwrap_err();
+        assert!(
+            matches!(&err, CircuitBreakerError::BaseTokenRatioGuardrail(msg) if msg.contains("changed by 100.00%")),
+            "{err}"
+        );
+    }
+}
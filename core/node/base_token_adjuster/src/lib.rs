@@ -2,9 +2,11 @@ pub use self::{
     base_token_l1_behaviour::{BaseTokenL1Behaviour, UpdateOnL1Params},
     base_token_ratio_persister::BaseTokenRatioPersister,
     base_token_ratio_provider::{DBBaseTokenRatioProvider, NoOpRatioProvider},
+    guardrails::BaseTokenRatioGuardrailChecker,
 };
 
 mod base_token_l1_behaviour;
 mod base_token_ratio_persister;
 mod base_token_ratio_provider;
+mod guardrails;
 mod metrics;
@@ -1,6 +1,6 @@
 use std::time::Duration;
 
-use vise::{Buckets, EncodeLabelSet, EncodeLabelValue, Family, Gauge, Histogram, Metrics};
+use vise::{Buckets, Counter, EncodeLabelSet, EncodeLabelValue, Family, Gauge, Histogram, Metrics};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelSet, EncodeLabelValue)]
 #[metrics(label = "operation_result", rename_all = "snake_case")]
@@ -14,6 +14,13 @@ pub(crate) struct OperationResultLabels {
     pub result: OperationResult,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelSet, EncodeLabelValue)]
+#[metrics(label = "kind", rename_all = "snake_case")]
+pub(crate) enum GuardrailViolationKind {
+    Change,
+    Staleness,
+}
+
 #[derive(Debug, Metrics)]
 #[metrics(prefix = "base_token_adjuster")]
 pub(crate) struct BaseTokenAdjusterMetrics {
@@ -23,6 +30,8 @@ pub(crate) struct BaseTokenAdjusterMetrics {
     pub external_price_api_latency: Family<OperationResultLabels, Histogram<Duration>>,
     #[metrics(buckets = Buckets::LATENCIES)]
     pub l1_update_latency: Family<OperationResultLabels, Histogram<Duration>>,
+    /// Number of fetched ratios rejected because of violating guardrails.
+    pub guardrail_violations: Family<GuardrailViolationKind, Counter>,
 }
 
 #[vise::register]
@@ -1,4 +1,9 @@
-use std::{path::Path, sync::Arc, time::Duration};
+use std::{
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
 
 use anyhow::Context as _;
 use serde::Serialize;
@@ -9,10 +14,10 @@ use zksync_dal::{ConnectionPool, Core, CoreDal};
 // Public re-export to simplify the API use.
 pub use zksync_eth_client as eth_client;
 use zksync_eth_client::{BoundEthInterface, CallFunctionArgs, EthInterface, Options};
-use zksync_merkle_tree::domain::ZkSyncTree;
-use zksync_object_store::{ObjectStore, ObjectStoreError};
-use zksync_state::RocksdbStorage;
-use zksync_storage::RocksDB;
+use zksync_merkle_tree::domain::{ZkSyncTree, ZkSyncTreeReader};
+use zksync_object_store::{Bucket, ObjectStore, ObjectStoreError};
+use zksync_state::{RocksdbStorage, RocksdbStorageBuilder, StateKeeperColumnFamily};
+use zksync_storage::{RocksDB, RocksDBOptions};
 use zksync_types::{
     aggregated_operations::AggregatedActionType,
     ethabi::Token,
@@ -21,7 +26,7 @@ use zksync_types::{
         SnapshotStorageLogsStorageKey,
     },
     web3::BlockNumber,
-    Address, L1BatchNumber, L2ChainId, H160, H256, U256,
+    Address, L1BatchNumber, L2BlockNumber, L2ChainId, H160, H256, U256,
 };
 
 #[cfg(test)]
@@ -61,6 +66,26 @@ pub enum NodeRole {
     External,
 }
 
+/// Step of a rollback persisted in the revert journal.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RevertStep {
+    MerkleTree,
+    StorageCaches,
+    SnapshotFiles,
+    Postgres,
+}
+
+impl RevertStep {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::MerkleTree => "merkle_tree",
+            Self::StorageCaches => "storage_caches",
+            Self::SnapshotFiles => "snapshot_files",
+            Self::Postgres => "postgres",
+        }
+    }
+}
+
 /// This struct is used to roll back node state and revert batches committed (but generally not finalized) on L1.
 ///
 /// Reversion is a rare event of manual intervention, when the node operator
@@ -139,8 +164,28 @@ impl BlockReverter {
         self
     }
 
-    /// Rolls back previously enabled DBs (Postgres + RocksDB) and the snapshot object store to a previous state.
-    pub async fn roll_back(&self, last_l1_batch_to_keep: L1BatchNumber) -> anyhow::Result<()> {
+    /// Returns rollback steps enabled for this reverter.
+    fn enabled_steps(&self) -> Vec<RevertStep> {
+        let mut steps = vec![];
+        if self.merkle_tree_path.is_some() {
+            steps.push(RevertStep::MerkleTree);
+        }
+        if !self.storage_cache_paths.is_empty() {
+            steps.push(RevertStep::StorageCaches);
+        }
+        if self.should_roll_back_postgres {
+            if self.snapshots_object_store.is_some() {
+                steps.push(RevertStep::SnapshotFiles);
+            }
+            steps.push(RevertStep::Postgres);
+        }
+        steps
+    }
+
+    async fn check_executed_batches(
+        &self,
+        last_l1_batch_to_keep: L1BatchNumber,
+    ) -> anyhow::Result<()> {
         if !self.allow_rolling_back_executed_batches {
             let mut storage = self.connection_pool.connection().await?;
             let last_executed_l1_batch = storage
@@ -152,72 +197,433 @@ impl BlockReverter {
                 "Attempt to roll back already executed L1 batches; the last executed batch is: {last_executed_l1_batch:?}"
             );
         }
+        Ok(())
+    }
+
+    /// Returns the target L1 batch of a revert that was started, but not finished (e.g., because the reverter
+    /// has crashed midway). Such a revert should be completed by calling [`Self::roll_back()`] with the returned
+    /// L1 batch number.
+    pub async fn unfinished_revert(&self) -> anyhow::Result<Option<L1BatchNumber>> {
+        let mut storage = self.connection_pool.connection().await?;
+        let revert = storage.block_reverts_dal().get_unfinished_revert().await?;
+        Ok(revert.map(|revert| revert.l1_batch_number))
+    }
+
+    /// Rolls back previously enabled DBs (Postgres + RocksDB) and the snapshot object store to a previous state.
+    ///
+    /// Progress of the rollback is persisted in Postgres. If a previous rollback was interrupted, it is resumed
+    /// (i.e., steps completed by the previous rollback are skipped), provided that it has the same target L1 batch.
+    /// The resumed rollback must have the same components enabled as the interrupted one.
+    /// A previous rollback to an earlier L1 batch must be completed before rolling back to a later batch.
+    pub async fn roll_back(&self, last_l1_batch_to_keep: L1BatchNumber) -> anyhow::Result<()> {
+        self.check_executed_batches(last_l1_batch_to_keep).await?;
+
+        let enabled_steps: Vec<_> = self
+            .enabled_steps()
+            .into_iter()
+            .map(RevertStep::as_str)
+            .collect();
+        let mut storage = self.connection_pool.connection().await?;
+        let unfinished_revert = storage.block_reverts_dal().get_unfinished_revert().await?;
+        let completed_steps = match unfinished_revert {
+            Some(revert) if revert.l1_batch_number == last_l1_batch_to_keep => {
+                anyhow::ensure!(
+                    revert.enabled_steps == enabled_steps,
+                    "Unfinished rollback to L1 batch #{last_l1_batch_to_keep} was started with steps {:?} enabled, \
+                     while the current rollback has steps {enabled_steps:?} enabled; resume it with the same components enabled",
+                    revert.enabled_steps
+                );
+                tracing::info!(
+                    "Resuming unfinished rollback to L1 batch #{last_l1_batch_to_keep}; completed steps: {:?}",
+                    revert.completed_steps
+                );
+                revert.completed_steps
+            }
+            Some(revert) if revert.l1_batch_number < last_l1_batch_to_keep => {
+                anyhow::bail!(
+                    "There is an unfinished rollback to L1 batch #{}, which is earlier than the requested L1 batch #{last_l1_batch_to_keep}; \
+                     complete it first",
+                    revert.l1_batch_number
+                );
+            }
+            unfinished_revert => {
+                if let Some(revert) = unfinished_revert {
+                    tracing::info!(
+                        "Discarding unfinished rollback to L1 batch #{} since it's superseded by rollback to L1 batch #{last_l1_batch_to_keep}",
+                        revert.l1_batch_number
+                    );
+                }
+                storage
+                    .block_reverts_dal()
+                    .start_revert(last_l1_batch_to_keep, &enabled_steps)
+                    .await?;
+                vec![]
+            }
+        };
+        drop(storage);
+        let is_completed = |step: RevertStep| {
+            let is_completed = completed_steps.iter().any(|name| name == step.as_str());
+            if is_completed {
+                tracing::info!(
+                    "Rollback step `{}` is already completed; skipping",
+                    step.as_str()
+                );
+            }
+            is_completed
+        };
 
         // Tree needs to be rolled back first to keep the state recoverable
-        self.roll_back_rocksdb_instances(last_l1_batch_to_keep)
+        if let Some(merkle_tree_path) = &self.merkle_tree_path {
+            if !is_completed(RevertStep::MerkleTree) {
+                self.roll_back_merkle_tree(last_l1_batch_to_keep, merkle_tree_path)
+                    .await?;
+                self.mark_step_completed(RevertStep::MerkleTree).await?;
+            }
+        }
+        if !self.storage_cache_paths.is_empty() && !is_completed(RevertStep::StorageCaches) {
+            self.roll_back_storage_caches(last_l1_batch_to_keep).await?;
+            self.mark_step_completed(RevertStep::StorageCaches).await?;
+        }
+
+        if self.should_roll_back_postgres {
+            // Snapshot files are removed before snapshot metadata, so that the files can be found again if removal fails.
+            if let Some(object_store) = self.snapshots_object_store.as_deref() {
+                if !is_completed(RevertStep::SnapshotFiles) {
+                    let snapshots = self
+                        .connection_pool
+                        .connection()
+                        .await?
+                        .snapshots_dal()
+                        .get_snapshots_after(last_l1_batch_to_keep)
+                        .await?;
+                    Self::delete_snapshot_files(object_store, &snapshots).await?;
+                    self.mark_step_completed(RevertStep::SnapshotFiles).await?;
+                }
+            }
+
+            if !is_completed(RevertStep::Postgres) {
+                let deleted_snapshots = self.roll_back_postgres(last_l1_batch_to_keep).await?;
+                if self.snapshots_object_store.is_none() && !deleted_snapshots.is_empty() {
+                    tracing::info!(
+                        "Did not remove snapshot files in object store since it was not provided; \
+                         metadata for deleted snapshots: {deleted_snapshots:?}"
+                    );
+                }
+            }
+        }
+
+        self.finish_revert(last_l1_batch_to_keep, &enabled_steps)
+            .await
+    }
+
+    /// Marks the rollback as finished after checking that it's the same rollback and that all its steps are completed.
+    async fn finish_revert(
+        &self,
+        last_l1_batch_to_keep: L1BatchNumber,
+        enabled_steps: &[&str],
+    ) -> anyhow::Result<()> {
+        let mut storage = self.connection_pool.connection().await?;
+        let revert = storage
+            .block_reverts_dal()
+            .get_unfinished_revert()
+            .await?
+            .context("rollback was finished or discarded concurrently")?;
+        anyhow::ensure!(
+            revert.l1_batch_number == last_l1_batch_to_keep
+                && revert.enabled_steps == enabled_steps,
+            "rollback was replaced concurrently: {revert:?}"
+        );
+        let pending_steps: Vec<_> = enabled_steps
+            .iter()
+            .filter(|&&step| !revert.completed_steps.iter().any(|name| name == step))
+            .collect();
+        anyhow::ensure!(
+            pending_steps.is_empty(),
+            "Cannot finish rollback to L1 batch #{last_l1_batch_to_keep}: steps {pending_steps:?} are not completed"
+        );
+
+        storage.block_reverts_dal().finish_revert().await?;
+        Ok(())
+    }
+
+    async fn mark_step_completed(&self, step: RevertStep) -> anyhow::Result<()> {
+        self.connection_pool
+            .connection()
+            .await?
+            .block_reverts_dal()
+            .mark_revert_step_completed(step.as_str())
             .await?;
-        let deleted_snapshots = if self.should_roll_back_postgres {
-            self.roll_back_postgres(last_l1_batch_to_keep).await?
+        Ok(())
+    }
+
+    /// Computes changes that [`Self::roll_back()`] would make with the same configuration without changing anything.
+    ///
+    /// All enabled components (Postgres, the Merkle tree, storage caches and the snapshot object store) are checked
+    /// to be reachable; an error is returned if any of them is not, or if [`Self::roll_back()`] would fail
+    /// its preliminary checks (e.g., if executed L1 batches would be rolled back).
+    pub async fn dry_run(
+        &self,
+        last_l1_batch_to_keep: L1BatchNumber,
+    ) -> anyhow::Result<RollbackReport> {
+        self.check_executed_batches(last_l1_batch_to_keep).await?;
+
+        let merkle_tree = if let Some(merkle_tree_path) = &self.merkle_tree_path {
+            Some(
+                self.merkle_tree_report(last_l1_batch_to_keep, merkle_tree_path)
+                    .await?,
+            )
         } else {
-            vec![]
+            None
         };
 
-        if let Some(object_store) = self.snapshots_object_store.as_deref() {
-            Self::delete_snapshot_files(object_store, &deleted_snapshots).await?;
-        } else if !deleted_snapshots.is_empty() {
+        let mut storage_caches = Vec::with_capacity(self.storage_cache_paths.len());
+        for storage_cache_path in &self.storage_cache_paths {
+            let sk_cache_exists = fs::try_exists(storage_cache_path).await.with_context(|| {
+                format!("cannot check whether storage cache path `{storage_cache_path}` exists")
+            })?;
+            anyhow::ensure!(
+                sk_cache_exists,
+                "Path with storage cache DB doesn't exist at `{storage_cache_path}`"
+            );
             tracing::info!(
-                "Did not remove snapshot files in object store since it was not provided; \
-                 metadata for deleted snapshots: {deleted_snapshots:?}"
+                "Opening DB with storage cache at `{storage_cache_path}` in read-only mode"
             );
+            let path = PathBuf::from(storage_cache_path);
+            let db = tokio::task::spawn_blocking(move || {
+                // The cache may be concurrently used by the state keeper, and the dry run must not modify it in any case.
+                let options = RocksDBOptions {
+                    read_only: true,
+                    ..RocksDBOptions::default()
+                };
+                RocksDB::<StateKeeperColumnFamily>::with_options(&path, options)
+                    .context("failed initializing storage cache")
+            })
+            .await
+            .context("panicked initializing storage cache")??;
+            let sk_cache = RocksdbStorageBuilder::from_rocksdb(db);
+            let l1_batch_number = sk_cache.l1_batch_number().await;
+            storage_caches.push(StorageCacheRollbackReport {
+                path: storage_cache_path.clone(),
+                l1_batch_number,
+                is_rolled_back: l1_batch_number > Some(last_l1_batch_to_keep + 1),
+            });
         }
 
-        Ok(())
+        let postgres = if self.should_roll_back_postgres {
+            Some(self.postgres_report(last_l1_batch_to_keep).await?)
+        } else {
+            None
+        };
+
+        let snapshot_files = if let Some(object_store) = self.snapshots_object_store.as_deref() {
+            Self::check_object_store(object_store).await?;
+            let removed_snapshots = postgres
+                .as_ref()
+                .map_or(&[][..], |report| report.removed_snapshots.as_slice());
+            let files = removed_snapshots.iter().flat_map(|snapshot| {
+                let storage_logs_filepaths = snapshot.storage_logs_filepaths.iter().flatten();
+                [&snapshot.factory_deps_filepath]
+                    .into_iter()
+                    .chain(storage_logs_filepaths)
+                    .cloned()
+            });
+            Some(files.collect())
+        } else {
+            None
+        };
+
+        Ok(RollbackReport {
+            last_l1_batch_to_keep,
+            postgres,
+            merkle_tree,
+            storage_caches,
+            snapshot_files,
+        })
     }
 
-    async fn roll_back_rocksdb_instances(
+    async fn merkle_tree_report(
         &self,
         last_l1_batch_to_keep: L1BatchNumber,
-    ) -> anyhow::Result<()> {
-        if let Some(merkle_tree_path) = &self.merkle_tree_path {
-            let storage_root_hash = self
-                .connection_pool
-                .connection()
-                .await?
-                .blocks_dal()
-                .get_l1_batch_state_root(last_l1_batch_to_keep)
-                .await?
-                .context("no state root hash for target L1 batch")?;
-
-            let merkle_tree_path = Path::new(merkle_tree_path);
-            let merkle_tree_exists = fs::try_exists(merkle_tree_path).await.with_context(|| {
-                format!(
-                    "cannot check whether Merkle tree path `{}` exists",
-                    merkle_tree_path.display()
-                )
-            })?;
-            if merkle_tree_exists {
-                tracing::info!(
-                    "Rolling back Merkle tree at `{}`",
-                    merkle_tree_path.display()
-                );
-                let merkle_tree_path = merkle_tree_path.to_path_buf();
-                tokio::task::spawn_blocking(move || {
-                    Self::roll_back_tree_blocking(
-                        last_l1_batch_to_keep,
-                        &merkle_tree_path,
-                        storage_root_hash,
-                    )
-                })
-                .await
-                .context("rolling back Merkle tree panicked")??;
-            } else {
-                tracing::info!(
-                    "Merkle tree not found at `{}`; skipping",
-                    merkle_tree_path.display()
+        merkle_tree_path: &str,
+    ) -> anyhow::Result<MerkleTreeRollbackReport> {
+        let storage_root_hash = self
+            .connection_pool
+            .connection()
+            .await?
+            .blocks_dal()
+            .get_l1_batch_state_root(last_l1_batch_to_keep)
+            .await?
+            .context("no state root hash for target L1 batch")?;
+
+        let path = Path::new(merkle_tree_path);
+        let merkle_tree_exists = fs::try_exists(path).await.with_context(|| {
+            format!(
+                "cannot check whether Merkle tree path `{}` exists",
+                path.display()
+            )
+        })?;
+        if !merkle_tree_exists {
+            return Ok(MerkleTreeRollbackReport {
+                path: merkle_tree_path.to_owned(),
+                next_l1_batch_number: None,
+                removed_versions: None,
+            });
+        }
+
+        tracing::info!(
+            "Opening Merkle tree at `{}` in read-only mode",
+            path.display()
+        );
+        let path = path.to_path_buf();
+        let next_l1_batch_number = tokio::task::spawn_blocking(move || {
+            // The tree may be concurrently used by another process, and the dry run must not modify it in any case.
+            let options = RocksDBOptions {
+                read_only: true,
+                ..RocksDBOptions::default()
+            };
+            let db = RocksDB::with_options(&path, options)
+                .context("failed initializing RocksDB for Merkle tree")?;
+            let tree =
+                ZkSyncTreeReader::new(db.into()).context("failed initializing Merkle tree")?;
+            let next_l1_batch_number = tree.next_l1_batch_number();
+            if next_l1_batch_number > last_l1_batch_to_keep + 1 {
+                let (tree_root_hash, _) = tree
+                    .root_info(last_l1_batch_to_keep)
+                    .context("Merkle tree doesn't contain target L1 batch")?;
+                anyhow::ensure!(
+                    tree_root_hash == storage_root_hash,
+                    "Mismatch between the tree root hash {tree_root_hash:?} and storage root hash {storage_root_hash:?} \
+                     for target L1 batch"
                 );
             }
+            anyhow::Ok(next_l1_batch_number)
+        })
+        .await
+        .context("opening Merkle tree panicked")??;
+
+        let first_removed_version = u64::from(last_l1_batch_to_keep.0) + 1;
+        let next_version = u64::from(next_l1_batch_number.0);
+        Ok(MerkleTreeRollbackReport {
+            path: merkle_tree_path.to_owned(),
+            next_l1_batch_number: Some(next_l1_batch_number),
+            removed_versions: (next_version > first_removed_version)
+                .then(|| first_removed_version..=next_version - 1),
+        })
+    }
+
+    async fn postgres_report(
+        &self,
+        last_l1_batch_to_keep: L1BatchNumber,
+    ) -> anyhow::Result<PostgresRollbackReport> {
+        let mut storage = self.connection_pool.connection().await?;
+        let (_, last_l2_block_to_keep) = storage
+            .blocks_dal()
+            .get_l2_block_range_of_l1_batch(last_l1_batch_to_keep)
+            .await?
+            .with_context(|| {
+                format!("L1 batch #{last_l1_batch_to_keep} doesn't contain L2 blocks")
+            })?;
+
+        let sealed_l1_batch = storage.blocks_dal().get_sealed_l1_batch_number().await?;
+        let removed_l1_batches = sealed_l1_batch
+            .filter(|&sealed| sealed > last_l1_batch_to_keep)
+            .map(|sealed| last_l1_batch_to_keep + 1..=sealed);
+        let sealed_l2_block = storage.blocks_dal().get_sealed_l2_block_number().await?;
+        let removed_l2_blocks = sealed_l2_block
+            .filter(|&sealed| sealed > last_l2_block_to_keep)
+            .map(|sealed| last_l2_block_to_keep + 1..=sealed);
+
+        let transactions_returned_to_mempool = storage
+            .transactions_dal()
+            .get_transaction_hashes_after_l2_block(last_l2_block_to_keep)
+            .await?;
+        let removed_eth_txs = storage
+            .eth_sender_dal()
+            .get_eth_tx_ids_after_l1_batch(last_l1_batch_to_keep)
+            .await?;
+        let removed_snapshots = storage
+            .snapshots_dal()
+            .get_snapshots_after(last_l1_batch_to_keep)
+            .await?;
+
+        Ok(PostgresRollbackReport {
+            last_l2_block_to_keep,
+            removed_l1_batches,
+            removed_l2_blocks,
+            transactions_returned_to_mempool,
+            removed_eth_txs,
+            removed_snapshots: removed_snapshots
+                .into_iter()
+                .map(SnapshotRollbackReport::from)
+                .collect(),
+            performs_consensus_hard_fork: self.node_role == NodeRole::Main,
+        })
+    }
+
+    /// Checks that the object store is reachable by requesting a non-existing object.
+    async fn check_object_store(object_store: &dyn ObjectStore) -> anyhow::Result<()> {
+        const PROBE_KEY: &str = "block_reverter_dry_run_probe";
+
+        match object_store
+            .get_raw(Bucket::StorageSnapshot, PROBE_KEY)
+            .await
+        {
+            Ok(_) | Err(ObjectStoreError::KeyNotFound(_)) => Ok(()),
+            Err(err) => {
+                Err(anyhow::Error::new(err).context("snapshot object store is not reachable"))
+            }
         }
+    }
+
+    async fn roll_back_merkle_tree(
+        &self,
+        last_l1_batch_to_keep: L1BatchNumber,
+        merkle_tree_path: &str,
+    ) -> anyhow::Result<()> {
+        let storage_root_hash = self
+            .connection_pool
+            .connection()
+            .await?
+            .blocks_dal()
+            .get_l1_batch_state_root(last_l1_batch_to_keep)
+            .await?
+            .context("no state root hash for target L1 batch")?;
 
+        let merkle_tree_path = Path::new(merkle_tree_path);
+        let merkle_tree_exists = fs::try_exists(merkle_tree_path).await.with_context(|| {
+            format!(
+                "cannot check whether Merkle tree path `{}` exists",
+                merkle_tree_path.display()
+            )
+        })?;
+        if merkle_tree_exists {
+            tracing::info!(
+                "Rolling back Merkle tree at `{}`",
+                merkle_tree_path.display()
+            );
+            let merkle_tree_path = merkle_tree_path.to_path_buf();
+            tokio::task::spawn_blocking(move || {
+                Self::roll_back_tree_blocking(
+                    last_l1_batch_to_keep,
+                    &merkle_tree_path,
+                    storage_root_hash,
+                )
+            })
+            .await
+            .context("rolling back Merkle tree panicked")??;
+        } else {
+            tracing::info!(
+                "Merkle tree not found at `{}`; skipping",
+                merkle_tree_path.display()
+            );
+        }
+        Ok(())
+    }
+
+    async fn roll_back_storage_caches(
+        &self,
+        last_l1_batch_to_keep: L1BatchNumber,
+    ) -> anyhow::Result<()> {
         for storage_cache_path in &self.storage_cache_paths {
             let sk_cache_exists = fs::try_exists(storage_cache_path).await.with_context(|| {
                 format!("cannot check whether storage cache path `{storage_cache_path}` exists")
@@ -375,6 +781,12 @@ impl BlockReverter {
             transaction.consensus_dal().fork().await?;
         }
 
+        // Marking the step as completed atomically with the rollback ensures that the consensus hard fork
+        // is performed exactly once.
+        transaction
+            .block_reverts_dal()
+            .mark_revert_step_completed(RevertStep::Postgres.as_str())
+            .await?;
         transaction.commit().await?;
         Ok(deleted_snapshots)
     }
@@ -622,3 +1034,73 @@ pub struct SuggestedRevertValues {
     pub nonce: u64,
     pub priority_fee: u64,
 }
+
+/// Report on changes that would be made by [`BlockReverter::roll_back()`]. Produced by [`BlockReverter::dry_run()`].
+#[derive(Debug, Serialize)]
+pub struct RollbackReport {
+    pub last_l1_batch_to_keep: L1BatchNumber,
+    /// `None` if rolling back Postgres is disabled.
+    pub postgres: Option<PostgresRollbackReport>,
+    /// `None` if rolling back the Merkle tree is disabled.
+    pub merkle_tree: Option<MerkleTreeRollbackReport>,
+    pub storage_caches: Vec<StorageCacheRollbackReport>,
+    /// Paths to the removed snapshot files. `None` if removing snapshot objects is disabled.
+    pub snapshot_files: Option<Vec<String>>,
+}
+
+/// Changes in Postgres made by a rollback.
+#[derive(Debug, Serialize)]
+pub struct PostgresRollbackReport {
+    pub last_l2_block_to_keep: L2BlockNumber,
+    /// `None` if there are no L1 batches to remove.
+    pub removed_l1_batches: Option<RangeInclusive<L1BatchNumber>>,
+    /// `None` if there are no L2 blocks to remove.
+    pub removed_l2_blocks: Option<RangeInclusive<L2BlockNumber>>,
+    /// Hashes of transactions returned to the mempool, ordered by their execution.
+    pub transactions_returned_to_mempool: Vec<H256>,
+    /// IDs of removed Ethereum transactions (i.e., rows in the `eth_txs` table).
+    pub removed_eth_txs: Vec<u32>,
+    /// Removed snapshot metadata.
+    pub removed_snapshots: Vec<SnapshotRollbackReport>,
+    /// Whether the consensus state is hard-forked, which is the case for the main node.
+    pub performs_consensus_hard_fork: bool,
+}
+
+/// Information about a snapshot removed by a rollback.
+#[derive(Debug, Serialize)]
+pub struct SnapshotRollbackReport {
+    pub l1_batch_number: L1BatchNumber,
+    pub factory_deps_filepath: String,
+    /// Paths to storage log chunks; `None` for chunks that are not produced yet.
+    pub storage_logs_filepaths: Vec<Option<String>>,
+}
+
+impl From<SnapshotMetadata> for SnapshotRollbackReport {
+    fn from(metadata: SnapshotMetadata) -> Self {
+        Self {
+            l1_batch_number: metadata.l1_batch_number,
+            factory_deps_filepath: metadata.factory_deps_filepath,
+            storage_logs_filepaths: metadata.storage_logs_filepaths,
+        }
+    }
+}
+
+/// Changes in the Merkle tree made by a rollback.
+#[derive(Debug, Serialize)]
+pub struct MerkleTreeRollbackReport {
+    pub path: String,
+    /// Next L1 batch to be processed by the tree. `None` if the tree doesn't exist; in this case, it is not rolled back.
+    pub next_l1_batch_number: Option<L1BatchNumber>,
+    /// Removed tree versions (equal to L1 batch numbers). `None` if the tree is not rolled back.
+    pub removed_versions: Option<RangeInclusive<u64>>,
+}
+
+/// Changes in a RocksDB storage cache made by a rollback.
+#[derive(Debug, Serialize)]
+pub struct StorageCacheRollbackReport {
+    pub path: String,
+    /// Next L1 batch to be processed by the cache.
+    pub l1_batch_number: Option<L1BatchNumber>,
+    /// Whether the cache is rolled back. The cache is not rolled back if it's behind the target L1 batch.
+    pub is_rolled_back: bool,
+}
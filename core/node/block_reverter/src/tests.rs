@@ -4,6 +4,7 @@ use std::{collections::HashSet, sync::Mutex};
 
 use assert_matches::assert_matches;
 use async_trait::async_trait;
+use futures::stream::BoxStream;
 use test_casing::test_casing;
 use tokio::sync::watch;
 use zksync_dal::Connection;
@@ -203,6 +204,198 @@ async fn block_reverter_basics(sync_merkle_tree: bool) {
     }
 }
 
+#[tokio::test]
+async fn dry_run_does_not_change_state() {
+    let storage_logs = gen_storage_logs();
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    setup_storage(&mut storage, &storage_logs).await;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let merkle_tree_path = temp_dir.path().join("tree");
+    let l1_batch_hashes = initialize_merkle_tree(&merkle_tree_path, &storage_logs);
+    for (number, hash) in (0..).zip(l1_batch_hashes) {
+        storage
+            .blocks_dal()
+            .set_l1_batch_hash(L1BatchNumber(number), hash)
+            .await
+            .unwrap();
+    }
+    let sk_cache_path = temp_dir.path().join("sk_cache");
+    let sk_cache = RocksdbStorage::builder(&sk_cache_path).await.unwrap();
+    let (_stop_sender, stop_receiver) = watch::channel(false);
+    sk_cache
+        .synchronize(&mut storage, &stop_receiver, None)
+        .await
+        .unwrap();
+    let object_store = MockObjectStore::arc();
+    create_mock_snapshot(&mut storage, &*object_store, L1BatchNumber(7), 0..2).await;
+    storage
+        .eth_sender_dal()
+        .insert_bogus_confirmed_eth_tx(
+            L1BatchNumber(7),
+            AggregatedActionType::Commit,
+            H256::repeat_byte(1),
+            Default::default(),
+        )
+        .await
+        .unwrap();
+
+    let merkle_tree_path = merkle_tree_path.to_str().unwrap().to_owned();
+    let sk_cache_path = sk_cache_path.to_str().unwrap().to_owned();
+    let mut block_reverter = BlockReverter::new(NodeRole::External, pool.clone());
+    block_reverter
+        .enable_rolling_back_postgres()
+        .enable_rolling_back_merkle_tree(merkle_tree_path.clone())
+        .add_rocksdb_storage_path_to_rollback(sk_cache_path.clone())
+        .enable_rolling_back_snapshot_objects(object_store.clone());
+    let report = block_reverter.dry_run(L1BatchNumber(5)).await.unwrap();
+
+    assert_eq!(report.last_l1_batch_to_keep, L1BatchNumber(5));
+    let postgres = report.postgres.unwrap();
+    assert_eq!(postgres.last_l2_block_to_keep, L2BlockNumber(5));
+    assert_eq!(
+        postgres.removed_l1_batches,
+        Some(L1BatchNumber(6)..=L1BatchNumber(9))
+    );
+    assert_eq!(
+        postgres.removed_l2_blocks,
+        Some(L2BlockNumber(6)..=L2BlockNumber(9))
+    );
+    assert!(postgres.transactions_returned_to_mempool.is_empty());
+    assert_eq!(postgres.removed_eth_txs.len(), 1);
+    assert!(!postgres.performs_consensus_hard_fork);
+    assert_eq!(postgres.removed_snapshots.len(), 1);
+    assert_eq!(
+        postgres.removed_snapshots[0].l1_batch_number,
+        L1BatchNumber(7)
+    );
+
+    let merkle_tree = report.merkle_tree.unwrap();
+    assert_eq!(merkle_tree.path, merkle_tree_path);
+    assert_eq!(merkle_tree.next_l1_batch_number, Some(L1BatchNumber(10)));
+    assert_eq!(merkle_tree.removed_versions, Some(6..=9));
+
+    assert_eq!(report.storage_caches.len(), 1);
+    assert_eq!(report.storage_caches[0].path, sk_cache_path);
+    assert_eq!(
+        report.storage_caches[0].l1_batch_number,
+        Some(L1BatchNumber(10))
+    );
+    assert!(report.storage_caches[0].is_rolled_back);
+
+    let snapshot_files = report.snapshot_files.unwrap();
+    assert_eq!(snapshot_files.len(), 3, "{snapshot_files:?}");
+
+    // Check that nothing has changed.
+    let last_l1_batch_number = storage
+        .blocks_dal()
+        .get_sealed_l1_batch_number()
+        .await
+        .unwrap();
+    assert_eq!(last_l1_batch_number, Some(L1BatchNumber(9)));
+    let all_snapshots = storage
+        .snapshots_dal()
+        .get_all_complete_snapshots()
+        .await
+        .unwrap();
+    assert_eq!(all_snapshots.snapshots_l1_batch_numbers, [L1BatchNumber(7)]);
+    object_store
+        .get::<SnapshotFactoryDependencies>(L1BatchNumber(7))
+        .await
+        .unwrap();
+
+    let db = RocksDB::new(Path::new(&merkle_tree_path)).unwrap();
+    let tree = ZkSyncTree::new(db.into()).unwrap();
+    assert_eq!(tree.next_l1_batch_number(), L1BatchNumber(10));
+    let sk_cache = RocksdbStorage::builder(sk_cache_path.as_ref())
+        .await
+        .unwrap();
+    assert_eq!(sk_cache.l1_batch_number().await, Some(L1BatchNumber(10)));
+}
+
+#[tokio::test]
+async fn dry_run_does_not_interfere_with_running_node() {
+    let storage_logs = gen_storage_logs();
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    setup_storage(&mut storage, &storage_logs).await;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let merkle_tree_path = temp_dir.path().join("tree");
+    let l1_batch_hashes = initialize_merkle_tree(&merkle_tree_path, &storage_logs);
+    for (number, hash) in (0..).zip(l1_batch_hashes) {
+        storage
+            .blocks_dal()
+            .set_l1_batch_hash(L1BatchNumber(number), hash)
+            .await
+            .unwrap();
+    }
+    let sk_cache_path = temp_dir.path().join("sk_cache");
+    let sk_cache = RocksdbStorage::builder(&sk_cache_path).await.unwrap();
+    let (_stop_sender, stop_receiver) = watch::channel(false);
+    // Emulate the node holding both DBs open in the read-write mode.
+    let mut sk_cache = sk_cache
+        .synchronize(&mut storage, &stop_receiver, None)
+        .await
+        .unwrap()
+        .expect("sk_cache syncing unexpectedly stopped");
+    let tree_db = RocksDB::new(&merkle_tree_path).unwrap();
+    let tree = ZkSyncTree::new(tree_db.into()).unwrap();
+
+    let mut block_reverter = BlockReverter::new(NodeRole::External, pool.clone());
+    block_reverter
+        .enable_rolling_back_merkle_tree(merkle_tree_path.to_str().unwrap().to_owned())
+        .add_rocksdb_storage_path_to_rollback(sk_cache_path.to_str().unwrap().to_owned());
+    let report = block_reverter.dry_run(L1BatchNumber(5)).await.unwrap();
+    assert_eq!(
+        report.merkle_tree.unwrap().next_l1_batch_number,
+        Some(L1BatchNumber(10))
+    );
+    assert_eq!(
+        report.storage_caches[0].l1_batch_number,
+        Some(L1BatchNumber(10))
+    );
+
+    // Check that the DBs are unchanged.
+    assert_eq!(tree.next_l1_batch_number(), L1BatchNumber(10));
+    assert_eq!(sk_cache.l1_batch_number().await, Some(L1BatchNumber(10)));
+    for log in &storage_logs {
+        assert_eq!(sk_cache.read_value(&log.key), log.value);
+    }
+}
+
+#[tokio::test]
+async fn dry_run_checks_executed_batches() {
+    let storage_logs = gen_storage_logs();
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    setup_storage(&mut storage, &storage_logs).await;
+    storage
+        .eth_sender_dal()
+        .insert_bogus_confirmed_eth_tx(
+            L1BatchNumber(7),
+            AggregatedActionType::Execute,
+            H256::repeat_byte(1),
+            Default::default(),
+        )
+        .await
+        .unwrap();
+
+    let mut block_reverter = BlockReverter::new(NodeRole::External, pool.clone());
+    block_reverter.enable_rolling_back_postgres();
+    let err = block_reverter
+        .dry_run(L1BatchNumber(5))
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("already executed"), "{err}");
+
+    block_reverter.allow_rolling_back_executed_batches();
+    let report = block_reverter.dry_run(L1BatchNumber(5)).await.unwrap();
+    assert_eq!(report.postgres.unwrap().removed_eth_txs.len(), 1);
+}
+
 async fn create_mock_snapshot(
     storage: &mut Connection<'_, Core>,
     object_store: &dyn ObjectStore,
@@ -231,6 +424,7 @@ async fn create_mock_snapshot(
             l1_batch_number,
             storage_logs_chunk_count,
             &factory_deps_key,
+            None,
         )
         .await
         .unwrap();
@@ -389,6 +583,14 @@ impl ObjectStore for ErroneousStore {
         })
     }
 
+    fn list_raw<'a>(
+        &'a self,
+        _bucket: Bucket,
+        _prefix: &'a str,
+    ) -> BoxStream<'a, Result<String, ObjectStoreError>> {
+        unreachable!("not called by reverter")
+    }
+
     fn storage_prefix_raw(&self, bucket: Bucket) -> String {
         bucket.to_string()
     }
@@ -419,19 +621,122 @@ async fn reverting_snapshot_propagates_fatal_errors() {
         }
     }));
 
-    // Check that snapshot metadata has been removed (it's not atomic with snapshot removal).
+    // Check that snapshot metadata has been retained, so that removing snapshot files can be retried.
     let all_snapshots = storage
         .snapshots_dal()
         .get_all_complete_snapshots()
         .await
         .unwrap();
-    assert_eq!(all_snapshots.snapshots_l1_batch_numbers, []);
+    assert_eq!(all_snapshots.snapshots_l1_batch_numbers, [L1BatchNumber(7)]);
 
     // Check that removal was called for all objects (i.e., the reverter doesn't bail early).
     let retained_object_keys = object_store.object_keys.lock().unwrap();
     assert!(retained_object_keys.is_empty(), "{retained_object_keys:?}");
 }
 
+#[tokio::test]
+async fn interrupted_rollback_is_resumed() {
+    let storage_logs = gen_storage_logs();
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    setup_storage(&mut storage, &storage_logs).await;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let sk_cache_path = temp_dir.path().join("sk_cache");
+    let sk_cache = RocksdbStorage::builder(&sk_cache_path).await.unwrap();
+    let (_stop_sender, stop_receiver) = watch::channel(false);
+    sk_cache
+        .synchronize(&mut storage, &stop_receiver, None)
+        .await
+        .unwrap();
+    let sk_cache_path = sk_cache_path.to_str().unwrap().to_owned();
+
+    let erroneous_store = Arc::new(ErroneousStore::default());
+    create_mock_snapshot(&mut storage, &*erroneous_store, L1BatchNumber(7), 0..5).await;
+
+    let mut block_reverter = BlockReverter::new(NodeRole::External, pool.clone());
+    block_reverter
+        .enable_rolling_back_postgres()
+        .add_rocksdb_storage_path_to_rollback(sk_cache_path.clone())
+        .enable_rolling_back_snapshot_objects(erroneous_store);
+    block_reverter
+        .roll_back(L1BatchNumber(5))
+        .await
+        .unwrap_err();
+
+    let unfinished_revert = block_reverter.unfinished_revert().await.unwrap();
+    assert_eq!(unfinished_revert, Some(L1BatchNumber(5)));
+    let journal_entry = storage
+        .block_reverts_dal()
+        .get_unfinished_revert()
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        journal_entry.enabled_steps,
+        ["storage_caches", "snapshot_files", "postgres"]
+    );
+    assert_eq!(journal_entry.completed_steps, ["storage_caches"]);
+    // Postgres must not be rolled back yet.
+    let last_l1_batch_number = storage
+        .blocks_dal()
+        .get_sealed_l1_batch_number()
+        .await
+        .unwrap();
+    assert_eq!(last_l1_batch_number, Some(L1BatchNumber(9)));
+
+    // Rolling back to a later batch is not allowed until the unfinished rollback is completed.
+    let err = block_reverter
+        .roll_back(L1BatchNumber(6))
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("unfinished rollback"), "{err}");
+
+    // Resuming the rollback with different components enabled is not allowed.
+    let mut block_reverter = BlockReverter::new(NodeRole::External, pool.clone());
+    block_reverter
+        .enable_rolling_back_postgres()
+        .add_rocksdb_storage_path_to_rollback(sk_cache_path.clone());
+    let err = block_reverter
+        .roll_back(L1BatchNumber(5))
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("same components"), "{err}");
+    let last_l1_batch_number = storage
+        .blocks_dal()
+        .get_sealed_l1_batch_number()
+        .await
+        .unwrap();
+    assert_eq!(last_l1_batch_number, Some(L1BatchNumber(9)));
+
+    let mut block_reverter = BlockReverter::new(NodeRole::External, pool.clone());
+    block_reverter
+        .enable_rolling_back_postgres()
+        .add_rocksdb_storage_path_to_rollback(sk_cache_path)
+        .enable_rolling_back_snapshot_objects(MockObjectStore::arc());
+    block_reverter.roll_back(L1BatchNumber(5)).await.unwrap();
+
+    assert_eq!(block_reverter.unfinished_revert().await.unwrap(), None);
+    let last_l1_batch_number = storage
+        .blocks_dal()
+        .get_sealed_l1_batch_number()
+        .await
+        .unwrap();
+    assert_eq!(last_l1_batch_number, Some(L1BatchNumber(5)));
+    let all_snapshots = storage
+        .snapshots_dal()
+        .get_all_complete_snapshots()
+        .await
+        .unwrap();
+    assert_eq!(all_snapshots.snapshots_l1_batch_numbers, []);
+
+    // Rolling back once again should start a new rollback rather than resume the finished one.
+    block_reverter.roll_back(L1BatchNumber(5)).await.unwrap();
+    assert_eq!(block_reverter.unfinished_revert().await.unwrap(), None);
+}
+
 #[tokio::test]
 async fn reverter_handles_incomplete_snapshot() {
     let storage_logs = gen_storage_logs();
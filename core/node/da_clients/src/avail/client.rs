@@ -1,21 +1,43 @@
-use std::{fmt::Debug, sync::Arc};
+use std::{fmt::Debug, sync::Arc, time::Duration};
 
+use anyhow::Context as _;
 use async_trait::async_trait;
-use jsonrpsee::ws_client::WsClientBuilder;
+use jsonrpsee::{
+    core::ClientError,
+    ws_client::{WsClient, WsClientBuilder},
+};
+use serde::Deserialize;
 use subxt_signer::ExposeSecret;
+use tokio::sync::Mutex;
 use zksync_config::configs::da_client::avail::{AvailConfig, AvailSecrets};
 use zksync_da_client::{
     types::{DAError, DispatchResponse, InclusionData},
     DataAvailabilityClient,
 };
+use zksync_types::{
+    ethabi::{self, Token},
+    H256, U256,
+};
+
+use crate::avail::sdk::{RawAvailClient, SubmissionError};
 
-use crate::avail::sdk::RawAvailClient;
+/// Substrate transaction pool error codes that indicate a transient condition, e.g. a nonce clash
+/// with a concurrently submitted extrinsic. Such extrinsics can be rebuilt and resubmitted.
+const RETRIABLE_POOL_ERROR_CODES: [i32; 3] = [
+    1012, // transaction is temporarily banned
+    1013, // transaction is already imported
+    1014, // priority is too low
+];
 
 /// An implementation of the `DataAvailabilityClient` trait that interacts with the Avail network.
 #[derive(Debug, Clone)]
 pub struct AvailClient {
     config: AvailConfig,
     sdk_client: Arc<RawAvailClient>,
+    api_client: reqwest::Client,
+    /// Websocket connection to the Avail node shared among all clones of the client. Established lazily,
+    /// and re-established if the connection is dropped.
+    ws_client: Arc<Mutex<Option<Arc<WsClient>>>>,
 }
 
 impl AvailClient {
@@ -25,11 +47,83 @@ impl AvailClient {
             .ok_or_else(|| anyhow::anyhow!("seed phrase"))?;
         let sdk_client = RawAvailClient::new(config.app_id, seed_phrase.0.expose_secret()).await?;
 
+        let api_client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(config.timeout as u64))
+            .build()
+            .context("failed to build bridge API client")?;
+
         Ok(Self {
             config,
             sdk_client: Arc::new(sdk_client),
+            api_client,
+            ws_client: Arc::default(),
         })
     }
+
+    /// Returns a connected websocket client, reconnecting to the Avail node if necessary.
+    async fn ws_client(&self) -> Result<Arc<WsClient>, DAError> {
+        let mut ws_client = self.ws_client.lock().await;
+        if let Some(client) = ws_client.as_ref() {
+            if client.is_connected() {
+                return Ok(client.clone());
+            }
+            tracing::info!("Connection to the Avail node was dropped; reconnecting");
+        }
+
+        let client = WsClientBuilder::default()
+            .request_timeout(Duration::from_secs(self.config.timeout as u64))
+            .build(self.config.api_node_url.as_str())
+            .await
+            .map_err(|err| to_da_error(err.into()))?;
+        let client = Arc::new(client);
+        *ws_client = Some(client.clone());
+        Ok(client)
+    }
+
+    /// Queries the bridge API for the Merkle proof of the blob inclusion into the data root
+    /// bridged to L1. Returns `None` if the data root covering the block is not bridged yet.
+    async fn fetch_bridge_proof(
+        &self,
+        block_hash: &str,
+        tx_id: usize,
+    ) -> Result<Option<BridgeApiResponse>, DAError> {
+        let url = format!(
+            "{}/eth/proof/0x{block_hash}?index={tx_id}",
+            self.config.bridge_api_url.trim_end_matches('/')
+        );
+        let response = self
+            .api_client
+            .get(&url)
+            .send()
+            .await
+            .map_err(to_retriable_da_error)?;
+
+        let status = response.status();
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !status.is_success() {
+            let error = anyhow::anyhow!(
+                "bridge API returned status {status} for block {block_hash}, tx {tx_id}: {}",
+                response.text().await.unwrap_or_default()
+            );
+            return Err(DAError {
+                // Server-side errors are likely transient, while client-side ones are not
+                is_retriable: status.is_server_error(),
+                error,
+            });
+        }
+
+        let response: BridgeApiResponse =
+            response.json().await.map_err(to_non_retriable_da_error)?;
+        if let Some(error) = &response.error {
+            tracing::debug!(
+                "Inclusion proof for block {block_hash}, tx {tx_id} is not available yet: {error}"
+            );
+            return Ok(None);
+        }
+        Ok(Some(response))
+    }
 }
 
 #[async_trait]
@@ -39,37 +133,50 @@ impl DataAvailabilityClient for AvailClient {
         _: u32, // batch_number
         data: Vec<u8>,
     ) -> anyhow::Result<DispatchResponse, DAError> {
-        let client = WsClientBuilder::default()
-            .build(self.config.api_node_url.as_str())
-            .await
-            .map_err(to_non_retriable_da_error)?;
+        let client = self.ws_client().await?;
 
         let extrinsic = self
             .sdk_client
             .build_extrinsic(&client, data)
             .await
-            .map_err(to_non_retriable_da_error)?;
+            .map_err(to_da_error)?;
 
         let block_hash = self
             .sdk_client
             .submit_extrinsic(&client, extrinsic.as_str())
             .await
-            .map_err(to_non_retriable_da_error)?;
+            .map_err(to_da_error)?;
         let tx_id = self
             .sdk_client
             .get_tx_id(&client, block_hash.as_str(), extrinsic.as_str())
             .await
-            .map_err(to_non_retriable_da_error)?;
+            .map_err(to_da_error)?;
 
         Ok(DispatchResponse::from(format!("{}:{}", block_hash, tx_id)))
     }
 
     async fn get_inclusion_data(
         &self,
-        _blob_id: &str,
+        blob_id: &str,
     ) -> anyhow::Result<Option<InclusionData>, DAError> {
-        // TODO: implement inclusion data retrieval
-        Ok(Some(InclusionData { data: vec![] }))
+        let (block_hash, tx_id) = parse_blob_id(blob_id).map_err(to_non_retriable_da_error)?;
+        let Some(response) = self.fetch_bridge_proof(block_hash, tx_id).await? else {
+            return Ok(None);
+        };
+
+        let proof = MerkleProofInput::try_from(response).map_err(to_non_retriable_da_error)?;
+        Ok(Some(InclusionData {
+            data: proof.abi_encode(),
+        }))
+    }
+
+    async fn get_blob(&self, blob_id: &str) -> anyhow::Result<Option<Vec<u8>>, DAError> {
+        let (block_hash, tx_id) = parse_blob_id(blob_id).map_err(to_non_retriable_da_error)?;
+        let client = self.ws_client().await?;
+        self.sdk_client
+            .get_submitted_data(&client, block_hash, tx_id)
+            .await
+            .map_err(to_da_error)
     }
 
     fn clone_boxed(&self) -> Box<dyn DataAvailabilityClient> {
@@ -87,3 +194,306 @@ pub fn to_non_retriable_da_error(error: impl Into<anyhow::Error>) -> DAError {
         is_retriable: false,
     }
 }
+
+pub fn to_retriable_da_error(error: impl Into<anyhow::Error>) -> DAError {
+    DAError {
+        error: error.into(),
+        is_retriable: true,
+    }
+}
+
+/// Converts an error returned by the Avail node client into a `DAError`, classifying it as retriable
+/// if it is caused by a network / connection issue or a transient transaction pool condition.
+fn to_da_error(error: anyhow::Error) -> DAError {
+    let is_retriable = error.chain().any(|cause| {
+        if let Some(err) = cause.downcast_ref::<ClientError>() {
+            is_retriable_client_error(err)
+        } else if let Some(err) = cause.downcast_ref::<SubmissionError>() {
+            err.is_retriable()
+        } else {
+            false
+        }
+    });
+    DAError {
+        error,
+        is_retriable,
+    }
+}
+
+fn is_retriable_client_error(err: &ClientError) -> bool {
+    match err {
+        ClientError::Transport(_) | ClientError::RestartNeeded(_) | ClientError::RequestTimeout => {
+            true
+        }
+        ClientError::Call(err) => RETRIABLE_POOL_ERROR_CODES.contains(&err.code()),
+        _ => false,
+    }
+}
+
+/// Splits a blob ID in the `{block_hash}:{tx_id}` format produced by `dispatch_blob()`.
+fn parse_blob_id(blob_id: &str) -> anyhow::Result<(&str, usize)> {
+    let (block_hash, tx_id) = blob_id
+        .split_once(':')
+        .with_context(|| format!("invalid blob ID format: {blob_id}"))?;
+    let block_hash = block_hash.strip_prefix("0x").unwrap_or(block_hash);
+    let tx_id = tx_id
+        .parse()
+        .with_context(|| format!("invalid transaction index in blob ID: {blob_id}"))?;
+    Ok((block_hash, tx_id))
+}
+
+/// Response of the Avail bridge API `/eth/proof/{block_hash}` endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BridgeApiResponse {
+    blob_root: Option<H256>,
+    bridge_root: Option<H256>,
+    data_root_index: Option<u64>,
+    data_root_proof: Option<Vec<H256>>,
+    leaf: Option<H256>,
+    leaf_index: Option<u64>,
+    leaf_proof: Option<Vec<H256>>,
+    range_hash: Option<H256>,
+    error: Option<String>,
+}
+
+/// Inclusion proof in the form expected by the `MerkleProofInput` struct of the L1 Avail attestation
+/// bridge (`VectorX`-based) verifier.
+#[derive(Debug, PartialEq)]
+struct MerkleProofInput {
+    data_root_proof: Vec<H256>,
+    leaf_proof: Vec<H256>,
+    range_hash: H256,
+    data_root_index: U256,
+    blob_root: H256,
+    bridge_root: H256,
+    leaf: H256,
+    leaf_index: U256,
+}
+
+impl TryFrom<BridgeApiResponse> for MerkleProofInput {
+    type Error = anyhow::Error;
+
+    fn try_from(response: BridgeApiResponse) -> Result<Self, Self::Error> {
+        Ok(Self {
+            data_root_proof: response
+                .data_root_proof
+                .context("missing `dataRootProof`")?,
+            leaf_proof: response.leaf_proof.context("missing `leafProof`")?,
+            range_hash: response.range_hash.context("missing `rangeHash`")?,
+            data_root_index: response
+                .data_root_index
+                .context("missing `dataRootIndex`")?
+                .into(),
+            blob_root: response.blob_root.context("missing `blobRoot`")?,
+            bridge_root: response.bridge_root.context("missing `bridgeRoot`")?,
+            leaf: response.leaf.context("missing `leaf`")?,
+            leaf_index: response.leaf_index.context("missing `leafIndex`")?.into(),
+        })
+    }
+}
+
+impl MerkleProofInput {
+    /// ABI-encodes the proof so that it can be decoded as `abi.decode(data, (MerkleProofInput))`.
+    fn abi_encode(&self) -> Vec<u8> {
+        let hashes = |hashes: &[H256]| {
+            Token::Array(
+                hashes
+                    .iter()
+                    .map(|hash| Token::FixedBytes(hash.as_bytes().to_vec()))
+                    .collect(),
+            )
+        };
+
+        ethabi::encode(&[Token::Tuple(vec![
+            hashes(&self.data_root_proof),
+            hashes(&self.leaf_proof),
+            Token::FixedBytes(self.range_hash.as_bytes().to_vec()),
+            Token::Uint(self.data_root_index),
+            Token::FixedBytes(self.blob_root.as_bytes().to_vec()),
+            Token::FixedBytes(self.bridge_root.as_bytes().to_vec()),
+            Token::FixedBytes(self.leaf.as_bytes().to_vec()),
+            Token::Uint(self.leaf_index),
+        ])])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::MockServer;
+    use jsonrpsee::types::ErrorObjectOwned;
+    use zksync_config::configs::da_client::avail::AvailSecrets;
+
+    use super::*;
+
+    const TEST_SEED_PHRASE: &str =
+        "bottom drive obey lake curtain smoke basket hold race lonely fit walk";
+    const BLOCK_HASH: &str = "b6b3b2d1c4e5a1f3d3c2b1a0f9e8d7c6b5a4f3e2d1c0b9a8f7e6d5c4b3a2f1e0";
+
+    async fn create_client(bridge_api_url: String) -> AvailClient {
+        let config = AvailConfig {
+            api_node_url: "ws://localhost:1".to_owned(),
+            bridge_api_url,
+            app_id: 1,
+            timeout: 5,
+            max_retries: 1,
+        };
+        let secrets = AvailSecrets {
+            seed_phrase: Some(TEST_SEED_PHRASE.parse().unwrap()),
+        };
+        AvailClient::new(config, secrets).await.unwrap()
+    }
+
+    fn mock_proof_response() -> serde_json::Value {
+        serde_json::json!({
+            "blobRoot": format!("{:?}", H256::repeat_byte(1)),
+            "bridgeRoot": format!("{:?}", H256::repeat_byte(2)),
+            "dataRootIndex": 4,
+            "dataRootProof": [format!("{:?}", H256::repeat_byte(3)), format!("{:?}", H256::repeat_byte(4))],
+            "leaf": format!("{:?}", H256::repeat_byte(5)),
+            "leafIndex": 7,
+            "leafProof": [format!("{:?}", H256::repeat_byte(6))],
+            "rangeHash": format!("{:?}", H256::repeat_byte(7)),
+        })
+    }
+
+    #[test]
+    fn parsing_blob_id() {
+        let blob_id = format!("{BLOCK_HASH}:3");
+        assert_eq!(parse_blob_id(&blob_id).unwrap(), (BLOCK_HASH, 3));
+        let blob_id = format!("0x{BLOCK_HASH}:12");
+        assert_eq!(parse_blob_id(&blob_id).unwrap(), (BLOCK_HASH, 12));
+
+        parse_blob_id(BLOCK_HASH).unwrap_err();
+        parse_blob_id(&format!("{BLOCK_HASH}:")).unwrap_err();
+        parse_blob_id(&format!("{BLOCK_HASH}:-1")).unwrap_err();
+    }
+
+    #[test]
+    fn merkle_proof_encoding() {
+        let proof = MerkleProofInput {
+            data_root_proof: vec![H256::repeat_byte(3), H256::repeat_byte(4)],
+            leaf_proof: vec![H256::repeat_byte(6)],
+            range_hash: H256::repeat_byte(7),
+            data_root_index: 4.into(),
+            blob_root: H256::repeat_byte(1),
+            bridge_root: H256::repeat_byte(2),
+            leaf: H256::repeat_byte(5),
+            leaf_index: 7.into(),
+        };
+        let encoded = proof.abi_encode();
+
+        let param_type = ethabi::ParamType::Tuple(vec![
+            ethabi::ParamType::Array(Box::new(ethabi::ParamType::FixedBytes(32))),
+            ethabi::ParamType::Array(Box::new(ethabi::ParamType::FixedBytes(32))),
+            ethabi::ParamType::FixedBytes(32),
+            ethabi::ParamType::Uint(256),
+            ethabi::ParamType::FixedBytes(32),
+            ethabi::ParamType::FixedBytes(32),
+            ethabi::ParamType::FixedBytes(32),
+            ethabi::ParamType::Uint(256),
+        ]);
+        let decoded = ethabi::decode(&[param_type], &encoded).unwrap();
+        let Token::Tuple(fields) = &decoded[0] else {
+            panic!("unexpected decoded token: {decoded:?}");
+        };
+        assert_eq!(fields.len(), 8);
+        assert_eq!(fields[3], Token::Uint(4.into()));
+        assert_eq!(
+            fields[6],
+            Token::FixedBytes(H256::repeat_byte(5).as_bytes().to_vec())
+        );
+        assert_eq!(fields[7], Token::Uint(7.into()));
+    }
+
+    #[test]
+    fn classifying_client_errors() {
+        let transport_err = ClientError::Transport(anyhow::anyhow!("connection reset"));
+        assert!(to_da_error(transport_err.into()).is_retriable());
+        let timeout_err = anyhow::Error::from(ClientError::RequestTimeout).context("submitting");
+        assert!(to_da_error(timeout_err).is_retriable());
+        let closed_err = anyhow::Error::from(SubmissionError::SubscriptionClosed);
+        assert!(to_da_error(closed_err).is_retriable());
+        let dropped_err = anyhow::Error::from(SubmissionError::NotIncluded("dropped".to_owned()));
+        assert!(to_da_error(dropped_err).is_retriable());
+        let invalid_err = anyhow::Error::from(SubmissionError::Invalid).context("submitting");
+        assert!(!to_da_error(invalid_err).is_retriable());
+
+        let pool_err = ErrorObjectOwned::owned(1014, "Priority is too low", None::<()>);
+        assert!(to_da_error(ClientError::Call(pool_err).into()).is_retriable());
+        let invalid_tx_err = ErrorObjectOwned::owned(1010, "Invalid Transaction", None::<()>);
+        assert!(!to_da_error(ClientError::Call(invalid_tx_err).into()).is_retriable());
+        assert!(!to_da_error(anyhow::anyhow!("DataAvailability pallet not found")).is_retriable());
+    }
+
+    #[tokio::test]
+    async fn getting_inclusion_data() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path(format!("/eth/proof/0x{BLOCK_HASH}"))
+                .query_param("index", "3");
+            then.status(200).json_body(mock_proof_response());
+        });
+        let client = create_client(server.url("")).await;
+
+        let inclusion_data = client
+            .get_inclusion_data(&format!("{BLOCK_HASH}:3"))
+            .await
+            .unwrap()
+            .expect("no inclusion data");
+        mock.assert();
+
+        let response: BridgeApiResponse = serde_json::from_value(mock_proof_response()).unwrap();
+        let expected_proof = MerkleProofInput::try_from(response).unwrap();
+        assert_eq!(inclusion_data.data, expected_proof.abi_encode());
+    }
+
+    #[tokio::test]
+    async fn inclusion_data_is_not_ready() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path(format!("/eth/proof/0x{BLOCK_HASH}"))
+                .query_param("index", "1");
+            then.status(200).json_body(serde_json::json!({
+                "error": "Block not yet bridged"
+            }));
+        });
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path(format!("/eth/proof/0x{BLOCK_HASH}"))
+                .query_param("index", "2");
+            then.status(404);
+        });
+        let client = create_client(server.url("")).await;
+
+        for tx_id in [1, 2] {
+            let inclusion_data = client
+                .get_inclusion_data(&format!("{BLOCK_HASH}:{tx_id}"))
+                .await
+                .unwrap();
+            assert!(inclusion_data.is_none());
+        }
+    }
+
+    #[tokio::test]
+    async fn bridge_api_errors() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path(format!("/eth/proof/0x{BLOCK_HASH}"));
+            then.status(503).body("service unavailable");
+        });
+        let client = create_client(server.url("")).await;
+
+        let err = client
+            .get_inclusion_data(&format!("{BLOCK_HASH}:1"))
+            .await
+            .unwrap_err();
+        assert!(err.is_retriable(), "{err}");
+
+        let err = client.get_inclusion_data("invalid").await.unwrap_err();
+        assert!(!err.is_retriable(), "{err}");
+    }
+}
@@ -14,10 +14,29 @@ use subxt_signer::{
     sr25519::{Keypair, Signature},
 };
 
-use crate::avail::client::to_non_retriable_da_error;
-
 const PROTOCOL_VERSION: u8 = 4;
 
+/// Errors that can occur while waiting for a submitted extrinsic to be included in a block.
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum SubmissionError {
+    #[error(
+        "extrinsic status subscription was closed before the extrinsic was included in a block"
+    )]
+    SubscriptionClosed,
+    #[error("extrinsic was not included in a block, status: {0}")]
+    NotIncluded(String),
+    #[error("extrinsic was rejected as invalid by the transaction pool")]
+    Invalid,
+}
+
+impl SubmissionError {
+    /// Checks whether the error is transient. Extrinsics that were dropped or usurped are rebuilt
+    /// (with a fresh nonce) on retry, while invalid extrinsics would be rejected again.
+    pub(crate) fn is_retriable(&self) -> bool {
+        !matches!(self, Self::Invalid)
+    }
+}
+
 /// An implementation of the `DataAvailabilityClient` trait that interacts with the Avail network.
 #[derive(Debug, Clone)]
 pub(crate) struct RawAvailClient {
@@ -53,18 +72,9 @@ impl RawAvailClient {
         client: &Client,
         data: Vec<u8>,
     ) -> anyhow::Result<String> {
-        let call_data = self
-            .get_encoded_call(client, data)
-            .await
-            .map_err(to_non_retriable_da_error)?;
-        let extra_params = self
-            .get_extended_params(client)
-            .await
-            .map_err(to_non_retriable_da_error)?;
-        let additional_params = self
-            .get_additional_params(client)
-            .await
-            .map_err(to_non_retriable_da_error)?;
+        let call_data = self.get_encoded_call(client, data).await?;
+        let extra_params = self.get_extended_params(client).await?;
+        let additional_params = self.get_additional_params(client).await?;
 
         let signature = self.get_signature(
             call_data.as_slice(),
@@ -284,17 +294,33 @@ impl RawAvailClient {
             .await?;
 
         let block_hash = loop {
-            let status = sub.next().await.transpose()?;
-
-            if status.is_some() && status.as_ref().unwrap().is_object() {
-                if let Some(block_hash) = status.unwrap().get("inBlock") {
-                    break block_hash
-                        .as_str()
-                        .ok_or_else(|| anyhow::anyhow!("Invalid block hash"))?
-                        .strip_prefix("0x")
-                        .ok_or_else(|| anyhow::anyhow!("Block hash doesn't have 0x prefix"))?
-                        .to_string();
+            let status = sub
+                .next()
+                .await
+                .transpose()?
+                .ok_or(SubmissionError::SubscriptionClosed)?;
+
+            match &status {
+                serde_json::Value::Object(status) => {
+                    if let Some(block_hash) = status.get("inBlock") {
+                        break block_hash
+                            .as_str()
+                            .ok_or_else(|| anyhow::anyhow!("Invalid block hash"))?
+                            .strip_prefix("0x")
+                            .ok_or_else(|| anyhow::anyhow!("Block hash doesn't have 0x prefix"))?
+                            .to_string();
+                    }
+                    if status.contains_key("usurped") {
+                        return Err(SubmissionError::NotIncluded("usurped".to_owned()).into());
+                    }
+                }
+                serde_json::Value::String(status) if status == "dropped" => {
+                    return Err(SubmissionError::NotIncluded(status.clone()).into());
                 }
+                serde_json::Value::String(status) if status == "invalid" => {
+                    return Err(SubmissionError::Invalid.into());
+                }
+                _ => { /* intermediate status, e.g. `ready` or `broadcast` */ }
             }
         };
         sub.unsubscribe().await?;
@@ -331,6 +357,104 @@ impl RawAvailClient {
 
         Ok(tx_id)
     }
+
+    /// Fetches the extrinsic with the specified index from the block and extracts the data submitted by it.
+    /// Returns `None` if the block doesn't contain an extrinsic with this index.
+    pub(crate) async fn get_submitted_data(
+        &self,
+        client: &Client,
+        block_hash: &str,
+        tx_id: usize,
+    ) -> anyhow::Result<Option<Vec<u8>>> {
+        let resp: serde_json::Value = client
+            .request("chain_getBlock", rpc_params![block_hash])
+            .await?;
+        if resp.is_null() {
+            return Ok(None);
+        }
+
+        let extrinsics = resp
+            .get("block")
+            .ok_or_else(|| anyhow::anyhow!("Invalid block"))?
+            .get("extrinsics")
+            .ok_or_else(|| anyhow::anyhow!("No field named extrinsics in block"))?
+            .as_array()
+            .ok_or_else(|| anyhow::anyhow!("Extrinsics field is not an array"))?;
+        let Some(extrinsic) = extrinsics.get(tx_id) else {
+            return Ok(None);
+        };
+
+        let extrinsic = extrinsic
+            .as_str()
+            .ok_or_else(|| anyhow::anyhow!("Extrinsic is not a string"))?;
+        let extrinsic = hex::decode(
+            extrinsic
+                .strip_prefix("0x")
+                .ok_or_else(|| anyhow::anyhow!("Extrinsic doesn't have 0x prefix"))?,
+        )?;
+        decode_submit_data_extrinsic(&extrinsic).map(Some)
+    }
+}
+
+/// Extracts the submitted data from an encoded signed `submit_data` extrinsic, i.e. performs the reverse
+/// of [`RawAvailClient::build_extrinsic()`]. Pallet and call indices are not checked since they depend
+/// on the runtime metadata.
+pub(crate) fn decode_submit_data_extrinsic(extrinsic: &[u8]) -> anyhow::Result<Vec<u8>> {
+    let input = &mut &extrinsic[..];
+    let len = Compact::<u32>::decode(input)?.0 as usize;
+    anyhow::ensure!(
+        input.len() == len,
+        "Extrinsic length mismatch: declared {len}, actual {}",
+        input.len()
+    );
+
+    let version = u8::decode(input)?;
+    anyhow::ensure!(
+        version == 0b10000000 + PROTOCOL_VERSION,
+        "Unsupported extrinsic version: {version:#x}"
+    );
+
+    // sender
+    let address_kind = u8::decode(input)?;
+    anyhow::ensure!(
+        address_kind == 0,
+        "Unsupported address kind: {address_kind}"
+    );
+    <[u8; 32]>::decode(input)?;
+
+    // signature
+    let signature_len = match u8::decode(input)? {
+        0 | 1 => 64, // Ed25519 | Sr25519
+        2 => 65,     // Ecdsa
+        kind => anyhow::bail!("Unsupported signature kind: {kind}"),
+    };
+    skip_bytes(input, signature_len)?;
+
+    // extra params
+    let era = u8::decode(input)?;
+    if era != 0 {
+        // mortal era is encoded in 2 bytes
+        skip_bytes(input, 1)?;
+    }
+    Compact::<u64>::decode(input)?; // nonce
+    Compact::<u128>::decode(input)?; // tip
+    Compact::<u32>::decode(input)?; // app ID
+
+    // call data
+    skip_bytes(input, 2)?; // pallet and call indices
+    let data = Vec::<u8>::decode(input)?;
+    anyhow::ensure!(
+        input.is_empty(),
+        "Extrinsic has {} unexpected trailing bytes",
+        input.len()
+    );
+    Ok(data)
+}
+
+fn skip_bytes(input: &mut &[u8], count: usize) -> anyhow::Result<()> {
+    anyhow::ensure!(input.len() >= count, "Extrinsic is truncated");
+    *input = &input[count..];
+    Ok(())
 }
 
 fn blake2<const N: usize>(data: Vec<u8>) -> [u8; N] {
@@ -369,3 +493,32 @@ fn ss58hash(data: &[u8]) -> Vec<u8> {
     ctx.update(data);
     ctx.finalize().to_vec()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn decoding_submit_data_extrinsic() {
+        let seed = "bottom drive obey lake curtain smoke basket hold race lonely fit walk";
+        let client = RawAvailClient::new(1, seed).await.unwrap();
+        let data = vec![42_u8; 1_000];
+
+        let mut call_data = vec![29, 1]; // pallet and call indices
+        data.encode_to(&mut call_data);
+        let mut extra_params = vec![0]; // immortal era
+        Compact(5_u64).encode_to(&mut extra_params);
+        Compact(0_u128).encode_to(&mut extra_params);
+        Compact(1_u32).encode_to(&mut extra_params);
+        let signature = client.get_signature(&call_data, &extra_params, &[]);
+        let extrinsic = client.get_submittable_extrinsic(signature, &extra_params, &call_data);
+
+        let decoded = decode_submit_data_extrinsic(&extrinsic).unwrap();
+        assert_eq!(decoded, data);
+
+        decode_submit_data_extrinsic(&extrinsic[..extrinsic.len() - 1]).unwrap_err();
+        let mut unsigned_extrinsic = extrinsic.clone();
+        unsigned_extrinsic[2] = PROTOCOL_VERSION; // the length prefix takes 2 bytes
+        decode_submit_data_extrinsic(&unsigned_extrinsic).unwrap_err();
+    }
+}
@@ -0,0 +1,50 @@
+//! Creation of DA clients from configuration.
+
+use anyhow::Context as _;
+use zksync_config::configs::{da_client::DAClientConfig, secrets::DataAvailabilitySecrets};
+use zksync_da_client::DataAvailabilityClient;
+
+use crate::{avail::AvailClient, multi::MultiDAClient, object_store::ObjectStoreDAClient};
+
+/// Creates a DA client based on the provided config. Multi-DA configs are supported, but the underlying clients
+/// cannot be multi-DA clients themselves.
+///
+/// # Errors
+///
+/// Returns an error if secrets required by the client are missing, or if the client cannot be initialized.
+pub async fn create_da_client(
+    config: DAClientConfig,
+    secrets: Option<DataAvailabilitySecrets>,
+) -> anyhow::Result<Box<dyn DataAvailabilityClient>> {
+    let DAClientConfig::Multi(config) = config else {
+        return create_single_client(config, secrets).await;
+    };
+
+    let mut clients = Vec::with_capacity(config.clients.len());
+    for (i, client_config) in config.clients.into_iter().enumerate() {
+        let client = create_single_client(client_config, secrets.clone())
+            .await
+            .with_context(|| format!("failed creating DA client #{i}"))?;
+        clients.push(client);
+    }
+    Ok(Box::new(MultiDAClient::new(
+        clients,
+        config.inclusion_quorum,
+    )?))
+}
+
+async fn create_single_client(
+    config: DAClientConfig,
+    secrets: Option<DataAvailabilitySecrets>,
+) -> anyhow::Result<Box<dyn DataAvailabilityClient>> {
+    Ok(match config {
+        DAClientConfig::Avail(config) => {
+            let Some(DataAvailabilitySecrets::Avail(secrets)) = secrets else {
+                anyhow::bail!("Avail secrets are required for the Avail DA client");
+            };
+            Box::new(AvailClient::new(config, secrets).await?)
+        }
+        DAClientConfig::ObjectStore(config) => Box::new(ObjectStoreDAClient::new(config).await?),
+        DAClientConfig::Multi(_) => anyhow::bail!("nested multi-DA clients are not supported"),
+    })
+}
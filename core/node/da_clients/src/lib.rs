@@ -1,3 +1,7 @@
+pub use self::factory::create_da_client;
+
 pub mod avail;
+mod factory;
+pub mod multi;
 pub mod no_da;
 pub mod object_store;
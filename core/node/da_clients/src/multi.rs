@@ -0,0 +1,477 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use zksync_da_client::{
+    types::{DAError, DispatchResponse, InclusionData},
+    DataAvailabilityClient,
+};
+use zksync_types::{
+    ethabi::{self, Token},
+    web3::keccak256,
+    H256,
+};
+
+/// Identifies a blob (or a blob chunk) being dispatched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct BlobKey {
+    batch_number: u32,
+    chunk_index: Option<usize>,
+    data_hash: H256,
+}
+
+/// An implementation of the `DataAvailabilityClient` trait that dispatches every blob to several
+/// DA layers for redundancy.
+///
+/// The blob ID returned by the client is a JSON array of the blob IDs returned by the underlying clients
+/// (in the order of clients). A blob is considered included once at least `inclusion_quorum` underlying
+/// clients provide the inclusion data for it.
+///
+/// If dispatching fails for some of the underlying clients with a retriable error, the blob IDs returned by the other
+/// clients are retained, so that the blob is only re-dispatched to the failed clients on retry. Retained blob IDs are
+/// discarded once the blob is fully dispatched, dispatching fails with a non-retriable error, or a blob with different
+/// data is dispatched for the same batch (and chunk).
+#[derive(Debug, Clone)]
+pub struct MultiDAClient {
+    clients: Vec<Box<dyn DataAvailabilityClient>>,
+    inclusion_quorum: usize,
+    /// Blob IDs returned by the underlying clients for partially dispatched blobs. Contains at most one entry
+    /// per batch number and chunk index.
+    partial_dispatches: Arc<Mutex<HashMap<BlobKey, Vec<Option<String>>>>>,
+}
+
+impl MultiDAClient {
+    pub fn new(
+        clients: Vec<Box<dyn DataAvailabilityClient>>,
+        inclusion_quorum: usize,
+    ) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            !clients.is_empty(),
+            "multi-DA client requires at least one underlying client"
+        );
+        anyhow::ensure!(
+            (1..=clients.len()).contains(&inclusion_quorum),
+            "inclusion quorum must be in 1..={}, got {inclusion_quorum}",
+            clients.len()
+        );
+
+        Ok(Self {
+            clients,
+            inclusion_quorum,
+            partial_dispatches: Arc::default(),
+        })
+    }
+
+    fn parse_blob_id(&self, blob_id: &str) -> Result<Vec<String>, DAError> {
+        let blob_ids: Vec<String> = serde_json::from_str(blob_id).map_err(|err| DAError {
+            error: anyhow::Error::from(err)
+                .context(format!("invalid composite blob ID: {blob_id}")),
+            is_retriable: false,
+        })?;
+
+        if blob_ids.len() != self.clients.len() {
+            return Err(DAError {
+                error: anyhow::anyhow!(
+                    "composite blob ID {blob_id} has {} parts, expected {}",
+                    blob_ids.len(),
+                    self.clients.len()
+                ),
+                is_retriable: false,
+            });
+        }
+        Ok(blob_ids)
+    }
+
+    async fn dispatch(
+        &self,
+        batch_number: u32,
+        chunk_index: Option<usize>,
+        data: Vec<u8>,
+    ) -> Result<DispatchResponse, DAError> {
+        let key = BlobKey {
+            batch_number,
+            chunk_index,
+            data_hash: H256(keccak256(&data)),
+        };
+        let mut blob_ids = self
+            .partial_dispatches
+            .lock()
+            .unwrap()
+            .get(&key)
+            .cloned()
+            .unwrap_or_else(|| vec![None; self.clients.len()]);
+
+        let pending_clients = self
+            .clients
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| blob_ids[i].is_none());
+        let dispatches = pending_clients.map(|(i, client)| {
+            let data = data.clone();
+            async move {
+                let response = match chunk_index {
+                    Some(chunk_index) => {
+                        client
+                            .dispatch_blob_chunk(batch_number, chunk_index, data)
+                            .await
+                    }
+                    None => client.dispatch_blob(batch_number, data).await,
+                };
+                (i, response)
+            }
+        });
+        let responses = futures::future::join_all(dispatches).await;
+
+        let mut errors = vec![];
+        for (i, response) in responses {
+            match response {
+                Ok(response) => blob_ids[i] = Some(response.blob_id),
+                Err(err) => {
+                    tracing::warn!(
+                        "Failed dispatching blob for batch {batch_number} via DA client #{i}: {err}"
+                    );
+                    errors.push((i, err));
+                }
+            }
+        }
+
+        let is_retriable = errors.iter().all(|(_, err)| err.is_retriable);
+        {
+            let mut partial_dispatches = self.partial_dispatches.lock().unwrap();
+            // Remove the entry for this blob together with entries for outdated data of the same batch / chunk.
+            partial_dispatches.retain(|other_key, _| {
+                other_key.batch_number != batch_number || other_key.chunk_index != chunk_index
+            });
+            if !errors.is_empty() && is_retriable {
+                partial_dispatches.insert(key, blob_ids.clone());
+            }
+        }
+
+        if !errors.is_empty() {
+            let (i, err) = errors.swap_remove(0);
+            return Err(DAError {
+                error: err
+                    .error
+                    .context(format!("failed dispatching blob via DA client #{i}")),
+                is_retriable,
+            });
+        }
+
+        // `unwrap()` is safe: all clients have returned blob IDs at this point
+        let blob_ids: Vec<_> = blob_ids.into_iter().map(Option::unwrap).collect();
+        let blob_id = serde_json::to_string(&blob_ids).map_err(|err| DAError {
+            error: err.into(),
+            is_retriable: false,
+        })?;
+        Ok(DispatchResponse::from(blob_id))
+    }
+}
+
+#[async_trait]
+impl DataAvailabilityClient for MultiDAClient {
+    async fn dispatch_blob(
+        &self,
+        batch_number: u32,
+        data: Vec<u8>,
+    ) -> Result<DispatchResponse, DAError> {
+        self.dispatch(batch_number, None, data).await
+    }
+
+    async fn dispatch_blob_chunk(
+        &self,
+        batch_number: u32,
+        chunk_index: usize,
+        data: Vec<u8>,
+    ) -> Result<DispatchResponse, DAError> {
+        self.dispatch(batch_number, Some(chunk_index), data).await
+    }
+
+    async fn get_inclusion_data(&self, blob_id: &str) -> Result<Option<InclusionData>, DAError> {
+        let blob_ids = self.parse_blob_id(blob_id)?;
+        let requests = self
+            .clients
+            .iter()
+            .zip(&blob_ids)
+            .map(|(client, blob_id)| client.get_inclusion_data(blob_id));
+        let responses = futures::future::join_all(requests).await;
+
+        let mut included_count = 0;
+        let mut first_error = None;
+        let mut inclusion_data = Vec::with_capacity(responses.len());
+        for (i, response) in responses.into_iter().enumerate() {
+            match response {
+                Ok(Some(data)) => {
+                    included_count += 1;
+                    inclusion_data.push(data.data);
+                }
+                Ok(None) => inclusion_data.push(vec![]),
+                Err(err) => {
+                    tracing::warn!(
+                        "Failed getting inclusion data for blob {} via DA client #{i}: {err}",
+                        blob_ids[i]
+                    );
+                    first_error.get_or_insert(err);
+                    inclusion_data.push(vec![]);
+                }
+            }
+        }
+
+        if included_count >= self.inclusion_quorum {
+            return Ok(Some(aggregate_inclusion_data(inclusion_data)));
+        }
+        match first_error {
+            Some(err) => Err(err),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the blob from the first underlying client that has it.
+    async fn get_blob(&self, blob_id: &str) -> Result<Option<Vec<u8>>, DAError> {
+        let blob_ids = self.parse_blob_id(blob_id)?;
+        let mut first_error = None;
+        for (i, (client, blob_id)) in self.clients.iter().zip(&blob_ids).enumerate() {
+            match client.get_blob(blob_id).await {
+                Ok(Some(blob)) => return Ok(Some(blob)),
+                Ok(None) => { /* try the next client */ }
+                Err(err) => {
+                    tracing::warn!("Failed getting blob {blob_id} via DA client #{i}: {err}");
+                    first_error.get_or_insert(err);
+                }
+            }
+        }
+
+        match first_error {
+            Some(err) => Err(err),
+            None => Ok(None),
+        }
+    }
+
+    fn clone_boxed(&self) -> Box<dyn DataAvailabilityClient> {
+        Box::new(self.clone())
+    }
+
+    fn blob_size_limit(&self) -> Option<usize> {
+        self.clients
+            .iter()
+            .filter_map(|client| client.blob_size_limit())
+            .min()
+    }
+}
+
+/// ABI-encodes the inclusion data of all underlying clients as `bytes[]`, in the order of clients.
+/// Clients that didn't provide the inclusion data are represented by empty bytes.
+fn aggregate_inclusion_data(inclusion_data: Vec<Vec<u8>>) -> InclusionData {
+    let tokens = inclusion_data.into_iter().map(Token::Bytes).collect();
+    InclusionData {
+        data: ethabi::encode(&[Token::Array(tokens)]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    use super::*;
+
+    /// Mock client returning blob IDs prefixed by its name.
+    #[derive(Debug, Clone)]
+    struct MockDAClient {
+        name: &'static str,
+        is_included: Arc<AtomicBool>,
+        fails_dispatch: Arc<AtomicBool>,
+        /// Whether dispatch errors are non-retriable.
+        fails_permanently: Arc<AtomicBool>,
+        dispatch_count: Arc<AtomicUsize>,
+        blob_size_limit: Option<usize>,
+    }
+
+    impl MockDAClient {
+        fn new(name: &'static str, blob_size_limit: Option<usize>) -> Self {
+            Self {
+                name,
+                is_included: Arc::default(),
+                fails_dispatch: Arc::default(),
+                fails_permanently: Arc::default(),
+                dispatch_count: Arc::default(),
+                blob_size_limit,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl DataAvailabilityClient for MockDAClient {
+        async fn dispatch_blob(
+            &self,
+            batch_number: u32,
+            _data: Vec<u8>,
+        ) -> Result<DispatchResponse, DAError> {
+            if self.fails_dispatch.load(Ordering::SeqCst) {
+                return Err(DAError {
+                    error: anyhow::anyhow!("{} is unavailable", self.name),
+                    is_retriable: !self.fails_permanently.load(Ordering::SeqCst),
+                });
+            }
+            self.dispatch_count.fetch_add(1, Ordering::SeqCst);
+            Ok(DispatchResponse::from(format!(
+                "{}:{batch_number}",
+                self.name
+            )))
+        }
+
+        async fn get_inclusion_data(
+            &self,
+            blob_id: &str,
+        ) -> Result<Option<InclusionData>, DAError> {
+            assert!(blob_id.starts_with(self.name), "{blob_id}");
+            Ok(self
+                .is_included
+                .load(Ordering::SeqCst)
+                .then(|| InclusionData {
+                    data: self.name.as_bytes().to_vec(),
+                }))
+        }
+
+        async fn get_blob(&self, blob_id: &str) -> Result<Option<Vec<u8>>, DAError> {
+            assert!(blob_id.starts_with(self.name), "{blob_id}");
+            Ok(self
+                .is_included
+                .load(Ordering::SeqCst)
+                .then(|| self.name.as_bytes().to_vec()))
+        }
+
+        fn clone_boxed(&self) -> Box<dyn DataAvailabilityClient> {
+            Box::new(self.clone())
+        }
+
+        fn blob_size_limit(&self) -> Option<usize> {
+            self.blob_size_limit
+        }
+    }
+
+    #[test]
+    fn creating_client_with_invalid_quorum() {
+        let clients = || -> Vec<Box<dyn DataAvailabilityClient>> {
+            vec![Box::new(MockDAClient::new("first", None))]
+        };
+        MultiDAClient::new(vec![], 1).unwrap_err();
+        MultiDAClient::new(clients(), 0).unwrap_err();
+        MultiDAClient::new(clients(), 2).unwrap_err();
+        MultiDAClient::new(clients(), 1).unwrap();
+    }
+
+    #[tokio::test]
+    async fn dispatching_and_inclusion_quorum() {
+        let first = MockDAClient::new("first", None);
+        let second = MockDAClient::new("second", Some(1_024));
+        let third = MockDAClient::new("third", Some(512));
+        let client = MultiDAClient::new(
+            vec![
+                Box::new(first.clone()),
+                Box::new(second.clone()),
+                Box::new(third),
+            ],
+            2,
+        )
+        .unwrap();
+        assert_eq!(client.blob_size_limit(), Some(512));
+
+        let blob_id = client
+            .dispatch_blob(5, vec![1, 2, 3])
+            .await
+            .unwrap()
+            .blob_id;
+        assert_eq!(blob_id, r#"["first:5","second:5","third:5"]"#);
+
+        assert!(client.get_inclusion_data(&blob_id).await.unwrap().is_none());
+        first.is_included.store(true, Ordering::SeqCst);
+        assert!(client.get_inclusion_data(&blob_id).await.unwrap().is_none());
+        second.is_included.store(true, Ordering::SeqCst);
+        let inclusion_data = client
+            .get_inclusion_data(&blob_id)
+            .await
+            .unwrap()
+            .expect("no inclusion data");
+
+        let expected_tokens = vec![
+            Token::Bytes(b"first".to_vec()),
+            Token::Bytes(b"second".to_vec()),
+            Token::Bytes(vec![]),
+        ];
+        let expected = ethabi::encode(&[Token::Array(expected_tokens)]);
+        assert_eq!(inclusion_data.data, expected);
+
+        assert_eq!(
+            client.get_blob(&blob_id).await.unwrap(),
+            Some(b"first".to_vec())
+        );
+
+        let err = client.get_inclusion_data("first:5").await.unwrap_err();
+        assert!(!err.is_retriable());
+        let err = client
+            .get_inclusion_data(r#"["first:5"]"#)
+            .await
+            .unwrap_err();
+        assert!(!err.is_retriable());
+    }
+
+    #[tokio::test]
+    async fn retrying_dispatch_only_for_failed_clients() {
+        let first = MockDAClient::new("first", None);
+        let second = MockDAClient::new("second", None);
+        second.fails_dispatch.store(true, Ordering::SeqCst);
+        let client =
+            MultiDAClient::new(vec![Box::new(first.clone()), Box::new(second.clone())], 1).unwrap();
+
+        let err = client.dispatch_blob(5, vec![1, 2, 3]).await.unwrap_err();
+        assert!(err.is_retriable());
+        assert_eq!(first.dispatch_count.load(Ordering::SeqCst), 1);
+        assert_eq!(second.dispatch_count.load(Ordering::SeqCst), 0);
+
+        second.fails_dispatch.store(false, Ordering::SeqCst);
+        let blob_id = client
+            .dispatch_blob(5, vec![1, 2, 3])
+            .await
+            .unwrap()
+            .blob_id;
+        assert_eq!(blob_id, r#"["first:5","second:5"]"#);
+        // The blob must not be re-dispatched to the client that has already accepted it.
+        assert_eq!(first.dispatch_count.load(Ordering::SeqCst), 1);
+        assert_eq!(second.dispatch_count.load(Ordering::SeqCst), 1);
+
+        // Once the blob is fully dispatched, dispatching it again is not deduplicated.
+        client.dispatch_blob(5, vec![1, 2, 3]).await.unwrap();
+        assert_eq!(first.dispatch_count.load(Ordering::SeqCst), 2);
+        // Different data for the same batch is dispatched to all clients.
+        second.fails_dispatch.store(true, Ordering::SeqCst);
+        client.dispatch_blob(5, vec![4, 5]).await.unwrap_err();
+        second.fails_dispatch.store(false, Ordering::SeqCst);
+        client.dispatch_blob(5, vec![1, 2, 3]).await.unwrap();
+        assert_eq!(first.dispatch_count.load(Ordering::SeqCst), 4);
+        assert_eq!(second.dispatch_count.load(Ordering::SeqCst), 3);
+        assert!(client.partial_dispatches.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn discarding_partial_dispatch_on_non_retriable_error() {
+        let first = MockDAClient::new("first", None);
+        let second = MockDAClient::new("second", None);
+        second.fails_dispatch.store(true, Ordering::SeqCst);
+        let client =
+            MultiDAClient::new(vec![Box::new(first.clone()), Box::new(second.clone())], 1).unwrap();
+
+        client.dispatch_blob(5, vec![1, 2, 3]).await.unwrap_err();
+        assert_eq!(client.partial_dispatches.lock().unwrap().len(), 1);
+        // Outdated data for the same batch is discarded.
+        client.dispatch_blob(5, vec![4, 5]).await.unwrap_err();
+        assert_eq!(client.partial_dispatches.lock().unwrap().len(), 1);
+        assert_eq!(first.dispatch_count.load(Ordering::SeqCst), 2);
+
+        second.fails_permanently.store(true, Ordering::SeqCst);
+        let err = client.dispatch_blob(5, vec![4, 5]).await.unwrap_err();
+        assert!(!err.is_retriable());
+        assert!(client.partial_dispatches.lock().unwrap().is_empty());
+        assert_eq!(first.dispatch_count.load(Ordering::SeqCst), 2);
+    }
+}
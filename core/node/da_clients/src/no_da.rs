@@ -18,6 +18,10 @@ impl DataAvailabilityClient for NoDAClient {
         return Ok(Some(InclusionData::default()));
     }
 
+    async fn get_blob(&self, _: &str) -> Result<Option<Vec<u8>>, DAError> {
+        Ok(None)
+    }
+
     fn clone_boxed(&self) -> Box<dyn DataAvailabilityClient> {
         Box::new(self.clone())
     }
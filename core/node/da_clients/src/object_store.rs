@@ -1,4 +1,5 @@
 use std::{
+    fmt,
     io::{Read, Write},
     sync::Arc,
 };
@@ -29,20 +30,13 @@ impl ObjectStoreDAClient {
                 .await?,
         })
     }
-}
 
-#[async_trait]
-impl DataAvailabilityClient for ObjectStoreDAClient {
-    async fn dispatch_blob(
+    async fn put_pubdata(
         &self,
-        batch_number: u32,
+        key: PubdataKey,
         data: Vec<u8>,
     ) -> Result<DispatchResponse, DAError> {
-        if let Err(err) = self
-            .object_store
-            .put(L1BatchNumber(batch_number), &StorablePubdata { data })
-            .await
-        {
+        if let Err(err) = self.object_store.put(key, &StorablePubdata { data }).await {
             return Err(DAError {
                 is_retriable: err.is_retriable(),
                 error: anyhow::Error::from(err),
@@ -50,21 +44,80 @@ impl DataAvailabilityClient for ObjectStoreDAClient {
         }
 
         Ok(DispatchResponse {
-            blob_id: batch_number.to_string(),
+            blob_id: key.to_string(),
         })
     }
+}
 
-    async fn get_inclusion_data(&self, key: &str) -> Result<Option<InclusionData>, DAError> {
-        let key_u32 = key.parse::<u32>().map_err(|err| DAError {
-            error: anyhow::Error::from(err).context(format!("Failed to parse blob key: {}", key)),
+/// Key of the pubdata stored in the object store. Pubdata chunks of the same L1 batch are stored separately;
+/// the blob ID of a chunk has the `{l1_batch_number}/{chunk_index}` format.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct PubdataKey {
+    l1_batch_number: L1BatchNumber,
+    chunk_index: Option<usize>,
+}
+
+impl PubdataKey {
+    fn new(l1_batch_number: L1BatchNumber, chunk_index: Option<usize>) -> Self {
+        Self {
+            l1_batch_number,
+            chunk_index,
+        }
+    }
+
+    fn parse(blob_id: &str) -> Result<Self, DAError> {
+        let (l1_batch_number, chunk_index) = match blob_id.split_once('/') {
+            Some((l1_batch_number, chunk_index)) => (l1_batch_number, Some(chunk_index)),
+            None => (blob_id, None),
+        };
+        let parse_error = |err: std::num::ParseIntError| DAError {
+            error: anyhow::Error::from(err)
+                .context(format!("Failed to parse blob key: {}", blob_id)),
             is_retriable: false,
-        })?;
+        };
+
+        let l1_batch_number = l1_batch_number.parse::<u32>().map_err(parse_error)?;
+        let chunk_index = chunk_index
+            .map(|index| index.parse::<usize>())
+            .transpose()
+            .map_err(parse_error)?;
+        Ok(Self::new(L1BatchNumber(l1_batch_number), chunk_index))
+    }
+}
 
-        if let Err(err) = self
-            .object_store
-            .get::<StorablePubdata>(L1BatchNumber(key_u32))
+impl fmt::Display for PubdataKey {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.chunk_index {
+            Some(chunk_index) => write!(formatter, "{}/{chunk_index}", self.l1_batch_number.0),
+            None => write!(formatter, "{}", self.l1_batch_number.0),
+        }
+    }
+}
+
+#[async_trait]
+impl DataAvailabilityClient for ObjectStoreDAClient {
+    async fn dispatch_blob(
+        &self,
+        batch_number: u32,
+        data: Vec<u8>,
+    ) -> Result<DispatchResponse, DAError> {
+        self.put_pubdata(PubdataKey::new(L1BatchNumber(batch_number), None), data)
             .await
-        {
+    }
+
+    async fn dispatch_blob_chunk(
+        &self,
+        batch_number: u32,
+        chunk_index: usize,
+        data: Vec<u8>,
+    ) -> Result<DispatchResponse, DAError> {
+        let key = PubdataKey::new(L1BatchNumber(batch_number), Some(chunk_index));
+        self.put_pubdata(key, data).await
+    }
+
+    async fn get_inclusion_data(&self, key: &str) -> Result<Option<InclusionData>, DAError> {
+        let key = PubdataKey::parse(key)?;
+        if let Err(err) = self.object_store.get::<StorablePubdata>(key).await {
             if let zksync_object_store::ObjectStoreError::KeyNotFound(_) = err {
                 return Ok(None);
             }
@@ -80,6 +133,18 @@ impl DataAvailabilityClient for ObjectStoreDAClient {
         return Ok(Some(InclusionData::default()));
     }
 
+    async fn get_blob(&self, key: &str) -> Result<Option<Vec<u8>>, DAError> {
+        let key = PubdataKey::parse(key)?;
+        match self.object_store.get::<StorablePubdata>(key).await {
+            Ok(pubdata) => Ok(Some(pubdata.data)),
+            Err(zksync_object_store::ObjectStoreError::KeyNotFound(_)) => Ok(None),
+            Err(err) => Err(DAError {
+                is_retriable: err.is_retriable(),
+                error: anyhow::Error::from(err),
+            }),
+        }
+    }
+
     fn clone_boxed(&self) -> Box<dyn DataAvailabilityClient> {
         Box::new(self.clone())
     }
@@ -97,10 +162,16 @@ struct StorablePubdata {
 
 impl StoredObject for StorablePubdata {
     const BUCKET: Bucket = Bucket::DataAvailability;
-    type Key<'a> = L1BatchNumber;
+    type Key<'a> = PubdataKey;
 
     fn encode_key(key: Self::Key<'_>) -> String {
-        format!("l1_batch_{key}_pubdata.gzip")
+        let l1_batch_number = key.l1_batch_number;
+        match key.chunk_index {
+            Some(chunk_index) => {
+                format!("l1_batch_{l1_batch_number}_chunk_{chunk_index}_pubdata.gzip")
+            }
+            None => format!("l1_batch_{l1_batch_number}_pubdata.gzip"),
+        }
     }
 
     fn serialize(&self) -> Result<Vec<u8>, BoxedError> {
@@ -126,9 +197,9 @@ impl StoredObject for StorablePubdata {
 mod tests {
     use tokio::fs;
     use zksync_object_store::{MockObjectStore, StoredObject};
-    use zksync_types::L1BatchNumber;
+    use zksync_types::{pubdata_da::ParsedPubdata, L1BatchNumber};
 
-    use super::StorablePubdata;
+    use super::*;
 
     #[tokio::test]
     async fn test_storable_pubdata_deserialization() {
@@ -147,20 +218,77 @@ mod tests {
         let batch_number = 123;
         let data = vec![1, 2, 3, 4, 5, 6, 123, 255, 0, 0];
 
+        let key = PubdataKey::new(L1BatchNumber(batch_number), None);
+        assert_eq!(
+            StorablePubdata::encode_key(key),
+            "l1_batch_123_pubdata.gzip"
+        );
         let store = MockObjectStore::arc();
         store
-            .put(
-                L1BatchNumber(batch_number),
-                &StorablePubdata { data: data.clone() },
-            )
+            .put(key, &StorablePubdata { data: data.clone() })
             .await
             .unwrap();
 
-        let resp = store
-            .get::<StorablePubdata>(L1BatchNumber(batch_number))
+        let resp = store.get::<StorablePubdata>(key).await.unwrap();
+
+        assert_eq!(data, resp.data);
+    }
+
+    #[tokio::test]
+    async fn getting_blob() {
+        let client = ObjectStoreDAClient {
+            object_store: MockObjectStore::arc(),
+        };
+        assert_eq!(client.get_blob("1").await.unwrap(), None);
+        client.get_blob("not a number").await.unwrap_err();
+
+        let data = vec![1, 2, 3];
+        let blob_id = client.dispatch_blob(1, data.clone()).await.unwrap().blob_id;
+        assert_eq!(client.get_blob(&blob_id).await.unwrap(), Some(data));
+    }
+
+    #[tokio::test]
+    async fn dispatching_blob_chunks() {
+        let client = ObjectStoreDAClient {
+            object_store: MockObjectStore::arc(),
+        };
+        client.get_blob("1/").await.unwrap_err();
+        client.get_blob("1/x").await.unwrap_err();
+
+        let chunks = [vec![1, 2, 3], vec![4, 5, 6], vec![7]];
+        let mut blob_ids = vec![];
+        for (chunk_index, chunk) in chunks.iter().enumerate() {
+            let blob_id = client
+                .dispatch_blob_chunk(1, chunk_index, chunk.clone())
+                .await
+                .unwrap()
+                .blob_id;
+            blob_ids.push(blob_id);
+        }
+        assert_eq!(blob_ids, ["1/0", "1/1", "1/2"]);
+
+        for (blob_id, chunk) in blob_ids.iter().zip(&chunks) {
+            assert_eq!(
+                client.get_blob(blob_id).await.unwrap().as_ref(),
+                Some(chunk)
+            );
+            assert!(client.get_inclusion_data(blob_id).await.unwrap().is_some());
+        }
+        // The non-chunked pubdata for the same batch is stored separately.
+        assert_eq!(client.get_blob("1").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn parsing_stored_pubdata() {
+        let serialized = fs::read("./src/test_data/l1_batch_123_pubdata.gzip")
             .await
             .unwrap();
+        let data = StorablePubdata::deserialize(serialized).unwrap().data;
 
-        assert_eq!(data, resp.data);
+        let pubdata = ParsedPubdata::parse(&data).unwrap();
+        assert_eq!(pubdata.user_logs.len(), 8);
+        assert!(pubdata.l2_to_l1_messages.is_empty());
+        assert!(pubdata.published_bytecodes.is_empty());
+        assert_eq!(pubdata.state_diffs.len(), 48);
     }
 }
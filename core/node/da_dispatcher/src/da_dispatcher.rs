@@ -10,7 +10,11 @@ use zksync_da_client::{
     DataAvailabilityClient,
 };
 use zksync_dal::{ConnectionPool, Core, CoreDal};
-use zksync_types::L1BatchNumber;
+use zksync_types::{
+    ethabi::{self, Token},
+    pubdata_da::DataAvailabilityChunk,
+    L1BatchNumber,
+};
 
 use crate::metrics::METRICS;
 
@@ -27,6 +31,12 @@ impl DataAvailabilityDispatcher {
         config: DADispatcherConfig,
         client: Box<dyn DataAvailabilityClient>,
     ) -> Self {
+        if config.use_dummy_inclusion_data() {
+            tracing::warn!(
+                "DA dispatcher is configured to use dummy inclusion data; batches will be committed \
+                 without inclusion proofs"
+            );
+        }
         Self {
             pool,
             config,
@@ -73,7 +83,7 @@ impl DataAvailabilityDispatcher {
     }
 
     /// Dispatches the blobs to the data availability layer, and saves the blob_id in the database.
-    async fn dispatch(&self) -> anyhow::Result<()> {
+    pub(crate) async fn dispatch(&self) -> anyhow::Result<()> {
         let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
         let batches = conn
             .data_availability_dal()
@@ -83,29 +93,34 @@ impl DataAvailabilityDispatcher {
 
         for batch in batches {
             let dispatch_latency = METRICS.blob_dispatch_latency.start();
-            let dispatch_response = retry(self.config.max_retries(), batch.l1_batch_number, || {
-                self.client
-                    .dispatch_blob(batch.l1_batch_number.0, batch.pubdata.clone())
-            })
-            .await
-            .with_context(|| {
-                format!(
-                    "failed to dispatch a blob with batch_number: {}, pubdata_len: {}",
-                    batch.l1_batch_number,
-                    batch.pubdata.len()
-                )
-            })?;
+            let blob_id = match self.client.blob_size_limit() {
+                Some(limit) if batch.pubdata.len() > limit => {
+                    self.dispatch_chunks(batch.l1_batch_number, &batch.pubdata, limit)
+                        .await?
+                }
+                _ => {
+                    retry(self.config.max_retries(), batch.l1_batch_number, || {
+                        self.client
+                            .dispatch_blob(batch.l1_batch_number.0, batch.pubdata.clone())
+                    })
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "failed to dispatch a blob with batch_number: {}, pubdata_len: {}",
+                            batch.l1_batch_number,
+                            batch.pubdata.len()
+                        )
+                    })?
+                    .blob_id
+                }
+            };
             let dispatch_latency_duration = dispatch_latency.observe();
 
             let sent_at = Utc::now().naive_utc();
 
             let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
             conn.data_availability_dal()
-                .insert_l1_batch_da(
-                    batch.l1_batch_number,
-                    dispatch_response.blob_id.as_str(),
-                    sent_at,
-                )
+                .insert_l1_batch_da(batch.l1_batch_number, blob_id.as_str(), sent_at)
                 .await?;
             drop(conn);
 
@@ -123,8 +138,73 @@ impl DataAvailabilityDispatcher {
         Ok(())
     }
 
+    /// Splits the pubdata of an L1 batch that exceeds the blob size limit into chunks and dispatches
+    /// each of them as a separate blob. Chunks dispatched on previous iterations are not re-dispatched.
+    /// Returns the composite blob_id, which is a JSON array of blob_ids of all chunks (the same encoding
+    /// as used by the multi-DA client).
+    async fn dispatch_chunks(
+        &self,
+        l1_batch_number: L1BatchNumber,
+        pubdata: &[u8],
+        limit: usize,
+    ) -> anyhow::Result<String> {
+        let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
+        let dispatched_chunks = conn
+            .data_availability_dal()
+            .get_l1_batch_da_chunks(l1_batch_number)
+            .await?;
+        drop(conn);
+
+        let chunks: Vec<_> = pubdata.chunks(limit).collect();
+        let mut blob_ids = Vec::with_capacity(chunks.len());
+        for (chunk_index, chunk) in chunks.iter().enumerate() {
+            let dispatched_chunk = dispatched_chunks
+                .iter()
+                .find(|dispatched| dispatched.chunk_index == chunk_index);
+            if let Some(dispatched_chunk) = dispatched_chunk {
+                blob_ids.push(dispatched_chunk.blob_id.clone());
+                continue;
+            }
+
+            let dispatch_response = retry(self.config.max_retries(), l1_batch_number, || {
+                self.client
+                    .dispatch_blob_chunk(l1_batch_number.0, chunk_index, chunk.to_vec())
+            })
+            .await
+            .with_context(|| {
+                format!(
+                    "failed to dispatch chunk #{chunk_index} of a blob with batch_number: {}, chunk_len: {}",
+                    l1_batch_number,
+                    chunk.len()
+                )
+            })?;
+
+            let sent_at = Utc::now().naive_utc();
+            let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
+            conn.data_availability_dal()
+                .insert_l1_batch_da_chunk(
+                    l1_batch_number,
+                    chunk_index,
+                    dispatch_response.blob_id.as_str(),
+                    sent_at,
+                )
+                .await?;
+            drop(conn);
+
+            tracing::debug!(
+                "Dispatched chunk #{chunk_index} of the DA blob for batch_number: {}, chunk_size: {}",
+                l1_batch_number,
+                chunk.len()
+            );
+            blob_ids.push(dispatch_response.blob_id);
+        }
+
+        METRICS.blob_chunks.observe(chunks.len());
+        serde_json::to_string(&blob_ids).context("failed serializing composite blob_id")
+    }
+
     /// Polls the data availability layer for inclusion data, and saves it in the database.
-    async fn poll_for_inclusion(&self) -> anyhow::Result<()> {
+    pub(crate) async fn poll_for_inclusion(&self) -> anyhow::Result<()> {
         let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
         let blob_info = conn
             .data_availability_dal()
@@ -136,16 +216,28 @@ impl DataAvailabilityDispatcher {
             return Ok(());
         };
 
-        let inclusion_data = if self.config.use_dummy_inclusion_data() {
-            self.client
-                .get_inclusion_data(blob_info.blob_id.as_str())
-                .await
-                .with_context(|| {
-                    format!(
-                        "failed to get inclusion data for blob_id: {}, batch_number: {}",
-                        blob_info.blob_id, blob_info.l1_batch_number
-                    )
-                })?
+        let inclusion_data = if !self.config.use_dummy_inclusion_data() {
+            let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
+            let chunks = conn
+                .data_availability_dal()
+                .get_l1_batch_da_chunks(blob_info.l1_batch_number)
+                .await?;
+            drop(conn);
+
+            if chunks.is_empty() {
+                self.client
+                    .get_inclusion_data(blob_info.blob_id.as_str())
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "failed to get inclusion data for blob_id: {}, batch_number: {}",
+                            blob_info.blob_id, blob_info.l1_batch_number
+                        )
+                    })?
+                    .map(|inclusion_data| aggregate_inclusion_data(vec![inclusion_data.data]))
+            } else {
+                self.poll_for_chunks_inclusion(chunks).await?
+            }
         } else {
             // if the inclusion verification is disabled, we don't need to wait for the inclusion
             // data before committing the batch, so simply return an empty vector
@@ -181,6 +273,61 @@ impl DataAvailabilityDispatcher {
 
         Ok(())
     }
+
+    /// Polls the data availability layer for inclusion data of the chunks that don't have it yet.
+    /// Returns the aggregated inclusion data once it's available for all chunks of the batch.
+    async fn poll_for_chunks_inclusion(
+        &self,
+        chunks: Vec<DataAvailabilityChunk>,
+    ) -> anyhow::Result<Option<InclusionData>> {
+        let mut chunks_inclusion_data = Vec::with_capacity(chunks.len());
+        for chunk in chunks {
+            if let Some(inclusion_data) = chunk.inclusion_data {
+                chunks_inclusion_data.push(inclusion_data);
+                continue;
+            }
+
+            let inclusion_data = self
+                .client
+                .get_inclusion_data(chunk.blob_id.as_str())
+                .await
+                .with_context(|| {
+                    format!(
+                        "failed to get inclusion data for blob_id: {}, batch_number: {}, chunk #{}",
+                        chunk.blob_id, chunk.l1_batch_number, chunk.chunk_index
+                    )
+                })?;
+            let Some(inclusion_data) = inclusion_data else {
+                return Ok(None);
+            };
+
+            let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
+            conn.data_availability_dal()
+                .save_l1_batch_chunk_inclusion_data(
+                    chunk.l1_batch_number,
+                    chunk.chunk_index,
+                    inclusion_data.data.as_slice(),
+                )
+                .await?;
+            drop(conn);
+            chunks_inclusion_data.push(inclusion_data.data);
+        }
+
+        Ok(Some(aggregate_inclusion_data(chunks_inclusion_data)))
+    }
+}
+
+/// ABI-encodes the inclusion data of all chunks of the L1 batch pubdata as `bytes[]`, in the order of chunks.
+/// Pubdata dispatched as a single blob is treated as a single chunk, so that the inclusion data has the same format
+/// regardless of chunking.
+fn aggregate_inclusion_data(chunks_inclusion_data: Vec<Vec<u8>>) -> InclusionData {
+    let tokens = chunks_inclusion_data
+        .into_iter()
+        .map(Token::Bytes)
+        .collect();
+    InclusionData {
+        data: ethabi::encode(&[Token::Array(tokens)]),
+    }
 }
 
 async fn retry<T, Fut, F>(
@@ -1,4 +1,7 @@
-pub use self::da_dispatcher::DataAvailabilityDispatcher;
+pub use self::{da_dispatcher::DataAvailabilityDispatcher, verifier::DataAvailabilityVerifier};
 
 mod da_dispatcher;
 mod metrics;
+#[cfg(test)]
+mod tests;
+mod verifier;
@@ -1,6 +1,6 @@
 use std::time::Duration;
 
-use vise::{Buckets, Gauge, Histogram, Metrics, Unit};
+use vise::{Buckets, Counter, Gauge, Histogram, Metrics, Unit};
 
 /// Buckets for `blob_dispatch_latency` (from 0.1 to 120 seconds).
 const DISPATCH_LATENCIES: Buckets =
@@ -19,6 +19,9 @@ pub(super) struct DataAvailabilityDispatcherMetrics {
     /// Buckets are bytes ranging from 1 KB to 16 MB, which has to satisfy all blob size values.
     #[metrics(buckets = Buckets::exponential(1_024.0..=16.0 * 1_024.0 * 1_024.0, 2.0), unit = Unit::Bytes)]
     pub blob_size: Histogram<usize>,
+    /// Number of chunks the L1 batch pubdata was split into, if it exceeded the blob size limit.
+    #[metrics(buckets = Buckets::linear(1.0..=16.0, 1.0))]
+    pub blob_chunks: Histogram<usize>,
 
     /// Number of transactions resent by the DA dispatcher.
     #[metrics(buckets = Buckets::linear(0.0..=10.0, 1.0))]
@@ -27,6 +30,15 @@ pub(super) struct DataAvailabilityDispatcherMetrics {
     pub last_dispatched_l1_batch: Gauge<usize>,
     /// Last L1 batch that has its inclusion finalized by DA layer.
     pub last_included_l1_batch: Gauge<usize>,
+    /// Last L1 batch which blob fetched from the DA layer matched the local pubdata.
+    pub last_verified_l1_batch: Gauge<usize>,
+    /// Number of L1 batches which blobs fetched from the DA layer didn't match the local pubdata.
+    pub verification_failures: Counter,
+    /// Number of L1 batches which blobs couldn't be verified because the local pubdata is not available.
+    pub unverifiable_blobs: Counter,
+    /// Number of failed attempts to fetch dispatched blobs from the DA layer for verification (e.g., because
+    /// a blob is not found); such blobs are re-checked on the next iterations.
+    pub unavailable_blobs: Counter,
 }
 
 #[vise::register]
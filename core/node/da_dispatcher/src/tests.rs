@@ -0,0 +1,347 @@
+//! Tests for the DA dispatcher.
+
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use async_trait::async_trait;
+use zksync_config::{
+    configs::object_store::ObjectStoreMode, DADispatcherConfig, ObjectStoreConfig,
+};
+use zksync_da_client::{
+    types::{DAError, DispatchResponse, InclusionData},
+    DataAvailabilityClient,
+};
+use zksync_da_clients::object_store::ObjectStoreDAClient;
+use zksync_dal::{Connection, ConnectionPool, Core, CoreDal};
+use zksync_node_genesis::{insert_genesis_batch, GenesisParams};
+use zksync_node_test_utils::create_l1_batch;
+use zksync_types::{
+    ethabi::{self, Token},
+    L1BatchNumber,
+};
+
+use crate::DataAvailabilityDispatcher;
+
+/// Wrapper enforcing a blob size limit on the wrapped DA client, so that the dispatcher splits pubdata into chunks.
+#[derive(Debug, Clone)]
+struct LimitedDAClient<C> {
+    inner: C,
+    blob_size_limit: usize,
+}
+
+#[async_trait]
+impl<C> DataAvailabilityClient for LimitedDAClient<C>
+where
+    C: DataAvailabilityClient + Clone + 'static,
+{
+    async fn dispatch_blob(
+        &self,
+        batch_number: u32,
+        data: Vec<u8>,
+    ) -> Result<DispatchResponse, DAError> {
+        assert!(data.len() <= self.blob_size_limit);
+        self.inner.dispatch_blob(batch_number, data).await
+    }
+
+    async fn dispatch_blob_chunk(
+        &self,
+        batch_number: u32,
+        chunk_index: usize,
+        data: Vec<u8>,
+    ) -> Result<DispatchResponse, DAError> {
+        assert!(data.len() <= self.blob_size_limit);
+        self.inner
+            .dispatch_blob_chunk(batch_number, chunk_index, data)
+            .await
+    }
+
+    async fn get_inclusion_data(&self, blob_id: &str) -> Result<Option<InclusionData>, DAError> {
+        self.inner.get_inclusion_data(blob_id).await
+    }
+
+    async fn get_blob(&self, blob_id: &str) -> Result<Option<Vec<u8>>, DAError> {
+        self.inner.get_blob(blob_id).await
+    }
+
+    fn clone_boxed(&self) -> Box<dyn DataAvailabilityClient> {
+        Box::new(self.clone())
+    }
+
+    fn blob_size_limit(&self) -> Option<usize> {
+        Some(self.blob_size_limit)
+    }
+}
+
+/// In-memory DA client. The inclusion data of a blob is its ID.
+#[derive(Debug, Clone)]
+pub(crate) struct MockDAClient {
+    blobs: Arc<Mutex<HashMap<String, Vec<u8>>>>,
+    is_included: Arc<AtomicBool>,
+    blob_size_limit: Option<usize>,
+}
+
+impl MockDAClient {
+    pub(crate) fn new(blob_size_limit: Option<usize>) -> Self {
+        Self {
+            blobs: Arc::default(),
+            is_included: Arc::default(),
+            blob_size_limit,
+        }
+    }
+}
+
+#[async_trait]
+impl DataAvailabilityClient for MockDAClient {
+    async fn dispatch_blob(
+        &self,
+        batch_number: u32,
+        data: Vec<u8>,
+    ) -> Result<DispatchResponse, DAError> {
+        if let Some(limit) = self.blob_size_limit {
+            assert!(data.len() <= limit);
+        }
+        let mut blobs = self.blobs.lock().unwrap();
+        let blob_id = format!("{batch_number}:{}", blobs.len());
+        blobs.insert(blob_id.clone(), data);
+        Ok(DispatchResponse::from(blob_id))
+    }
+
+    async fn get_inclusion_data(&self, blob_id: &str) -> Result<Option<InclusionData>, DAError> {
+        assert!(
+            self.blobs.lock().unwrap().contains_key(blob_id),
+            "{blob_id}"
+        );
+        Ok(self
+            .is_included
+            .load(Ordering::SeqCst)
+            .then(|| InclusionData {
+                data: blob_id.as_bytes().to_vec(),
+            }))
+    }
+
+    async fn get_blob(&self, blob_id: &str) -> Result<Option<Vec<u8>>, DAError> {
+        Ok(self.blobs.lock().unwrap().get(blob_id).cloned())
+    }
+
+    fn clone_boxed(&self) -> Box<dyn DataAvailabilityClient> {
+        Box::new(self.clone())
+    }
+
+    fn blob_size_limit(&self) -> Option<usize> {
+        self.blob_size_limit
+    }
+}
+
+pub(crate) async fn insert_l1_batch_with_pubdata(
+    storage: &mut Connection<'_, Core>,
+    number: u32,
+    pubdata: Vec<u8>,
+) {
+    let mut header = create_l1_batch(number);
+    header.pubdata_input = Some(pubdata);
+    storage
+        .blocks_dal()
+        .insert_mock_l1_batch(&header)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn dispatching_chunked_pubdata_via_object_store() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    insert_genesis_batch(&mut storage, &GenesisParams::mock())
+        .await
+        .unwrap();
+    let pubdata: Vec<u8> = (0..10).collect();
+    insert_l1_batch_with_pubdata(&mut storage, 1, pubdata.clone()).await;
+
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let object_store_config = ObjectStoreConfig {
+        mode: ObjectStoreMode::FileBacked {
+            file_backed_base_path: temp_dir.path().to_str().unwrap().to_owned(),
+        },
+        max_retries: 0,
+        local_mirror_path: None,
+        zstd_compression_level: None,
+    };
+    let client = LimitedDAClient {
+        inner: ObjectStoreDAClient::new(object_store_config).await.unwrap(),
+        blob_size_limit: 4,
+    };
+    let dispatcher = DataAvailabilityDispatcher::new(
+        pool.clone(),
+        DADispatcherConfig::for_tests(),
+        Box::new(client.clone()),
+    );
+    dispatcher.dispatch().await.unwrap();
+
+    let chunks = storage
+        .data_availability_dal()
+        .get_l1_batch_da_chunks(L1BatchNumber(1))
+        .await
+        .unwrap();
+    assert_eq!(chunks.len(), 3);
+
+    // Each chunk must be stored separately, rather than overwrite the previous chunks.
+    let mut fetched_pubdata = vec![];
+    for chunk in &chunks {
+        let blob = client.get_blob(&chunk.blob_id).await.unwrap();
+        fetched_pubdata.extend(blob.expect("chunk is missing"));
+    }
+    assert_eq!(fetched_pubdata, pubdata);
+}
+
+#[tokio::test]
+async fn dispatching_chunked_pubdata_and_polling_inclusion() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    insert_genesis_batch(&mut storage, &GenesisParams::mock())
+        .await
+        .unwrap();
+    let pubdata: Vec<u8> = (0..10).collect();
+    insert_l1_batch_with_pubdata(&mut storage, 1, pubdata.clone()).await;
+
+    let client = MockDAClient::new(Some(4));
+    let config = DADispatcherConfig::for_tests();
+    let dispatcher =
+        DataAvailabilityDispatcher::new(pool.clone(), config, Box::new(client.clone()));
+    dispatcher.dispatch().await.unwrap();
+
+    let blob = storage
+        .data_availability_dal()
+        .get_l1_batch_da_blob(L1BatchNumber(1))
+        .await
+        .unwrap()
+        .expect("blob was not dispatched");
+    let chunk_blob_ids: Vec<String> = serde_json::from_str(&blob.blob_id).unwrap();
+    assert_eq!(chunk_blob_ids, ["1:0", "1:1", "1:2"]);
+    let chunks = storage
+        .data_availability_dal()
+        .get_l1_batch_da_chunks(L1BatchNumber(1))
+        .await
+        .unwrap();
+    let stored_blob_ids: Vec<_> = chunks.iter().map(|chunk| chunk.blob_id.clone()).collect();
+    assert_eq!(stored_blob_ids, chunk_blob_ids);
+
+    let mut fetched_pubdata = vec![];
+    for blob_id in &chunk_blob_ids {
+        fetched_pubdata.extend(client.get_blob(blob_id).await.unwrap().unwrap());
+    }
+    assert_eq!(fetched_pubdata, pubdata);
+
+    // Blobs are not included yet.
+    dispatcher.poll_for_inclusion().await.unwrap();
+    let blob = storage
+        .data_availability_dal()
+        .get_l1_batch_da_blob(L1BatchNumber(1))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(blob.inclusion_data, None);
+
+    client.is_included.store(true, Ordering::SeqCst);
+    dispatcher.poll_for_inclusion().await.unwrap();
+    let blob = storage
+        .data_availability_dal()
+        .get_l1_batch_da_blob(L1BatchNumber(1))
+        .await
+        .unwrap()
+        .unwrap();
+    let expected_tokens = chunk_blob_ids
+        .iter()
+        .map(|blob_id| Token::Bytes(blob_id.as_bytes().to_vec()))
+        .collect();
+    let expected_inclusion_data = ethabi::encode(&[Token::Array(expected_tokens)]);
+    assert_eq!(blob.inclusion_data, Some(expected_inclusion_data));
+
+    // Pubdata fitting into a single blob is dispatched without chunking.
+    insert_l1_batch_with_pubdata(&mut storage, 2, vec![1, 2, 3]).await;
+    dispatcher.dispatch().await.unwrap();
+    let blob = storage
+        .data_availability_dal()
+        .get_l1_batch_da_blob(L1BatchNumber(2))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(blob.blob_id, "2:3");
+    let chunks = storage
+        .data_availability_dal()
+        .get_l1_batch_da_chunks(L1BatchNumber(2))
+        .await
+        .unwrap();
+    assert!(chunks.is_empty());
+}
+
+#[tokio::test]
+async fn polling_inclusion_data_from_client_by_default() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    insert_genesis_batch(&mut storage, &GenesisParams::mock())
+        .await
+        .unwrap();
+    insert_l1_batch_with_pubdata(&mut storage, 1, vec![1, 2, 3]).await;
+
+    let client = MockDAClient::new(None);
+    let dispatcher = DataAvailabilityDispatcher::new(
+        pool.clone(),
+        DADispatcherConfig::for_tests(),
+        Box::new(client.clone()),
+    );
+    dispatcher.dispatch().await.unwrap();
+
+    // The batch must not be marked as included until the client provides the inclusion data.
+    dispatcher.poll_for_inclusion().await.unwrap();
+    let blob = storage
+        .data_availability_dal()
+        .get_l1_batch_da_blob(L1BatchNumber(1))
+        .await
+        .unwrap()
+        .expect("blob was not dispatched");
+    assert_eq!(blob.inclusion_data, None);
+
+    client.is_included.store(true, Ordering::SeqCst);
+    dispatcher.poll_for_inclusion().await.unwrap();
+    let blob = storage
+        .data_availability_dal()
+        .get_l1_batch_da_blob(L1BatchNumber(1))
+        .await
+        .unwrap()
+        .unwrap();
+    // Inclusion data for a single blob has the same format as for chunked pubdata.
+    let expected_tokens = vec![Token::Bytes(blob.blob_id.as_bytes().to_vec())];
+    let expected_inclusion_data = ethabi::encode(&[Token::Array(expected_tokens)]);
+    assert_eq!(blob.inclusion_data, Some(expected_inclusion_data));
+}
+
+#[tokio::test]
+async fn using_dummy_inclusion_data() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    insert_genesis_batch(&mut storage, &GenesisParams::mock())
+        .await
+        .unwrap();
+    insert_l1_batch_with_pubdata(&mut storage, 1, vec![1, 2, 3]).await;
+
+    let config = DADispatcherConfig {
+        use_dummy_inclusion_data: Some(true),
+        ..DADispatcherConfig::for_tests()
+    };
+    let dispatcher =
+        DataAvailabilityDispatcher::new(pool.clone(), config, Box::new(MockDAClient::new(None)));
+    dispatcher.dispatch().await.unwrap();
+    dispatcher.poll_for_inclusion().await.unwrap();
+
+    let blob = storage
+        .data_availability_dal()
+        .get_l1_batch_da_blob(L1BatchNumber(1))
+        .await
+        .unwrap()
+        .expect("blob was not dispatched");
+    assert_eq!(blob.inclusion_data, Some(vec![]));
+}
@@ -0,0 +1,515 @@
+use std::time::Duration;
+
+use serde::Serialize;
+use tokio::sync::watch::Receiver;
+use zksync_da_client::{types::DAError, DataAvailabilityClient};
+use zksync_dal::{ConnectionPool, Core, CoreDal};
+use zksync_health_check::{Health, HealthStatus, HealthUpdater, ReactiveHealthCheck};
+use zksync_types::{pubdata_da::DataAvailabilityBlob, L1BatchNumber};
+
+use crate::metrics::METRICS;
+
+/// Health details reported by [`DataAvailabilityVerifier`].
+#[derive(Debug, Default, Serialize)]
+struct VerifierDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_verified_batch: Option<L1BatchNumber>,
+    /// Last batch which blob couldn't be verified because its pubdata is not available locally.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_unverifiable_batch: Option<L1BatchNumber>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    mismatched_batches: Vec<L1BatchNumber>,
+}
+
+/// Outcome of checking a dispatched blob against the local pubdata.
+#[derive(Debug, PartialEq)]
+enum BlobVerification {
+    Matched,
+    Mismatched(String),
+    /// The blob couldn't be fetched from the DA layer (e.g., it's not found, or the DA client returned
+    /// a non-retriable error). Such a blob is re-checked on the following iterations, and is only considered
+    /// mismatched once the max number of attempts is reached.
+    Unavailable(String),
+    /// The local pubdata is not available (e.g., it was pruned), so the blob cannot be checked.
+    PubdataMissing,
+}
+
+impl VerifierDetails {
+    fn health(&self) -> Health {
+        let status = if self.mismatched_batches.is_empty() {
+            HealthStatus::Ready
+        } else {
+            HealthStatus::Affected
+        };
+        Health::from(status).with_details(self)
+    }
+}
+
+/// Periodically re-fetches the blobs dispatched by [`DataAvailabilityDispatcher`](crate::DataAvailabilityDispatcher)
+/// from the DA layer and checks that they match the L1 batch pubdata stored in Postgres.
+///
+/// Verification results are persisted, so that a mismatch is picked up by the DA verification circuit breaker.
+/// Blobs that cannot be fetched because of transient errors are re-checked on the next iteration. Blobs that are
+/// not found on the DA layer are re-checked as well, but are considered mismatched after `max_attempts` iterations.
+/// A mismatch can be cleared by an operator (e.g., via the `reset-verification` command of the pubdata reconstructor),
+/// after which the blob is verified again.
+#[derive(Debug)]
+pub struct DataAvailabilityVerifier {
+    client: Box<dyn DataAvailabilityClient>,
+    pool: ConnectionPool<Core>,
+    polling_interval: Duration,
+    max_blobs_per_iteration: usize,
+    max_attempts: u32,
+    health_check: ReactiveHealthCheck,
+    health_updater: HealthUpdater,
+}
+
+impl DataAvailabilityVerifier {
+    pub fn new(
+        pool: ConnectionPool<Core>,
+        client: Box<dyn DataAvailabilityClient>,
+        polling_interval: Duration,
+        max_blobs_per_iteration: usize,
+        max_attempts: u32,
+    ) -> Self {
+        let (health_check, health_updater) = ReactiveHealthCheck::new("da_verifier");
+        Self {
+            client,
+            pool,
+            polling_interval,
+            max_blobs_per_iteration,
+            max_attempts,
+            health_check,
+            health_updater,
+        }
+    }
+
+    /// Returns health check associated with this verifier.
+    pub fn health_check(&self) -> &ReactiveHealthCheck {
+        &self.health_check
+    }
+
+    pub async fn run(self, mut stop_receiver: Receiver<bool>) -> anyhow::Result<()> {
+        let mut details = VerifierDetails::default();
+        let mut conn = self.pool.connection_tagged("da_verifier").await?;
+        if let Some((number, _)) = conn
+            .data_availability_dal()
+            .get_first_failed_da_verification()
+            .await?
+        {
+            details.mismatched_batches.push(number);
+        }
+        drop(conn);
+        self.health_updater.update(details.health());
+
+        while !*stop_receiver.borrow() {
+            if let Err(err) = self.verify_blobs(&mut details).await {
+                tracing::error!("DA verification error {err:?}");
+            }
+
+            if tokio::time::timeout(self.polling_interval, stop_receiver.changed())
+                .await
+                .is_ok()
+            {
+                break;
+            }
+        }
+
+        tracing::info!("Stop signal received, da_verifier is shutting down");
+        Ok(())
+    }
+
+    async fn verify_blobs(&self, details: &mut VerifierDetails) -> anyhow::Result<()> {
+        let mut conn = self.pool.connection_tagged("da_verifier").await?;
+        let blobs = conn
+            .data_availability_dal()
+            .get_da_blobs_awaiting_verification(self.max_blobs_per_iteration)
+            .await?;
+        drop(conn);
+
+        for blob in blobs {
+            let number = blob.l1_batch_number;
+            let verification = match self.verify_blob(&blob).await {
+                Ok(verification) => verification,
+                Err(err) if err.is_retriable() => {
+                    tracing::warn!("Failed fetching DA blob for batch_number: {number}, will retry later: {err}");
+                    continue;
+                }
+                Err(err) => BlobVerification::Unavailable(format!("failed fetching blob: {err}")),
+            };
+
+            let mut conn = self.pool.connection_tagged("da_verifier").await?;
+            let mismatch = match verification {
+                BlobVerification::Matched => {
+                    conn.data_availability_dal()
+                        .save_l1_batch_da_verification_result(number, None)
+                        .await?;
+                    tracing::info!("Verified the DA blob for batch_number: {number}");
+                    METRICS.last_verified_l1_batch.set(number.0 as usize);
+                    details.last_verified_batch = Some(number);
+                    None
+                }
+                BlobVerification::Mismatched(error) => Some(error),
+                BlobVerification::Unavailable(error) => {
+                    let attempts = conn
+                        .data_availability_dal()
+                        .record_failed_l1_batch_da_verification_attempt(number)
+                        .await?;
+                    if attempts < self.max_attempts {
+                        tracing::warn!(
+                            "DA blob for batch_number: {number} is unavailable (attempt {attempts}/{}), \
+                             will re-check later: {error}",
+                            self.max_attempts
+                        );
+                        METRICS.unavailable_blobs.inc();
+                        None
+                    } else {
+                        Some(format!("{error} (after {attempts} attempts)"))
+                    }
+                }
+                BlobVerification::PubdataMissing => {
+                    conn.data_availability_dal()
+                        .mark_l1_batch_da_pubdata_missing(number)
+                        .await?;
+                    tracing::warn!(
+                        "Pubdata for batch_number: {number} is not available locally, DA blob cannot be verified"
+                    );
+                    METRICS.unverifiable_blobs.inc();
+                    details.last_unverifiable_batch = Some(number);
+                    None
+                }
+            };
+
+            if let Some(error) = mismatch {
+                conn.data_availability_dal()
+                    .save_l1_batch_da_verification_result(number, Some(&error))
+                    .await?;
+                tracing::error!(
+                    "DA blob for batch_number: {number} doesn't match the local pubdata: {error}"
+                );
+                METRICS.verification_failures.inc();
+                details.mismatched_batches.push(number);
+            }
+            drop(conn);
+            self.health_updater.update(details.health());
+        }
+        Ok(())
+    }
+
+    async fn verify_blob(&self, blob: &DataAvailabilityBlob) -> Result<BlobVerification, DAError> {
+        let number = blob.l1_batch_number;
+        let mut conn = self
+            .pool
+            .connection_tagged("da_verifier")
+            .await
+            .map_err(to_internal_error)?;
+        let Some(pubdata) = conn
+            .data_availability_dal()
+            .get_l1_batch_pubdata(number)
+            .await
+            .map_err(to_internal_error)?
+        else {
+            return Ok(BlobVerification::PubdataMissing);
+        };
+        let chunks = conn
+            .data_availability_dal()
+            .get_l1_batch_da_chunks(number)
+            .await
+            .map_err(to_internal_error)?;
+        drop(conn);
+
+        let blob_ids = if chunks.is_empty() {
+            vec![blob.blob_id.clone()]
+        } else {
+            chunks.into_iter().map(|chunk| chunk.blob_id).collect()
+        };
+
+        let mut posted_data = Vec::with_capacity(pubdata.len());
+        for blob_id in &blob_ids {
+            let Some(data) = self.client.get_blob(blob_id).await? else {
+                return Ok(BlobVerification::Unavailable(format!(
+                    "blob {blob_id} is not found on the DA layer"
+                )));
+            };
+            posted_data.extend(data);
+        }
+        Ok(match compare_blob(&posted_data, &pubdata) {
+            Some(error) => BlobVerification::Mismatched(error),
+            None => BlobVerification::Matched,
+        })
+    }
+}
+
+fn to_internal_error(err: impl Into<anyhow::Error>) -> DAError {
+    DAError {
+        error: err.into().context("failed accessing Postgres"),
+        is_retriable: true,
+    }
+}
+
+fn compare_blob(posted_data: &[u8], pubdata: &[u8]) -> Option<String> {
+    if posted_data.len() != pubdata.len() {
+        return Some(format!(
+            "posted data size ({}) differs from the pubdata size ({})",
+            posted_data.len(),
+            pubdata.len()
+        ));
+    }
+    let offset = posted_data
+        .iter()
+        .zip(pubdata)
+        .position(|(posted, expected)| posted != expected)?;
+    Some(format!(
+        "posted data differs from the pubdata at byte {offset}"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use zksync_da_client::types::{DispatchResponse, InclusionData};
+    use zksync_dal::Connection;
+    use zksync_health_check::CheckHealth;
+    use zksync_node_genesis::{insert_genesis_batch, GenesisParams};
+    use zksync_node_test_utils::create_l1_batch;
+
+    use super::*;
+    use crate::tests::{insert_l1_batch_with_pubdata, MockDAClient};
+
+    async fn dispatch_blob(
+        storage: &mut Connection<'_, Core>,
+        client: &MockDAClient,
+        number: u32,
+        data: Vec<u8>,
+    ) {
+        let blob_id = client.dispatch_blob(number, data).await.unwrap().blob_id;
+        storage
+            .data_availability_dal()
+            .insert_l1_batch_da(
+                L1BatchNumber(number),
+                &blob_id,
+                chrono::Utc::now().naive_utc(),
+            )
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    fn comparing_blobs() {
+        assert_eq!(compare_blob(&[1, 2, 3], &[1, 2, 3]), None);
+        assert_eq!(
+            compare_blob(&[1, 2], &[1, 2, 3]).unwrap(),
+            "posted data size (2) differs from the pubdata size (3)"
+        );
+        assert_eq!(
+            compare_blob(&[1, 5, 3], &[1, 2, 3]).unwrap(),
+            "posted data differs from the pubdata at byte 1"
+        );
+    }
+
+    #[tokio::test]
+    async fn verifying_blobs() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut storage = pool.connection().await.unwrap();
+        insert_genesis_batch(&mut storage, &GenesisParams::mock())
+            .await
+            .unwrap();
+        insert_l1_batch_with_pubdata(&mut storage, 1, vec![1, 2, 3]).await;
+        insert_l1_batch_with_pubdata(&mut storage, 2, vec![4, 5, 6]).await;
+        let mut header = create_l1_batch(3);
+        header.pubdata_input = None;
+        storage
+            .blocks_dal()
+            .insert_mock_l1_batch(&header)
+            .await
+            .unwrap();
+
+        let client = MockDAClient::new(None);
+        dispatch_blob(&mut storage, &client, 1, vec![1, 2, 3]).await;
+        dispatch_blob(&mut storage, &client, 2, vec![4, 5, 0]).await;
+        dispatch_blob(&mut storage, &client, 3, vec![7, 8, 9]).await;
+
+        let verifier =
+            DataAvailabilityVerifier::new(pool.clone(), Box::new(client), Duration::ZERO, 10, 3);
+        let mut details = VerifierDetails::default();
+        verifier.verify_blobs(&mut details).await.unwrap();
+
+        assert_eq!(details.last_verified_batch, Some(L1BatchNumber(1)));
+        assert_eq!(details.mismatched_batches, [L1BatchNumber(2)]);
+        assert_eq!(details.last_unverifiable_batch, Some(L1BatchNumber(3)));
+        let health = verifier.health_check().check_health().await;
+        assert_matches!(health.status(), HealthStatus::Affected);
+
+        let mut dal = storage.data_availability_dal();
+        assert!(dal
+            .get_da_blobs_awaiting_verification(10)
+            .await
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            dal.get_first_failed_da_verification().await.unwrap(),
+            Some((
+                L1BatchNumber(2),
+                "posted data differs from the pubdata at byte 2".to_owned()
+            ))
+        );
+
+        // Verified blobs are not re-checked.
+        let mut details = VerifierDetails::default();
+        verifier.verify_blobs(&mut details).await.unwrap();
+        assert_eq!(details.last_verified_batch, None);
+        assert!(details.mismatched_batches.is_empty());
+        assert_eq!(details.last_unverifiable_batch, None);
+    }
+
+    #[tokio::test]
+    async fn rechecking_unavailable_blobs() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut storage = pool.connection().await.unwrap();
+        insert_genesis_batch(&mut storage, &GenesisParams::mock())
+            .await
+            .unwrap();
+        insert_l1_batch_with_pubdata(&mut storage, 1, vec![1, 2, 3]).await;
+        insert_l1_batch_with_pubdata(&mut storage, 2, vec![4, 5, 6]).await;
+        storage
+            .data_availability_dal()
+            .insert_l1_batch_da(L1BatchNumber(1), "missing", chrono::Utc::now().naive_utc())
+            .await
+            .unwrap();
+        let client = MockDAClient::new(None);
+        dispatch_blob(&mut storage, &client, 2, vec![4, 5, 6]).await;
+
+        let verifier =
+            DataAvailabilityVerifier::new(pool.clone(), Box::new(client), Duration::ZERO, 10, 2);
+        let mut details = VerifierDetails::default();
+        verifier.verify_blobs(&mut details).await.unwrap();
+        // The missing blob doesn't block verification of the following blobs, and isn't flagged yet.
+        assert_eq!(details.last_verified_batch, Some(L1BatchNumber(2)));
+        assert!(details.mismatched_batches.is_empty());
+        let blobs = storage
+            .data_availability_dal()
+            .get_da_blobs_awaiting_verification(10)
+            .await
+            .unwrap();
+        assert_eq!(blobs.len(), 1);
+        assert_eq!(blobs[0].l1_batch_number, L1BatchNumber(1));
+
+        verifier.verify_blobs(&mut details).await.unwrap();
+        assert_eq!(details.mismatched_batches, [L1BatchNumber(1)]);
+        assert_eq!(
+            storage
+                .data_availability_dal()
+                .get_first_failed_da_verification()
+                .await
+                .unwrap(),
+            Some((
+                L1BatchNumber(1),
+                "blob missing is not found on the DA layer (after 2 attempts)".to_owned()
+            ))
+        );
+
+        // The mismatch can be cleared, after which the blob is re-checked.
+        storage
+            .data_availability_dal()
+            .reset_l1_batch_da_verification(L1BatchNumber(1))
+            .await
+            .unwrap();
+        assert_eq!(
+            storage
+                .data_availability_dal()
+                .get_first_failed_da_verification()
+                .await
+                .unwrap(),
+            None
+        );
+        let mut details = VerifierDetails::default();
+        verifier.verify_blobs(&mut details).await.unwrap();
+        assert!(details.mismatched_batches.is_empty());
+    }
+
+    /// Client failing to fetch the specified blob with a retriable error.
+    #[derive(Debug, Clone)]
+    struct FlakyDAClient {
+        inner: MockDAClient,
+        failing_blob_id: String,
+    }
+
+    #[async_trait::async_trait]
+    impl DataAvailabilityClient for FlakyDAClient {
+        async fn dispatch_blob(
+            &self,
+            batch_number: u32,
+            data: Vec<u8>,
+        ) -> Result<DispatchResponse, DAError> {
+            self.inner.dispatch_blob(batch_number, data).await
+        }
+
+        async fn get_inclusion_data(
+            &self,
+            blob_id: &str,
+        ) -> Result<Option<InclusionData>, DAError> {
+            self.inner.get_inclusion_data(blob_id).await
+        }
+
+        async fn get_blob(&self, blob_id: &str) -> Result<Option<Vec<u8>>, DAError> {
+            if blob_id == self.failing_blob_id {
+                return Err(DAError {
+                    error: anyhow::anyhow!("connection reset"),
+                    is_retriable: true,
+                });
+            }
+            self.inner.get_blob(blob_id).await
+        }
+
+        fn clone_boxed(&self) -> Box<dyn DataAvailabilityClient> {
+            Box::new(self.clone())
+        }
+
+        fn blob_size_limit(&self) -> Option<usize> {
+            None
+        }
+    }
+
+    #[tokio::test]
+    async fn retriable_errors_do_not_block_other_blobs() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut storage = pool.connection().await.unwrap();
+        insert_genesis_batch(&mut storage, &GenesisParams::mock())
+            .await
+            .unwrap();
+        insert_l1_batch_with_pubdata(&mut storage, 1, vec![1, 2, 3]).await;
+        insert_l1_batch_with_pubdata(&mut storage, 2, vec![4, 5, 6]).await;
+        let client = MockDAClient::new(None);
+        dispatch_blob(&mut storage, &client, 1, vec![1, 2, 3]).await;
+        dispatch_blob(&mut storage, &client, 2, vec![4, 5, 6]).await;
+        let failing_blob_id = storage
+            .data_availability_dal()
+            .get_l1_batch_da_blob(L1BatchNumber(1))
+            .await
+            .unwrap()
+            .unwrap()
+            .blob_id;
+
+        let client = FlakyDAClient {
+            inner: client,
+            failing_blob_id,
+        };
+        let verifier =
+            DataAvailabilityVerifier::new(pool.clone(), Box::new(client), Duration::ZERO, 10, 1);
+        let mut details = VerifierDetails::default();
+        verifier.verify_blobs(&mut details).await.unwrap();
+        assert_eq!(details.last_verified_batch, Some(L1BatchNumber(2)));
+        assert!(details.mismatched_batches.is_empty());
+
+        // Retriable errors are not counted as failed attempts.
+        verifier.verify_blobs(&mut details).await.unwrap();
+        assert!(details.mismatched_batches.is_empty());
+        let blobs = storage
+            .data_availability_dal()
+            .get_da_blobs_awaiting_verification(10)
+            .await
+            .unwrap();
+        assert_eq!(blobs.len(), 1);
+        assert_eq!(blobs[0].l1_batch_number, L1BatchNumber(1));
+    }
+}
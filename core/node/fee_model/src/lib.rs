@@ -15,6 +15,7 @@ use zksync_utils::ceil_div_u256;
 use crate::l1_gas_price::GasAdjuster;
 
 pub mod l1_gas_price;
+pub mod priority_fee;
 
 /// Trait responsible for providing numerator and denominator for adjusting gas price that is denominated
 /// in a non-eth base token
@@ -0,0 +1,109 @@
+//! Utilities to reason about priority fees charged from L2 transactions, used by `eth_feeHistory`
+//! and `eth_maxPriorityFeePerGas` methods.
+
+use zksync_types::{fee::TxPriorityFee, U256};
+
+/// Percentile of priority fees (weighted by gas used) in each L2 block used to suggest a priority fee.
+const SUGGESTED_PRIORITY_FEE_PERCENTILE: f32 = 60.0;
+
+/// Checks that reward percentiles are in the `[0, 100]` range and are sorted in the ascending order.
+pub fn validate_reward_percentiles(percentiles: &[f32]) -> bool {
+    let in_range = percentiles
+        .iter()
+        .all(|&percentile| (0.0..=100.0).contains(&percentile));
+    in_range && percentiles.windows(2).all(|window| window[0] <= window[1])
+}
+
+/// Computes rewards for an L2 block at the specified percentiles, the same way as Ethereum clients do:
+/// priority fees are sorted in the ascending order, and each reward is the priority fee of a transaction
+/// at which the cumulative gas used reaches the corresponding percentage of gas used by all transactions.
+///
+/// Returns zero rewards for a block without L2 transactions. Percentiles must be validated using
+/// [`validate_reward_percentiles()`].
+pub fn compute_rewards(mut fees: Vec<TxPriorityFee>, percentiles: &[f32]) -> Vec<U256> {
+    if fees.is_empty() {
+        return vec![U256::zero(); percentiles.len()];
+    }
+    fees.sort_unstable_by_key(|fee| fee.priority_fee_per_gas);
+    let total_gas_used: u64 = fees.iter().map(|fee| fee.gas_used).sum();
+
+    let mut tx_index = 0;
+    let mut cumulative_gas_used = fees[0].gas_used;
+    percentiles
+        .iter()
+        .map(|&percentile| {
+            let threshold = (total_gas_used as f64 * f64::from(percentile) / 100.0) as u64;
+            while cumulative_gas_used < threshold && tx_index < fees.len() - 1 {
+                tx_index += 1;
+                cumulative_gas_used += fees[tx_index].gas_used;
+            }
+            fees[tx_index].priority_fee_per_gas
+        })
+        .collect()
+}
+
+/// Suggests a priority fee based on priority fees charged in recent L2 blocks. The suggestion is the median
+/// of priority fees at a fixed percentile across the blocks containing L2 transactions, or zero if there are no
+/// such blocks.
+pub fn suggest_max_priority_fee(recent_fees: impl IntoIterator<Item = Vec<TxPriorityFee>>) -> U256 {
+    let mut block_rewards: Vec<_> = recent_fees
+        .into_iter()
+        .filter(|fees| !fees.is_empty())
+        .map(|fees| compute_rewards(fees, &[SUGGESTED_PRIORITY_FEE_PERCENTILE])[0])
+        .collect();
+    if block_rewards.is_empty() {
+        return U256::zero();
+    }
+    block_rewards.sort_unstable();
+    block_rewards[block_rewards.len() / 2]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fee(priority_fee_per_gas: u64, gas_used: u64) -> TxPriorityFee {
+        TxPriorityFee {
+            priority_fee_per_gas: priority_fee_per_gas.into(),
+            gas_used,
+        }
+    }
+
+    #[test]
+    fn validating_reward_percentiles() {
+        assert!(validate_reward_percentiles(&[]));
+        assert!(validate_reward_percentiles(&[0.0, 25.0, 25.0, 100.0]));
+        assert!(!validate_reward_percentiles(&[50.0, 25.0]));
+        assert!(!validate_reward_percentiles(&[-1.0]));
+        assert!(!validate_reward_percentiles(&[100.5]));
+        assert!(!validate_reward_percentiles(&[f32::NAN]));
+    }
+
+    #[test]
+    fn computing_rewards() {
+        let rewards = compute_rewards(vec![], &[10.0, 90.0]);
+        assert_eq!(rewards, [U256::zero(); 2]);
+
+        let fees = vec![fee(30, 100), fee(10, 100), fee(20, 200)];
+        let rewards = compute_rewards(fees, &[0.0, 25.0, 26.0, 75.0, 76.0, 100.0]);
+        let expected: Vec<U256> = [10_u64, 10, 20, 20, 30, 30]
+            .into_iter()
+            .map(U256::from)
+            .collect();
+        assert_eq!(rewards, expected);
+    }
+
+    #[test]
+    fn suggesting_max_priority_fee() {
+        assert_eq!(suggest_max_priority_fee([]), U256::zero());
+        assert_eq!(suggest_max_priority_fee([vec![], vec![]]), U256::zero());
+
+        let recent_fees = [
+            vec![fee(5, 100)],
+            vec![],
+            vec![fee(1, 100), fee(3, 300)],
+            vec![fee(100, 100)],
+        ];
+        assert_eq!(suggest_max_priority_fee(recent_fees), U256::from(5));
+    }
+}
@@ -1,3 +1,4 @@
 pub mod blocks_state_reporter;
 mod metrics;
+pub mod object_store_retention;
 pub mod periodic_job;
@@ -1,4 +1,4 @@
-use vise::{Gauge, Metrics};
+use vise::{Counter, Gauge, LabeledFamily, Metrics};
 
 #[derive(Debug, Metrics)]
 #[metrics(prefix = "fri_prover")]
@@ -9,3 +9,15 @@ pub(crate) struct FriProverMetrics {
 
 #[vise::register]
 pub(crate) static FRI_PROVER_METRICS: vise::Global<FriProverMetrics> = vise::Global::new();
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "server_object_store_retention")]
+pub(crate) struct ObjectStoreRetentionMetrics {
+    /// Number of objects removed from the object store, labeled by the bucket.
+    #[metrics(labels = ["bucket"])]
+    pub removed_objects: LabeledFamily<String, Counter>,
+}
+
+#[vise::register]
+pub(crate) static OBJECT_STORE_RETENTION_METRICS: vise::Global<ObjectStoreRetentionMetrics> =
+    vise::Global::new();
@@ -0,0 +1,442 @@
+use std::{collections::HashSet, sync::Arc, time::Duration};
+
+use anyhow::Context as _;
+use async_trait::async_trait;
+use futures::TryStreamExt;
+use zksync_dal::{Connection, ConnectionPool, Core, CoreDal, DalResult};
+use zksync_object_store::{Bucket, ObjectStore};
+use zksync_types::L1BatchNumber;
+
+use crate::{metrics::OBJECT_STORE_RETENTION_METRICS, periodic_job::PeriodicJob};
+
+/// Objects with keys of the form `{key_prefix}{l1_batch_number}...` in a specific bucket.
+#[derive(Debug, Clone, Copy)]
+struct L1BatchObjects {
+    bucket: Bucket,
+    key_prefix: &'static str,
+}
+
+impl L1BatchObjects {
+    const fn new(bucket: Bucket, key_prefix: &'static str) -> Self {
+        Self { bucket, key_prefix }
+    }
+
+    /// Extracts the L1 batch number from the object key.
+    fn l1_batch_number(&self, key: &str) -> Option<L1BatchNumber> {
+        let suffix = key.strip_prefix(self.key_prefix)?;
+        let digits_len = suffix
+            .find(|ch: char| !ch.is_ascii_digit())
+            .unwrap_or(suffix.len());
+        suffix[..digits_len].parse().ok().map(L1BatchNumber)
+    }
+}
+
+/// Objects subject to retention. Keys must be kept in sync with the corresponding `StoredObject` implementations.
+const RETAINED_OBJECTS: &[L1BatchObjects] = &[
+    L1BatchObjects::new(Bucket::WitnessInput, "merkel_tree_paths_"),
+    L1BatchObjects::new(Bucket::WitnessInput, "vm_run_data_"),
+    L1BatchObjects::new(Bucket::WitnessInput, "witness_inputs_"),
+    L1BatchObjects::new(Bucket::ProofsFri, "l1_batch_proof_"),
+    L1BatchObjects::new(Bucket::StorageSnapshot, "snapshot_l1_batch_"),
+];
+
+/// Periodically removes witness inputs, proofs and snapshot objects for old L1 batches from the object store,
+/// so that buckets don't grow unboundedly. Snapshot objects are removed from the snapshots object store
+/// (i.e., the one used by the snapshot creator); if it's not set, snapshots are not touched.
+///
+/// Objects are removed for L1 batches that are more than the configured number of batches behind the last
+/// executed L1 batch. The newest snapshot, the newest complete snapshot and snapshots for L1 batches after
+/// the retention threshold are never removed, together with all base snapshots they (transitively) depend on,
+/// so that nodes can recover from them. When a snapshot is removed, its metadata is deleted from Postgres
+/// before its objects, so that the snapshot is no longer advertised to nodes (e.g., via `snapshots_getAllSnapshots`).
+#[derive(Debug)]
+pub struct ObjectStoreRetentionJob {
+    pool: ConnectionPool<Core>,
+    object_store: Arc<dyn ObjectStore>,
+    snapshots_object_store: Option<Arc<dyn ObjectStore>>,
+    retained_l1_batches: u32,
+    interval: Duration,
+}
+
+impl ObjectStoreRetentionJob {
+    pub fn new(
+        pool: ConnectionPool<Core>,
+        object_store: Arc<dyn ObjectStore>,
+        retained_l1_batches: u32,
+        interval: Duration,
+    ) -> Self {
+        Self {
+            pool,
+            object_store,
+            snapshots_object_store: None,
+            retained_l1_batches,
+            interval,
+        }
+    }
+
+    /// Sets the object store containing snapshot objects.
+    #[must_use]
+    pub fn with_snapshots_object_store(mut self, object_store: Arc<dyn ObjectStore>) -> Self {
+        self.snapshots_object_store = Some(object_store);
+        self
+    }
+
+    async fn remove_stale_objects(&self) -> anyhow::Result<()> {
+        let last_executed_l1_batch = self
+            .pool
+            .connection_tagged("object_store_retention")
+            .await?
+            .blocks_dal()
+            .get_number_of_last_l1_batch_executed_on_eth()
+            .await?;
+        let Some(last_executed_l1_batch) = last_executed_l1_batch else {
+            return Ok(());
+        };
+        let Some(threshold) = last_executed_l1_batch
+            .0
+            .checked_sub(self.retained_l1_batches)
+        else {
+            return Ok(());
+        };
+        self.remove_objects_before(L1BatchNumber(threshold)).await
+    }
+
+    /// Removes objects for L1 batches before `threshold`.
+    async fn remove_objects_before(&self, threshold: L1BatchNumber) -> anyhow::Result<()> {
+        let retained_snapshots = if self.snapshots_object_store.is_some() {
+            let mut conn = self
+                .pool
+                .connection_tagged("object_store_retention")
+                .await?;
+            let retained_snapshots = Self::retained_snapshots(&mut conn, threshold).await?;
+            let retained_snapshots_list: Vec<_> = retained_snapshots.iter().copied().collect();
+            let removed_snapshots = conn
+                .snapshots_dal()
+                .delete_snapshots_before(threshold, &retained_snapshots_list)
+                .await?;
+            if !removed_snapshots.is_empty() {
+                tracing::info!(
+                    "Removed metadata of snapshots for L1 batches {removed_snapshots:?}"
+                );
+            }
+            retained_snapshots
+        } else {
+            HashSet::new()
+        };
+
+        let no_retained_batches = HashSet::new();
+        for objects in RETAINED_OBJECTS {
+            let (object_store, retained_l1_batches) = if objects.bucket == Bucket::StorageSnapshot {
+                let Some(object_store) = &self.snapshots_object_store else {
+                    continue;
+                };
+                (object_store, &retained_snapshots)
+            } else {
+                (&self.object_store, &no_retained_batches)
+            };
+            remove_objects(
+                object_store.as_ref(),
+                objects,
+                threshold,
+                retained_l1_batches,
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    /// Returns L1 batches of snapshots that must be retained: the newest snapshot (which may be in progress),
+    /// the newest complete snapshot, snapshots at or after `threshold` and all snapshots reachable from them
+    /// via `base_l1_batch_number`.
+    async fn retained_snapshots(
+        conn: &mut Connection<'_, Core>,
+        threshold: L1BatchNumber,
+    ) -> DalResult<HashSet<L1BatchNumber>> {
+        let newest_snapshot = conn
+            .snapshots_dal()
+            .get_newest_snapshot_metadata()
+            .await?
+            .map(|snapshot| snapshot.l1_batch_number);
+        let newest_complete_snapshot = conn
+            .snapshots_dal()
+            .get_all_complete_snapshots()
+            .await?
+            .snapshots_l1_batch_numbers
+            .first()
+            .copied();
+        let recent_snapshots = conn
+            .snapshots_dal()
+            .get_snapshots_after(L1BatchNumber(threshold.0.saturating_sub(1)))
+            .await?
+            .into_iter()
+            .map(|snapshot| Some(snapshot.l1_batch_number));
+
+        let mut retained_snapshots = HashSet::new();
+        let roots = [newest_snapshot, newest_complete_snapshot]
+            .into_iter()
+            .chain(recent_snapshots);
+        for mut next_snapshot in roots {
+            while let Some(l1_batch_number) = next_snapshot {
+                if !retained_snapshots.insert(l1_batch_number) {
+                    break; // The remaining chain is already retained
+                }
+                next_snapshot = conn
+                    .snapshots_dal()
+                    .get_snapshot_metadata(l1_batch_number)
+                    .await?
+                    .and_then(|snapshot| snapshot.base_l1_batch_number);
+            }
+        }
+        Ok(retained_snapshots)
+    }
+}
+
+/// Removes `objects` for L1 batches before `threshold`, except for `retained_l1_batches`.
+async fn remove_objects(
+    object_store: &dyn ObjectStore,
+    objects: &L1BatchObjects,
+    threshold: L1BatchNumber,
+    retained_l1_batches: &HashSet<L1BatchNumber>,
+) -> anyhow::Result<()> {
+    let stale_keys: Vec<_> = object_store
+        .list_raw(objects.bucket, objects.key_prefix)
+        .try_filter(|key| {
+            let is_stale = objects
+                .l1_batch_number(key)
+                .is_some_and(|number| number < threshold && !retained_l1_batches.contains(&number));
+            async move { is_stale }
+        })
+        .try_collect()
+        .await
+        .with_context(|| {
+            format!(
+                "failed listing objects with prefix `{}` in bucket {}",
+                objects.key_prefix, objects.bucket
+            )
+        })?;
+
+    for key in &stale_keys {
+        object_store
+            .remove_raw(objects.bucket, key)
+            .await
+            .with_context(|| format!("failed removing object `{key}` from {}", objects.bucket))?;
+    }
+    if !stale_keys.is_empty() {
+        tracing::info!(
+            "Removed {} objects with prefix `{}` for L1 batches before #{threshold} from bucket {}",
+            stale_keys.len(),
+            objects.key_prefix,
+            objects.bucket
+        );
+        OBJECT_STORE_RETENTION_METRICS.removed_objects[&objects.bucket.to_string()]
+            .inc_by(stale_keys.len() as u64);
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl PeriodicJob for ObjectStoreRetentionJob {
+    const SERVICE_NAME: &'static str = "ObjectStoreRetentionJob";
+
+    async fn run_routine_task(&mut self) -> anyhow::Result<()> {
+        // Object store errors are not fatal; stale objects will be removed on the next iteration.
+        if let Err(err) = self.remove_stale_objects().await {
+            tracing::warn!("Failed removing stale objects from the object store: {err:#}");
+        }
+        Ok(())
+    }
+
+    fn polling_interval_ms(&self) -> u64 {
+        self.interval.as_millis() as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_object_store::MockObjectStore;
+    use zksync_types::snapshots::SnapshotVersion;
+
+    use super::*;
+
+    #[test]
+    fn extracting_l1_batch_number_from_key() {
+        let objects = L1BatchObjects::new(Bucket::StorageSnapshot, "snapshot_l1_batch_");
+        assert_eq!(
+            objects.l1_batch_number("snapshot_l1_batch_42_storage_logs_part_0001.proto.gzip"),
+            Some(L1BatchNumber(42))
+        );
+        assert_eq!(
+            objects.l1_batch_number("snapshot_l1_batch_7_factory_deps.proto.gzip"),
+            Some(L1BatchNumber(7))
+        );
+        assert_eq!(objects.l1_batch_number("snapshot_l1_batch_.bin"), None);
+        assert_eq!(objects.l1_batch_number("other_42.bin"), None);
+
+        let objects = L1BatchObjects::new(Bucket::WitnessInput, "witness_inputs_");
+        assert_eq!(
+            objects.l1_batch_number("witness_inputs_123.bin"),
+            Some(L1BatchNumber(123))
+        );
+    }
+
+    #[tokio::test]
+    async fn removing_stale_objects() {
+        let object_store = MockObjectStore::arc();
+        for number in 0..5 {
+            let key = format!("snapshot_l1_batch_{number}_factory_deps.proto.gzip");
+            object_store
+                .put_raw(Bucket::StorageSnapshot, &key, vec![1])
+                .await
+                .unwrap();
+        }
+        object_store
+            .put_raw(Bucket::StorageSnapshot, "unrelated.bin", vec![1])
+            .await
+            .unwrap();
+
+        let objects = L1BatchObjects::new(Bucket::StorageSnapshot, "snapshot_l1_batch_");
+        remove_objects(
+            object_store.as_ref(),
+            &objects,
+            L1BatchNumber(3),
+            &HashSet::from([L1BatchNumber(1)]),
+        )
+        .await
+        .unwrap();
+
+        let mut keys: Vec<_> = object_store
+            .list_raw(Bucket::StorageSnapshot, "")
+            .try_collect()
+            .await
+            .unwrap();
+        keys.sort_unstable();
+        assert_eq!(
+            keys,
+            [
+                "snapshot_l1_batch_1_factory_deps.proto.gzip",
+                "snapshot_l1_batch_3_factory_deps.proto.gzip",
+                "snapshot_l1_batch_4_factory_deps.proto.gzip",
+                "unrelated.bin",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn retaining_snapshot_chains() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        let retained = ObjectStoreRetentionJob::retained_snapshots(&mut conn, L1BatchNumber(17))
+            .await
+            .unwrap();
+        assert!(retained.is_empty());
+
+        let snapshots = [
+            (5, None),
+            (10, None),
+            (15, Some(10)),
+            (20, Some(10)),
+            (30, Some(20)),
+        ];
+        for (number, base) in snapshots {
+            conn.snapshots_dal()
+                .add_snapshot(
+                    SnapshotVersion::Version1,
+                    L1BatchNumber(number),
+                    1,
+                    &format!("factory_deps_{number}.bin"),
+                    base.map(L1BatchNumber),
+                )
+                .await
+                .unwrap();
+            // The newest snapshot is still being created.
+            if number != 30 {
+                conn.snapshots_dal()
+                    .add_storage_logs_filepath_for_snapshot(
+                        L1BatchNumber(number),
+                        0,
+                        &format!("storage_logs_{number}.bin"),
+                    )
+                    .await
+                    .unwrap();
+            }
+        }
+
+        let retained = ObjectStoreRetentionJob::retained_snapshots(&mut conn, L1BatchNumber(17))
+            .await
+            .unwrap();
+        let expected = HashSet::from([10, 20, 30].map(L1BatchNumber));
+        assert_eq!(retained, expected);
+        // Snapshots after the threshold are retained together with their bases.
+        let retained = ObjectStoreRetentionJob::retained_snapshots(&mut conn, L1BatchNumber(12))
+            .await
+            .unwrap();
+        let expected = HashSet::from([10, 15, 20, 30].map(L1BatchNumber));
+        assert_eq!(retained, expected);
+    }
+
+    #[tokio::test]
+    async fn removing_snapshot_metadata_together_with_objects() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        let snapshots_object_store = MockObjectStore::arc();
+        for (number, base) in [(5, None), (10, None), (15, Some(10)), (20, None)] {
+            let factory_deps_key = format!("snapshot_l1_batch_{number}_factory_deps.proto.gzip");
+            snapshots_object_store
+                .put_raw(Bucket::StorageSnapshot, &factory_deps_key, vec![1])
+                .await
+                .unwrap();
+            conn.snapshots_dal()
+                .add_snapshot(
+                    SnapshotVersion::Version1,
+                    L1BatchNumber(number),
+                    1,
+                    &factory_deps_key,
+                    base.map(L1BatchNumber),
+                )
+                .await
+                .unwrap();
+            conn.snapshots_dal()
+                .add_storage_logs_filepath_for_snapshot(
+                    L1BatchNumber(number),
+                    0,
+                    &format!("storage_logs_{number}.bin"),
+                )
+                .await
+                .unwrap();
+        }
+
+        let job = ObjectStoreRetentionJob::new(
+            pool.clone(),
+            MockObjectStore::arc(),
+            10,
+            Duration::from_secs(60),
+        )
+        .with_snapshots_object_store(snapshots_object_store.clone());
+        job.remove_objects_before(L1BatchNumber(12)).await.unwrap();
+
+        // Snapshot #5 is removed; #10 is retained as the base of #15.
+        let snapshots = conn
+            .snapshots_dal()
+            .get_all_complete_snapshots()
+            .await
+            .unwrap();
+        assert_eq!(
+            snapshots.snapshots_l1_batch_numbers,
+            [20, 15, 10].map(L1BatchNumber)
+        );
+        let mut keys: Vec<_> = snapshots_object_store
+            .list_raw(Bucket::StorageSnapshot, "")
+            .try_collect()
+            .await
+            .unwrap();
+        keys.sort_unstable();
+        assert_eq!(
+            keys,
+            [
+                "snapshot_l1_batch_10_factory_deps.proto.gzip",
+                "snapshot_l1_batch_15_factory_deps.proto.gzip",
+                "snapshot_l1_batch_20_factory_deps.proto.gzip",
+            ]
+        );
+    }
+}
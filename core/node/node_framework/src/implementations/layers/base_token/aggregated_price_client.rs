@@ -0,0 +1,118 @@
+use std::sync::Arc;
+
+use anyhow::Context as _;
+use zksync_config::configs::ExternalPriceApiClientConfig;
+use zksync_external_price_api::{
+    aggregated::AggregatedPriceClient, coingecko_api::CoinGeckoPriceAPIClient,
+    coinmarketcap_api::CoinMarketCapPriceAPIClient, forced_price_client::ForcedPriceClient,
+    uniswap_twap::UniswapTwapPriceClient, PriceAPIClient,
+};
+
+use super::{
+    coingecko_client::CoingeckoClientLayer, coinmarketcap_client::CoinMarketCapClientLayer,
+    forced_price_client::ForcedPriceClientLayer, uniswap_twap_client::UniswapTwapClientLayer,
+};
+use crate::{
+    implementations::resources::{
+        eth_interface::EthInterfaceResource, healthcheck::AppHealthCheckResource,
+        price_api_client::PriceAPIClientResource,
+    },
+    wiring_layer::{WiringError, WiringLayer},
+    FromContext, IntoContext,
+};
+
+/// Wiring layer for `AggregatedPriceClient`
+///
+/// Inserts a resource with a client aggregating base token prices from multiple sources to be used
+/// by the `BaseTokenRatioPersister`, and a health check reporting the health of individual sources.
+#[derive(Debug)]
+pub struct AggregatedPriceClientLayer {
+    config: ExternalPriceApiClientConfig,
+}
+
+impl AggregatedPriceClientLayer {
+    pub fn new(config: ExternalPriceApiClientConfig) -> Self {
+        Self { config }
+    }
+
+    /// Identifier of used client type.
+    /// Can be used to choose the layer for the client based on configuration variables.
+    pub const CLIENT_NAME: &'static str = "aggregated";
+}
+
+#[derive(Debug, FromContext)]
+#[context(crate = crate)]
+pub struct Input {
+    /// Required only if the Uniswap TWAP client is one of the sources.
+    pub eth_client: Option<EthInterfaceResource>,
+    #[context(default)]
+    pub app_health: AppHealthCheckResource,
+}
+
+#[derive(Debug, IntoContext)]
+#[context(crate = crate)]
+pub struct Output {
+    pub price_api_client: PriceAPIClientResource,
+}
+
+fn create_source(
+    config: ExternalPriceApiClientConfig,
+    eth_client: Option<&EthInterfaceResource>,
+) -> anyhow::Result<Arc<dyn PriceAPIClient>> {
+    Ok(match config.source.as_str() {
+        CoingeckoClientLayer::CLIENT_NAME => Arc::new(CoinGeckoPriceAPIClient::new(config)),
+        CoinMarketCapClientLayer::CLIENT_NAME => Arc::new(CoinMarketCapPriceAPIClient::new(config)),
+        ForcedPriceClientLayer::CLIENT_NAME => Arc::new(ForcedPriceClient::new(config)),
+        UniswapTwapClientLayer::CLIENT_NAME => {
+            let twap_config = config
+                .uniswap_twap
+                .context("Uniswap TWAP client started with no config")?;
+            let eth_client = eth_client.context("Uniswap TWAP client requires an L1 client")?;
+            Arc::new(UniswapTwapPriceClient::new(
+                twap_config,
+                eth_client.0.clone(),
+            ))
+        }
+        source => anyhow::bail!("Unsupported price source for the aggregated client: {source}"),
+    })
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for AggregatedPriceClientLayer {
+    type Input = Input;
+    type Output = Output;
+
+    fn layer_name(&self) -> &'static str {
+        "aggregated_price_client"
+    }
+
+    async fn wire(self, input: Self::Input) -> Result<Self::Output, WiringError> {
+        let config = self.config.aggregated.ok_or_else(|| {
+            WiringError::Configuration("aggregated price client started with no config".to_owned())
+        })?;
+        let sources = config
+            .sources
+            .iter()
+            .enumerate()
+            .map(|(i, source_config)| {
+                let name = source_config.source.clone();
+                let client = create_source(source_config.clone(), input.eth_client.as_ref())
+                    .with_context(|| format!("sources[{i}]"))?;
+                Ok((name, client))
+            })
+            .collect::<anyhow::Result<_>>()
+            .map_err(|err| WiringError::Configuration(format!("{err:#}")))?;
+        let client = AggregatedPriceClient::new(&config, sources)
+            .map_err(|err| WiringError::Configuration(format!("{err:#}")))?;
+
+        input
+            .app_health
+            .0
+            .insert_component(client.health_check())
+            .map_err(WiringError::internal)?;
+
+        Ok(Output {
+            price_api_client: Arc::new(client).into(),
+        })
+    }
+}
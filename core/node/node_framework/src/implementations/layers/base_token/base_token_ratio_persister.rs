@@ -9,7 +9,9 @@ use zksync_types::L1ChainId;
 
 use crate::{
     implementations::resources::{
+        circuit_breakers::CircuitBreakersResource,
         eth_interface::EthInterfaceResource,
+        healthcheck::AppHealthCheckResource,
         l1_tx_params::TxParamsResource,
         pools::{MasterPool, PoolResource},
         price_api_client::PriceAPIClientResource,
@@ -40,6 +42,10 @@ pub struct Input {
     pub price_api_client: PriceAPIClientResource,
     pub eth_client: EthInterfaceResource,
     pub tx_params: TxParamsResource,
+    #[context(default)]
+    pub app_health: AppHealthCheckResource,
+    #[context(default)]
+    pub circuit_breakers: CircuitBreakersResource,
 }
 
 #[derive(Debug, IntoContext)]
@@ -115,6 +121,7 @@ impl WiringLayer for BaseTokenRatioPersisterLayer {
             })
             .unwrap_or(BaseTokenL1Behaviour::NoOp);
 
+        let halt_on_guardrail_violation = self.config.halt_on_guardrail_violation;
         let persister = BaseTokenRatioPersister::new(
             master_pool,
             self.config,
@@ -122,6 +129,18 @@ impl WiringLayer for BaseTokenRatioPersisterLayer {
             price_api_client.0,
             l1_behaviour,
         );
+        input
+            .app_health
+            .0
+            .insert_component(persister.health_check())
+            .map_err(WiringError::internal)?;
+        if halt_on_guardrail_violation {
+            input
+                .circuit_breakers
+                .breakers
+                .insert(Box::new(persister.guardrail_checker()))
+                .await;
+        }
 
         Ok(Output { persister })
     }
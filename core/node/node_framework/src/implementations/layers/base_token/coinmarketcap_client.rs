@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use zksync_config::configs::ExternalPriceApiClientConfig;
+use zksync_external_price_api::coinmarketcap_api::CoinMarketCapPriceAPIClient;
+
+use crate::{
+    implementations::resources::price_api_client::PriceAPIClientResource,
+    wiring_layer::{WiringError, WiringLayer},
+    IntoContext,
+};
+
+/// Wiring layer for `CoinMarketCapPriceAPIClient`
+///
+/// Responsible for inserting a resource with a client to get base token prices from CoinMarketCap to be
+/// used by the `BaseTokenRatioPersister`.
+#[derive(Debug)]
+pub struct CoinMarketCapClientLayer {
+    config: ExternalPriceApiClientConfig,
+}
+
+impl CoinMarketCapClientLayer {
+    /// Identifier of used client type.
+    /// Can be used to choose the layer for the client based on configuration variables.
+    pub const CLIENT_NAME: &'static str = "coinmarketcap";
+}
+
+#[derive(Debug, IntoContext)]
+#[context(crate = crate)]
+pub struct Output {
+    pub price_api_client: PriceAPIClientResource,
+}
+
+impl CoinMarketCapClientLayer {
+    pub fn new(config: ExternalPriceApiClientConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for CoinMarketCapClientLayer {
+    type Input = ();
+    type Output = Output;
+
+    fn layer_name(&self) -> &'static str {
+        "coinmarketcap_api_client"
+    }
+
+    async fn wire(self, _input: Self::Input) -> Result<Self::Output, WiringError> {
+        let cmc_client = Arc::new(CoinMarketCapPriceAPIClient::new(self.config));
+
+        Ok(Output {
+            price_api_client: cmc_client.into(),
+        })
+    }
+}
@@ -1,5 +1,8 @@
+pub mod aggregated_price_client;
 pub mod base_token_ratio_persister;
 pub mod base_token_ratio_provider;
 pub mod coingecko_client;
+pub mod coinmarketcap_client;
 pub mod forced_price_client;
 pub mod no_op_external_price_api_client;
+pub mod uniswap_twap_client;
@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use zksync_config::configs::ExternalPriceApiClientConfig;
+use zksync_external_price_api::uniswap_twap::UniswapTwapPriceClient;
+
+use crate::{
+    implementations::resources::{
+        eth_interface::EthInterfaceResource, price_api_client::PriceAPIClientResource,
+    },
+    wiring_layer::{WiringError, WiringLayer},
+    FromContext, IntoContext,
+};
+
+/// Wiring layer for `UniswapTwapPriceClient`
+///
+/// Inserts a resource with a client computing base token prices from a Uniswap pool on L1 to be used
+/// by the `BaseTokenRatioPersister`.
+#[derive(Debug)]
+pub struct UniswapTwapClientLayer {
+    config: ExternalPriceApiClientConfig,
+}
+
+impl UniswapTwapClientLayer {
+    pub fn new(config: ExternalPriceApiClientConfig) -> Self {
+        Self { config }
+    }
+
+    /// Identifier of used client type.
+    /// Can be used to choose the layer for the client based on configuration variables.
+    pub const CLIENT_NAME: &'static str = "uniswap_twap";
+}
+
+#[derive(Debug, FromContext)]
+#[context(crate = crate)]
+pub struct Input {
+    pub eth_client: EthInterfaceResource,
+}
+
+#[derive(Debug, IntoContext)]
+#[context(crate = crate)]
+pub struct Output {
+    pub price_api_client: PriceAPIClientResource,
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for UniswapTwapClientLayer {
+    type Input = Input;
+    type Output = Output;
+
+    fn layer_name(&self) -> &'static str {
+        "uniswap_twap_price_client"
+    }
+
+    async fn wire(self, input: Self::Input) -> Result<Self::Output, WiringError> {
+        let config = self.config.uniswap_twap.ok_or_else(|| {
+            WiringError::Configuration("Uniswap TWAP client started with no config".to_owned())
+        })?;
+        let client = Arc::new(UniswapTwapPriceClient::new(config, input.eth_client.0));
+
+        Ok(Output {
+            price_api_client: client.into(),
+        })
+    }
+}
@@ -1,3 +1,4 @@
 pub mod avail;
+pub mod multi;
 pub mod no_da;
 pub mod object_store;
@@ -0,0 +1,48 @@
+use zksync_config::configs::{
+    da_client::{multi::MultiDAClientConfig, DAClientConfig},
+    secrets::DataAvailabilitySecrets,
+};
+use zksync_da_clients::create_da_client;
+
+use crate::{
+    implementations::resources::da_client::DAClientResource,
+    wiring_layer::{WiringError, WiringLayer},
+    IntoContext,
+};
+
+/// Wiring layer for the DA client dispatching blobs to several DA layers.
+#[derive(Debug)]
+pub struct MultiDAClientWiringLayer {
+    config: MultiDAClientConfig,
+    secrets: Option<DataAvailabilitySecrets>,
+}
+
+impl MultiDAClientWiringLayer {
+    pub fn new(config: MultiDAClientConfig, secrets: Option<DataAvailabilitySecrets>) -> Self {
+        Self { config, secrets }
+    }
+}
+
+#[derive(Debug, IntoContext)]
+#[context(crate = crate)]
+pub struct Output {
+    pub client: DAClientResource,
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for MultiDAClientWiringLayer {
+    type Input = ();
+    type Output = Output;
+
+    fn layer_name(&self) -> &'static str {
+        "multi_da_client_layer"
+    }
+
+    async fn wire(self, _input: Self::Input) -> Result<Self::Output, WiringError> {
+        let client = create_da_client(DAClientConfig::Multi(self.config), self.secrets).await?;
+
+        Ok(Output {
+            client: DAClientResource(client),
+        })
+    }
+}
@@ -1,10 +1,13 @@
+use zksync_circuit_breaker::da_verification::DAVerificationChecker;
 use zksync_config::configs::{chain::StateKeeperConfig, da_dispatcher::DADispatcherConfig};
-use zksync_da_dispatcher::DataAvailabilityDispatcher;
+use zksync_da_dispatcher::{DataAvailabilityDispatcher, DataAvailabilityVerifier};
 
 use crate::{
     implementations::resources::{
+        circuit_breakers::CircuitBreakersResource,
         da_client::DAClientResource,
-        pools::{MasterPool, PoolResource},
+        healthcheck::AppHealthCheckResource,
+        pools::{MasterPool, PoolResource, ReplicaPool},
     },
     service::StopReceiver,
     task::{Task, TaskId},
@@ -13,6 +16,9 @@ use crate::{
 };
 
 /// A layer that wires the data availability dispatcher task.
+///
+/// If blob verification is enabled in the config, also wires the task checking dispatched blobs against
+/// the local pubdata, and adds the corresponding health check and circuit breaker.
 #[derive(Debug)]
 pub struct DataAvailabilityDispatcherLayer {
     state_keeper_config: StateKeeperConfig,
@@ -24,6 +30,11 @@ pub struct DataAvailabilityDispatcherLayer {
 pub struct Input {
     pub master_pool: PoolResource<MasterPool>,
     pub da_client: DAClientResource,
+    pub replica_pool: PoolResource<ReplicaPool>,
+    #[context(default)]
+    pub app_health: AppHealthCheckResource,
+    #[context(default)]
+    pub circuit_breakers: CircuitBreakersResource,
 }
 
 #[derive(Debug, IntoContext)]
@@ -31,6 +42,8 @@ pub struct Input {
 pub struct Output {
     #[context(task)]
     pub da_dispatcher_task: DataAvailabilityDispatcher,
+    #[context(task)]
+    pub da_verifier_task: Option<DataAvailabilityVerifier>,
 }
 
 impl DataAvailabilityDispatcherLayer {
@@ -65,10 +78,38 @@ impl WiringLayer for DataAvailabilityDispatcherLayer {
             }
         }
 
+        let da_verifier_task = if let Some(interval) = self.da_config.verification_interval() {
+            let verifier = DataAvailabilityVerifier::new(
+                input.master_pool.get_singleton().await?,
+                da_client.clone(),
+                interval,
+                self.da_config.max_rows_to_dispatch() as usize,
+                self.da_config.verification_max_attempts(),
+            );
+            input
+                .app_health
+                .0
+                .insert_component(verifier.health_check().clone())
+                .map_err(WiringError::internal)?;
+
+            let replica_pool = input.replica_pool.get().await?;
+            input
+                .circuit_breakers
+                .breakers
+                .insert(Box::new(DAVerificationChecker { pool: replica_pool }))
+                .await;
+            Some(verifier)
+        } else {
+            None
+        };
+
         let da_dispatcher_task =
             DataAvailabilityDispatcher::new(master_pool, self.da_config, da_client);
 
-        Ok(Output { da_dispatcher_task })
+        Ok(Output {
+            da_dispatcher_task,
+            da_verifier_task,
+        })
     }
 }
 
@@ -82,3 +123,14 @@ impl Task for DataAvailabilityDispatcher {
         (*self).run(stop_receiver.0).await
     }
 }
+
+#[async_trait::async_trait]
+impl Task for DataAvailabilityVerifier {
+    fn id(&self) -> TaskId {
+        "da_verifier".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        (*self).run(stop_receiver.0).await
+    }
+}
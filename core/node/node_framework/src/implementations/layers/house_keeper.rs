@@ -1,10 +1,18 @@
-use zksync_config::configs::house_keeper::HouseKeeperConfig;
+use zksync_config::{
+    configs::{house_keeper::HouseKeeperConfig, secrets::ObjectStoreSecrets},
+    ObjectStoreConfig,
+};
 use zksync_house_keeper::{
-    blocks_state_reporter::L1BatchMetricsReporter, periodic_job::PeriodicJob,
+    blocks_state_reporter::L1BatchMetricsReporter, object_store_retention::ObjectStoreRetentionJob,
+    periodic_job::PeriodicJob,
 };
+use zksync_object_store::ObjectStoreFactory;
 
 use crate::{
-    implementations::resources::pools::{PoolResource, ReplicaPool},
+    implementations::resources::{
+        object_store::ObjectStoreResource,
+        pools::{PoolResource, ReplicaPool},
+    },
     service::StopReceiver,
     task::{Task, TaskId},
     wiring_layer::{WiringError, WiringLayer},
@@ -16,12 +24,15 @@ use crate::{
 #[derive(Debug)]
 pub struct HouseKeeperLayer {
     house_keeper_config: HouseKeeperConfig,
+    snapshots_object_store_config: Option<(ObjectStoreConfig, Option<ObjectStoreSecrets>)>,
 }
 
 #[derive(Debug, FromContext)]
 #[context(crate = crate)]
 pub struct Input {
     pub replica_pool: PoolResource<ReplicaPool>,
+    /// Required if object store retention is enabled.
+    pub object_store: Option<ObjectStoreResource>,
 }
 
 #[derive(Debug, IntoContext)]
@@ -29,14 +40,28 @@ pub struct Input {
 pub struct Output {
     #[context(task)]
     pub l1_batch_metrics_reporter: L1BatchMetricsReporter,
+    #[context(task)]
+    pub object_store_retention_job: Option<ObjectStoreRetentionJob>,
 }
 
 impl HouseKeeperLayer {
     pub fn new(house_keeper_config: HouseKeeperConfig) -> Self {
         Self {
             house_keeper_config,
+            snapshots_object_store_config: None,
         }
     }
+
+    /// Sets the config of the object store used by the snapshot creator. If not set, snapshot objects
+    /// are not subject to object store retention.
+    pub fn with_snapshots_object_store(
+        mut self,
+        config: ObjectStoreConfig,
+        secrets: Option<ObjectStoreSecrets>,
+    ) -> Self {
+        self.snapshots_object_store_config = Some((config, secrets));
+        self
+    }
 }
 
 #[async_trait::async_trait]
@@ -59,8 +84,36 @@ impl WiringLayer for HouseKeeperLayer {
             replica_pool.clone(),
         );
 
+        let object_store_retention_job = if let Some(retained_l1_batches) =
+            self.house_keeper_config.object_store_retention_l1_batches
+        {
+            let object_store = input.object_store.ok_or_else(|| {
+                WiringError::Configuration(
+                    "object store retention is enabled, but object store is not configured"
+                        .to_owned(),
+                )
+            })?;
+            let mut job = ObjectStoreRetentionJob::new(
+                replica_pool,
+                object_store.0,
+                retained_l1_batches,
+                self.house_keeper_config.object_store_retention_interval(),
+            );
+            if let Some((config, secrets)) = self.snapshots_object_store_config {
+                let snapshots_object_store = ObjectStoreFactory::new(config)
+                    .with_secrets(secrets)
+                    .create_store()
+                    .await?;
+                job = job.with_snapshots_object_store(snapshots_object_store);
+            }
+            Some(job)
+        } else {
+            None
+        };
+
         Ok(Output {
             l1_batch_metrics_reporter,
+            object_store_retention_job,
         })
     }
 }
@@ -75,3 +128,14 @@ impl Task for L1BatchMetricsReporter {
         (*self).run(stop_receiver.0).await
     }
 }
+
+#[async_trait::async_trait]
+impl Task for ObjectStoreRetentionJob {
+    fn id(&self) -> TaskId {
+        "object_store_retention_job".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        (*self).run(stop_receiver.0).await
+    }
+}
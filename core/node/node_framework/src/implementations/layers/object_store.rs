@@ -1,4 +1,4 @@
-use zksync_config::ObjectStoreConfig;
+use zksync_config::{configs::secrets::ObjectStoreSecrets, ObjectStoreConfig};
 use zksync_object_store::ObjectStoreFactory;
 
 use crate::{
@@ -10,11 +10,21 @@ use crate::{
 #[derive(Debug)]
 pub struct ObjectStoreLayer {
     config: ObjectStoreConfig,
+    secrets: Option<ObjectStoreSecrets>,
 }
 
 impl ObjectStoreLayer {
     pub fn new(config: ObjectStoreConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            secrets: None,
+        }
+    }
+
+    /// Sets secrets for the object store (e.g., the S3 secret access key).
+    pub fn with_secrets(mut self, secrets: Option<ObjectStoreSecrets>) -> Self {
+        self.secrets = secrets;
+        self
     }
 }
 
@@ -28,7 +38,10 @@ impl WiringLayer for ObjectStoreLayer {
     }
 
     async fn wire(self, _input: Self::Input) -> Result<Self::Output, WiringError> {
-        let object_store = ObjectStoreFactory::new(self.config).create_store().await?;
+        let object_store = ObjectStoreFactory::new(self.config)
+            .with_secrets(self.secrets)
+            .create_store()
+            .await?;
         let resource = ObjectStoreResource(object_store);
         Ok(resource)
     }
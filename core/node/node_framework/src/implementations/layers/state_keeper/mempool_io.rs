@@ -3,11 +3,14 @@ use zksync_config::configs::{
     chain::{MempoolConfig, StateKeeperConfig},
     wallets,
 };
-use zksync_state_keeper::{MempoolFetcher, MempoolGuard, MempoolIO, SequencerSealer};
+use zksync_state_keeper::{
+    seal_criteria::SealCriterion, MempoolFetcher, MempoolGuard, MempoolIO, SequencerSealer,
+};
 use zksync_types::L2ChainId;
 
 use crate::{
     implementations::resources::{
+        da_client::DAClientResource,
         fee_input::SequencerFeeInputResource,
         pools::{MasterPool, PoolResource},
         state_keeper::{ConditionalSealerResource, StateKeeperIOResource},
@@ -24,6 +27,7 @@ use crate::{
 ///
 /// - `FeeInputResource`
 /// - `PoolResource<MasterPool>`
+/// - `DAClientResource` (optional)
 ///
 /// ## Adds resources
 ///
@@ -33,12 +37,17 @@ use crate::{
 /// ## Adds tasks
 ///
 /// - `MempoolFetcherTask`
+///
+/// Custom seal criteria can be registered with [`Self::with_seal_criterion()`]; they are checked
+/// by the provided conditional sealer in addition to the default ones. If a DA client with a blob size limit
+/// is provided, L1 batches are sealed so that their pubdata fits into a single blob.
 #[derive(Debug)]
 pub struct MempoolIOLayer {
     zksync_network_id: L2ChainId,
     state_keeper_config: StateKeeperConfig,
     mempool_config: MempoolConfig,
     wallets: wallets::StateKeeper,
+    seal_criteria: Vec<Box<dyn SealCriterion>>,
 }
 
 #[derive(Debug, FromContext)]
@@ -46,6 +55,7 @@ pub struct MempoolIOLayer {
 pub struct Input {
     pub fee_input: SequencerFeeInputResource,
     pub master_pool: PoolResource<MasterPool>,
+    pub da_client: Option<DAClientResource>,
 }
 
 #[derive(Debug, IntoContext)]
@@ -69,9 +79,17 @@ impl MempoolIOLayer {
             state_keeper_config,
             mempool_config,
             wallets,
+            seal_criteria: Vec::new(),
         }
     }
 
+    /// Registers a custom seal criterion for the sequencer.
+    #[must_use]
+    pub fn with_seal_criterion(mut self, criterion: impl SealCriterion) -> Self {
+        self.seal_criteria.push(Box::new(criterion));
+        self
+    }
+
     async fn build_mempool_guard(
         &self,
         master_pool: &PoolResource<MasterPool>,
@@ -84,7 +102,12 @@ impl MempoolIOLayer {
             .connection()
             .await
             .context("Access storage to build mempool")?;
-        let mempool = MempoolGuard::from_storage(&mut storage, self.mempool_config.capacity).await;
+        let mempool = MempoolGuard::from_storage(
+            &mut storage,
+            self.mempool_config.capacity,
+            self.state_keeper_config.mempool_ordering,
+        )
+        .await;
         mempool.register_metrics();
         Ok(mempool)
     }
@@ -132,7 +155,14 @@ impl WiringLayer for MempoolIOLayer {
         )?;
 
         // Create sealer.
-        let sealer = SequencerSealer::new(self.state_keeper_config);
+        let mut sealer =
+            SequencerSealer::new(self.state_keeper_config).with_criteria(self.seal_criteria);
+        let blob_size_limit = input
+            .da_client
+            .and_then(|client| client.0.blob_size_limit());
+        if let Some(blob_size_limit) = blob_size_limit {
+            sealer = sealer.with_da_blob_size_limit(blob_size_limit);
+        }
 
         Ok(Output {
             state_keeper_io: io.into(),
@@ -10,7 +10,18 @@ use crate::{
 };
 
 /// Wiring layer for [`MasterPoolSink`], [`TxSink`](zksync_node_api_server::tx_sender::tx_sink::TxSink) implementation.
-pub struct MasterPoolSinkLayer;
+#[derive(Debug)]
+pub struct MasterPoolSinkLayer {
+    replacement_fee_bump_percentage: u32,
+}
+
+impl MasterPoolSinkLayer {
+    pub fn new(replacement_fee_bump_percentage: u32) -> Self {
+        Self {
+            replacement_fee_bump_percentage,
+        }
+    }
+}
 
 #[derive(Debug, FromContext)]
 #[context(crate = crate)]
@@ -36,7 +47,7 @@ impl WiringLayer for MasterPoolSinkLayer {
     async fn wire(self, input: Self::Input) -> Result<Self::Output, WiringError> {
         let pool = input.master_pool.get().await?;
         Ok(Output {
-            tx_sink: MasterPoolSink::new(pool).into(),
+            tx_sink: MasterPoolSink::new(pool, self.replacement_fee_bump_percentage).into(),
         })
     }
 }
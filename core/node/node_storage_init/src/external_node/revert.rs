@@ -1,7 +1,7 @@
 use anyhow::Context as _;
 use tokio::sync::watch;
 use zksync_block_reverter::BlockReverter;
-use zksync_dal::{ConnectionPool, Core};
+use zksync_dal::{ConnectionPool, Core, CoreDal};
 use zksync_reorg_detector::ReorgDetector;
 use zksync_types::L1BatchNumber;
 use zksync_web3_decl::client::{DynClient, L2};
@@ -38,6 +38,24 @@ impl RevertStorage for ExternalNodeReverter {
         &self,
         stop_receiver: watch::Receiver<bool>,
     ) -> anyhow::Result<Option<L1BatchNumber>> {
+        // An interrupted rollback must be completed before checking for reorgs since the node state
+        // may be inconsistent across components.
+        let unfinished_revert = self
+            .pool
+            .connection_tagged("node_init")
+            .await?
+            .block_reverts_dal()
+            .get_unfinished_revert()
+            .await?;
+        if let Some(revert) = unfinished_revert {
+            tracing::warn!(
+                "Detected unfinished rollback to L1 batch #{} with completed steps {:?}",
+                revert.l1_batch_number,
+                revert.completed_steps
+            );
+            return Ok(Some(revert.l1_batch_number));
+        }
+
         let mut reorg_detector = ReorgDetector::new(self.client.clone(), self.pool.clone());
         let batch = match reorg_detector.run_once(stop_receiver).await {
             Ok(()) => {
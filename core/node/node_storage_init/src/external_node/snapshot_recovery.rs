@@ -7,7 +7,8 @@ use zksync_health_check::AppHealthCheck;
 use zksync_object_store::ObjectStoreFactory;
 use zksync_shared_metrics::{SnapshotRecoveryStage, APP_METRICS};
 use zksync_snapshots_applier::{
-    RecoveryCompletionStatus, SnapshotsApplierConfig, SnapshotsApplierTask,
+    LocalSnapshotBundle, RecoveryCompletionStatus, SnapshotsApplierConfig,
+    SnapshotsApplierMainNodeClient, SnapshotsApplierTask,
 };
 use zksync_web3_decl::client::{DynClient, L2};
 
@@ -36,24 +37,32 @@ impl InitializeStorage for ExternalNodeSnapshotRecovery {
             );
         }
 
-        let object_store_config =
-            self.recovery_config.object_store_config.clone().context(
-                "Snapshot object store must be presented if snapshot recovery is activated",
-            )?;
-        let object_store = ObjectStoreFactory::new(object_store_config)
-            .create_store()
-            .await?;
+        let (client, object_store): (Box<dyn SnapshotsApplierMainNodeClient>, _) =
+            if let Some(bundle) = self.load_bundle().await? {
+                tracing::info!(
+                    "Recovering from snapshot bundle at {:?}",
+                    self.recovery_config.bundle_path
+                );
+                let object_store = bundle.object_store().await?;
+                (Box::new(bundle), object_store)
+            } else {
+                let object_store_config =
+                    self.recovery_config.object_store_config.clone().context(
+                        "Snapshot object store must be presented if snapshot recovery is activated",
+                    )?;
+                let object_store = ObjectStoreFactory::new(object_store_config)
+                    .create_store()
+                    .await?;
+                let client = self.client.clone().for_component("snapshot_recovery");
+                (Box::new(client), object_store)
+            };
 
         let config = SnapshotsApplierConfig {
             max_concurrency: self.max_concurrency,
             ..SnapshotsApplierConfig::default()
         };
-        let mut snapshots_applier_task = SnapshotsApplierTask::new(
-            config,
-            self.pool.clone(),
-            Box::new(self.client.clone().for_component("snapshot_recovery")),
-            object_store,
-        );
+        let mut snapshots_applier_task =
+            SnapshotsApplierTask::new(config, self.pool.clone(), client, object_store);
         if let Some(snapshot_l1_batch) = self.recovery_config.snapshot_l1_batch_override {
             tracing::info!(
                 "Using a specific snapshot with L1 batch #{snapshot_l1_batch}; this may not work \
@@ -85,15 +94,32 @@ impl InitializeStorage for ExternalNodeSnapshotRecovery {
     }
 
     async fn is_initialized(&self) -> anyhow::Result<bool> {
+        let bundle = self.load_bundle().await?;
+        let client: &dyn SnapshotsApplierMainNodeClient = match &bundle {
+            Some(bundle) => bundle,
+            None => &self.client,
+        };
         let mut storage = self.pool.connection_tagged("en").await?;
         let completed = matches!(
-            SnapshotsApplierTask::is_recovery_completed(&mut storage, &self.client).await?,
+            SnapshotsApplierTask::is_recovery_completed(&mut storage, client).await?,
             RecoveryCompletionStatus::Completed
         );
         Ok(completed)
     }
 }
 
+impl ExternalNodeSnapshotRecovery {
+    async fn load_bundle(&self) -> anyhow::Result<Option<LocalSnapshotBundle>> {
+        let Some(path) = &self.recovery_config.bundle_path else {
+            return Ok(None);
+        };
+        let bundle = LocalSnapshotBundle::load(path.clone())
+            .await
+            .with_context(|| format!("failed loading snapshot bundle from {path:?}"))?;
+        Ok(Some(bundle))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::future;
@@ -131,6 +157,7 @@ mod tests {
                 snapshot_l1_batch_override: None,
                 drop_storage_key_preimages: false,
                 object_store_config: None,
+                bundle_path: None,
             },
             app_health,
         };
@@ -1,4 +1,4 @@
-use std::{future::Future, sync::Arc, time::Duration};
+use std::{future::Future, path::PathBuf, sync::Arc, time::Duration};
 
 use tokio::sync::watch;
 use zksync_config::ObjectStoreConfig;
@@ -17,6 +17,9 @@ pub struct SnapshotRecoveryConfig {
     pub snapshot_l1_batch_override: Option<L1BatchNumber>,
     pub drop_storage_key_preimages: bool,
     pub object_store_config: Option<ObjectStoreConfig>,
+    /// Path to a local snapshot bundle. If specified, the bundle is used instead of the main node client
+    /// and the object store.
+    pub bundle_path: Option<PathBuf>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -125,6 +128,19 @@ impl NodeStorageInitializer {
         }
 
         // Now we may check whether we're in the invalid state and should perform a rollback.
+        // If the node cannot perform rollbacks, it still must not start if a rollback was interrupted midway.
+        if self.strategy.block_reverter.is_none() {
+            let mut storage = self.pool.connection_tagged("node_init").await?;
+            let unfinished_revert = storage.block_reverts_dal().get_unfinished_revert().await?;
+            if let Some(revert) = unfinished_revert {
+                anyhow::bail!(
+                    "Rollback to L1 batch #{} was started, but not finished (completed steps: {:?}). \
+                     Re-run the block reverter with the same L1 batch to complete it",
+                    revert.l1_batch_number,
+                    revert.completed_steps
+                );
+            }
+        }
         if let Some(reverter) = &self.strategy.block_reverter {
             if let Some(to_batch) = reverter
                 .last_correct_batch_for_reorg(stop_receiver.clone())
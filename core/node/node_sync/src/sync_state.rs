@@ -42,6 +42,16 @@ impl SyncState {
         self.0.borrow().local_block.unwrap_or_default()
     }
 
+    /// Returns the local L2 block at which the node has started syncing (i.e., the latest block when the node was started
+    /// or has fallen behind the main node). If the node is synced, returns the current local block.
+    pub fn get_starting_block(&self) -> L2BlockNumber {
+        let inner = self.0.borrow();
+        inner
+            .starting_block
+            .or(inner.local_block)
+            .unwrap_or_default()
+    }
+
     pub async fn wait_for_local_block(&self, want: L2BlockNumber) {
         self.0
             .subscribe()
@@ -135,6 +145,8 @@ impl StateKeeperOutputHandler for SyncState {
 pub(crate) struct SyncStateInner {
     pub(crate) main_node_block: Option<L2BlockNumber>,
     pub(crate) local_block: Option<L2BlockNumber>,
+    /// Local L2 block at which the current syncing process has started. `None` if the node is synced.
+    pub(crate) starting_block: Option<L2BlockNumber>,
 }
 
 impl SyncStateInner {
@@ -146,6 +158,7 @@ impl SyncStateInner {
             }
         }
         self.main_node_block = Some(block);
+        self.update_starting_block();
         self.update_sync_metric();
     }
 
@@ -159,6 +172,7 @@ impl SyncStateInner {
             }
         }
         self.local_block = Some(block);
+        self.update_starting_block();
         self.update_sync_metric();
     }
 }
@@ -187,6 +201,14 @@ impl SyncStateInner {
         }
     }
 
+    fn update_starting_block(&mut self) {
+        if self.is_synced().0 {
+            self.starting_block = None;
+        } else if self.starting_block.is_none() {
+            self.starting_block = self.local_block;
+        }
+    }
+
     fn update_sync_metric(&self) {
         let (is_synced, lag) = self.is_synced();
         EN_METRICS.synced.set(is_synced.into());
@@ -263,6 +285,28 @@ mod tests {
         assert!(!sync_state.is_synced());
     }
 
+    #[test]
+    fn starting_block_is_updated() {
+        let sync_state = SyncState::default();
+        assert_eq!(sync_state.get_starting_block(), L2BlockNumber(0));
+
+        sync_state.set_local_block(L2BlockNumber(5));
+        sync_state.set_main_node_block(L2BlockNumber(100));
+        sync_state.set_local_block(L2BlockNumber(50));
+        assert!(!sync_state.is_synced());
+        assert_eq!(sync_state.get_starting_block(), L2BlockNumber(5));
+
+        sync_state.set_local_block(L2BlockNumber(100));
+        assert!(sync_state.is_synced());
+        assert_eq!(sync_state.get_starting_block(), L2BlockNumber(100));
+
+        // The node falls behind the main node again.
+        sync_state.set_main_node_block(L2BlockNumber(200));
+        sync_state.set_local_block(L2BlockNumber(150));
+        assert!(!sync_state.is_synced());
+        assert_eq!(sync_state.get_starting_block(), L2BlockNumber(100));
+    }
+
     #[test]
     fn test_sync_state_doesnt_panic_on_local_block() {
         let sync_state = SyncState::default();
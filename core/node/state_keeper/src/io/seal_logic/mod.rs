@@ -19,12 +19,13 @@ use zksync_multivm::{
 use zksync_shared_metrics::{BlockStage, L2BlockStage, APP_METRICS};
 use zksync_types::{
     block::{build_bloom, L1BatchHeader, L2BlockHeader},
+    fee::L2BlockFeeStats,
     helpers::unix_timestamp_ms,
     l2_to_l1_log::UserL2ToL1Log,
     tx::IncludedTxLocation,
     utils::display_timestamp,
     Address, BloomInput, ExecuteTransactionCommon, ProtocolVersionId, StorageKey, StorageLog,
-    Transaction, H256,
+    Transaction, H256, U256,
 };
 use zksync_utils::u256_to_h256;
 
@@ -400,6 +401,10 @@ impl L2BlockSealCommand {
             .blocks_dal()
             .insert_l2_block(&l2_block_header)
             .await?;
+        connection
+            .blocks_dal()
+            .insert_l2_block_fee_stats(self.l2_block.number, &self.fee_stats())
+            .await?;
         progress.observe(None);
 
         // Report metrics.
@@ -411,6 +416,32 @@ impl L2BlockSealCommand {
         Ok(())
     }
 
+    /// Computes fee statistics for the L2 block. Priority fees are the ones actually charged, i.e. the difference
+    /// between the effective gas price and the base fee.
+    fn fee_stats(&self) -> L2BlockFeeStats {
+        let base_fee_per_gas = U256::from(self.base_fee_per_gas);
+        let mut stats = L2BlockFeeStats::default();
+        for tx in &self.l2_block.executed_transactions {
+            let gas_used = tx
+                .transaction
+                .gas_limit()
+                .saturating_sub(tx.refunded_gas.into());
+            let gas_used = u64::try_from(gas_used).unwrap_or(u64::MAX);
+            let priority_fee_per_gas = match &tx.transaction.common_data {
+                ExecuteTransactionCommon::L2(data) => Some(
+                    data.fee
+                        .get_effective_gas_price(base_fee_per_gas)
+                        .saturating_sub(base_fee_per_gas),
+                ),
+                ExecuteTransactionCommon::L1(_) | ExecuteTransactionCommon::ProtocolUpgrade(_) => {
+                    None
+                }
+            };
+            stats.push_tx(priority_fee_per_gas, gas_used);
+        }
+        stats
+    }
+
     /// Performs several sanity checks to make sure that the L2 block is valid.
     fn ensure_valid_l2_block(&self, is_fictive: bool) -> anyhow::Result<()> {
         if is_fictive {
@@ -6,12 +6,13 @@ use zksync_dal::{ConnectionPool, Core, CoreDal};
 use zksync_mempool::L2TxFilter;
 use zksync_multivm::{
     interface::{TransactionExecutionMetrics, VmEvent, VmExecutionMetrics},
-    utils::derive_base_fee_and_gas_per_pubdata,
+    utils::{derive_base_fee_and_gas_per_pubdata, get_max_batch_gas_limit},
 };
-use zksync_node_test_utils::prepare_recovery_snapshot;
+use zksync_node_test_utils::{create_l2_transaction, prepare_recovery_snapshot};
 use zksync_types::{
     block::{BlockGasCount, L2BlockHasher},
     commitment::L1BatchCommitmentMode,
+    fee::TxPriorityFee,
     fee_model::{BatchFeeInput, PubdataIndependentBatchFeeModelInput},
     AccountTreeId, Address, L1BatchNumber, L2BlockNumber, L2ChainId, ProtocolVersion,
     ProtocolVersionId, StorageKey, H256, U256,
@@ -402,6 +403,89 @@ async fn processing_events_when_sealing_l2_block() {
     }
 }
 
+#[tokio::test]
+async fn persisting_fee_stats_when_sealing_l2_block() {
+    let pool =
+        ConnectionPool::<Core>::constrained_test_pool(L2BlockSealProcess::subtasks_len()).await;
+    let l2_block_number = L2BlockNumber(3);
+    let mut l2_block = L2BlockUpdates::new(
+        0,
+        l2_block_number,
+        H256::zero(),
+        1,
+        ProtocolVersionId::latest(),
+    );
+
+    let mut expected_gas_used = 0;
+    for (max_priority_fee_per_gas, gas_refunded) in [(5_u64, 1_000), (0, 2_000)] {
+        let mut tx = create_l2_transaction(20, 100);
+        tx.common_data.fee.max_priority_fee_per_gas = max_priority_fee_per_gas.into();
+        expected_gas_used += tx.common_data.fee.gas_limit.as_u64() - gas_refunded;
+        let mut execution_result = create_execution_result([]);
+        execution_result.refunds.gas_refunded = gas_refunded;
+        l2_block.extend_from_executed_transaction(
+            tx.into(),
+            execution_result,
+            BlockGasCount::default(),
+            VmExecutionMetrics::default(),
+            vec![],
+            HashMap::new(),
+            vec![],
+        );
+    }
+
+    let seal_command = L2BlockSealCommand {
+        l1_batch_number: L1BatchNumber(2),
+        l2_block,
+        first_tx_index: 0,
+        fee_account_address: Address::repeat_byte(0x23),
+        fee_input: BatchFeeInput::PubdataIndependent(PubdataIndependentBatchFeeModelInput {
+            l1_gas_price: 100,
+            fair_l2_gas_price: 100,
+            fair_pubdata_price: 100,
+        }),
+        base_fee_per_gas: 10,
+        base_system_contracts_hashes: BaseSystemContractsHashes::default(),
+        protocol_version: Some(ProtocolVersionId::latest()),
+        l2_shared_bridge_addr: Address::default(),
+        pre_insert_txs: false,
+    };
+    pool.connection()
+        .await
+        .unwrap()
+        .protocol_versions_dal()
+        .save_protocol_version_with_tx(&ProtocolVersion::default())
+        .await
+        .unwrap();
+    seal_command.seal(pool.clone()).await.unwrap();
+    let mut conn = pool.connection().await.unwrap();
+
+    // Only the base fee is charged, so the priority fee must be zero regardless of `max_priority_fee_per_gas`.
+    let priority_fees = conn
+        .blocks_web3_dal()
+        .get_priority_fees(l2_block_number..=l2_block_number)
+        .await
+        .unwrap();
+    assert_eq!(
+        priority_fees[&l2_block_number],
+        [TxPriorityFee {
+            priority_fee_per_gas: U256::zero(),
+            gas_used: expected_gas_used,
+        }]
+    );
+
+    let (_, _, gas_used_ratio) = conn
+        .blocks_web3_dal()
+        .get_fee_history(l2_block_number, 1)
+        .await
+        .unwrap();
+    let gas_limit = get_max_batch_gas_limit(ProtocolVersionId::latest().into());
+    assert_eq!(
+        gas_used_ratio,
+        [expected_gas_used as f64 / gas_limit as f64]
+    );
+}
+
 #[test_casing(2, COMMITMENT_MODES)]
 #[tokio::test]
 async fn l2_block_processing_after_snapshot_recovery(commitment_mode: L1BatchCommitmentMode) {
@@ -36,6 +36,7 @@ pub trait ConditionalSealer: 'static + fmt::Debug + Send + Sync {
 
 /// Implementation of [`ConditionalSealer`] used by the main node.
 /// Internally uses a set of [`SealCriterion`]s to determine whether the batch should be sealed.
+/// Besides the default criteria, custom ones can be added using [`Self::with_criterion()`].
 ///
 /// The checks are deterministic, i.e., should depend solely on execution metrics and [`StateKeeperConfig`].
 /// Non-deterministic seal criteria are expressed using [`IoSealCriteria`](super::IoSealCriteria).
@@ -121,6 +122,30 @@ impl SequencerSealer {
         Self { config, sealers }
     }
 
+    /// Adds a custom seal criterion to be checked in addition to the default ones.
+    #[must_use]
+    pub fn with_criterion(mut self, criterion: impl SealCriterion) -> Self {
+        self.sealers.push(Box::new(criterion));
+        self
+    }
+
+    /// Adds multiple custom seal criteria to be checked in addition to the default ones.
+    #[must_use]
+    pub fn with_criteria(
+        mut self,
+        criteria: impl IntoIterator<Item = Box<dyn SealCriterion>>,
+    ) -> Self {
+        self.sealers.extend(criteria);
+        self
+    }
+
+    /// Adds a criterion ensuring that pubdata of each L1 batch fits into a single blob of the data availability layer.
+    /// Transactions which pubdata doesn't fit into a blob on their own are marked as unexecutable.
+    #[must_use]
+    pub fn with_da_blob_size_limit(self, blob_size_limit: usize) -> Self {
+        self.with_criterion(criteria::DABlobSizeCriterion { blob_size_limit })
+    }
+
     #[cfg(test)]
     pub(crate) fn with_sealers(
         config: StateKeeperConfig,
@@ -170,3 +195,52 @@ impl ConditionalSealer for NoopSealer {
         SealResolution::NoSeal
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Criterion sealing the batch once it contains a transaction that has consumed too much gas.
+    #[derive(Debug)]
+    struct MaxTxGasCriterion(u32);
+
+    impl SealCriterion for MaxTxGasCriterion {
+        fn should_seal(
+            &self,
+            _config: &StateKeeperConfig,
+            _block_open_timestamp_ms: u128,
+            _tx_count: usize,
+            _block_data: &SealData,
+            tx_data: &SealData,
+            _protocol_version: ProtocolVersionId,
+        ) -> SealResolution {
+            if tx_data.gas_count().execute > self.0 {
+                SealResolution::IncludeAndSeal
+            } else {
+                SealResolution::NoSeal
+            }
+        }
+
+        fn prom_criterion_name(&self) -> &'static str {
+            "max_tx_gas"
+        }
+    }
+
+    #[test]
+    fn custom_seal_criterion() {
+        let config = StateKeeperConfig::for_tests();
+        let sealer = SequencerSealer::new(config).with_criterion(MaxTxGasCriterion(100));
+        let mut tx_data = SealData {
+            gas_remaining: u32::MAX,
+            ..SealData::default()
+        };
+        let resolution =
+            sealer.should_seal_l1_batch(1, 0, 1, &tx_data, &tx_data, ProtocolVersionId::latest());
+        assert_eq!(resolution, SealResolution::NoSeal);
+
+        tx_data.gas_count.execute = 101;
+        let resolution =
+            sealer.should_seal_l1_batch(1, 0, 1, &tx_data, &tx_data, ProtocolVersionId::latest());
+        assert_eq!(resolution, SealResolution::IncludeAndSeal);
+    }
+}
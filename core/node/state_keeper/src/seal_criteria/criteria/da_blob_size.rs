@@ -0,0 +1,166 @@
+use zksync_multivm::utils::execution_metrics_bootloader_batch_tip_overhead;
+use zksync_types::ProtocolVersionId;
+
+use crate::seal_criteria::{
+    SealCriterion, SealData, SealResolution, StateKeeperConfig, UnexecutableReason,
+};
+
+/// Checks whether the pubdata of an L1 batch fits into a single blob of the data availability layer
+/// the pubdata is dispatched to.
+///
+/// Pubdata size is estimated the same way as in [`PubDataBytesCriterion`](super::PubDataBytesCriterion);
+/// storage diffs are accounted for in their compressed form. Like for other criteria, transactions are rejected
+/// (including by the API server) if their pubdata exceeds `reject_tx_at_eth_params_percentage` of the blob size limit.
+#[derive(Debug)]
+pub struct DABlobSizeCriterion {
+    /// Maximum blob size (in bytes) reported by the DA client.
+    pub blob_size_limit: usize,
+}
+
+impl DABlobSizeCriterion {
+    fn estimate_pubdata_size(data: &SealData, protocol_version: ProtocolVersionId) -> usize {
+        // VMs after the one with virtual blocks report the actual pubdata size; for older VMs,
+        // the size is estimated based on execution and storage write metrics.
+        if data.execution_metrics.pubdata_published == 0 {
+            data.execution_metrics.size() + data.writes_metrics.size(protocol_version)
+        } else {
+            data.execution_metrics.pubdata_published as usize
+        }
+    }
+}
+
+impl SealCriterion for DABlobSizeCriterion {
+    fn should_seal(
+        &self,
+        config: &StateKeeperConfig,
+        _block_open_timestamp_ms: u128,
+        _tx_count: usize,
+        block_data: &SealData,
+        tx_data: &SealData,
+        protocol_version: ProtocolVersionId,
+    ) -> SealResolution {
+        let overhead = execution_metrics_bootloader_batch_tip_overhead(protocol_version.into());
+        let reject_bound = (self.blob_size_limit as f64 * config.reject_tx_at_eth_params_percentage)
+            .round() as usize;
+        let include_and_seal_bound = (self.blob_size_limit as f64
+            * config.close_block_at_eth_params_percentage)
+            .round() as usize;
+
+        let tx_size = Self::estimate_pubdata_size(tx_data, protocol_version) + overhead;
+        let block_size = Self::estimate_pubdata_size(block_data, protocol_version) + overhead;
+        if tx_size > reject_bound {
+            UnexecutableReason::DABlobSizeLimit.into()
+        } else if block_size > self.blob_size_limit {
+            SealResolution::ExcludeAndSeal
+        } else if block_size > include_and_seal_bound {
+            SealResolution::IncludeAndSeal
+        } else {
+            SealResolution::NoSeal
+        }
+    }
+
+    fn prom_criterion_name(&self) -> &'static str {
+        "da_blob_size"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_multivm::interface::VmExecutionMetrics;
+
+    use super::*;
+
+    fn seal_data(pubdata_published: u32) -> SealData {
+        SealData {
+            execution_metrics: VmExecutionMetrics {
+                pubdata_published,
+                ..VmExecutionMetrics::default()
+            },
+            ..SealData::default()
+        }
+    }
+
+    #[test]
+    fn seal_criterion() {
+        let config = StateKeeperConfig {
+            reject_tx_at_eth_params_percentage: 0.95,
+            close_block_at_eth_params_percentage: 0.9,
+            ..Default::default()
+        };
+        let criterion = DABlobSizeCriterion {
+            blob_size_limit: 100_000,
+        };
+        let protocol_version = ProtocolVersionId::latest();
+        let overhead = execution_metrics_bootloader_batch_tip_overhead(protocol_version.into());
+        let tx_data = seal_data(1_000);
+
+        let resolution = criterion.should_seal(
+            &config,
+            0,
+            1,
+            &seal_data(80_000),
+            &tx_data,
+            protocol_version,
+        );
+        assert_eq!(resolution, SealResolution::NoSeal);
+
+        let resolution = criterion.should_seal(
+            &config,
+            0,
+            1,
+            &seal_data(90_000),
+            &tx_data,
+            protocol_version,
+        );
+        assert_eq!(resolution, SealResolution::IncludeAndSeal);
+
+        let block_size = (100_000 - overhead + 1) as u32;
+        let resolution = criterion.should_seal(
+            &config,
+            0,
+            1,
+            &seal_data(block_size),
+            &tx_data,
+            protocol_version,
+        );
+        assert_eq!(resolution, SealResolution::ExcludeAndSeal);
+
+        let resolution = criterion.should_seal(
+            &config,
+            0,
+            1,
+            &seal_data(block_size),
+            &seal_data(block_size),
+            protocol_version,
+        );
+        assert_eq!(
+            resolution,
+            SealResolution::Unexecutable(UnexecutableReason::DABlobSizeLimit)
+        );
+
+        // Transactions are rejected once they exceed the reject bound, even if they'd fit into a blob.
+        let tx_size = (95_000 - overhead + 1) as u32;
+        let resolution = criterion.should_seal(
+            &config,
+            0,
+            1,
+            &seal_data(tx_size),
+            &seal_data(tx_size),
+            protocol_version,
+        );
+        assert_eq!(
+            resolution,
+            SealResolution::Unexecutable(UnexecutableReason::DABlobSizeLimit)
+        );
+        let tx_size = (95_000 - overhead) as u32;
+        let resolution = criterion.should_seal(
+            &config,
+            0,
+            1,
+            &seal_data(tx_size),
+            &seal_data(tx_size),
+            protocol_version,
+        );
+        assert_eq!(resolution, SealResolution::IncludeAndSeal);
+    }
+}
@@ -1,3 +1,4 @@
+mod da_blob_size;
 mod gas;
 mod gas_for_batch_tip;
 mod geometry_seal_criteria;
@@ -6,7 +7,8 @@ mod slots;
 mod tx_encoding_size;
 
 pub(crate) use self::{
-    gas::GasCriterion, gas_for_batch_tip::GasForBatchTipCriterion,
-    geometry_seal_criteria::CircuitsCriterion, pubdata_bytes::PubDataBytesCriterion,
-    slots::SlotsCriterion, tx_encoding_size::TxEncodingSizeCriterion,
+    da_blob_size::DABlobSizeCriterion, gas::GasCriterion,
+    gas_for_batch_tip::GasForBatchTipCriterion, geometry_seal_criteria::CircuitsCriterion,
+    pubdata_bytes::PubDataBytesCriterion, slots::SlotsCriterion,
+    tx_encoding_size::TxEncodingSizeCriterion,
 };
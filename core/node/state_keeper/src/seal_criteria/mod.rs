@@ -63,6 +63,7 @@ pub enum UnexecutableReason {
     TxEncodingSize,
     LargeEncodingSize,
     PubdataLimit,
+    DABlobSizeLimit,
     ProofWillFail,
     TooMuchGas,
     OutOfGasForBatchTip,
@@ -77,6 +78,7 @@ impl UnexecutableReason {
             UnexecutableReason::TxEncodingSize => "TxEncodingSize",
             UnexecutableReason::LargeEncodingSize => "LargeEncodingSize",
             UnexecutableReason::PubdataLimit => "PubdataLimit",
+            UnexecutableReason::DABlobSizeLimit => "DABlobSizeLimit",
             UnexecutableReason::ProofWillFail => "ProofWillFail",
             UnexecutableReason::TooMuchGas => "TooMuchGas",
             UnexecutableReason::OutOfGasForBatchTip => "OutOfGasForBatchTip",
@@ -101,6 +103,9 @@ impl fmt::Display for UnexecutableReason {
                 write!(f, "Transaction encoding size is too big")
             }
             UnexecutableReason::PubdataLimit => write!(f, "Pubdata limit reached"),
+            UnexecutableReason::DABlobSizeLimit => {
+                write!(f, "Pubdata doesn't fit into a data availability blob")
+            }
             UnexecutableReason::ProofWillFail => write!(f, "Proof will fail"),
             UnexecutableReason::TooMuchGas => write!(f, "Too much gas"),
             UnexecutableReason::OutOfGasForBatchTip => write!(f, "Out of gas for batch tip"),
@@ -185,9 +190,40 @@ impl SealData {
             gas_remaining: tx_metrics.gas_remaining,
         }
     }
+
+    /// Returns VM execution metrics.
+    pub fn execution_metrics(&self) -> &VmExecutionMetrics {
+        &self.execution_metrics
+    }
+
+    /// Returns the gas count.
+    pub fn gas_count(&self) -> BlockGasCount {
+        self.gas_count
+    }
+
+    /// Returns the cumulative bootloader encoding size of transactions.
+    pub fn cumulative_size(&self) -> usize {
+        self.cumulative_size
+    }
+
+    /// Returns metrics for deduplicated storage writes.
+    pub fn writes_metrics(&self) -> &DeduplicatedWritesMetrics {
+        &self.writes_metrics
+    }
+
+    /// Returns the amount of gas remaining after execution.
+    pub fn gas_remaining(&self) -> u32 {
+        self.gas_remaining
+    }
 }
 
-pub(super) trait SealCriterion: fmt::Debug + Send + Sync + 'static {
+/// Deterministic criterion deciding whether an L1 batch should be sealed after executing a transaction.
+///
+/// Besides the built-in criteria, custom criteria (e.g., a per-contract gas quota) can be registered
+/// with [`SequencerSealer::with_criterion()`].
+pub trait SealCriterion: fmt::Debug + Send + Sync + 'static {
+    /// Determines the seal resolution for the L1 batch with the specified data after executing
+    /// a transaction with `tx_data`. `block_data` includes the data of the executed transaction.
     fn should_seal(
         &self,
         config: &StateKeeperConfig,
@@ -198,6 +234,7 @@ pub(super) trait SealCriterion: fmt::Debug + Send + Sync + 'static {
         protocol_version: ProtocolVersionId,
     ) -> SealResolution;
 
+    /// Returns the criterion name used in logs and metrics.
     // We need self here only for rust restrictions for creating an object from trait
     // https://doc.rust-lang.org/reference/items/traits.html#object-safety
     fn prom_criterion_name(&self) -> &'static str;
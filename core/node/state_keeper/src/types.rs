@@ -3,8 +3,9 @@ use std::{
     sync::{Arc, Mutex},
 };
 
+use zksync_config::configs::chain;
 use zksync_dal::{Connection, Core, CoreDal};
-use zksync_mempool::{L2TxFilter, MempoolInfo, MempoolStore};
+use zksync_mempool::{L2TxFilter, MempoolInfo, MempoolOrdering, MempoolStore};
 use zksync_multivm::interface::{VmExecutionMetrics, VmExecutionResultAndLogs};
 use zksync_types::{block::BlockGasCount, Address, Nonce, PriorityOpId, Transaction};
 
@@ -17,16 +18,33 @@ use super::{
 pub struct MempoolGuard(Arc<Mutex<MempoolStore>>);
 
 impl MempoolGuard {
-    pub async fn from_storage(storage_processor: &mut Connection<'_, Core>, capacity: u64) -> Self {
+    pub async fn from_storage(
+        storage_processor: &mut Connection<'_, Core>,
+        capacity: u64,
+        ordering: chain::MempoolOrdering,
+    ) -> Self {
         let next_priority_id = storage_processor
             .transactions_dal()
             .next_priority_id()
             .await;
-        Self::new(next_priority_id, capacity)
+        let ordering = match ordering {
+            chain::MempoolOrdering::Fifo => MempoolOrdering::Fifo,
+            chain::MempoolOrdering::PriorityFee => MempoolOrdering::PriorityFee,
+        };
+        Self::with_ordering(next_priority_id, capacity, ordering)
     }
 
+    #[cfg(test)]
     pub(super) fn new(next_priority_id: PriorityOpId, capacity: u64) -> Self {
-        let store = MempoolStore::new(next_priority_id, capacity);
+        Self::with_ordering(next_priority_id, capacity, MempoolOrdering::default())
+    }
+
+    fn with_ordering(
+        next_priority_id: PriorityOpId,
+        capacity: u64,
+        ordering: MempoolOrdering,
+    ) -> Self {
+        let store = MempoolStore::with_ordering(next_priority_id, capacity, ordering);
         Self(Arc::new(Mutex::new(store)))
     }
 
@@ -36,6 +36,7 @@ pub(crate) fn create_gcs_bucket(
         },
         max_retries: PROVER_STORE_MAX_RETRIES,
         local_mirror_path: None,
+        zstd_compression_level: None,
     })
 }
 
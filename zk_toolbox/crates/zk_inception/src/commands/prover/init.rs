@@ -122,6 +122,7 @@ fn get_object_store_config(
             },
             max_retries: PROVER_STORE_MAX_RETRIES,
             local_mirror_path: None,
+            zstd_compression_level: None,
         }),
         Some(ProofStorageConfig::GCSCreateBucket(config)) => {
             Some(create_gcs_bucket(shell, config)?)
@@ -177,6 +178,7 @@ fn init_file_backed_proof_storage(
         },
         max_retries: PROVER_STORE_MAX_RETRIES,
         local_mirror_path: None,
+        zstd_compression_level: None,
     };
 
     Ok(object_store_config)